@@ -1,7 +1,9 @@
 use anyhow::Result;
 use crossterm::{
+    cursor::MoveToColumn,
     event::{self, Event, KeyCode},
-    terminal::{disable_raw_mode, enable_raw_mode},
+    queue,
+    terminal::{Clear, ClearType, disable_raw_mode, enable_raw_mode},
 };
 use std::io::{IsTerminal, Write};
 use std::thread;
@@ -31,6 +33,7 @@ pub fn load_transcription_config_with_language(
 
     // Handle different provider types:
     // - Cloud providers: require API key
+    // - Wyoming: requires server address
     // - LocalWhisper: requires model path
     let api_key = match &provider {
         TranscriptionProvider::LocalWhisper => {
@@ -67,6 +70,21 @@ pub fn load_transcription_config_with_language(
                 }
             }
         }
+        TranscriptionProvider::Wyoming | TranscriptionProvider::WyomingRealtime => {
+            // Wyoming: use server address
+            match settings.transcription.api_key_for(&provider) {
+                Some(url) => url,
+                None => {
+                    eprintln!("Error: No Wyoming server configured.");
+                    eprintln!("(Required for {} transcription)", provider.display_name());
+                    eprintln!("\nSet the server address with:");
+                    eprintln!("  whis config wyoming-url tcp://homeassistant.local:10300\n");
+                    eprintln!("Or set the WYOMING_URL environment variable.");
+                    eprintln!("\nTip: Run 'whis setup local' for guided setup.");
+                    std::process::exit(1);
+                }
+            }
+        }
         _ => {
             // Cloud providers: require API key
             match settings.transcription.api_key_for(&provider) {
@@ -179,6 +197,32 @@ pub fn print_status(message: &str, provider: Option<&TranscriptionProvider>) {
     }
 }
 
+/// Rewrite the current line as the status followed by a live partial transcript
+///
+/// Only the tail of the partial that fits the terminal width is shown, so the
+/// latest words stay visible. An empty partial restores the bare status.
+pub fn print_live_status(status: &str, partial: &str) {
+    let width = crossterm::terminal::size().map_or(80, |(cols, _)| cols as usize);
+    let available = width.saturating_sub(status.chars().count() + 2);
+    let partial = partial.replace('\n', " ");
+    let count = partial.chars().count();
+    let tail = if count > available {
+        let skip = count + 1 - available;
+        format!("…{}", partial.chars().skip(skip).collect::<String>())
+    } else {
+        partial
+    };
+
+    let mut stdout = std::io::stdout();
+    let _ = queue!(stdout, MoveToColumn(0), Clear(ClearType::CurrentLine));
+    if tail.is_empty() {
+        let _ = write!(stdout, "{status}");
+    } else {
+        let _ = write!(stdout, "{status} {tail}");
+    }
+    stdout.flush().ok();
+}
+
 /// Check if provider is a real-time streaming variant
 ///
 /// Real-time providers are so fast (~150ms) that the typewriter animation (475ms for
@@ -187,6 +231,11 @@ pub fn print_status(message: &str, provider: Option<&TranscriptionProvider>) {
 fn is_realtime_provider(provider: Option<&TranscriptionProvider>) -> bool {
     matches!(
         provider,
-        Some(TranscriptionProvider::OpenAIRealtime) | Some(TranscriptionProvider::DeepgramRealtime)
+        Some(TranscriptionProvider::OpenAIRealtime)
+            | Some(TranscriptionProvider::DeepgramRealtime)
+            | Some(TranscriptionProvider::AssemblyAIRealtime)
+            | Some(TranscriptionProvider::SpeechmaticsRealtime)
+            | Some(TranscriptionProvider::GladiaRealtime)
+            | Some(TranscriptionProvider::WyomingRealtime)
     )
 }
@@ -1,5 +1,6 @@
 use clap::{Args, Parser, Subcommand, ValueHint};
 use std::time::Duration;
+use whis_core::preset::ConflictPolicy;
 
 /// Parse a duration string like "10s", "30s", "1m", "90"
 fn parse_duration(s: &str) -> Result<Duration, String> {
@@ -170,11 +171,23 @@ pub enum Commands {
         action: Option<PresetAction>,
     },
 
+    /// Manage the replacement dictionary ("whiz" -> "whis")
+    Dict {
+        #[command(subcommand)]
+        action: Option<DictAction>,
+    },
+
     /// List available models (whisper, parakeet, ollama)
     Model {
         #[command(subcommand)]
         action: Option<ModelAction>,
     },
+
+    /// Run the background service at login (systemd user unit or XDG autostart)
+    Service {
+        #[command(subcommand)]
+        action: ServiceAction,
+    },
 }
 
 #[derive(Subcommand)]
@@ -209,6 +222,100 @@ pub enum PresetAction {
         #[arg(value_hint = ValueHint::Other)]
         name: String,
     },
+
+    /// Print presets as a shareable JSON bundle (whis preset export a b > bundle.json)
+    Export {
+        /// Names of the presets to export
+        #[arg(required = true, value_hint = ValueHint::Other)]
+        names: Vec<String>,
+    },
+
+    /// Import presets from a bundle file ("-" reads stdin)
+    Import {
+        /// Bundle file created by `whis preset export`
+        #[arg(value_hint = ValueHint::FilePath)]
+        file: String,
+
+        /// What to do when a preset name is already taken: rename (import
+        /// as name-2, name-3, ...), overwrite or skip
+        #[arg(long, default_value_t = ConflictPolicy::Skip, value_name = "POLICY")]
+        on_conflict: ConflictPolicy,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DictAction {
+    /// List dictionary entries (default)
+    List {
+        /// List a preset's own entries instead of the user dictionary
+        #[arg(long, value_hint = ValueHint::Other)]
+        preset: Option<String>,
+    },
+
+    /// Add or update an entry
+    Add {
+        /// Text (or regex pattern with --regex) to replace
+        #[arg(value_hint = ValueHint::Other)]
+        from: String,
+
+        /// Replacement text (regex entries may use $1 or ${name})
+        #[arg(value_hint = ValueHint::Other)]
+        to: String,
+
+        /// Treat FROM as a regular expression
+        #[arg(long)]
+        regex: bool,
+
+        /// Only match whole words
+        #[arg(short, long)]
+        whole_word: bool,
+
+        /// Match any case and keep the matched text's case
+        #[arg(short = 'c', long)]
+        preserve_case: bool,
+
+        /// Add to a preset's dictionary instead of the user dictionary
+        #[arg(long, value_hint = ValueHint::Other)]
+        preset: Option<String>,
+    },
+
+    /// Remove an entry by its FROM text
+    Remove {
+        /// Text (or pattern) of the entry to remove
+        #[arg(value_hint = ValueHint::Other)]
+        from: String,
+
+        /// Remove from a preset's dictionary instead of the user dictionary
+        #[arg(long, value_hint = ValueHint::Other)]
+        preset: Option<String>,
+    },
+
+    /// Show what the dictionary does to some text
+    Test {
+        /// Text to run through the dictionary
+        #[arg(required = true, value_hint = ValueHint::Other)]
+        text: Vec<String>,
+
+        /// Also apply a preset's entries
+        #[arg(long, value_hint = ValueHint::Other)]
+        preset: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ServiceAction {
+    /// Install and enable the service for the current user
+    Install {
+        /// Use an XDG autostart entry even if systemd is available
+        #[arg(long)]
+        autostart: bool,
+    },
+
+    /// Disable and remove the installed service
+    Uninstall,
+
+    /// Show how the service is installed and whether it is running
+    Status,
 }
 
 #[derive(Subcommand)]
@@ -218,6 +325,79 @@ pub enum ModelAction {
         #[command(subcommand)]
         model_type: Option<ModelType>,
     },
+
+    /// Download a local transcription model
+    Download {
+        /// Model engine
+        engine: LocalEngine,
+
+        /// Model name (default: recommended model for the engine)
+        #[arg(value_hint = ValueHint::Other)]
+        name: Option<String>,
+    },
+
+    /// Delete a downloaded model
+    Remove {
+        /// Model engine
+        engine: LocalEngine,
+
+        /// Model name
+        #[arg(value_hint = ValueHint::Other)]
+        name: String,
+    },
+
+    /// Check that downloaded models are complete
+    Verify {
+        /// Model engine (default: all engines)
+        engine: Option<LocalEngine>,
+
+        /// Model name (default: all installed models)
+        #[arg(value_hint = ValueHint::Other)]
+        name: Option<String>,
+    },
+
+    /// Import a model file or directory without downloading (offline install)
+    Import {
+        /// Whisper .bin/.gguf file or Parakeet model directory
+        #[arg(value_hint = ValueHint::AnyPath)]
+        path: std::path::PathBuf,
+
+        /// Name to select the model by (default: derived from the file name)
+        #[arg(long, value_hint = ValueHint::Other)]
+        name: Option<String>,
+
+        /// Copy the model into the models directory (always done for catalog
+        /// models like ggml-small.bin unless --symlink is given)
+        #[arg(long, conflicts_with = "symlink")]
+        copy: bool,
+
+        /// Symlink the model into the models directory
+        #[arg(long)]
+        symlink: bool,
+
+        /// Switch transcription to the imported model
+        #[arg(long = "use")]
+        use_model: bool,
+    },
+
+    /// Switch transcription to a downloaded or imported model
+    Use {
+        /// Model engine
+        engine: LocalEngine,
+
+        /// Model name
+        #[arg(value_hint = ValueHint::Other)]
+        name: String,
+    },
+}
+
+/// Local transcription engine
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum LocalEngine {
+    /// whisper.cpp GGML models
+    Whisper,
+    /// NVIDIA Parakeet ONNX models
+    Parakeet,
 }
 
 #[derive(Subcommand)]
@@ -1,6 +1,9 @@
 use anyhow::{Context, Result, anyhow};
 use whis_core::defaults::{DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL};
-use whis_core::settings::CliShortcutMode;
+use whis_core::model::{ModelType, ParakeetModel, WhisperModel};
+use whis_core::settings::{
+    AnthropicConfig, AssemblyAIConfig, CliShortcutMode, DataRegion, WhisperTuning,
+};
 use whis_core::{PostProcessor, Preset, Settings, TranscriptionProvider};
 
 use crate::ui::mask_key;
@@ -14,10 +17,34 @@ const VALID_KEYS: &[&str] = &[
     "groq-api-key",
     "deepgram-api-key",
     "elevenlabs-api-key",
+    "assemblyai-api-key",
+    "assemblyai-speaker-labels",
+    "assemblyai-keyterms",
+    "speechmatics-api-key",
+    "speechmatics-region",
+    "gladia-api-key",
+    "gladia-region",
+    "wyoming-url",
     "whisper-model-path",
     "parakeet-model-path",
+    "whisper-threads",
+    "whisper-beam-size",
+    "whisper-temperature",
+    "whisper-temperature-increment",
+    "whisper-no-speech-threshold",
+    "whisper-suppress-blank",
+    "local-streaming",
     "post-processor",
     "post-processing-prompt",
+    "voice-commands",
+    "anthropic-api-key",
+    "anthropic-model",
+    "anthropic-max-tokens",
+    "anthropic-temperature",
+    "custom-llm-url",
+    "custom-llm-model",
+    "custom-llm-api-key",
+    "custom-llm-params",
     "ollama-url",
     "ollama-model",
     "microphone-device",
@@ -28,6 +55,9 @@ const VALID_KEYS: &[&str] = &[
     "vad",
     "vad-threshold",
     "chunk-size",
+    "http-api",
+    "http-api-port",
+    "http-api-token",
 ];
 
 pub fn run(key: Option<String>, value: Option<String>, list: bool, path: bool) -> Result<()> {
@@ -140,11 +170,78 @@ fn set_config(key: &str, value: &str) -> Result<()> {
             );
             println!("elevenlabs-api-key = {}", mask_key(value_trimmed));
         }
+        "assemblyai-api-key" => {
+            validate_api_key(value_trimmed, "AssemblyAI")?;
+            settings.transcription.set_api_key(
+                &TranscriptionProvider::AssemblyAI,
+                value_trimmed.to_string(),
+            );
+            println!("assemblyai-api-key = {}", mask_key(value_trimmed));
+        }
+        "speechmatics-api-key" => {
+            validate_api_key(value_trimmed, "Speechmatics")?;
+            settings.transcription.set_api_key(
+                &TranscriptionProvider::Speechmatics,
+                value_trimmed.to_string(),
+            );
+            println!("speechmatics-api-key = {}", mask_key(value_trimmed));
+        }
+        "gladia-api-key" => {
+            validate_api_key(value_trimmed, "Gladia")?;
+            settings
+                .transcription
+                .set_api_key(&TranscriptionProvider::Gladia, value_trimmed.to_string());
+            println!("gladia-api-key = {}", mask_key(value_trimmed));
+        }
+        "speechmatics-region" | "gladia-region" => {
+            let region: DataRegion = value_trimmed.parse().map_err(|e: String| anyhow!(e))?;
+            if key == "speechmatics-region" {
+                settings.transcription.regions.speechmatics = region;
+            } else {
+                settings.transcription.regions.gladia = region;
+            }
+            println!("{} = {}", key, region);
+        }
+        "wyoming-url" => {
+            if value_trimmed.is_empty() {
+                anyhow::bail!("Invalid Wyoming URL: cannot be empty");
+            }
+            settings.transcription.wyoming.url = Some(value_trimmed.to_string());
+            println!("wyoming-url = {}", value_trimmed);
+        }
+        "assemblyai-speaker-labels" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.transcription.assemblyai.speaker_labels = enabled;
+            println!("assemblyai-speaker-labels = {}", enabled);
+        }
+        "assemblyai-keyterms" => {
+            // Comma-separated; an empty value clears the list
+            let keyterms: Vec<String> = value_trimmed
+                .split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect();
+            let updated = AssemblyAIConfig {
+                keyterms,
+                ..settings.transcription.assemblyai.clone()
+            };
+            updated.validate()?;
+            settings.transcription.assemblyai = updated;
+            println!(
+                "assemblyai-keyterms = {}",
+                keyterms_value(&settings.transcription.assemblyai)
+            );
+        }
         "whisper-model-path" => {
             if value_trimmed.is_empty() {
                 anyhow::bail!("Invalid whisper model path: cannot be empty");
             }
             let expanded_path = expand_home_dir(value_trimmed);
+            WhisperModel
+                .validate(std::path::Path::new(&expanded_path))
+                .map_err(|e| anyhow!("{e}\nImport models with: whis model import <path>"))?;
             settings.transcription.local_models.whisper_path = Some(expanded_path.clone());
             println!("whisper-model-path = {}", expanded_path);
         }
@@ -153,9 +250,36 @@ fn set_config(key: &str, value: &str) -> Result<()> {
                 anyhow::bail!("Invalid parakeet model path: cannot be empty");
             }
             let expanded_path = expand_home_dir(value_trimmed);
+            ParakeetModel
+                .validate(std::path::Path::new(&expanded_path))
+                .map_err(|e| anyhow!("{e}\nImport models with: whis model import <path>"))?;
             settings.transcription.local_models.parakeet_path = Some(expanded_path.clone());
             println!("parakeet-model-path = {}", expanded_path);
         }
+        "local-streaming" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.transcription.local_models.streaming = enabled;
+            println!("local-streaming = {}", enabled);
+        }
+        "whisper-threads"
+        | "whisper-beam-size"
+        | "whisper-temperature"
+        | "whisper-temperature-increment"
+        | "whisper-no-speech-threshold"
+        | "whisper-suppress-blank" => {
+            set_whisper_tuning(
+                &mut settings.transcription.local_models.whisper,
+                key,
+                value_trimmed,
+            )?;
+            println!(
+                "{} = {}",
+                key,
+                whisper_tuning_value(&settings.transcription.local_models.whisper, key)
+            );
+        }
         "post-processor" => {
             let processor = value_trimmed
                 .parse::<PostProcessor>()
@@ -173,6 +297,96 @@ fn set_config(key: &str, value: &str) -> Result<()> {
                 truncate_prompt(value_trimmed)
             );
         }
+        "voice-commands" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.post_processing.voice_commands = enabled;
+            println!("voice-commands = {}", enabled);
+        }
+        "anthropic-api-key" => {
+            validate_api_key(value_trimmed, "Anthropic")?;
+            settings
+                .transcription
+                .api_keys
+                .insert("anthropic".to_string(), value_trimmed.to_string());
+            println!("anthropic-api-key = {}", mask_key(value_trimmed));
+        }
+        "anthropic-model" | "anthropic-max-tokens" | "anthropic-temperature" => {
+            // "default" clears the value
+            let clear = value_trimmed.eq_ignore_ascii_case("default");
+            let mut updated = settings.post_processing.anthropic.clone();
+            match key {
+                "anthropic-model" if clear => updated.model = None,
+                "anthropic-model" => {
+                    if value_trimmed.is_empty() {
+                        anyhow::bail!("Invalid Anthropic model: cannot be empty");
+                    }
+                    updated.model = Some(value_trimmed.to_string());
+                }
+                "anthropic-max-tokens" if clear => updated.max_tokens = None,
+                "anthropic-max-tokens" => {
+                    updated.max_tokens = Some(
+                        value_trimmed
+                            .parse()
+                            .context("Invalid value. Use a token count, e.g. 4096")?,
+                    );
+                }
+                _ if clear => updated.temperature = None,
+                _ => {
+                    updated.temperature = Some(
+                        value_trimmed
+                            .parse()
+                            .context("Invalid value. Use a number between 0.0 and 1.0")?,
+                    );
+                }
+            }
+            updated.validate()?;
+            settings.post_processing.anthropic = updated;
+            println!(
+                "{} = {}",
+                key,
+                anthropic_value(&settings.post_processing.anthropic, key)
+            );
+        }
+        "custom-llm-url" => {
+            if value_trimmed.is_empty() {
+                anyhow::bail!("Invalid custom LLM URL: cannot be empty");
+            }
+            if !value_trimmed.starts_with("http://") && !value_trimmed.starts_with("https://") {
+                anyhow::bail!("Invalid custom LLM URL: must start with http:// or https://");
+            }
+            settings.post_processing.custom.base_url = Some(value_trimmed.to_string());
+            println!("custom-llm-url = {}", value_trimmed);
+        }
+        "custom-llm-model" => {
+            if value_trimmed.is_empty() {
+                anyhow::bail!("Invalid custom LLM model: cannot be empty");
+            }
+            settings.post_processing.custom.model = Some(value_trimmed.to_string());
+            println!("custom-llm-model = {}", value_trimmed);
+        }
+        "custom-llm-api-key" => {
+            // "none" or an empty value clears the key (servers without auth)
+            if value_trimmed.is_empty() || value_trimmed.eq_ignore_ascii_case("none") {
+                settings.post_processing.custom.api_key = None;
+                println!("custom-llm-api-key = (none)");
+            } else {
+                settings.post_processing.custom.api_key = Some(value_trimmed.to_string());
+                println!("custom-llm-api-key = {}", mask_key(value_trimmed));
+            }
+        }
+        "custom-llm-params" => {
+            // JSON object merged into each request; "{}" clears it
+            let params: serde_json::Map<String, serde_json::Value> =
+                serde_json::from_str(value_trimmed)
+                    .context("Invalid value. Use a JSON object, e.g. '{\"temperature\": 0.2}'")?;
+            settings.post_processing.custom.params = params;
+            println!(
+                "custom-llm-params = {}",
+                custom_params_value(&settings.post_processing.custom.params)
+            );
+        }
         "ollama-url" => {
             if value_trimmed.is_empty() {
                 anyhow::bail!("Invalid Ollama URL: cannot be empty");
@@ -257,6 +471,46 @@ fn set_config(key: &str, value: &str) -> Result<()> {
             settings.shortcuts.cli_push_to_talk = enabled;
             println!("cli-push-to-talk = {}", enabled);
         }
+        "http-api" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.services.http_api.enabled = enabled;
+            println!("http-api = {}", enabled);
+            if enabled {
+                // Clients need a token, generate one on first enable
+                let had_token = settings.services.http_api.token().is_some();
+                let token = settings.services.http_api.ensure_token()?.to_string();
+                if !had_token {
+                    println!("http-api-token = {}", token);
+                }
+            }
+        }
+        "http-api-port" => {
+            let port = value_trimmed
+                .parse::<u16>()
+                .context("Invalid port. Use a number between 1024 and 65535")?;
+            if port < 1024 {
+                anyhow::bail!("Invalid port: must be between 1024 and 65535");
+            }
+            settings.services.http_api.port = port;
+            println!("http-api-port = {}", port);
+        }
+        "http-api-token" => {
+            if value_trimmed.to_lowercase() == "generate" {
+                settings.services.http_api.token = None;
+                let token = settings.services.http_api.ensure_token()?.to_string();
+                println!("http-api-token = {}", token);
+            } else {
+                if value_trimmed.len() < 16 {
+                    anyhow::bail!(
+                        "Invalid HTTP API token: use at least 16 characters (or 'generate')"
+                    );
+                }
+                settings.services.http_api.token = Some(value_trimmed.to_string());
+                println!("http-api-token = {}", mask_key(value_trimmed));
+            }
+        }
         _ => unreachable!("Key validation should prevent this"),
     }
 
@@ -278,6 +532,22 @@ fn get_config(key: &str) -> Result<()> {
         "groq-api-key" => print_api_key(&settings, &TranscriptionProvider::Groq),
         "deepgram-api-key" => print_api_key(&settings, &TranscriptionProvider::Deepgram),
         "elevenlabs-api-key" => print_api_key(&settings, &TranscriptionProvider::ElevenLabs),
+        "assemblyai-api-key" => print_api_key(&settings, &TranscriptionProvider::AssemblyAI),
+        "speechmatics-api-key" => print_api_key(&settings, &TranscriptionProvider::Speechmatics),
+        "gladia-api-key" => print_api_key(&settings, &TranscriptionProvider::Gladia),
+        "speechmatics-region" => println!("{}", settings.transcription.regions.speechmatics),
+        "gladia-region" => println!("{}", settings.transcription.regions.gladia),
+        "wyoming-url" => {
+            if let Some(url) = &settings.transcription.wyoming.url {
+                println!("{}", url);
+            } else {
+                println!("(not set, using $WYOMING_URL)");
+            }
+        }
+        "assemblyai-speaker-labels" => {
+            println!("{}", settings.transcription.assemblyai.speaker_labels)
+        }
+        "assemblyai-keyterms" => println!("{}", keyterms_value(&settings.transcription.assemblyai)),
         "whisper-model-path" => {
             if let Some(path) = &settings.transcription.local_models.whisper_path {
                 println!("{}", path);
@@ -292,6 +562,16 @@ fn get_config(key: &str) -> Result<()> {
                 println!("(not set, using $LOCAL_PARAKEET_MODEL_PATH)");
             }
         }
+        "local-streaming" => println!("{}", settings.transcription.local_models.streaming),
+        "whisper-threads"
+        | "whisper-beam-size"
+        | "whisper-temperature"
+        | "whisper-temperature-increment"
+        | "whisper-no-speech-threshold"
+        | "whisper-suppress-blank" => println!(
+            "{}",
+            whisper_tuning_value(&settings.transcription.local_models.whisper, key)
+        ),
         "post-processor" => println!("{}", settings.post_processing.processor),
         "post-processing-prompt" => {
             if let Some(prompt) = &settings.post_processing.prompt {
@@ -300,6 +580,30 @@ fn get_config(key: &str) -> Result<()> {
                 println!("(default)");
             }
         }
+        "voice-commands" => println!("{}", settings.post_processing.voice_commands),
+        "anthropic-api-key" => match anthropic_api_key(&settings) {
+            Some(key) => println!("{}", mask_key(&key)),
+            None => println!("(not set, using $ANTHROPIC_API_KEY)"),
+        },
+        "anthropic-model" | "anthropic-max-tokens" | "anthropic-temperature" => println!(
+            "{}",
+            anthropic_value(&settings.post_processing.anthropic, key)
+        ),
+        "custom-llm-url" => println!(
+            "{}",
+            optional_value(&settings.post_processing.custom.base_url)
+        ),
+        "custom-llm-model" => {
+            println!("{}", optional_value(&settings.post_processing.custom.model))
+        }
+        "custom-llm-api-key" => match &settings.post_processing.custom.api_key {
+            Some(key) => println!("{}", mask_key(key)),
+            None => println!("(none)"),
+        },
+        "custom-llm-params" => println!(
+            "{}",
+            custom_params_value(&settings.post_processing.custom.params)
+        ),
         "ollama-url" => {
             if let Some(url) = &settings.services.ollama.url {
                 println!("{}", url);
@@ -328,6 +632,12 @@ fn get_config(key: &str) -> Result<()> {
         "cli-key" => println!("{}", settings.shortcuts.cli_key),
         "cli-push-to-talk" => println!("{}", settings.shortcuts.cli_push_to_talk),
         "desktop-key" => println!("{}", settings.shortcuts.desktop_key),
+        "http-api" => println!("{}", settings.services.http_api.enabled),
+        "http-api-port" => println!("{}", settings.services.http_api.port),
+        "http-api-token" => match settings.services.http_api.token() {
+            Some(token) => println!("{}", token),
+            None => println!("(not set)"),
+        },
         _ => unreachable!("Key validation should prevent this"),
     }
 
@@ -355,7 +665,10 @@ fn show_all_settings() -> Result<()> {
         settings.transcription.language.as_deref().unwrap_or("auto")
     );
 
-    for provider in TranscriptionProvider::all() {
+    for provider in TranscriptionProvider::all()
+        .iter()
+        .filter(|p| p.requires_api_key())
+    {
         let key_name = format!(
             "{}-api-key",
             provider.to_string().to_lowercase().replace('_', "-")
@@ -372,6 +685,24 @@ fn show_all_settings() -> Result<()> {
         };
         println!("{} = {}", key_name, key_status);
     }
+    println!(
+        "assemblyai-speaker-labels = {}",
+        settings.transcription.assemblyai.speaker_labels
+    );
+    println!(
+        "assemblyai-keyterms = {}",
+        keyterms_value(&settings.transcription.assemblyai)
+    );
+    println!(
+        "speechmatics-region = {}",
+        settings.transcription.regions.speechmatics
+    );
+    println!("gladia-region = {}", settings.transcription.regions.gladia);
+    if let Some(url) = &settings.transcription.wyoming.url {
+        println!("wyoming-url = {}", url);
+    } else {
+        println!("wyoming-url = (not set, using $WYOMING_URL)");
+    }
 
     println!();
     println!("[Local Models]");
@@ -386,6 +717,17 @@ fn show_all_settings() -> Result<()> {
     } else {
         println!("parakeet-model-path = (not set, using $LOCAL_PARAKEET_MODEL_PATH)");
     }
+    println!(
+        "local-streaming = {}",
+        settings.transcription.local_models.streaming
+    );
+    for key in WHISPER_TUNING_KEYS {
+        println!(
+            "{} = {}",
+            key,
+            whisper_tuning_value(&settings.transcription.local_models.whisper, key)
+        );
+    }
 
     println!();
     println!("[Post-Processing]");
@@ -395,6 +737,41 @@ fn show_all_settings() -> Result<()> {
     } else {
         println!("post-processing-prompt = (default)");
     }
+    println!(
+        "voice-commands = {}",
+        settings.post_processing.voice_commands
+    );
+    let anthropic_status = if let Some(key) = settings.transcription.api_keys.get("anthropic") {
+        // Key is in settings.json
+        mask_key(key)
+    } else if anthropic_api_key(&settings).is_some() {
+        "(from $ANTHROPIC_API_KEY)".to_string()
+    } else {
+        "(not set)".to_string()
+    };
+    println!("anthropic-api-key = {}", anthropic_status);
+    for key in [
+        "anthropic-model",
+        "anthropic-max-tokens",
+        "anthropic-temperature",
+    ] {
+        println!(
+            "{} = {}",
+            key,
+            anthropic_value(&settings.post_processing.anthropic, key)
+        );
+    }
+    let custom = &settings.post_processing.custom;
+    println!("custom-llm-url = {}", optional_value(&custom.base_url));
+    println!("custom-llm-model = {}", optional_value(&custom.model));
+    match &custom.api_key {
+        Some(key) => println!("custom-llm-api-key = {}", mask_key(key)),
+        None => println!("custom-llm-api-key = (none)"),
+    }
+    println!(
+        "custom-llm-params = {}",
+        custom_params_value(&custom.params)
+    );
 
     println!();
     println!("[Services]");
@@ -408,6 +785,12 @@ fn show_all_settings() -> Result<()> {
     } else {
         println!("ollama-model = {}", DEFAULT_OLLAMA_MODEL);
     }
+    println!("http-api = {}", settings.services.http_api.enabled);
+    println!("http-api-port = {}", settings.services.http_api.port);
+    match settings.services.http_api.token() {
+        Some(token) => println!("http-api-token = {}", mask_key(token)),
+        None => println!("http-api-token = (not set)"),
+    }
 
     println!();
     println!("[Audio]");
@@ -440,6 +823,84 @@ fn show_all_settings() -> Result<()> {
     Ok(())
 }
 
+/// Local Whisper inference keys, in display order
+const WHISPER_TUNING_KEYS: &[&str] = &[
+    "whisper-threads",
+    "whisper-beam-size",
+    "whisper-temperature",
+    "whisper-temperature-increment",
+    "whisper-no-speech-threshold",
+    "whisper-suppress-blank",
+];
+
+/// Set a local Whisper inference key ("default" clears it)
+fn set_whisper_tuning(tuning: &mut WhisperTuning, key: &str, value: &str) -> Result<()> {
+    fn parse<T: std::str::FromStr>(value: &str, hint: &str) -> Result<Option<T>> {
+        if value.eq_ignore_ascii_case("default") {
+            return Ok(None);
+        }
+        value
+            .parse::<T>()
+            .map(Some)
+            .map_err(|_| anyhow!("Invalid value. {hint} (or 'default')"))
+    }
+
+    let mut updated = tuning.clone();
+    match key {
+        "whisper-threads" => updated.threads = parse(value, "Use a thread count, e.g. 4")?,
+        "whisper-beam-size" => {
+            updated.beam_size = parse(value, "Use a beam size, 1 for greedy decoding")?
+        }
+        "whisper-temperature" => {
+            updated.temperature = parse(value, "Use a number between 0.0 and 1.0")?
+        }
+        "whisper-temperature-increment" => {
+            updated.temperature_increment = parse(
+                value,
+                "Use a number between 0.0 and 1.0, 0 disables fallback",
+            )?
+        }
+        "whisper-no-speech-threshold" => {
+            updated.no_speech_threshold = parse(value, "Use a number between 0.0 and 1.0")?
+        }
+        "whisper-suppress-blank" => updated.suppress_blank = parse(value, "Use 'true' or 'false'")?,
+        _ => unreachable!("Key validation should prevent this"),
+    }
+
+    updated.validate()?;
+    *tuning = updated;
+    Ok(())
+}
+
+/// Display a local Whisper inference key, showing the default when unset
+fn whisper_tuning_value(tuning: &WhisperTuning, key: &str) -> String {
+    fn show<T: std::fmt::Display>(value: Option<T>, default: &str) -> String {
+        value.map_or_else(|| format!("(default: {default})"), |v| v.to_string())
+    }
+
+    match key {
+        "whisper-threads" => show(tuning.threads, "auto"),
+        "whisper-beam-size" => show(
+            tuning.beam_size,
+            &WhisperTuning::DEFAULT_BEAM_SIZE.to_string(),
+        ),
+        "whisper-temperature" => show(
+            tuning.temperature,
+            &WhisperTuning::DEFAULT_TEMPERATURE.to_string(),
+        ),
+        "whisper-temperature-increment" => show(
+            tuning.temperature_increment,
+            &WhisperTuning::DEFAULT_TEMPERATURE_INCREMENT.to_string(),
+        ),
+        "whisper-no-speech-threshold" => show(
+            tuning.no_speech_threshold,
+            &WhisperTuning::DEFAULT_NO_SPEECH_THRESHOLD.to_string(),
+        ),
+        "whisper-suppress-blank" => show(tuning.suppress_blank, "true"),
+        _ => unreachable!("Key validation should prevent this"),
+    }
+}
+
 fn show_usage() {
     eprintln!("Usage:");
     eprintln!("  whis config <key> <value>    Set a configuration value");
@@ -452,8 +913,12 @@ fn show_usage() {
     eprintln!("  whis config openai-api-key sk-...");
     eprintln!("  whis config language en");
     eprintln!("  whis config post-processor ollama");
+    eprintln!("  whis config custom-llm-url http://localhost:1234/v1");
+    eprintln!("  whis config voice-commands true");
     eprintln!("  whis config vad true");
     eprintln!("  whis config chunk-size 30");
+    eprintln!("  whis config whisper-beam-size 5");
+    eprintln!("  whis config http-api true");
     eprintln!();
     eprintln!("Run 'whis config --list' to see all available keys and current values");
 }
@@ -477,6 +942,49 @@ fn validate_api_key(key: &str, provider_name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Anthropic API key from settings, falling back to $ANTHROPIC_API_KEY
+fn anthropic_api_key(settings: &Settings) -> Option<String> {
+    settings
+        .transcription
+        .api_keys
+        .get("anthropic")
+        .cloned()
+        .or_else(|| std::env::var("ANTHROPIC_API_KEY").ok())
+}
+
+/// Display value for an Anthropic request option
+fn anthropic_value(config: &AnthropicConfig, key: &str) -> String {
+    let value = match key {
+        "anthropic-model" => config.model.clone(),
+        "anthropic-max-tokens" => config.max_tokens.map(|v| v.to_string()),
+        _ => config.temperature.map(|v| v.to_string()),
+    };
+    value.unwrap_or_else(|| "(default)".to_string())
+}
+
+/// Display value for an optional setting
+fn optional_value(value: &Option<String>) -> &str {
+    value.as_deref().unwrap_or("(not set)")
+}
+
+/// Display value for extra custom LLM request params
+fn custom_params_value(params: &serde_json::Map<String, serde_json::Value>) -> String {
+    if params.is_empty() {
+        "(none)".to_string()
+    } else {
+        serde_json::Value::Object(params.clone()).to_string()
+    }
+}
+
+/// Display value for the AssemblyAI keyterm list
+fn keyterms_value(config: &AssemblyAIConfig) -> String {
+    if config.keyterms.is_empty() {
+        "(none)".to_string()
+    } else {
+        config.keyterms.join(", ")
+    }
+}
+
 fn truncate_prompt(prompt: &str) -> String {
     if prompt.len() > 50 {
         format!("{}...", &prompt[..47])
@@ -0,0 +1,161 @@
+use anyhow::{Result, anyhow};
+use whis_core::preset::{Dictionary, Preset, Replacement};
+
+use crate::args::DictAction;
+
+pub fn run(action: Option<DictAction>) -> Result<()> {
+    match action {
+        None => list(None),
+        Some(DictAction::List { preset }) => list(preset.as_deref()),
+        Some(DictAction::Add {
+            from,
+            to,
+            regex,
+            whole_word,
+            preserve_case,
+            preset,
+        }) => add(
+            Replacement {
+                from,
+                to,
+                regex,
+                whole_word,
+                preserve_case,
+            },
+            preset.as_deref(),
+        ),
+        Some(DictAction::Remove { from, preset }) => remove(&from, preset.as_deref()),
+        Some(DictAction::Test { text, preset }) => test(&text.join(" "), preset.as_deref()),
+    }
+}
+
+fn load_preset(name: &str) -> Result<Preset> {
+    let (preset, _) = Preset::load(name).map_err(|e| anyhow!("{}", e))?;
+    Ok(preset)
+}
+
+/// Where an entry list lives: the user dictionary or a preset file
+fn location(preset: Option<&str>) -> String {
+    match preset {
+        Some(name) => Preset::presets_dir()
+            .join(format!("{}.json", name))
+            .display()
+            .to_string(),
+        None => Dictionary::path().display().to_string(),
+    }
+}
+
+/// Load the entries to edit, let `edit` change them, and save them back
+fn update(
+    preset: Option<&str>,
+    edit: impl FnOnce(&mut Vec<Replacement>) -> Result<()>,
+) -> Result<()> {
+    match preset {
+        Some(name) => {
+            // Saving a built-in creates a user copy that overrides it
+            let mut preset = load_preset(name)?;
+            edit(&mut preset.dictionary)?;
+            preset.save().map_err(|e| anyhow!("{}", e))
+        }
+        None => {
+            let mut dictionary = Dictionary::load();
+            edit(&mut dictionary.replacements)?;
+            dictionary.save().map_err(|e| anyhow!("{}", e))
+        }
+    }
+}
+
+fn list(preset: Option<&str>) -> Result<()> {
+    let entries = match preset {
+        Some(name) => load_preset(name)?.dictionary,
+        None => Dictionary::load().replacements,
+    };
+
+    if entries.is_empty() {
+        println!("No dictionary entries.");
+        println!();
+        println!("Add one with: whis dict add <from> <to>");
+        return Ok(());
+    }
+
+    let from_width = entries
+        .iter()
+        .map(|r| r.from.chars().count())
+        .max()
+        .unwrap_or(4)
+        .max(4);
+
+    println!("{:<from_width$}  TO", "FROM");
+    for entry in &entries {
+        let flags: Vec<&str> = [
+            (entry.regex, "regex"),
+            (entry.whole_word, "whole-word"),
+            (entry.preserve_case, "preserve-case"),
+        ]
+        .into_iter()
+        .filter_map(|(set, name)| set.then_some(name))
+        .collect();
+
+        if flags.is_empty() {
+            println!("{:<from_width$}  {}", entry.from, entry.to);
+        } else {
+            println!(
+                "{:<from_width$}  {}  ({})",
+                entry.from,
+                entry.to,
+                flags.join(", ")
+            );
+        }
+    }
+
+    println!();
+    println!("Dictionary: {}", location(preset));
+    Ok(())
+}
+
+fn add(entry: Replacement, preset: Option<&str>) -> Result<()> {
+    entry.validate().map_err(|e| anyhow!("{}", e))?;
+
+    let from = entry.from.clone();
+    let mut updated = false;
+    update(preset, |entries| {
+        // Same FROM replaces the existing entry in place (keeps order)
+        match entries.iter_mut().find(|e| e.from == entry.from) {
+            Some(existing) => {
+                *existing = entry;
+                updated = true;
+            }
+            None => entries.push(entry),
+        }
+        Ok(())
+    })?;
+
+    let action = if updated { "Updated" } else { "Added" };
+    println!("{} '{}' in {}", action, from, location(preset));
+    Ok(())
+}
+
+fn remove(from: &str, preset: Option<&str>) -> Result<()> {
+    update(preset, |entries| {
+        let before = entries.len();
+        entries.retain(|e| e.from != from);
+        if entries.len() == before {
+            return Err(anyhow!("No dictionary entry for '{}'", from));
+        }
+        Ok(())
+    })?;
+
+    println!("Removed '{}' from {}", from, location(preset));
+    Ok(())
+}
+
+fn test(text: &str, preset: Option<&str>) -> Result<()> {
+    let preset = preset.map(load_preset).transpose()?;
+    let result = Dictionary::load().apply(text, preset.as_ref());
+
+    println!("{}", result);
+    if result == text {
+        eprintln!("(no entries matched)");
+    }
+    Ok(())
+}
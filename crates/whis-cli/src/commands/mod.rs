@@ -1,8 +1,10 @@
 pub mod config;
+pub mod dict;
 pub mod model;
 pub mod preset;
 pub mod record;
 pub mod restart;
+pub mod service;
 pub mod setup;
 pub mod start;
 pub mod status;
@@ -1,38 +1,148 @@
-//! Model listing commands for whisper, parakeet, and ollama
-
-use anyhow::{Context, Result};
+//! Model management commands for whisper, parakeet, and ollama
+//!
+//! - `list`: catalog with install status, disk usage and orphaned files
+//! - `download` / `remove`: manage local model files
+//! - `import`: register a model copied in without a download (offline)
+//! - `verify`: check downloaded models are complete
+//! - `use`: point settings at a downloaded or imported model
+
+use anyhow::{Context, Result, anyhow};
 use serde::Deserialize;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
-use whis_core::model::{ModelType, WhisperModel};
-use whis_core::ollama;
+use whis_core::model::import::{ImportMode, Imported};
+use whis_core::model::{self, ModelType, WhisperModel};
+use whis_core::{Settings, TranscriptionProvider, ollama};
 
 #[cfg(feature = "local-transcription")]
 use whis_core::model::ParakeetModel;
 
-use crate::args::{ModelAction, ModelType as ModelTypeArg};
+use crate::args::{LocalEngine, ModelAction, ModelType as ModelTypeArg};
+use crate::ipc;
 
 /// Run the model command
 pub fn run(action: Option<ModelAction>) -> Result<()> {
     match action {
-        None | Some(ModelAction::List { model_type: None }) => list_whisper_models(),
-        Some(ModelAction::List {
+        None
+        | Some(ModelAction::List { model_type: None })
+        | Some(ModelAction::List {
             model_type: Some(ModelTypeArg::Whisper),
-        }) => list_whisper_models(),
+        }) => list_local_models(
+            &WhisperModel,
+            Some(recommended_whisper_model(&Settings::load())),
+        ),
+        #[cfg(feature = "local-transcription")]
+        Some(ModelAction::List {
+            model_type: Some(ModelTypeArg::Parakeet),
+        }) => list_local_models(&ParakeetModel, None),
+        #[cfg(not(feature = "local-transcription"))]
         Some(ModelAction::List {
             model_type: Some(ModelTypeArg::Parakeet),
-        }) => list_parakeet_models(),
+        }) => Err(parakeet_unavailable()),
         Some(ModelAction::List {
             model_type: Some(ModelTypeArg::Ollama { url }),
         }) => list_ollama_models(url),
+        Some(ModelAction::Download { engine, name }) => match engine {
+            LocalEngine::Whisper => download(
+                &WhisperModel,
+                name.as_deref().unwrap_or(model::DEFAULT_MODEL),
+            ),
+            #[cfg(feature = "local-transcription")]
+            LocalEngine::Parakeet => download(
+                &ParakeetModel,
+                name.as_deref().unwrap_or(model::DEFAULT_PARAKEET_MODEL),
+            ),
+            #[cfg(not(feature = "local-transcription"))]
+            LocalEngine::Parakeet => Err(parakeet_unavailable()),
+        },
+        Some(ModelAction::Remove { engine, name }) => match engine {
+            LocalEngine::Whisper => remove(&WhisperModel, engine, &name),
+            #[cfg(feature = "local-transcription")]
+            LocalEngine::Parakeet => remove(&ParakeetModel, engine, &name),
+            #[cfg(not(feature = "local-transcription"))]
+            LocalEngine::Parakeet => Err(parakeet_unavailable()),
+        },
+        Some(ModelAction::Verify { engine, name }) => {
+            let mut failures = 0;
+            if engine != Some(LocalEngine::Parakeet) {
+                failures += verify(&WhisperModel, name.as_deref())?;
+            }
+            #[cfg(feature = "local-transcription")]
+            if engine != Some(LocalEngine::Whisper) {
+                failures += verify(&ParakeetModel, name.as_deref())?;
+            }
+            #[cfg(not(feature = "local-transcription"))]
+            if engine == Some(LocalEngine::Parakeet) {
+                return Err(parakeet_unavailable());
+            }
+
+            if failures > 0 {
+                return Err(anyhow!(
+                    "{failures} model(s) failed verification.\n\
+                    Re-download with: whis model remove <engine> <name> && whis model download <engine> <name>"
+                ));
+            }
+            Ok(())
+        }
+        Some(ModelAction::Import {
+            path,
+            name,
+            copy,
+            symlink,
+            use_model,
+        }) => {
+            let mode = if copy {
+                ImportMode::Copy
+            } else if symlink {
+                ImportMode::Symlink
+            } else {
+                ImportMode::Reference
+            };
+
+            // Parakeet models are directories, whisper models single files
+            if path.is_dir() {
+                #[cfg(feature = "local-transcription")]
+                return import(
+                    &ParakeetModel,
+                    LocalEngine::Parakeet,
+                    &path,
+                    name.as_deref(),
+                    mode,
+                    use_model,
+                );
+                #[cfg(not(feature = "local-transcription"))]
+                return Err(parakeet_unavailable());
+            }
+            import(
+                &WhisperModel,
+                LocalEngine::Whisper,
+                &path,
+                name.as_deref(),
+                mode,
+                use_model,
+            )
+        }
+        Some(ModelAction::Use { engine, name }) => match engine {
+            LocalEngine::Whisper => use_model(&WhisperModel, engine, &name),
+            #[cfg(feature = "local-transcription")]
+            LocalEngine::Parakeet => use_model(&ParakeetModel, engine, &name),
+            #[cfg(not(feature = "local-transcription"))]
+            LocalEngine::Parakeet => Err(parakeet_unavailable()),
+        },
     }
 }
 
-/// List available whisper models with install status
-fn list_whisper_models() -> Result<()> {
-    println!("Available whisper models:\n");
+/// List available models with install status and disk usage
+///
+/// Models are printed in catalog order, with a header per model family.
+fn list_local_models<M: ModelType>(model_type: &M, recommended: Option<&str>) -> Result<()> {
+    let settings = Settings::load();
+    let current = configured_path(&settings, engine_of(model_type));
+
+    println!("Available {} models:\n", model_type.name());
 
     // Calculate column widths
-    let name_width = WhisperModel
+    let name_width = model_type
         .models()
         .iter()
         .map(|model| model.name.len())
@@ -42,88 +152,370 @@ fn list_whisper_models() -> Result<()> {
 
     // Print header
     println!(
-        "{:<name_width$}  STATUS       DESCRIPTION",
+        "{:<name_width$}  STATUS       SIZE      DESCRIPTION",
         "NAME",
         name_width = name_width
     );
     println!("{}", "-".repeat(60));
 
     // Print each model
-    for model in WhisperModel.models() {
-        let path = WhisperModel.default_path(model.name);
-        let status = if WhisperModel.verify(&path) {
-            "[installed]"
+    let grouped = model_type
+        .models()
+        .iter()
+        .any(|m| m.family != model_type.models()[0].family);
+    let mut family = "";
+    let mut total_size = 0;
+    let mut installed_count = 0;
+    for model in model_type.models() {
+        if grouped && model.family != family {
+            family = model.family;
+            println!("-- {family} --");
+        }
+
+        let recommended = if recommended == Some(model.name) {
+            " (recommended)"
         } else {
             ""
         };
 
+        let path = model_type.default_path(model.name);
+        let (status, size) = if model_type.verify(&path) {
+            let size = model::storage::disk_usage(&path);
+            total_size += size;
+            installed_count += 1;
+
+            if current.as_deref() == Some(path.to_string_lossy().as_ref()) {
+                ("[current]", size)
+            } else {
+                ("[installed]", size)
+            }
+        } else {
+            ("", 0)
+        };
+
         println!(
-            "{:<name_width$}  {:<11}  {}",
+            "{:<name_width$}  {:<11}  {:<8}  {}{}",
             model.name,
             status,
+            format_size(size),
             model.description,
+            recommended,
             name_width = name_width
         );
     }
 
+    let imported = model::import::list(model_type);
+    if !imported.is_empty() {
+        println!("-- imported --");
+    }
+    for model in &imported {
+        let status = if !model_type.verify(&model.path) {
+            "[missing]"
+        } else if current.as_deref() == Some(model.path.to_string_lossy().as_ref()) {
+            "[current]"
+        } else {
+            "[installed]"
+        };
+        total_size += model::storage::disk_usage(&model.path);
+        installed_count += 1;
+
+        println!(
+            "{:<name_width$}  {:<11}  {:<8}  {}",
+            model.name,
+            status,
+            format_size(model.size_bytes),
+            model.source.display(),
+            name_width = name_width.max(model.name.len())
+        );
+    }
+
     println!();
-    println!("Models directory: {}", WhisperModel.default_dir().display());
+    println!("Models directory: {}", model_type.default_dir().display());
+    if installed_count > 0 {
+        println!(
+            "Disk usage: {} ({} installed)",
+            format_size(total_size),
+            installed_count
+        );
+    }
+
+    let orphans = model::storage::find_orphans(model_type);
+    if !orphans.is_empty() {
+        println!();
+        println!("Orphaned files (not part of any known model):");
+        for path in &orphans {
+            println!(
+                "  {}  {}",
+                path.display(),
+                format_size(model::storage::disk_usage(path))
+            );
+        }
+        println!("These may be interrupted downloads; delete them to free space.");
+    }
+
     println!();
-    println!("To download a model, run: whis setup local");
+    println!(
+        "To download a model, run: whis model download {} <name>",
+        model_type.name()
+    );
 
     Ok(())
 }
 
-/// List available Parakeet models with install status
-fn list_parakeet_models() -> Result<()> {
-    println!("Available Parakeet models:\n");
+/// Download a model into the default models directory
+fn download<M: ModelType>(model_type: &M, name: &str) -> Result<()> {
+    let info = model_type
+        .find(name)
+        .ok_or_else(|| unknown_model(model_type, name))?;
 
-    // Calculate column widths
-    let name_width = ParakeetModel
-        .models()
-        .iter()
-        .map(|model| model.name.len())
-        .max()
-        .unwrap_or(6)
-        .max(4);
+    let path = model_type.default_path(info.name);
+    if model_type.verify(&path) {
+        println!(
+            "{} model '{}' is already installed: {}",
+            model_type.name(),
+            info.name,
+            path.display()
+        );
+        return Ok(());
+    }
 
-    // Print header
     println!(
-        "{:<name_width$}  STATUS       DESCRIPTION",
-        "NAME",
-        name_width = name_width
+        "Downloading {} model '{}' ({})...",
+        model_type.name(),
+        info.name,
+        info.description
     );
-    println!("{}", "-".repeat(60));
+    model::download::download(model_type, info.name, &path)?;
 
-    // Print each model
-    for model in ParakeetModel.models() {
-        let path = ParakeetModel.default_path(model.name);
-        let status = if ParakeetModel.verify(&path) {
-            "[installed]"
+    println!("Saved to {}", path.display());
+    println!();
+    println!(
+        "To use it, run: whis model use {} {}",
+        model_type.name(),
+        info.name
+    );
+
+    Ok(())
+}
+
+/// Delete a downloaded model, clearing the setting if it was in use
+fn remove<M: ModelType>(model_type: &M, engine: LocalEngine, name: &str) -> Result<()> {
+    let (path, freed) = match model::import::find(model_type, name) {
+        Some(imported) if model_type.find(name).is_none() => {
+            (imported.path, model::import::remove(model_type, name)?)
+        }
+        _ => (
+            model_type.default_path(name),
+            model::storage::remove(model_type, name)?,
+        ),
+    };
+    println!(
+        "Removed {} model '{}' ({} freed)",
+        model_type.name(),
+        name,
+        format_size(freed)
+    );
+
+    let path = path.to_string_lossy().to_string();
+    let mut settings = Settings::load();
+    let slot = path_setting(&mut settings, engine);
+    if slot.as_deref() == Some(path.as_str()) {
+        *slot = None;
+        settings.save()?;
+        println!(
+            "This was the active {0} model. Pick another with: whis model use {0} <name>",
+            model_type.name()
+        );
+    }
+
+    Ok(())
+}
+
+/// Verify downloaded and imported models, returning the number of failures
+///
+/// Without a name, checks every model that has files on disk. Catalog model
+/// files are checked against the catalog size and SHA-256, imported models
+/// by their format.
+fn verify<M: ModelType>(model_type: &M, name: Option<&str>) -> Result<usize> {
+    let imported = model::import::list(model_type);
+    let mut models: Vec<(String, PathBuf, Option<&model::ModelInfo>)> = model_type
+        .models()
+        .iter()
+        .map(|m| (m.name.to_string(), model_type.default_path(m.name), Some(m)))
+        .chain(imported.into_iter().map(|m| (m.name, m.path, None)))
+        .collect();
+
+    match name {
+        Some(name) => {
+            models.retain(|(n, _, _)| n == name);
+            if models.is_empty() {
+                return Err(unknown_model(model_type, name));
+            }
+        }
+        None => models.retain(|(_, path, info)| info.is_none() || path.exists()),
+    }
+
+    if models.is_empty() {
+        println!("No {} models installed", model_type.name());
+        return Ok(0);
+    }
+
+    let mut failures = 0;
+    for (name, path, info) in models {
+        let size = model::storage::disk_usage(&path);
+
+        let problem = if !path.exists() {
+            Some("not installed".to_string())
+        } else if let Some(info) = info
+            && !model_type.needs_extraction()
+        {
+            model::download::verify_checksum(model_type, info, &path)
+                .err()
+                .map(|e| e.to_string())
         } else {
-            ""
+            // Extracted archives and imports have no checksum, only a format
+            model_type.validate(&path).err().map(|e| e.to_string())
         };
 
-        println!(
-            "{:<name_width$}  {:<11}  {}",
+        match problem {
+            Some(problem) => {
+                failures += 1;
+                println!("[FAIL] {} {}: {}", model_type.name(), name, problem);
+            }
+            None => println!(
+                "[ OK ] {} {} ({})",
+                model_type.name(),
+                name,
+                format_size(size)
+            ),
+        }
+    }
+
+    Ok(failures)
+}
+
+/// Import a model from a local path, optionally switching to it
+fn import<M: ModelType>(
+    model_type: &M,
+    engine: LocalEngine,
+    path: &Path,
+    name: Option<&str>,
+    mode: ImportMode,
+    switch: bool,
+) -> Result<()> {
+    let imported = model::import::import(model_type, path, name, mode)?;
+
+    match &imported {
+        Imported::Catalog { name, path } => println!(
+            "Installed {} model '{}' at {}",
+            model_type.name(),
+            name,
+            path.display()
+        ),
+        Imported::Custom(model) => println!(
+            "Imported {} model '{}' ({}, {}) from {}",
+            model_type.name(),
             model.name,
-            status,
-            model.description,
-            name_width = name_width
+            model.format,
+            format_size(model.size_bytes),
+            model.source.display()
+        ),
+    }
+
+    if switch {
+        use_model(model_type, engine, imported.name())
+    } else {
+        println!();
+        println!(
+            "To use it, run: whis model use {} {}",
+            model_type.name(),
+            imported.name()
         );
+        Ok(())
     }
+}
 
-    println!();
-    println!(
-        "Models directory: {}",
-        WhisperModel.default_dir().join("parakeet").display()
-    );
-    println!();
-    println!("To download a model, run: whis setup local");
+/// Point settings at a downloaded model and switch to its engine
+fn use_model<M: ModelType>(model_type: &M, engine: LocalEngine, name: &str) -> Result<()> {
+    let path = match (model_type.find(name), model::import::find(model_type, name)) {
+        (Some(info), _) => model_type.default_path(info.name),
+        (None, Some(imported)) => imported.path,
+        (None, None) => return Err(unknown_model(model_type, name)),
+    };
+
+    if !model_type.verify(&path) {
+        return Err(anyhow!(
+            "{0} model '{1}' is not installed.\n\
+            Download it with: whis model download {0} {1}",
+            model_type.name(),
+            name
+        ));
+    }
+
+    let mut settings = Settings::load();
+    *path_setting(&mut settings, engine) = Some(path.to_string_lossy().to_string());
+    settings.transcription.provider = match engine {
+        LocalEngine::Whisper => TranscriptionProvider::LocalWhisper,
+        LocalEngine::Parakeet => TranscriptionProvider::LocalParakeet,
+    };
+    settings.save()?;
+
+    println!("Now using {} model '{}'", model_type.name(), name);
+    if ipc::is_service_running() {
+        println!("Restart the background service to apply: whis restart");
+    }
 
     Ok(())
 }
 
+/// Recommend a whisper model for this machine and the configured language
+pub fn recommended_whisper_model(settings: &Settings) -> &'static str {
+    let english_only = settings.transcription.language.as_deref() == Some("en");
+    model::recommend_whisper(&model::SystemResources::detect(), english_only)
+}
+
+/// Map a model type to its CLI engine argument
+fn engine_of<M: ModelType>(model_type: &M) -> LocalEngine {
+    match model_type.name() {
+        "parakeet" => LocalEngine::Parakeet,
+        _ => LocalEngine::Whisper,
+    }
+}
+
+/// Configured model path for an engine (settings only, no env fallback)
+fn configured_path(settings: &Settings, engine: LocalEngine) -> Option<String> {
+    let local_models = &settings.transcription.local_models;
+    match engine {
+        LocalEngine::Whisper => local_models.whisper_path.clone(),
+        LocalEngine::Parakeet => local_models.parakeet_path.clone(),
+    }
+}
+
+/// Mutable model path setting for an engine
+fn path_setting(settings: &mut Settings, engine: LocalEngine) -> &mut Option<String> {
+    let local_models = &mut settings.transcription.local_models;
+    match engine {
+        LocalEngine::Whisper => &mut local_models.whisper_path,
+        LocalEngine::Parakeet => &mut local_models.parakeet_path,
+    }
+}
+
+/// Error for Parakeet commands in builds without local transcription
+#[cfg(not(feature = "local-transcription"))]
+fn parakeet_unavailable() -> anyhow::Error {
+    anyhow!("Parakeet models require the 'local-transcription' feature (not enabled in this build)")
+}
+
+fn unknown_model<M: ModelType>(model_type: &M, name: &str) -> anyhow::Error {
+    let available: Vec<_> = model_type.models().iter().map(|m| m.name).collect();
+    anyhow!(
+        "Unknown {} model: {}. Available: {}",
+        model_type.name(),
+        name,
+        available.join(", ")
+    )
+}
+
 /// Response from Ollama /api/tags endpoint
 #[derive(Debug, Deserialize)]
 struct TagsResponse {
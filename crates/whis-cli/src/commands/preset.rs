@@ -1,5 +1,7 @@
 use anyhow::{Context, Result, anyhow};
-use whis_core::{Preset, PresetSource};
+use std::io::Read;
+use whis_core::preset::{ConflictPolicy, PresetBundle};
+use whis_core::{Preset, PresetSource, PresetStep};
 
 use crate::args::PresetAction;
 
@@ -10,6 +12,8 @@ pub fn run(action: Option<PresetAction>) -> Result<()> {
         Some(PresetAction::New { name }) => new(&name),
         Some(PresetAction::Edit { name }) => edit(&name),
         Some(PresetAction::Delete { name }) => delete(&name),
+        Some(PresetAction::Export { names }) => export(&names),
+        Some(PresetAction::Import { file, on_conflict }) => import(&file, on_conflict),
     }
 }
 
@@ -58,9 +62,41 @@ fn show(name: &str) -> Result<()> {
     println!("Description:");
     println!("  {}", preset.description);
     println!();
-    println!("Prompt:");
-    for line in preset.prompt.lines() {
-        println!("  {}", line);
+    if preset.steps.is_empty() {
+        println!("Prompt:");
+        for line in preset.prompt.lines() {
+            println!("  {}", line);
+        }
+    } else {
+        println!("Steps:");
+        for (i, step) in preset.steps.iter().enumerate() {
+            match step {
+                PresetStep::Llm {
+                    prompt,
+                    post_processor,
+                    model,
+                } => {
+                    let overrides: Vec<&str> = [post_processor.as_deref(), model.as_deref()]
+                        .into_iter()
+                        .flatten()
+                        .collect();
+                    if overrides.is_empty() {
+                        println!("  {}. llm", i + 1);
+                    } else {
+                        println!("  {}. llm ({})", i + 1, overrides.join(", "));
+                    }
+                    for line in prompt.lines() {
+                        println!("     {}", line);
+                    }
+                }
+                PresetStep::Replace { replacements } => {
+                    println!("  {}. replace", i + 1);
+                    for r in replacements {
+                        println!("     \"{}\" -> \"{}\"", r.from, r.to);
+                    }
+                }
+            }
+        }
     }
 
     // Show overrides if any
@@ -75,6 +111,21 @@ fn show(name: &str) -> Result<()> {
         }
     }
 
+    // Show structured output schema/template if any
+    if let Some(output) = &preset.output {
+        println!();
+        println!("Output schema:");
+        for line in serde_json::to_string_pretty(&output.schema)?.lines() {
+            println!("  {}", line);
+        }
+        if let Some(template) = &output.template {
+            println!("Output template:");
+            for line in template.lines() {
+                println!("  {}", line);
+            }
+        }
+    }
+
     // Show file location for user presets
     if source == PresetSource::User {
         println!();
@@ -151,3 +202,36 @@ fn delete(name: &str) -> Result<()> {
     println!("Deleted preset: {}", name);
     Ok(())
 }
+
+fn export(names: &[String]) -> Result<()> {
+    let bundle = PresetBundle::export(names).map_err(|e| anyhow!("{}", e))?;
+    println!("{}", bundle.to_json().map_err(|e| anyhow!("{}", e))?);
+    eprintln!("Exported {} preset(s)", bundle.presets.len());
+    Ok(())
+}
+
+fn import(file: &str, policy: ConflictPolicy) -> Result<()> {
+    let json = if file == "-" {
+        let mut json = String::new();
+        std::io::stdin()
+            .read_to_string(&mut json)
+            .context("Failed to read bundle from stdin")?;
+        json
+    } else {
+        std::fs::read_to_string(file).with_context(|| format!("Failed to read {}", file))?
+    };
+
+    let bundle = PresetBundle::from_json(&json).map_err(|e| anyhow!("{}", e))?;
+    let outcomes = bundle.import(policy).map_err(|e| anyhow!("{}", e))?;
+
+    for outcome in &outcomes {
+        println!("{}", outcome);
+    }
+    if outcomes.is_empty() {
+        println!("Bundle contains no presets.");
+    } else {
+        println!();
+        println!("User presets: {}", Preset::presets_dir().display());
+    }
+    Ok(())
+}
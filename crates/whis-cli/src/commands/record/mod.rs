@@ -52,9 +52,16 @@ mod types;
 // Re-export public types for external use
 pub use types::RecordConfig;
 
+// The post-processing phase is shared with the background service
+pub(crate) use pipeline::{ProcessingConfig, correct, run_steps};
+pub(crate) use types::TranscriptionResult;
+
 use anyhow::Result;
+use std::io::IsTerminal;
+use std::sync::{Arc, Mutex};
 
 use crate::app;
+use crate::args::OutputFormat;
 
 /// Execute the record command with clean pipeline phases
 pub fn run(config: RecordConfig) -> Result<()> {
@@ -70,7 +77,12 @@ pub fn run(config: RecordConfig) -> Result<()> {
     // Branch: file transcription vs microphone recording
     let transcription_result = if let Some(ref input_file) = config.input_file {
         // File transcription mode
-        runtime.block_on(transcribe_file(input_file, &transcription_config, quiet))?
+        runtime.block_on(transcribe_file(
+            input_file,
+            &transcription_config,
+            config.preset.as_ref(),
+            quiet,
+        ))?
     } else {
         // Microphone: Record and transcribe concurrently (streaming)
         let mic_config = modes::MicrophoneConfig {
@@ -82,6 +94,7 @@ pub fn run(config: RecordConfig) -> Result<()> {
         runtime.block_on(progressive_record_and_transcribe(
             mic_config,
             &transcription_config,
+            config.preset.as_ref(),
             quiet,
         ))?
     };
@@ -90,6 +103,13 @@ pub fn run(config: RecordConfig) -> Result<()> {
     let processing_cfg = pipeline::ProcessingConfig {
         enabled: config.post_process,
         preset: config.preset,
+        language: transcription_config
+            .language
+            .clone()
+            .or_else(|| transcription_result.language.clone()),
+        stream_to_stdout: config.print
+            && config.format == OutputFormat::Txt
+            && std::io::stdout().is_terminal(),
     };
     let processed_result = runtime.block_on(pipeline::process(
         transcription_result,
@@ -110,6 +130,9 @@ pub fn run(config: RecordConfig) -> Result<()> {
     Ok(())
 }
 
+/// Transcript text and the language the provider detected (if reported)
+type Transcript = (String, Option<String>);
+
 /// Progressive recording + transcription (combines recording and transcription phases)
 ///
 /// This function overlaps recording and transcription using the progressive
@@ -117,28 +140,34 @@ pub fn run(config: RecordConfig) -> Result<()> {
 /// (cloud providers transcribe chunks in parallel, local providers sequentially).
 ///
 /// For realtime providers (deepgram-realtime, openai-realtime), audio is streamed
-/// directly to WebSocket without chunking for lower latency.
+/// directly to WebSocket without chunking for lower latency. Local providers in
+/// streaming mode transcribe each utterance at speech pauses instead.
 async fn progressive_record_and_transcribe(
     mic_config: modes::MicrophoneConfig,
     transcription_config: &app::TranscriptionConfig,
+    preset: Option<&whis_core::Preset>,
     quiet: bool,
 ) -> Result<types::TranscriptionResult> {
     use tokio::sync::mpsc;
     #[cfg(feature = "local-transcription")]
     use whis_core::progressive_transcribe_local;
     use whis_core::{
-        AudioRecorder, ChunkerConfig, ProgressiveChunker, Settings, TranscriptionProvider,
-        WarmupConfig, progressive_transcribe_cloud, warmup_configured,
+        AudioRecorder, ChunkerConfig, ProgressiveChunker, Settings, WarmupConfig,
+        progressive_transcribe_cloud, warmup_configured,
     };
 
+    #[cfg(not(feature = "local-transcription"))]
+    let _ = preset;
+
+    let settings = Settings::load();
+
     // Check if this is a realtime provider (for branching later)
-    let is_realtime = whis_core::is_realtime_provider(&transcription_config.provider);
+    let is_realtime = settings.transcription.uses_realtime();
 
     // Create recorder
     let mut recorder = AudioRecorder::new()?;
 
     // Configure VAD (disabled for realtime - they handle silence detection)
-    let settings = Settings::load();
     let vad_enabled = settings.ui.vad.enabled && !mic_config.no_vad && !is_realtime;
     recorder.set_vad(vad_enabled, settings.ui.vad.threshold);
 
@@ -173,8 +202,10 @@ async fn progressive_record_and_transcribe(
         let config = WarmupConfig {
             provider: Some(provider),
             provider_api_key: Some(api_key),
+            speechmatics_region: settings.transcription.regions.speechmatics,
             post_processor,
             post_processor_api_key,
+            custom_base_url: settings.post_processing.custom.base_url.clone(),
         };
 
         tokio::spawn(async move {
@@ -199,22 +230,83 @@ async fn progressive_record_and_transcribe(
         }
     });
 
+    // Status line shown before live partial transcripts (realtime only)
+    let live_status = Arc::new(Mutex::new(String::new()));
+
+    let partial_task: Option<tokio::task::JoinHandle<bool>>;
+
     // Branch based on provider type: realtime streaming vs chunked progressive
     let (transcription_task, chunker_task): (
-        tokio::task::JoinHandle<anyhow::Result<String>>,
+        tokio::task::JoinHandle<anyhow::Result<Transcript>>,
         Option<tokio::task::JoinHandle<anyhow::Result<()>>>,
     ) = if is_realtime {
         // REALTIME PATH: Stream audio directly to WebSocket (no chunking)
         #[cfg(feature = "realtime")]
         {
-            let realtime_backend = whis_core::get_realtime_backend(&transcription_config.provider)?;
+            // Local models have no backend; they stream through `transcribe_local` below
+            let realtime_backend = if transcription_config.provider.is_local() {
+                None
+            } else {
+                Some(whis_core::get_realtime_backend(
+                    &transcription_config.provider,
+                    &settings.transcription.provider_options(),
+                )?)
+            };
+            let provider = transcription_config.provider.clone();
             let api_key = transcription_config.api_key.clone();
             let language = transcription_config.language.clone();
 
+            // Rewrite the status line with partial transcripts on interactive terminals
+            let show_partials =
+                !quiet && !whis_core::verbose::is_verbose() && std::io::stdout().is_terminal();
+            let partial_tx = if show_partials {
+                let (tx, rx) = mpsc::unbounded_channel();
+                partial_task = Some(tokio::spawn(display_partials(rx, Arc::clone(&live_status))));
+                Some(tx)
+            } else {
+                partial_task = None;
+                None
+            };
+
+            // Local models stream with the preset's Whisper tuning and the VAD setting
+            #[cfg(feature = "local-transcription")]
+            let local =
+                whis_core::LocalRealtimeProvider::for_provider(&transcription_config.provider)
+                    .map(|engine| {
+                        let mut config = whis_core::LocalStreamConfig::from_settings(
+                            &settings,
+                            preset.and_then(|p| p.whisper.as_ref()),
+                        )?;
+                        config.vad &= !mic_config.no_vad;
+                        anyhow::Ok((engine, config))
+                    })
+                    .transpose()?;
+
             let task = tokio::spawn(async move {
-                realtime_backend
-                    .transcribe_stream(&api_key, audio_rx_unbounded, language)
-                    .await
+                #[cfg(feature = "local-transcription")]
+                if let Some((engine, config)) = local {
+                    let result = engine
+                        .transcribe_local(
+                            &api_key,
+                            audio_rx_unbounded,
+                            language,
+                            config,
+                            partial_tx,
+                        )
+                        .await?;
+                    return Ok((result.text, result.language));
+                }
+
+                let Some(realtime_backend) = realtime_backend else {
+                    anyhow::bail!(
+                        "Provider '{}' requires the 'local-transcription' feature",
+                        provider.as_str()
+                    );
+                };
+                let text = realtime_backend
+                    .transcribe_stream(&api_key, audio_rx_unbounded, language, partial_tx)
+                    .await?;
+                Ok((text, None))
             });
 
             (task, None) // No chunker task for realtime
@@ -254,31 +346,49 @@ async fn progressive_record_and_transcribe(
             let provider = transcription_config.provider.clone();
             let api_key = transcription_config.api_key.clone();
             let language = transcription_config.language.clone();
+            let options = settings.transcription.provider_options();
+            #[cfg(feature = "local-transcription")]
+            let tuning = {
+                let tuning = settings
+                    .transcription
+                    .local_models
+                    .whisper
+                    .with_overrides(preset.and_then(|p| p.whisper.as_ref()));
+                tuning.validate()?;
+                tuning
+            };
 
             tokio::spawn(async move {
                 #[cfg(feature = "local-transcription")]
-                if provider == TranscriptionProvider::LocalParakeet {
-                    // Local Parakeet progressive transcription
-                    let model_path = Settings::load()
-                        .transcription
-                        .parakeet_model_path()
-                        .ok_or_else(|| anyhow::anyhow!("Parakeet model path not configured"))?;
-
-                    return progressive_transcribe_local(&model_path, chunk_rx, None).await;
+                if provider.is_local() {
+                    // Local progressive transcription (api_key is the model path)
+                    let result = progressive_transcribe_local(
+                        &provider,
+                        &api_key,
+                        language.as_deref(),
+                        &tuning,
+                        chunk_rx,
+                        None,
+                    )
+                    .await?;
+                    return Ok((result.text, result.language));
                 }
 
                 // Cloud provider progressive transcription
-                progressive_transcribe_cloud(
+                let text = progressive_transcribe_cloud(
                     &provider,
+                    &options,
                     &api_key,
                     language.as_deref(),
                     chunk_rx,
                     None,
                 )
-                .await
+                .await?;
+                Ok((text, None))
             })
         };
 
+        partial_task = None;
         (transcription_task, Some(chunker_task))
     };
 
@@ -289,7 +399,9 @@ async fn progressive_record_and_transcribe(
             if whis_core::verbose::is_verbose() {
                 println!("Recording for {} seconds...", dur.as_secs());
             } else {
-                print!("Recording for {} seconds...", dur.as_secs());
+                let status = format!("Recording for {} seconds...", dur.as_secs());
+                print!("{status}");
+                *live_status.lock().unwrap() = status;
                 use std::io::Write;
                 std::io::stdout().flush()?;
             }
@@ -303,6 +415,7 @@ async fn progressive_record_and_transcribe(
                 println!("Recording...");
             } else {
                 print!("Recording...");
+                *live_status.lock().unwrap() = "Recording...".to_string();
                 use std::io::Write;
                 std::io::stdout().flush()?;
             }
@@ -323,16 +436,47 @@ async fn progressive_record_and_transcribe(
     // Wait for transcription to finish
     if !quiet {
         app::print_status(" Transcribing...", Some(&transcription_config.provider));
+        live_status.lock().unwrap().push_str(" Transcribing...");
     }
 
-    let text = transcription_task.await??;
+    let (text, language) = transcription_task.await??;
+
+    // Drop the last partial so the completion message follows the status
+    if let Some(partial_task) = partial_task
+        && partial_task.await.unwrap_or(false)
+    {
+        app::print_live_status(&live_status.lock().unwrap(), "");
+    }
 
     // Print completion message immediately after transcription finishes
     if !quiet {
         println!(" Done.");
+        if let Some(language) = &language {
+            eprintln!("Detected language: {}", language);
+        }
     }
 
-    Ok(types::TranscriptionResult { text })
+    Ok(types::TranscriptionResult {
+        text,
+        language,
+        words: Vec::new(),
+    })
+}
+
+/// Show partial transcripts after the current status line until the stream ends
+///
+/// Returns whether any partial was displayed.
+#[cfg(feature = "realtime")]
+async fn display_partials(
+    mut partial_rx: tokio::sync::mpsc::UnboundedReceiver<String>,
+    status: Arc<Mutex<String>>,
+) -> bool {
+    let mut shown = false;
+    while let Some(partial) = partial_rx.recv().await {
+        app::print_live_status(&status.lock().unwrap(), &partial);
+        shown = true;
+    }
+    shown
 }
 
 /// Preload models in background to reduce latency (extracted from MicrophoneMode)
@@ -370,9 +514,15 @@ fn preload_models(config: &modes::MicrophoneConfig) {
 async fn transcribe_file(
     input_file: &std::path::Path,
     transcription_config: &app::TranscriptionConfig,
+    preset: Option<&whis_core::Preset>,
     quiet: bool,
 ) -> Result<types::TranscriptionResult> {
-    use whis_core::{TranscriptionProvider, http::get_http_client, provider::TranscriptionRequest};
+    #[cfg(feature = "local-transcription")]
+    use whis_core::TranscriptionProvider;
+    use whis_core::{http::get_http_client, provider::TranscriptionRequest};
+
+    #[cfg(not(feature = "local-transcription"))]
+    let _ = preset;
 
     if !quiet {
         eprintln!(
@@ -385,7 +535,7 @@ async fn transcribe_file(
     let samples = modes::file::read_audio_file(input_file)?;
 
     // Handle local vs cloud providers differently
-    let text = match &transcription_config.provider {
+    let (text, language, words) = match &transcription_config.provider {
         #[cfg(feature = "local-transcription")]
         TranscriptionProvider::LocalParakeet => {
             let model_path = whis_core::Settings::load()
@@ -393,49 +543,60 @@ async fn transcribe_file(
                 .parakeet_model_path()
                 .ok_or_else(|| anyhow::anyhow!("Parakeet model path not configured"))?;
 
-            tokio::task::spawn_blocking(move || {
-                whis_core::provider::transcribe_raw_parakeet(&model_path, samples)
+            let result = tokio::task::spawn_blocking(move || {
+                whis_core::provider::transcribe_chunked_parakeet(&model_path, samples)
             })
-            .await??
-            .text
+            .await??;
+            (result.text, result.language, result.words)
         }
 
         #[cfg(feature = "local-transcription")]
         TranscriptionProvider::LocalWhisper => {
             let model_path = transcription_config.api_key.clone();
             let language = transcription_config.language.clone();
-            tokio::task::spawn_blocking(move || {
-                whis_core::provider::transcribe_raw(&model_path, &samples, language.as_deref())
+            let tuning = whis_core::Settings::load()
+                .transcription
+                .local_models
+                .whisper
+                .with_overrides(preset.and_then(|p| p.whisper.as_ref()));
+            tuning.validate()?;
+
+            let result = tokio::task::spawn_blocking(move || {
+                whis_core::provider::transcribe_raw(
+                    &model_path,
+                    &samples,
+                    language.as_deref(),
+                    &tuning,
+                )
             })
-            .await??
-            .text
+            .await??;
+
+            if let Some(lang) = &result.language
+                && !quiet
+            {
+                eprintln!("Detected language: {}", lang);
+            }
+            (result.text, result.language, result.words)
         }
 
         _ => {
-            // Cloud providers: encode to MP3 and send
-            let encoder = whis_core::audio::create_encoder();
-            let mp3_data =
-                encoder.encode_samples(&samples, whis_core::resample::WHISPER_SAMPLE_RATE)?;
-
+            // Cloud and network providers: encode (MP3, or WAV for raw PCM) and send
             let client = get_http_client()?;
             let provider =
                 whis_core::provider::registry().get_by_kind(&transcription_config.provider)?;
 
-            let request = TranscriptionRequest {
-                audio_data: mp3_data,
-                language: transcription_config.language.clone(),
-                filename: format!(
-                    "{}.mp3",
-                    input_file.file_stem().unwrap_or_default().to_string_lossy()
-                ),
-                mime_type: "audio/mpeg".to_string(),
-                progress: None,
-            };
+            let request = TranscriptionRequest::from_samples(
+                &transcription_config.provider,
+                &samples,
+                transcription_config.language.clone(),
+                &input_file.file_stem().unwrap_or_default().to_string_lossy(),
+            )?
+            .with_options(whis_core::Settings::load().transcription.provider_options());
 
-            provider
+            let result = provider
                 .transcribe_async(client, &transcription_config.api_key, request)
-                .await?
-                .text
+                .await?;
+            (result.text, result.language, result.words)
         }
     };
 
@@ -443,5 +604,9 @@ async fn transcribe_file(
         eprintln!("Done.");
     }
 
-    Ok(types::TranscriptionResult { text })
+    Ok(types::TranscriptionResult {
+        text,
+        language,
+        words,
+    })
 }
@@ -11,6 +11,7 @@ pub struct MicrophoneConfig {
     /// Disable VAD
     pub no_vad: bool,
     /// Provider (for preloading)
+    #[cfg_attr(not(feature = "local-transcription"), allow(dead_code))]
     pub provider: TranscriptionProvider,
     /// Whether post-processing will be used (for preloading)
     pub will_post_process: bool,
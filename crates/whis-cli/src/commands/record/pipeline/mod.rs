@@ -10,4 +10,4 @@ pub mod output;
 pub mod process;
 
 pub use output::{OutputMode, output};
-pub use process::{ProcessingConfig, process};
+pub use process::{ProcessingConfig, correct, process, run_steps};
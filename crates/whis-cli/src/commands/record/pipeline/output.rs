@@ -4,7 +4,7 @@ use anyhow::Result;
 use std::fs;
 use std::io::{self, IsTerminal};
 use std::path::PathBuf;
-use whis_core::{Settings, copy_to_clipboard};
+use whis_core::{Settings, WordTimestamp, copy_to_clipboard};
 
 use crate::args::OutputFormat;
 
@@ -23,16 +23,52 @@ pub enum OutputMode {
 // Subtitle timing constants
 const CHARS_PER_SECOND: f64 = 15.0;
 const SUBTITLE_GAP_SECS: f64 = 0.5;
+/// Longest cue built from word timings before it is split
+const MAX_CUE_SECS: f64 = 7.0;
 
 /// A text segment with calculated start/end times
-struct TimedSegment<'a> {
-    text: &'a str,
+struct TimedSegment {
+    text: String,
     start: f64,
     end: f64,
+    /// Speaker label, when the provider diarized the audio
+    speaker: Option<String>,
+}
+
+/// Group provider word timings into cues at sentence ends, speaker
+/// changes, or every `MAX_CUE_SECS`
+fn segments_from_words(words: &[WordTimestamp]) -> Vec<TimedSegment> {
+    let mut result: Vec<TimedSegment> = Vec::new();
+    let mut open = false;
+
+    for word in words {
+        let start = word.start_ms as f64 / 1000.0;
+        let end = word.end_ms as f64 / 1000.0;
+
+        match result.last_mut() {
+            Some(seg) if open && seg.speaker == word.speaker && end - seg.start <= MAX_CUE_SECS => {
+                seg.text.push(' ');
+                seg.text.push_str(&word.text);
+                seg.end = end;
+            }
+            _ => result.push(TimedSegment {
+                text: word.text.clone(),
+                start,
+                end,
+                speaker: word.speaker.clone(),
+            }),
+        }
+        open = !word.text.ends_with(['.', '!', '?']);
+    }
+
+    result
 }
 
 /// Split text into timed segments for subtitle generation
-fn split_into_timed_segments(text: &str) -> Vec<TimedSegment<'_>> {
+///
+/// Timings are estimated from text length; see `segments_from_words`
+/// for providers that report real word timings.
+fn split_into_timed_segments(text: &str) -> Vec<TimedSegment> {
     let segments: Vec<&str> = text
         .split(['.', '!', '?'])
         .map(|s| s.trim())
@@ -45,9 +81,10 @@ fn split_into_timed_segments(text: &str) -> Vec<TimedSegment<'_>> {
     for segment in segments {
         let duration = (segment.len() as f64 / CHARS_PER_SECOND).max(1.0);
         result.push(TimedSegment {
-            text: segment,
+            text: segment.to_string(),
             start: time_offset,
             end: time_offset + duration,
+            speaker: None,
         });
         time_offset += duration + SUBTITLE_GAP_SECS;
     }
@@ -76,9 +113,8 @@ fn format_vtt_time(seconds: f64) -> String {
     format!("{h:02}:{m:02}:{s:02}.{ms:03}")
 }
 
-/// Format text as SRT subtitle
-fn format_srt(text: &str) -> String {
-    let segments = split_into_timed_segments(text);
+/// Format segments as SRT subtitle
+fn format_srt(segments: &[TimedSegment]) -> String {
     if segments.is_empty() {
         return String::new();
     }
@@ -90,37 +126,51 @@ fn format_srt(text: &str) -> String {
             i + 1,
             format_srt_time(seg.start),
             format_srt_time(seg.end),
-            seg.text
+            match &seg.speaker {
+                Some(speaker) => format!("Speaker {speaker}: {}", seg.text),
+                None => seg.text.clone(),
+            }
         ));
     }
     output.trim_end().to_string()
 }
 
-/// Format text as WebVTT subtitle
-fn format_vtt(text: &str) -> String {
-    let segments = split_into_timed_segments(text);
+/// Format segments as WebVTT subtitle
+fn format_vtt(segments: &[TimedSegment]) -> String {
     if segments.is_empty() {
         return "WEBVTT\n".to_string();
     }
 
     let mut output = String::from("WEBVTT\n\n");
-    for seg in &segments {
+    for seg in segments {
         output.push_str(&format!(
             "{} --> {}\n{}\n\n",
             format_vtt_time(seg.start),
             format_vtt_time(seg.end),
-            seg.text
+            match &seg.speaker {
+                Some(speaker) => format!("<v Speaker {speaker}>{}", seg.text),
+                None => seg.text.clone(),
+            }
         ));
     }
     output.trim_end().to_string()
 }
 
 /// Format text according to the specified output format
-pub fn format_text(text: &str, format: OutputFormat) -> String {
+///
+/// Subtitles use the provider's word timings when available.
+pub fn format_text(text: &str, words: &[WordTimestamp], format: OutputFormat) -> String {
+    let segments = || {
+        if words.is_empty() {
+            split_into_timed_segments(text)
+        } else {
+            segments_from_words(words)
+        }
+    };
     match format {
         OutputFormat::Txt => text.to_string(),
-        OutputFormat::Srt => format_srt(text),
-        OutputFormat::Vtt => format_vtt(text),
+        OutputFormat::Srt => format_srt(&segments()),
+        OutputFormat::Vtt => format_vtt(&segments()),
     }
 }
 
@@ -131,10 +181,14 @@ pub fn output(
     format: OutputFormat,
     quiet: bool,
 ) -> Result<()> {
-    let text = result.text.trim();
-    let formatted = format_text(text, format);
+    let text = result.rendered_text();
+    let formatted = format_text(&text, &result.words, format);
 
     match mode {
+        OutputMode::Print if result.streamed => {
+            // Already printed while streaming; just end the line
+            println!();
+        }
         OutputMode::Print => {
             println!("{}", formatted);
         }
@@ -1,8 +1,13 @@
 //! Post-processing pipeline phase
 
 use anyhow::{Result, anyhow};
+use std::io::Write;
+use whis_core::preset::{PromptContext, apply_replacements, expand_prompt};
+use whis_core::settings::PostProcessingSettings;
 use whis_core::{
-    DEFAULT_POST_PROCESSING_PROMPT, PostProcessor, Preset, Settings, ollama, post_process,
+    DEFAULT_POST_PROCESSING_PROMPT, Dictionary, PostProcessConfig, PostProcessor, Preset,
+    PresetStep, Settings, apply_voice_commands, ollama, post_process, post_process_streaming,
+    post_process_structured,
 };
 
 use super::super::types::{ProcessedResult, TranscriptionResult};
@@ -12,6 +17,11 @@ use crate::app;
 pub struct ProcessingConfig {
     pub enabled: bool,
     pub preset: Option<Preset>,
+    /// Transcription language (with --language override, else the detected
+    /// one) for `{{language}}`
+    pub language: Option<String>,
+    /// Print the LLM reply to stdout as it streams in (--print on a TTY)
+    pub stream_to_stdout: bool,
 }
 
 /// Execute post-processing phase
@@ -20,58 +30,184 @@ pub async fn process(
     config: &ProcessingConfig,
     quiet: bool,
 ) -> Result<ProcessedResult> {
-    let mut text = transcription.text;
+    let mut result = correct(transcription, config);
 
-    // If post-processing is enabled OR a preset is provided, apply LLM processing
+    // If post-processing is enabled OR a preset is provided, run the preset's steps
     if config.enabled || config.preset.is_some() {
-        let settings = Settings::load();
-        let (processor, api_key, model, prompt) =
-            resolve_post_processor(&config.preset, &settings)?;
+        run_steps(&mut result, config, quiet).await?;
+    }
+
+    Ok(result)
+}
+
+/// Apply the replacement dictionary (plus the preset's entries) and spoken
+/// formatting commands, which apply even without post-processing
+pub fn correct(transcription: TranscriptionResult, config: &ProcessingConfig) -> ProcessedResult {
+    let settings = Settings::load();
+    let mut text = transcription.text;
+    let mut words = transcription.words;
+
+    let mut corrected = Dictionary::load().apply(&text, config.preset.as_ref());
+    if settings.post_processing.voice_commands {
+        corrected = apply_voice_commands(&corrected, config.language.as_deref());
+    }
+    if corrected != text {
+        text = corrected;
+        // Timings no longer match the rewritten text
+        words.clear();
+    }
+
+    ProcessedResult {
+        text,
+        words,
+        streamed: false,
+    }
+}
+
+/// Run the preset's steps (or the configured prompt) on `result` in order
+///
+/// If a step fails, `result` keeps the text of the steps before it.
+pub async fn run_steps(
+    result: &mut ProcessedResult,
+    config: &ProcessingConfig,
+    quiet: bool,
+) -> Result<()> {
+    let settings = Settings::load();
+    let steps = match &config.preset {
+        Some(preset) => preset.pipeline(),
+        None => vec![PresetStep::Llm {
+            prompt: settings
+                .post_processing
+                .prompt
+                .clone()
+                .unwrap_or_else(|| DEFAULT_POST_PROCESSING_PROMPT.to_string()),
+            post_processor: None,
+            model: None,
+        }],
+    };
+    let llm_steps = steps.iter().filter(|step| step.is_llm()).count();
+    let output_step = steps.iter().rposition(PresetStep::is_llm);
+    let prompt_context = PromptContext {
+        language: config.language.clone(),
+        ..PromptContext::from_settings(&settings)
+    };
+    let mut llm_step = 0;
+
+    // Timings no longer match the rewritten text
+    result.words.clear();
+
+    for (index, step) in steps.iter().enumerate() {
+        let (prompt, post_processor, model) = match step {
+            PresetStep::Replace { replacements } => {
+                result.text = apply_replacements(&result.text, replacements);
+                continue;
+            }
+            PresetStep::Llm {
+                prompt,
+                post_processor,
+                model,
+            } => (prompt, post_processor, model),
+        };
+        llm_step += 1;
+        let prompt = expand_prompt(prompt, &prompt_context)?;
+
+        let (processor, api_key_or_url, model) =
+            resolve_post_processor(post_processor.as_deref(), model.clone(), &settings)?;
+        let llm = PostProcessConfig {
+            processor,
+            prompt,
+            api_key_or_url,
+            model,
+            ollama_keep_alive: settings.services.ollama.keep_alive(),
+            custom: settings.post_processing.custom.clone(),
+            anthropic: settings.post_processing.anthropic.clone(),
+        };
 
         // Re-warm Ollama model (in case it unloaded during long recording > keep_alive timeout)
-        if processor == PostProcessor::Ollama && model.is_some() {
+        if llm.processor == PostProcessor::Ollama && llm.model.is_some() {
             settings.services.ollama.preload();
             // Brief pause to allow warmup to complete (runs in background thread)
             tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
         }
 
         if !quiet {
-            app::print_status(" Post-processing...", None);
+            if llm_steps > 1 {
+                let status = format!(" Post-processing ({llm_step}/{llm_steps})...");
+                app::print_status(&status, None);
+            } else {
+                app::print_status(" Post-processing...", None);
+            }
         }
 
-        text = post_process(&text, &processor, &api_key, &prompt, model.as_deref()).await?;
+        let last = index == steps.len() - 1;
+        let output = config.preset.as_ref().and_then(|p| p.output.as_ref());
+
+        // The last LLM step of a structured-output preset replies in JSON;
+        // later replace steps run on the rendered reply
+        if let Some(output) = output.filter(|_| Some(index) == output_step) {
+            let value = post_process_structured(&result.text, &llm, &output.schema).await?;
+            result.text = output.render(&value);
+            continue;
+        }
+
+        // Only the final step's reply is what gets output, so only it is streamed
+        result.text = if config.stream_to_stdout && last {
+            result.streamed = true;
+            stream_to_stdout(&result.text, &llm).await?
+        } else {
+            post_process(&result.text, &llm).await?
+        };
     }
 
-    Ok(ProcessedResult { text })
+    Ok(())
+}
+
+/// Post-process while printing the reply to stdout as it arrives
+async fn stream_to_stdout(text: &str, config: &PostProcessConfig) -> Result<String> {
+    let (delta_tx, mut delta_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+
+    let printer = tokio::spawn(async move {
+        let mut stdout = std::io::stdout();
+        // Skip the reply's leading whitespace, like the trimmed final output
+        let mut started = false;
+        while let Some(delta) = delta_rx.recv().await {
+            let delta = if started {
+                &delta[..]
+            } else {
+                delta.trim_start()
+            };
+            if delta.is_empty() {
+                continue;
+            }
+            started = true;
+            let _ = write!(stdout, "{delta}");
+            let _ = stdout.flush();
+        }
+    });
+
+    let result = post_process_streaming(text, config, &delta_tx).await;
+    drop(delta_tx);
+    let _ = printer.await;
+    result
 }
 
-/// Resolve post-processing configuration from settings and preset
+/// Resolve the post-processor, API key/URL and model for one LLM step
+///
+/// `post_processor` and `model` are the step's (or preset's) overrides.
 fn resolve_post_processor(
-    preset: &Option<Preset>,
+    post_processor: Option<&str>,
+    model: Option<String>,
     settings: &Settings,
-) -> Result<(PostProcessor, String, Option<String>, String)> {
-    // Determine which post-processor to use
-    let processor = if let Some(p) = preset {
-        if let Some(post_processor_str) = &p.post_processor {
-            post_processor_str
-                .parse()
-                .unwrap_or(settings.post_processing.processor.clone())
-        } else {
-            settings.post_processing.processor.clone()
-        }
-    } else {
-        settings.post_processing.processor.clone()
-    };
+) -> Result<(PostProcessor, String, Option<String>)> {
+    // Determine which post-processor to use: step/preset > settings
+    let processor = post_processor
+        .and_then(|p| p.parse().ok())
+        .unwrap_or_else(|| settings.post_processing.processor.clone());
 
-    // Determine prompt: preset > settings > default
-    let prompt = if let Some(p) = preset {
-        p.prompt.clone()
-    } else {
-        settings
-            .post_processing
-            .prompt
-            .clone()
-            .unwrap_or_else(|| DEFAULT_POST_PROCESSING_PROMPT.to_string())
+    // Look up keys for the resolved processor (a preset may override the configured one)
+    let post_processing = PostProcessingSettings {
+        processor: processor.clone(),
+        ..settings.post_processing.clone()
     };
 
     // Get API key/URL and model based on processor type
@@ -85,49 +221,43 @@ fn resolve_post_processor(
                 .ok_or_else(|| anyhow!("Ollama URL not configured"))?;
             ollama::ensure_ollama_running(&ollama_url)?;
 
-            // Model priority: preset > settings
-            let model = if let Some(p) = preset {
-                p.model.clone()
-            } else {
-                settings.services.ollama.model()
-            };
+            // Model priority: step/preset > settings
+            let model = model.or_else(|| settings.services.ollama.model());
 
             if model.is_none() {
                 return Err(anyhow!("Ollama model not configured"));
             }
 
-            Ok((PostProcessor::Ollama, ollama_url, model, prompt))
+            Ok((PostProcessor::Ollama, ollama_url, model))
         }
-        PostProcessor::OpenAI => {
-            let api_key = settings
-                .post_processing
+        PostProcessor::Custom => {
+            // Empty key for servers without authentication
+            let api_key = post_processing
                 .api_key(&settings.transcription.api_keys)
                 .ok_or_else(|| {
                     anyhow!(
-                        "OpenAI API key not configured. Set it with: whis config --openai-api-key <key>"
+                        "Custom post-processor not configured. Set it with: whis config custom-llm-url <url>"
                     )
                 })?;
 
-            // Model from preset if available
-            let model = preset.as_ref().and_then(|p| p.model.clone());
-
-            Ok((PostProcessor::OpenAI, api_key, model, prompt))
+            // Model priority: step/preset > settings (resolved in whis-core)
+            Ok((PostProcessor::Custom, api_key, model))
         }
-        PostProcessor::Mistral => {
-            let api_key = settings
-                .post_processing
+        PostProcessor::None => Err(anyhow!("Post-processing not configured. Run: whis setup")),
+        processor => {
+            // Cloud post-processors: OpenAI, Mistral, Groq, Anthropic
+            let api_key = post_processing
                 .api_key(&settings.transcription.api_keys)
                 .ok_or_else(|| {
                     anyhow!(
-                        "Mistral API key not configured. Set it with: whis config --mistral-api-key <key>"
+                        "{} API key not configured. Set it with: whis config {}-api-key <key>",
+                        processor.display_name(),
+                        processor
                     )
                 })?;
 
-            // Model from preset if available
-            let model = preset.as_ref().and_then(|p| p.model.clone());
-
-            Ok((PostProcessor::Mistral, api_key, model, prompt))
+            // Model from step/preset if available (default resolved in whis-core)
+            Ok((processor, api_key, model))
         }
-        PostProcessor::None => Err(anyhow!("Post-processing not configured. Run: whis setup")),
     }
 }
@@ -13,7 +13,7 @@
 //! └─────────────────┘
 //!     ↓
 //! ┌─────────────────┐
-//! │  Progressive    │  → TranscriptionResult { text }
+//! │  Progressive    │  → TranscriptionResult { text, language, words }
 //! │  Transcription  │
 //! └─────────────────┘
 //!     ↓
@@ -35,7 +35,7 @@
 use anyhow::Result;
 use std::path::PathBuf;
 use std::time::Duration;
-use whis_core::Preset;
+use whis_core::{Preset, WordTimestamp};
 
 use crate::args::{InputOptions, OutputFormat, OutputOptions, ProcessingOptions};
 
@@ -72,6 +72,9 @@ impl RecordConfig {
         // Load preset if provided
         let preset = if let Some(name) = &processing.preset {
             let (p, _source) = Preset::load(name).map_err(|e| anyhow::anyhow!("{}", e))?;
+            // Catch unknown template variables before recording, not after
+            p.validate_prompts().map_err(|e| anyhow::anyhow!("{}", e))?;
+            p.validate_output().map_err(|e| anyhow::anyhow!("{}", e))?;
             Some(p)
         } else {
             None
@@ -112,6 +115,10 @@ impl RecordConfig {
 pub struct TranscriptionResult {
     /// The transcribed text
     pub text: String,
+    /// Language detected by the provider, when it reports one
+    pub language: Option<String>,
+    /// Word timings from the provider (empty if it reports none)
+    pub words: Vec<WordTimestamp>,
 }
 
 /// Result of post-processing phase
@@ -119,4 +126,15 @@ pub struct TranscriptionResult {
 pub struct ProcessedResult {
     /// The processed text
     pub text: String,
+    /// Word timings, kept only while `text` is the unmodified transcript
+    pub words: Vec<WordTimestamp>,
+    /// Whether `text` was already printed to stdout while it streamed in
+    pub streamed: bool,
+}
+
+impl ProcessedResult {
+    /// Final text (trimmed)
+    pub fn rendered_text(&self) -> String {
+        self.text.trim().to_string()
+    }
 }
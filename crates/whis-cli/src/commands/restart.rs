@@ -1,7 +1,22 @@
-use crate::ipc;
-use anyhow::Result;
+use crate::{ipc, systemd};
+use anyhow::{Result, anyhow};
 
 pub fn run() -> Result<()> {
+    // Let systemd restart its unit (a manual start would take over its socket)
+    if systemd::socket_unit_active() {
+        let status = std::process::Command::new("systemctl")
+            .args(["--user", "restart", systemd::SERVICE_UNIT])
+            .status()?;
+        if !status.success() {
+            return Err(anyhow!(
+                "systemctl --user restart {} failed",
+                systemd::SERVICE_UNIT
+            ));
+        }
+        println!("Service restarted");
+        return Ok(());
+    }
+
     // Stop the service if running
     if ipc::is_service_running() {
         let mut client = ipc::IpcClient::connect()?;
@@ -0,0 +1,300 @@
+//! Login service management (`whis service install/uninstall/status`)
+//!
+//! Installs `whis start` so it runs with the user session instead of a terminal
+//! or compositor exec line.
+//!
+//! # Backends
+//!
+//! - systemd (preferred): `whis.socket` + `whis.service` user units. The socket
+//!   listens on the IPC path, so `whis toggle` starts the service on demand.
+//!   The service uses `Type=notify` and `Restart=on-failure`; logs go to the
+//!   journal (`journalctl --user -u whis`), errors and warnings with their
+//!   priority.
+//! - XDG autostart (fallback): `~/.config/autostart/whis.desktop`, for sessions
+//!   without a systemd user manager. No automatic restart.
+
+use anyhow::{Context, Result, anyhow};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::args::ServiceAction;
+use crate::ipc;
+use crate::systemd::{self, SERVICE_UNIT, SOCKET_UNIT};
+
+const AUTOSTART_FILE: &str = "whis.desktop";
+
+pub fn run(action: ServiceAction) -> Result<()> {
+    if !cfg!(target_os = "linux") {
+        return Err(anyhow!(
+            "'whis service' is only supported on Linux.\n\
+            Run 'whis start' from your login items instead."
+        ));
+    }
+
+    match action {
+        ServiceAction::Install { autostart } => install(autostart),
+        ServiceAction::Uninstall => uninstall(),
+        ServiceAction::Status => status(),
+    }
+}
+
+fn install(force_autostart: bool) -> Result<()> {
+    let exe = std::env::current_exe().context("Failed to locate the whis executable")?;
+
+    if !force_autostart && has_systemd_user() {
+        install_systemd(&exe)
+    } else {
+        if !force_autostart {
+            println!("systemd user session not available, using XDG autostart");
+        }
+        install_autostart(&exe)
+    }
+}
+
+fn install_systemd(exe: &Path) -> Result<()> {
+    // A manually started service holds the socket; systemd can't take it over
+    if !systemd::socket_unit_active() && ipc::is_service_running() {
+        return Err(anyhow!(
+            "whis service is already running.\n\
+            Stop it first with: whis stop"
+        ));
+    }
+
+    let dir = systemd_unit_dir()?;
+    std::fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    let socket_path = dir.join(SOCKET_UNIT);
+    let service_path = dir.join(SERVICE_UNIT);
+    std::fs::write(&socket_path, socket_unit())
+        .with_context(|| format!("Failed to write {}", socket_path.display()))?;
+    std::fs::write(&service_path, service_unit(exe))
+        .with_context(|| format!("Failed to write {}", service_path.display()))?;
+
+    systemctl_checked(&["daemon-reload"])?;
+    systemctl_checked(&["enable", "--now", SOCKET_UNIT])?;
+    systemctl_checked(&["enable", SERVICE_UNIT])?;
+
+    // Remove a stale autostart entry so the service isn't started twice
+    if let Ok(path) = autostart_path()
+        && path.exists()
+    {
+        let _ = std::fs::remove_file(&path);
+        println!("Removed autostart entry: {}", path.display());
+    }
+
+    println!("Installed systemd user units:");
+    println!("  {}", socket_path.display());
+    println!("  {}", service_path.display());
+    println!();
+    println!("The service starts at login and on the first 'whis toggle'.");
+    println!("Logs: journalctl --user -u whis");
+    Ok(())
+}
+
+fn install_autostart(exe: &Path) -> Result<()> {
+    let path = autostart_path()?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+
+    std::fs::write(&path, desktop_entry(exe))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    println!("Installed autostart entry: {}", path.display());
+    println!();
+    println!("The service starts at your next login. To start it now, run: whis start");
+    Ok(())
+}
+
+fn uninstall() -> Result<()> {
+    let mut removed = false;
+
+    let dir = systemd_unit_dir()?;
+    let socket_path = dir.join(SOCKET_UNIT);
+    let service_path = dir.join(SERVICE_UNIT);
+    if socket_path.exists() || service_path.exists() {
+        // Best effort: units may already be disabled or the manager unavailable
+        systemctl(&["disable", "--now", SERVICE_UNIT, SOCKET_UNIT]);
+
+        for path in [&socket_path, &service_path] {
+            if path.exists() {
+                std::fs::remove_file(path)
+                    .with_context(|| format!("Failed to remove {}", path.display()))?;
+                println!("Removed {}", path.display());
+            }
+        }
+
+        systemctl(&["daemon-reload"]);
+        removed = true;
+    }
+
+    let autostart = autostart_path()?;
+    if autostart.exists() {
+        std::fs::remove_file(&autostart)
+            .with_context(|| format!("Failed to remove {}", autostart.display()))?;
+        println!("Removed {}", autostart.display());
+        removed = true;
+    }
+
+    if !removed {
+        println!("whis service is not installed");
+    }
+    Ok(())
+}
+
+fn status() -> Result<()> {
+    let dir = systemd_unit_dir()?;
+    let systemd_installed = dir.join(SERVICE_UNIT).exists();
+    let autostart = autostart_path()?;
+
+    if systemd_installed {
+        println!("Installed: systemd user unit");
+        println!(
+            "  Socket:  {}",
+            systemctl_query(&["is-active", SOCKET_UNIT])
+        );
+        println!(
+            "  Service: {} ({})",
+            systemctl_query(&["is-active", SERVICE_UNIT]),
+            systemctl_query(&["is-enabled", SERVICE_UNIT])
+        );
+        println!("  Logs:    journalctl --user -u whis");
+    } else if autostart.exists() {
+        println!("Installed: XDG autostart ({})", autostart.display());
+        if ipc::is_service_running() {
+            println!("Status: Running");
+        } else {
+            println!("Status: Not running");
+        }
+    } else {
+        println!("Installed: no");
+        println!("Install with: whis service install");
+    }
+
+    Ok(())
+}
+
+/// Generate the socket unit listening on the IPC path
+///
+/// `%t` is the user's runtime directory (`$XDG_RUNTIME_DIR`), which
+/// [`ipc::socket_name`] uses as well.
+fn socket_unit() -> &'static str {
+    "[Unit]\n\
+    Description=Whis voice-to-text IPC socket\n\
+    \n\
+    [Socket]\n\
+    ListenStream=%t/whis.sock\n\
+    SocketMode=0600\n\
+    RemoveOnStop=yes\n\
+    \n\
+    [Install]\n\
+    WantedBy=sockets.target\n"
+}
+
+/// Generate the service unit running `whis start`
+fn service_unit(exe: &Path) -> String {
+    format!(
+        "[Unit]\n\
+        Description=Whis voice-to-text service\n\
+        Documentation=https://whis.ink\n\
+        Requires={SOCKET_UNIT}\n\
+        After={SOCKET_UNIT} graphical-session.target\n\
+        \n\
+        [Service]\n\
+        Type=notify\n\
+        ExecStart={} start\n\
+        Restart=on-failure\n\
+        RestartSec=5\n\
+        SyslogIdentifier=whis\n\
+        \n\
+        [Install]\n\
+        WantedBy=default.target\n",
+        quote_exec(exe)
+    )
+}
+
+/// Generate the XDG autostart desktop entry running `whis start`
+fn desktop_entry(exe: &Path) -> String {
+    format!(
+        "[Desktop Entry]\n\
+        Type=Application\n\
+        Name=Whis\n\
+        Comment=Voice-to-text background service\n\
+        Exec={} start\n\
+        Terminal=false\n\
+        NoDisplay=true\n\
+        X-GNOME-Autostart-enabled=true\n",
+        quote_exec(exe)
+    )
+}
+
+/// Quote an executable path for `ExecStart=` / `Exec=` if it contains spaces
+fn quote_exec(exe: &Path) -> String {
+    let exe = exe.display().to_string();
+    if exe.contains(char::is_whitespace) {
+        format!("\"{}\"", exe.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        exe
+    }
+}
+
+fn systemd_unit_dir() -> Result<PathBuf> {
+    dirs::config_dir()
+        .map(|dir| dir.join("systemd").join("user"))
+        .ok_or_else(|| anyhow!("Could not determine config directory"))
+}
+
+fn autostart_path() -> Result<PathBuf> {
+    dirs::config_dir()
+        .map(|dir| dir.join("autostart").join(AUTOSTART_FILE))
+        .ok_or_else(|| anyhow!("Could not determine config directory"))
+}
+
+/// Check whether a systemd user manager is reachable
+fn has_systemd_user() -> bool {
+    Path::new("/run/systemd/system").exists() && systemctl(&["show-environment"])
+}
+
+/// Run `systemctl --user`, returning whether it succeeded
+fn systemctl(args: &[&str]) -> bool {
+    Command::new("systemctl")
+        .arg("--user")
+        .args(args)
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Run `systemctl --user`, failing with its stderr on error
+fn systemctl_checked(args: &[&str]) -> Result<()> {
+    let output = Command::new("systemctl")
+        .arg("--user")
+        .args(args)
+        .output()
+        .context("Failed to run systemctl")?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "systemctl --user {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+/// Run a `systemctl --user` query and return its first output line
+fn systemctl_query(args: &[&str]) -> String {
+    Command::new("systemctl")
+        .arg("--user")
+        .args(args)
+        .output()
+        .ok()
+        .and_then(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .next()
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| "unknown".to_string())
+}
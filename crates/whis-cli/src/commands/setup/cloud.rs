@@ -3,21 +3,37 @@
 //! Handles API key configuration for cloud providers:
 //! - OpenAI (standard + realtime streaming)
 //! - Deepgram (standard + realtime streaming)
+//! - AssemblyAI (standard + realtime streaming)
+//! - Speechmatics, Gladia (standard + realtime streaming, EU/US region)
 //! - Mistral, Groq, ElevenLabs
 //!
 //! # Flow
 //!
 //! 1. Select provider (with [configured] markers for existing keys)
-//! 2. Choose method (standard vs streaming) for OpenAI/Deepgram
-//! 3. Enter/confirm API key with format validation
-//! 4. Save to settings
+//! 2. Choose method (standard vs streaming) for providers with a realtime variant
+//! 3. Choose region (EU vs US) for providers with regional endpoints
+//! 4. Enter/confirm API key with format validation
+//! 5. Save to settings
 
 use anyhow::{Result, anyhow};
+use whis_core::settings::DataRegion;
 use whis_core::{Settings, TranscriptionProvider};
 
 use super::interactive;
 use super::provider_helpers::{api_key_url, cloud_providers, provider_description};
 
+/// Realtime (streaming) variant of a base cloud provider, if it has one
+fn realtime_variant(provider: &TranscriptionProvider) -> Option<TranscriptionProvider> {
+    match provider {
+        TranscriptionProvider::OpenAI => Some(TranscriptionProvider::OpenAIRealtime),
+        TranscriptionProvider::Deepgram => Some(TranscriptionProvider::DeepgramRealtime),
+        TranscriptionProvider::AssemblyAI => Some(TranscriptionProvider::AssemblyAIRealtime),
+        TranscriptionProvider::Speechmatics => Some(TranscriptionProvider::SpeechmaticsRealtime),
+        TranscriptionProvider::Gladia => Some(TranscriptionProvider::GladiaRealtime),
+        _ => None,
+    }
+}
+
 /// Prompt for streaming method selection (Standard vs Streaming)
 ///
 /// Returns the realtime variant if streaming selected, otherwise the base provider.
@@ -32,6 +48,18 @@ fn select_streaming_method(
     Ok(if choice == 1 { realtime } else { base })
 }
 
+/// Prompt for the processing region (EU vs US)
+fn select_region(current: DataRegion) -> Result<DataRegion> {
+    let regions = vec!["EU - Audio stays in the EU", "US"];
+    let default_region = if current == DataRegion::Us { 1 } else { 0 };
+    let choice = interactive::select("Which region?", &regions, Some(default_region))?;
+    Ok(if choice == 1 {
+        DataRegion::Us
+    } else {
+        DataRegion::Eu
+    })
+}
+
 /// Prompt for and validate an API key
 pub fn prompt_and_validate_key(provider: &TranscriptionProvider) -> Result<String> {
     // Validation loop with secure password input
@@ -87,10 +115,7 @@ pub fn setup_transcription_cloud() -> Result<()> {
             );
             // Check if this provider or its realtime variant is configured
             let marker = if settings.transcription.has_configured_api_key(provider)
-                || (*provider == TranscriptionProvider::OpenAI
-                    && settings.transcription.provider == TranscriptionProvider::OpenAIRealtime)
-                || (*provider == TranscriptionProvider::Deepgram
-                    && settings.transcription.provider == TranscriptionProvider::DeepgramRealtime)
+                || realtime_variant(provider).as_ref() == Some(&settings.transcription.provider)
             {
                 " [configured]"
             } else if settings.transcription.api_key_for(provider).is_some() {
@@ -109,10 +134,7 @@ pub fn setup_transcription_cloud() -> Result<()> {
     // Treat realtime variants same as base provider for default selection
     let default = providers.iter().position(|p| {
         *p == settings.transcription.provider
-            || (*p == TranscriptionProvider::OpenAI
-                && settings.transcription.provider == TranscriptionProvider::OpenAIRealtime)
-            || (*p == TranscriptionProvider::Deepgram
-                && settings.transcription.provider == TranscriptionProvider::DeepgramRealtime)
+            || realtime_variant(p).as_ref() == Some(&settings.transcription.provider)
     });
 
     // Fallback: if on local provider, find first cloud provider with configured API key
@@ -125,20 +147,16 @@ pub fn setup_transcription_cloud() -> Result<()> {
     let choice = interactive::select_clean("Which provider?", &items, &clean_items, default)?;
     let mut provider = providers[choice].clone();
 
-    // If OpenAI or Deepgram selected, ask for method (Standard vs Streaming)
-    provider = match provider {
-        TranscriptionProvider::OpenAI => select_streaming_method(
-            TranscriptionProvider::OpenAI,
-            TranscriptionProvider::OpenAIRealtime,
-            &settings.transcription.provider,
-        )?,
-        TranscriptionProvider::Deepgram => select_streaming_method(
-            TranscriptionProvider::Deepgram,
-            TranscriptionProvider::DeepgramRealtime,
-            &settings.transcription.provider,
-        )?,
-        _ => provider,
-    };
+    // If the provider has a realtime variant, ask for method (Standard vs Streaming)
+    if let Some(realtime) = realtime_variant(&provider) {
+        provider = select_streaming_method(provider, realtime, &settings.transcription.provider)?;
+    }
+
+    // Providers with regional endpoints: ask where audio is processed
+    if let Some(current) = settings.transcription.regions.get(&provider) {
+        let region = select_region(current)?;
+        settings.transcription.regions.set(&provider, region);
+    }
 
     // Check if API key already exists for this provider
     if let Some(existing_key) = settings.transcription.api_key_for(&provider) {
@@ -110,6 +110,15 @@ pub fn password(prompt: &str) -> Result<String> {
         .interact()?)
 }
 
+/// Get optional password/secret input (hidden, may be empty)
+pub fn password_optional(prompt: &str) -> Result<String> {
+    let theme = theme();
+    Ok(Password::with_theme(&theme)
+        .with_prompt(prompt)
+        .allow_empty_password(true)
+        .interact()?)
+}
+
 /// Print an error message
 pub fn error(text: &str) {
     eprintln!("{} {}", style("[!]").bold(), text);
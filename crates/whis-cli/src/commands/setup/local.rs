@@ -1,23 +1,27 @@
-//! Local (on-device) transcription setup
+//! Local (on-device or LAN) transcription setup
 //!
 //! Handles model selection and download for local transcription engines:
 //! - Parakeet (NVIDIA NeMo, fast, English-optimized)
 //! - Whisper (OpenAI, multilingual)
 //!
+//! Or points whis at a Wyoming ASR server on the local network.
+//!
 //! # Flow
 //!
-//! 1. Select engine (Parakeet/Whisper) with [current] marker
+//! 1. Select engine (Parakeet/Whisper/Wyoming) with [current] marker
 //! 2. Select model variant with [installed]/[current] markers
+//!    (Wyoming: server address and method instead)
 //! 3. Download model if not present
 //! 4. Save to settings
 
 use anyhow::Result;
+use whis_core::model::{ModelType, WhisperModel};
 use whis_core::{Settings, TranscriptionProvider, model};
 
 use super::interactive;
 
 #[cfg(feature = "local-transcription")]
-use whis_core::model::{ModelType, ParakeetModel, WhisperModel};
+use whis_core::model::ParakeetModel;
 
 /// Streamlined local transcription setup (no post-processing config)
 /// Used by the unified wizard
@@ -28,6 +32,7 @@ pub fn setup_transcription_local() -> Result<()> {
     let current_engine = match settings.transcription.provider {
         TranscriptionProvider::LocalParakeet => Some(1),
         TranscriptionProvider::LocalWhisper => Some(2),
+        TranscriptionProvider::Wyoming | TranscriptionProvider::WyomingRealtime => Some(3),
         _ => None,
     };
 
@@ -54,6 +59,17 @@ pub fn setup_transcription_local() -> Result<()> {
             ),
             "Whisper".to_string(),
         ),
+        (
+            format!(
+                "Wyoming server{}",
+                if current_engine == Some(3) {
+                    " [current]"
+                } else {
+                    ""
+                }
+            ),
+            "Wyoming server".to_string(),
+        ),
     ]
     .into_iter()
     .unzip();
@@ -66,7 +82,16 @@ pub fn setup_transcription_local() -> Result<()> {
         Some(default),
     )? + 1;
 
+    if engine_choice == 3 {
+        return setup_wyoming(settings);
+    }
+
     let (provider, model_path) = match engine_choice {
+        #[cfg(not(feature = "local-transcription"))]
+        1 => anyhow::bail!(
+            "Parakeet models require the 'local-transcription' feature (not enabled in this build)"
+        ),
+        #[cfg(feature = "local-transcription")]
         1 => {
             // Parakeet - show model options (matching Whisper pattern)
 
@@ -143,6 +168,9 @@ pub fn setup_transcription_local() -> Result<()> {
                         .map(|m| m.name)
                 });
 
+            // Recommend a model based on available RAM and CPU cores
+            let recommended = crate::commands::model::recommended_whisper_model(&settings);
+
             // Build selection items with markers, clean items without
             let (items, clean_items): (Vec<String>, Vec<String>) = WhisperModel
                 .models()
@@ -159,20 +187,26 @@ pub fn setup_transcription_local() -> Result<()> {
                     } else {
                         ""
                     };
+                    let hint = if model.name == recommended {
+                        " (recommended)"
+                    } else {
+                        ""
+                    };
                     (
                         format!(
-                            "{} - {}{}{}",
-                            model.name, model.description, installed, current
+                            "{} - {}{}{}{}",
+                            model.name, model.description, hint, installed, current
                         ),
                         model.name.to_string(),
                     )
                 })
                 .unzip();
 
-            // Default to current model or "small" (index 2)
+            // Default to current model or the recommended one
             let default_idx = current_model
+                .or(Some(recommended))
                 .and_then(|name| WhisperModel.models().iter().position(|m| m.name == name))
-                .unwrap_or(2);
+                .unwrap_or(0);
 
             let model_choice = interactive::select_clean(
                 "Which Whisper model?",
@@ -210,3 +244,44 @@ pub fn setup_transcription_local() -> Result<()> {
 
     Ok(())
 }
+
+/// Wyoming server setup: server address, then method (standard vs streaming)
+fn setup_wyoming(mut settings: Settings) -> Result<()> {
+    let current = settings
+        .transcription
+        .wyoming
+        .url
+        .clone()
+        .unwrap_or_else(|| "tcp://localhost:10300".to_string());
+
+    let url = loop {
+        let input = interactive::input("Wyoming server?", Some(&current))?;
+        if input.trim().is_empty() {
+            interactive::error("Server address cannot be empty");
+            continue;
+        }
+        break input.trim().to_string();
+    };
+
+    let methods = vec![
+        "Standard - Send after recording",
+        "Streaming - Send while recording",
+    ];
+    let default_method =
+        if settings.transcription.provider == TranscriptionProvider::WyomingRealtime {
+            1
+        } else {
+            0
+        };
+    let choice = interactive::select("Which method?", &methods, Some(default_method))?;
+
+    settings.transcription.wyoming.url = Some(url);
+    settings.transcription.provider = if choice == 1 {
+        TranscriptionProvider::WyomingRealtime
+    } else {
+        TranscriptionProvider::Wyoming
+    };
+    settings.save()?;
+
+    Ok(())
+}
@@ -27,7 +27,7 @@
 //! # Sub-modules
 //!
 //! - `cloud` - Cloud provider API key setup
-//! - `local` - Local model (Whisper/Parakeet) selection
+//! - `local` - Local model (Whisper/Parakeet) selection or Wyoming server
 //! - `post_processing` - Ollama or cloud LLM configuration
 //! - `interactive` - UI helpers (prompts, selection menus)
 //! - `provider_helpers` - Provider metadata (URLs, descriptions)
@@ -54,7 +54,10 @@ fn setup_wizard() -> Result<()> {
 
     // Default to current provider type (Local if using local, else Cloud)
     let default = match settings.transcription.provider {
-        TranscriptionProvider::LocalParakeet | TranscriptionProvider::LocalWhisper => 1,
+        TranscriptionProvider::LocalParakeet
+        | TranscriptionProvider::LocalWhisper
+        | TranscriptionProvider::Wyoming
+        | TranscriptionProvider::WyomingRealtime => 1,
         _ => 0,
     };
 
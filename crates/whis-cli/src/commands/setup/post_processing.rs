@@ -1,4 +1,4 @@
-//! Post-processing setup (Ollama, OpenAI, Mistral)
+//! Post-processing setup (OpenAI, Mistral, Groq, Anthropic, Ollama, custom endpoint)
 
 use anyhow::{Result, anyhow};
 use std::io::Write;
@@ -6,15 +6,11 @@ use whis_core::{PostProcessor, Settings, TranscriptionProvider, ollama};
 
 use super::cloud::prompt_and_validate_key;
 use super::interactive;
-use super::provider_helpers::{PP_PROVIDERS, api_key_url};
+use super::provider_helpers::{CUSTOM_ENDPOINTS, PP_PROVIDERS, pp_api_key_url};
 
 /// Display a progress bar for ollama model pulls (with bracket notation prefix)
 fn display_progress(downloaded: u64, total: u64) {
-    let progress = if total > 0 {
-        (downloaded * 100 / total) as usize
-    } else {
-        0
-    };
+    let progress = (downloaded * 100).checked_div(total).unwrap_or(0) as usize;
 
     let bar_width = 20;
     let filled = (bar_width * progress) / 100;
@@ -209,12 +205,16 @@ pub fn setup_post_processing_step(_prefer_cloud: bool) -> Result<()> {
 
     // Default to current processor setting
     let default = match settings.post_processing.processor {
-        PostProcessor::OpenAI | PostProcessor::Mistral => 0, // Cloud
+        PostProcessor::OpenAI
+        | PostProcessor::Mistral
+        | PostProcessor::Groq
+        | PostProcessor::Anthropic => 0, // Cloud
         PostProcessor::Ollama => 1,
-        PostProcessor::None => 2, // Skip
+        PostProcessor::Custom => 2,
+        PostProcessor::None => 3, // Skip
     };
 
-    let options = vec!["Cloud", "Ollama", "Skip"];
+    let options = vec!["Cloud", "Ollama", "Custom (OpenAI-compatible)", "Skip"];
     let choice = interactive::select("Configure post-processing?", &options, Some(default))?;
 
     match choice {
@@ -243,7 +243,8 @@ pub fn setup_post_processing_step(_prefer_cloud: bool) -> Result<()> {
             settings.services.ollama.url = Some(ollama_url.to_string());
             settings.services.ollama.model = Some(model);
         }
-        2 => {
+        2 => setup_custom_post_processing(&mut settings)?,
+        3 => {
             settings.post_processing.processor = PostProcessor::None;
         }
         _ => unreachable!(),
@@ -253,72 +254,181 @@ pub fn setup_post_processing_step(_prefer_cloud: bool) -> Result<()> {
     Ok(())
 }
 
-/// Setup cloud post-processing (OpenAI or Mistral)
+/// Setup cloud post-processing (OpenAI, Mistral, Groq or Anthropic)
 fn setup_cloud_post_processing(settings: &mut Settings) -> Result<()> {
     // Build provider items: with markers for selection, clean for confirmation
     let (items, clean_items): (Vec<String>, Vec<String>) = PP_PROVIDERS
         .iter()
-        .map(|provider| {
-            let base = provider.display_name().to_string();
-            let marker = if settings.transcription.has_configured_api_key(provider) {
-                " [configured]"
-            } else if settings.transcription.api_key_for(provider).is_some() {
-                " [available]"
-            } else {
-                ""
+        .map(|processor| {
+            let base = processor.display_name().to_string();
+            let marker = match existing_key(settings, processor) {
+                Some((_, true)) => " [configured]",
+                Some((_, false)) => " [available]",
+                None => "",
             };
             (format!("{}{}", base, marker), base)
         })
         .unzip();
 
-    // Default to current processor if it matches a PP provider
-    let default = match settings.post_processing.processor {
-        PostProcessor::OpenAI => PP_PROVIDERS
-            .iter()
-            .position(|p| *p == TranscriptionProvider::OpenAI),
-        PostProcessor::Mistral => PP_PROVIDERS
-            .iter()
-            .position(|p| *p == TranscriptionProvider::Mistral),
-        _ => Some(0),
-    };
+    // Default to current processor if it is a cloud post-processor
+    let default = PP_PROVIDERS
+        .iter()
+        .position(|p| *p == settings.post_processing.processor)
+        .or(Some(0));
 
     let choice = interactive::select_clean("Which provider?", &items, &clean_items, default)?;
-    let provider = PP_PROVIDERS[choice].clone();
+    let processor = PP_PROVIDERS[choice].clone();
+    let key_name = processor
+        .api_key_name()
+        .expect("cloud post-processors have an API key");
 
     // Check if API key already exists
-    if let Some(existing_key) = settings.transcription.api_key_for(&provider) {
-        let is_configured = settings.transcription.has_configured_api_key(&provider);
-
+    let api_key = if let Some((existing_key, is_configured)) = existing_key(settings, &processor) {
         let keep = interactive::select("Keep current key?", &["Yes", "No"], Some(0))? == 0;
 
         if keep {
             // If key is env-only, save it to settings
             if !is_configured {
-                settings.transcription.set_api_key(&provider, existing_key);
                 interactive::info("API key saved to settings");
             }
+            existing_key
         } else {
             interactive::info(&format!(
                 "Get your API key from: {}",
-                api_key_url(&provider)
+                pp_api_key_url(&processor)
             ));
-            let api_key = prompt_and_validate_key(&provider)?;
-            settings.transcription.set_api_key(&provider, api_key);
+            prompt_post_processor_key(&processor)?
         }
     } else {
         interactive::info(&format!(
             "Get your API key from: {}",
-            api_key_url(&provider)
+            pp_api_key_url(&processor)
         ));
-        let api_key = prompt_and_validate_key(&provider)?;
-        settings.transcription.set_api_key(&provider, api_key);
+        prompt_post_processor_key(&processor)?
+    };
+
+    settings
+        .transcription
+        .api_keys
+        .insert(key_name.to_string(), api_key);
+    settings.post_processing.processor = processor;
+
+    Ok(())
+}
+
+/// Existing API key for a cloud post-processor and whether it is saved in settings
+/// (false when it only comes from the environment)
+fn existing_key(settings: &Settings, processor: &PostProcessor) -> Option<(String, bool)> {
+    let saved = processor
+        .api_key_name()
+        .and_then(|name| settings.transcription.api_keys.get(name))
+        .filter(|key| !key.is_empty());
+
+    match saved {
+        Some(key) => Some((key.clone(), true)),
+        None => processor
+            .api_key_env_var()
+            .and_then(|var| std::env::var(var).ok())
+            .map(|key| (key, false)),
     }
+}
 
-    settings.post_processing.processor = match provider {
-        TranscriptionProvider::OpenAI => PostProcessor::OpenAI,
-        TranscriptionProvider::Mistral => PostProcessor::Mistral,
-        _ => unreachable!(),
+/// Prompt for a post-processor API key
+///
+/// Providers that also transcribe reuse the transcription key validation.
+fn prompt_post_processor_key(processor: &PostProcessor) -> Result<String> {
+    if let Some(provider) = processor
+        .api_key_name()
+        .and_then(|name| name.parse::<TranscriptionProvider>().ok())
+    {
+        return prompt_and_validate_key(&provider);
+    }
+
+    loop {
+        let api_key = interactive::password(&format!("{} API key", processor.display_name()))?;
+
+        if *processor == PostProcessor::Anthropic && !api_key.starts_with("sk-ant-") {
+            interactive::error("Invalid Anthropic key format. Keys start with 'sk-ant-'");
+        } else if api_key.len() < 20 {
+            interactive::error("API key seems too short");
+        } else {
+            return Ok(api_key);
+        }
+    }
+}
+
+/// Setup a custom OpenAI-compatible endpoint (OpenRouter, LM Studio, llama.cpp, vLLM)
+fn setup_custom_post_processing(settings: &mut Settings) -> Result<()> {
+    let current = settings.post_processing.custom.clone();
+
+    let items: Vec<&str> = CUSTOM_ENDPOINTS.iter().map(|(name, _, _)| *name).collect();
+    // Default to the known server matching the current URL, otherwise "Other"
+    let default = current
+        .base_url
+        .as_deref()
+        .and_then(|url| {
+            CUSTOM_ENDPOINTS
+                .iter()
+                .position(|(_, base, _)| !base.is_empty() && url == *base)
+        })
+        .or(if current.base_url.is_some() {
+            Some(CUSTOM_ENDPOINTS.len() - 1)
+        } else {
+            Some(0)
+        });
+
+    let choice = interactive::select("Which server?", &items, default)?;
+    let (_, known_url, needs_key) = CUSTOM_ENDPOINTS[choice];
+
+    // Base URL: keep the current one when re-running setup for the same server
+    let url_default = match current.base_url.as_deref() {
+        Some(url) if known_url.is_empty() || url == known_url => url,
+        _ => known_url,
+    };
+    let base_url = loop {
+        let url = interactive::input(
+            "Base URL (e.g., http://localhost:1234/v1)",
+            Some(url_default).filter(|u| !u.is_empty()),
+        )?;
+        let url = url.trim().to_string();
+
+        if url.starts_with("http://") || url.starts_with("https://") {
+            break url;
+        }
+        interactive::error("URL must start with http:// or https://");
     };
 
+    let model = loop {
+        let model = interactive::input("Model name", current.model.as_deref())?;
+        let model = model.trim().to_string();
+
+        if !model.is_empty() {
+            break model;
+        }
+        interactive::error("Model name cannot be empty");
+    };
+
+    let api_key = if needs_key {
+        loop {
+            let key = interactive::password("API key")?;
+            if !key.is_empty() {
+                break Some(key);
+            }
+            interactive::error("This server requires an API key");
+        }
+    } else {
+        let key = interactive::password_optional("API key (leave empty if none)")?;
+        Some(key).filter(|k| !k.is_empty())
+    };
+
+    settings.post_processing.custom.base_url = Some(base_url);
+    settings.post_processing.custom.model = Some(model);
+    settings.post_processing.custom.api_key = api_key;
+    settings.post_processing.processor = PostProcessor::Custom;
+
+    interactive::info(
+        "Extra request params (e.g. temperature) can be set with: whis config custom-llm-params '{\"temperature\": 0.2}'",
+    );
+
     Ok(())
 }
@@ -1,6 +1,6 @@
 //! Provider-related constants and helpers for setup
 
-use whis_core::TranscriptionProvider;
+use whis_core::{PostProcessor, TranscriptionProvider};
 
 /// Get cloud providers for setup wizard (ordered by recommendation from whis-core).
 ///
@@ -11,17 +11,37 @@ pub fn cloud_providers() -> Vec<TranscriptionProvider> {
 }
 
 /// Cloud post-processing providers
-pub const PP_PROVIDERS: &[TranscriptionProvider] = &[
-    TranscriptionProvider::OpenAI,
-    TranscriptionProvider::Mistral,
+pub const PP_PROVIDERS: &[PostProcessor] = &[
+    PostProcessor::OpenAI,
+    PostProcessor::Mistral,
+    PostProcessor::Groq,
+    PostProcessor::Anthropic,
+];
+
+/// Known OpenAI-compatible servers for the custom post-processor:
+/// (name, default base URL, requires API key)
+pub const CUSTOM_ENDPOINTS: &[(&str, &str, bool)] = &[
+    ("OpenRouter", "https://openrouter.ai/api/v1", true),
+    (
+        "Anthropic (OpenAI-compatible)",
+        "https://api.anthropic.com/v1",
+        true,
+    ),
+    ("LM Studio", "http://localhost:1234/v1", false),
+    ("llama.cpp server", "http://localhost:8080/v1", false),
+    ("vLLM", "http://localhost:8000/v1", false),
+    ("Other", "", false),
 ];
 
 /// Provider descriptions for display
 pub fn provider_description(provider: &TranscriptionProvider) -> &'static str {
     match provider {
-        TranscriptionProvider::OpenAIRealtime | TranscriptionProvider::DeepgramRealtime => {
-            "Streaming"
-        }
+        TranscriptionProvider::OpenAIRealtime
+        | TranscriptionProvider::DeepgramRealtime
+        | TranscriptionProvider::AssemblyAIRealtime
+        | TranscriptionProvider::SpeechmaticsRealtime
+        | TranscriptionProvider::GladiaRealtime => "Streaming",
+        TranscriptionProvider::Speechmatics | TranscriptionProvider::Gladia => "EU or US region",
         _ => "",
     }
 }
@@ -38,6 +58,26 @@ pub fn api_key_url(provider: &TranscriptionProvider) -> &'static str {
             "https://console.deepgram.com"
         }
         TranscriptionProvider::ElevenLabs => "https://elevenlabs.io/app/settings/api-keys",
+        TranscriptionProvider::AssemblyAI | TranscriptionProvider::AssemblyAIRealtime => {
+            "https://www.assemblyai.com/app/api-keys"
+        }
+        TranscriptionProvider::Speechmatics | TranscriptionProvider::SpeechmaticsRealtime => {
+            "https://portal.speechmatics.com/settings/api-keys"
+        }
+        TranscriptionProvider::Gladia | TranscriptionProvider::GladiaRealtime => {
+            "https://app.gladia.io/apikeys"
+        }
         _ => "",
     }
 }
+
+/// Get the API key URL for a cloud post-processor
+pub fn pp_api_key_url(processor: &PostProcessor) -> &'static str {
+    match processor {
+        PostProcessor::Anthropic => "https://console.anthropic.com/settings/keys",
+        processor => processor
+            .api_key_name()
+            .and_then(|name| name.parse::<TranscriptionProvider>().ok())
+            .map_or("", |provider| api_key_url(&provider)),
+    }
+}
@@ -1,16 +1,23 @@
-use crate::{app, hotkey, ipc, service};
+use crate::{app, hotkey, ipc, service, systemd};
 use anyhow::Result;
 use whis_core::Settings;
 use whis_core::settings::CliShortcutMode;
 
 pub fn run() -> Result<()> {
-    // Check if service is already running
-    if ipc::is_service_running() {
+    // Check if service is already running (a systemd-activated socket is ours)
+    if !systemd::is_socket_activated() && ipc::is_service_running() {
         eprintln!("Error: whis service is already running.");
         eprintln!("Use 'whis stop' to stop the existing service first.");
         std::process::exit(1);
     }
 
+    // Starting a second copy would replace the socket systemd listens on
+    if !systemd::is_socket_activated() && systemd::socket_unit_active() {
+        eprintln!("Error: whis is installed as a systemd user service.");
+        eprintln!("Start it with 'whis toggle' or 'systemctl --user start whis'.");
+        std::process::exit(1);
+    }
+
     // Load settings and transcription configuration
     let settings = Settings::load();
     let config = app::load_transcription_config()?;
@@ -42,13 +49,10 @@ pub fn run() -> Result<()> {
                     }
 
                     runtime.block_on(async {
-                        let service = service::Service::new(config)?;
+                        let service = std::sync::Arc::new(service::Service::new(config)?);
                         tokio::select! {
                             result = service.run(Some(hotkey_rx), push_to_talk) => result,
-                            _ = tokio::signal::ctrl_c() => {
-                                println!("\nShutting down...");
-                                Ok(())
-                            }
+                            _ = shutdown_signal() => Ok(()),
                         }
                     })
                 }
@@ -70,15 +74,46 @@ pub fn run() -> Result<()> {
             println!("Listening. Press your configured shortcut to record. Ctrl+C to stop.");
 
             runtime.block_on(async {
-                let service = service::Service::new(config)?;
+                let service = std::sync::Arc::new(service::Service::new(config)?);
                 tokio::select! {
                     result = service.run(None, false) => result,
-                    _ = tokio::signal::ctrl_c() => {
-                        println!("\nShutting down...");
-                        Ok(())
-                    }
+                    _ = shutdown_signal() => Ok(()),
                 }
             })
         }
     }
 }
+
+/// Wait for Ctrl+C or SIGTERM (sent by `systemctl stop`)
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = sigterm.recv() => {}
+                }
+            }
+            Err(_) => {
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+
+    systemd::notify_stopping();
+
+    // Under systemd every line goes to the journal, skip the terminal spacing
+    if systemd::is_under_systemd() {
+        println!("Shutting down...");
+    } else {
+        println!("\nShutting down...");
+    }
+}
@@ -1,10 +1,14 @@
-use crate::ipc;
+use crate::{ipc, systemd};
 use anyhow::Result;
 
 pub fn run() -> Result<()> {
     if !ipc::is_service_running() {
         println!("Status: Not running");
-        println!("Start with: whis start");
+        if systemd::socket_unit_active() {
+            println!("Starts on the next 'whis toggle' (systemd socket activation)");
+        } else {
+            println!("Start with: whis start");
+        }
         return Ok(());
     }
 
@@ -2,6 +2,12 @@ use crate::ipc;
 use anyhow::Result;
 
 pub fn run() -> Result<()> {
+    // Checked first: connecting to a systemd-owned socket would start the service
+    if !ipc::is_service_running() {
+        println!("Service is not running");
+        return Ok(());
+    }
+
     let mut client = ipc::IpcClient::connect()?;
     let _ = client.send_message(ipc::IpcMessage::Stop)?;
     println!("Service stopped");
@@ -33,6 +33,9 @@ pub fn display_error(err: &WhisError) {
                 "Groq" => eprintln!("  https://console.groq.com/keys"),
                 "Deepgram" => eprintln!("  https://console.deepgram.com/"),
                 "ElevenLabs" => eprintln!("  https://elevenlabs.io/app/settings/api-keys"),
+                "AssemblyAI" => eprintln!("  https://www.assemblyai.com/app/api-keys"),
+                "Speechmatics" => eprintln!("  https://portal.speechmatics.com/settings/api-keys"),
+                "Gladia" => eprintln!("  https://app.gladia.io/apikeys"),
                 _ => eprintln!("  (Check your provider's console)"),
             }
         }
@@ -8,6 +8,9 @@
 //! - Unix: Domain socket at `$XDG_RUNTIME_DIR/whis.sock` (fallback: `/tmp/whis.sock`)
 //! - Windows: Named pipe `whis`
 //!
+//! When started by systemd with socket activation (`whis service install`),
+//! the server adopts the already-bound socket instead of creating its own.
+//!
 //! # Messages
 //!
 //! - `Stop` → Terminate the service
@@ -46,26 +49,43 @@ pub enum IpcResponse {
 
 /// Get the socket name for IPC communication
 #[cfg(unix)]
-fn socket_name() -> String {
+pub fn socket_name() -> String {
     std::env::var("XDG_RUNTIME_DIR")
         .map(|dir| format!("{dir}/whis.sock"))
         .unwrap_or_else(|_| "/tmp/whis.sock".to_string())
 }
 
 #[cfg(windows)]
-fn socket_name() -> String {
+pub fn socket_name() -> String {
     "whis".to_string()
 }
 
 /// IPC Server for the background service
 pub struct IpcServer {
     listener: LocalSocketListener,
+    /// Socket file to remove on drop (None when the socket is owned by systemd)
     #[cfg(unix)]
-    socket_path: PathBuf,
+    socket_path: Option<PathBuf>,
 }
 
 impl IpcServer {
     pub fn new() -> Result<Self> {
+        // Adopt the socket passed by systemd (socket activation)
+        #[cfg(unix)]
+        if let Some(fd) = crate::systemd::take_listen_fd() {
+            use interprocess::os::unix::uds_local_socket;
+
+            let listener = LocalSocketListener::from(uds_local_socket::Listener::from(fd));
+            listener
+                .set_nonblocking(ListenerNonblockingMode::Both)
+                .context("Failed to set non-blocking mode")?;
+
+            return Ok(Self {
+                listener,
+                socket_path: None,
+            });
+        }
+
         let name_str = socket_name();
 
         // On Unix, save socket path for cleanup and remove old socket if it exists
@@ -93,7 +113,7 @@ impl IpcServer {
         Ok(Self {
             listener,
             #[cfg(unix)]
-            socket_path,
+            socket_path: Some(socket_path),
         })
     }
 
@@ -109,10 +129,10 @@ impl IpcServer {
 
 impl Drop for IpcServer {
     fn drop(&mut self) {
-        // On Unix, clean up the socket file
+        // On Unix, clean up the socket file (systemd cleans up its own)
         #[cfg(unix)]
-        {
-            let _ = std::fs::remove_file(&self.socket_path);
+        if let Some(path) = &self.socket_path {
+            let _ = std::fs::remove_file(path);
         }
         // On Windows, named pipes are cleaned up automatically by the OS
     }
@@ -205,6 +225,10 @@ impl IpcClient {
 }
 
 /// Check if the service is already running
+///
+/// While systemd owns the socket (`whis service install`), connecting would
+/// start the service, so systemd is asked for the unit state instead. That
+/// only happens when the socket exists and the socket unit is installed.
 pub fn is_service_running() -> bool {
     let name_str = socket_name();
 
@@ -217,6 +241,10 @@ pub fn is_service_running() -> bool {
         return false;
     }
 
+    if crate::systemd::socket_unit_installed() && crate::systemd::socket_unit_active() {
+        return crate::systemd::service_unit_active();
+    }
+
     // Try to connect to check if service is actually running
     let name = match name_str.to_fs_name::<GenericFilePath>() {
         Ok(n) => n,
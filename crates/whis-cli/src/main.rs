@@ -5,6 +5,7 @@ mod error;
 mod hotkey;
 mod ipc;
 mod service;
+mod systemd;
 mod ui;
 
 use anyhow::Result;
@@ -39,7 +40,9 @@ fn run() -> Result<()> {
         }) => commands::config::run(key, value, list, path),
         Some(args::Commands::Preset { action }) => commands::preset::run(action),
         Some(args::Commands::Setup) => commands::setup::run(),
+        Some(args::Commands::Dict { action }) => commands::dict::run(action),
         Some(args::Commands::Model { action }) => commands::model::run(action),
+        Some(args::Commands::Service { action }) => commands::service::run(action),
         None => {
             // Microphone recording or file transcription
             let config =
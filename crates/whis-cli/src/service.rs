@@ -28,8 +28,12 @@
 //! # Architecture
 //!
 //! - Polling loop checks IPC server + hotkey channel (non-blocking)
+//! - Optional HTTP API (`http-api` feature) forwards commands into the same loop
+//! - D-Bus `ink.whis.Recorder` object (`dbus` feature, Linux) does the same
 //! - Progressive transcription: audio chunks sent during recording
-//! - Post-processing and clipboard copy on completion
+//!   (local models stream utterances instead when `local-streaming` is on)
+//! - Transcription, post-processing and clipboard copy run in a spawned task,
+//!   so the loop stays responsive; commands are rejected while it runs
 
 use anyhow::{Context, Result};
 use std::sync::mpsc::Receiver;
@@ -37,12 +41,13 @@ use std::sync::{Arc, Mutex};
 use tokio::time::sleep;
 
 use crate::app::TranscriptionConfig;
+use crate::commands::record::{self, ProcessingConfig};
 use crate::hotkey::HotkeyEvent;
 use crate::ipc::{IpcMessage, IpcResponse, IpcServer};
 use std::time::Duration;
 use whis_core::{
-    AudioRecorder, DEFAULT_POST_PROCESSING_PROMPT, Settings, TranscriptionProvider,
-    copy_to_clipboard, post_process,
+    AudioRecorder, PostProcessor, Preset, Settings, TranscriptionProvider, TranscriptionResult,
+    copy_to_clipboard,
 };
 
 // Type aliases to reduce complexity warnings
@@ -55,16 +60,32 @@ enum ServiceState {
     Transcribing,
 }
 
+impl From<ServiceState> for whis_core::RecordingState {
+    fn from(state: ServiceState) -> Self {
+        match state {
+            ServiceState::Idle => Self::Idle,
+            ServiceState::Recording => Self::Recording,
+            ServiceState::Transcribing => Self::Transcribing,
+        }
+    }
+}
+
 pub struct Service {
     state: Arc<Mutex<ServiceState>>,
     recorder: Arc<Mutex<Option<AudioRecorder>>>,
     // Store handles for background tasks (progressive transcription)
     chunker_handle: TaskHandle<Result<(), String>>,
-    transcription_handle: TaskHandle<Result<String>>,
+    transcription_handle: TaskHandle<Result<TranscriptionResult>>,
     provider: TranscriptionProvider,
     api_key: String,
     language: Option<String>,
     recording_counter: Arc<Mutex<u32>>,
+    /// HTTP API handle for reporting state and transcripts (if enabled)
+    #[cfg(feature = "http-api")]
+    http_api: std::sync::OnceLock<whis_core::http_api::ApiHandle>,
+    /// D-Bus handle for publishing state, preset and transcripts (Linux)
+    #[cfg(all(feature = "dbus", target_os = "linux"))]
+    dbus: std::sync::OnceLock<whis_core::dbus::DbusHandle>,
 }
 
 impl Service {
@@ -78,18 +99,33 @@ impl Service {
             api_key: config.api_key,
             language: config.language,
             recording_counter: Arc::new(Mutex::new(0)),
+            #[cfg(feature = "http-api")]
+            http_api: std::sync::OnceLock::new(),
+            #[cfg(all(feature = "dbus", target_os = "linux"))]
+            dbus: std::sync::OnceLock::new(),
         })
     }
 
     /// Run the service main loop
     pub async fn run(
-        &self,
+        self: &Arc<Self>,
         hotkey_rx: Option<Receiver<HotkeyEvent>>,
         push_to_talk: bool,
     ) -> Result<()> {
         // Create IPC server
         let ipc_server = IpcServer::new().context("Failed to create IPC server")?;
 
+        // Start the local HTTP API if enabled in settings
+        #[cfg(feature = "http-api")]
+        let mut api_rx = self.start_http_api().await;
+
+        // Serve the D-Bus recorder object on the session bus
+        #[cfg(all(feature = "dbus", target_os = "linux"))]
+        let mut dbus_rx = self.start_dbus().await;
+
+        // Signal readiness when running as a systemd unit (Type=notify)
+        crate::systemd::notify_ready();
+
         // Configure model caching for local transcription in listen mode
         // This respects the user's model_memory settings for speed vs memory tradeoff
         #[cfg(feature = "local-transcription")]
@@ -108,31 +144,52 @@ impl Service {
                         let _ = conn.send(response);
                     }
                     Err(e) => {
-                        eprintln!("Error receiving message: {e}");
+                        eprintln!(
+                            "{}Error receiving message: {e}",
+                            crate::systemd::log_prefix(crate::systemd::WARNING)
+                        );
                         let _ = conn.send(IpcResponse::Error(e.to_string()));
                     }
                 }
             }
 
+            // Check for HTTP API commands (non-blocking)
+            #[cfg(feature = "http-api")]
+            if let Some(rx) = api_rx.as_mut()
+                && let Ok(request) = rx.try_recv()
+            {
+                let result = self.handle_remote_command(request.command).await;
+                let _ = request.reply.send(result);
+            }
+
+            // Check for D-Bus method calls (non-blocking)
+            #[cfg(all(feature = "dbus", target_os = "linux"))]
+            if let Some(rx) = dbus_rx.as_mut()
+                && let Ok(request) = rx.try_recv()
+            {
+                let result = self.handle_remote_command(request.command).await;
+                let _ = request.reply.send(result);
+            }
+
             // Check for hotkey events
-            if let Some(ref rx) = hotkey_rx {
-                if let Ok(event) = rx.try_recv() {
-                    if push_to_talk {
-                        // Push-to-talk mode: press starts, release stops
-                        match event {
-                            HotkeyEvent::Pressed => {
-                                self.handle_start().await;
-                            }
-                            HotkeyEvent::Released => {
-                                self.handle_stop().await;
-                            }
+            if let Some(ref rx) = hotkey_rx
+                && let Ok(event) = rx.try_recv()
+            {
+                if push_to_talk {
+                    // Push-to-talk mode: press starts, release stops
+                    match event {
+                        HotkeyEvent::Pressed => {
+                            self.handle_start().await;
                         }
-                    } else {
-                        // Toggle mode: only respond to press events
-                        if event == HotkeyEvent::Pressed {
-                            self.handle_toggle().await;
+                        HotkeyEvent::Released => {
+                            self.handle_stop();
                         }
                     }
+                } else {
+                    // Toggle mode: only respond to press events
+                    if event == HotkeyEvent::Pressed {
+                        self.handle_toggle().await;
+                    }
                 }
             }
 
@@ -142,7 +199,7 @@ impl Service {
     }
 
     /// Handle an IPC message
-    async fn handle_message(&self, message: IpcMessage) -> IpcResponse {
+    async fn handle_message(self: &Arc<Self>, message: IpcMessage) -> IpcResponse {
         match message {
             IpcMessage::Toggle => self.handle_toggle().await,
             IpcMessage::Stop => {
@@ -165,8 +222,107 @@ impl Service {
         }
     }
 
+    /// Start the HTTP API server if enabled, returning its command receiver
+    #[cfg(feature = "http-api")]
+    async fn start_http_api(
+        &self,
+    ) -> Option<tokio::sync::mpsc::Receiver<whis_core::http_api::ApiRequest>> {
+        let settings = Settings::load();
+        let config = settings.services.http_api;
+        if !config.enabled {
+            return None;
+        }
+
+        match whis_core::http_api::start_server(&config, settings.transcription).await {
+            Ok((handle, rx)) => {
+                println!("HTTP API listening on http://127.0.0.1:{}", config.port);
+                let _ = self.http_api.set(handle);
+                Some(rx)
+            }
+            Err(e) => {
+                eprintln!(
+                    "{}HTTP API disabled: {e}",
+                    crate::systemd::log_prefix(crate::systemd::WARNING)
+                );
+                None
+            }
+        }
+    }
+
+    /// Serve the D-Bus recorder object, returning its command receiver
+    #[cfg(all(feature = "dbus", target_os = "linux"))]
+    async fn start_dbus(
+        &self,
+    ) -> Option<tokio::sync::mpsc::Receiver<whis_core::dbus::DbusRequest>> {
+        let active_preset = Settings::load().ui.active_preset;
+
+        match whis_core::dbus::start_service(active_preset.as_deref()).await {
+            Ok((handle, rx)) => {
+                whis_core::verbose!(
+                    "D-Bus interface registered as {}",
+                    whis_core::dbus::BUS_NAME
+                );
+                let _ = self.dbus.set(handle);
+                Some(rx)
+            }
+            Err(e) => {
+                eprintln!(
+                    "{}D-Bus interface disabled: {e}",
+                    crate::systemd::log_prefix(crate::systemd::WARNING)
+                );
+                None
+            }
+        }
+    }
+
+    /// Handle a command received through the HTTP API or over D-Bus
+    #[cfg(any(feature = "http-api", all(feature = "dbus", target_os = "linux")))]
+    async fn handle_remote_command(
+        self: &Arc<Self>,
+        command: whis_core::RemoteCommand,
+    ) -> std::result::Result<(), String> {
+        use whis_core::RemoteAction;
+
+        let current_state = *self.state.lock().unwrap();
+        let response = match command.resolve(current_state.into())? {
+            RemoteAction::Toggle => self.handle_toggle().await,
+            RemoteAction::Cancel => self.handle_cancel(),
+        };
+
+        match response {
+            IpcResponse::Error(e) => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    /// Update the service state and notify HTTP API and D-Bus subscribers
+    fn set_state(&self, state: ServiceState) {
+        *self.state.lock().unwrap() = state;
+
+        #[cfg(feature = "http-api")]
+        if let Some(api) = self.http_api.get() {
+            api.set_state(state.into());
+        }
+
+        #[cfg(all(feature = "dbus", target_os = "linux"))]
+        if let Some(dbus) = self.dbus.get() {
+            dbus.set_state(state.into());
+        }
+    }
+
+    /// Notify HTTP API subscribers of a failed recording or transcription
+    fn report_error(&self, message: &str) {
+        #[cfg(feature = "http-api")]
+        if let Some(api) = self.http_api.get() {
+            api.report_error(message);
+        }
+
+        #[cfg(not(feature = "http-api"))]
+        let _ = message;
+    }
+
     /// Handle toggle command (start/stop recording)
-    async fn handle_toggle(&self) -> IpcResponse {
+    async fn handle_toggle(self: &Arc<Self>) -> IpcResponse {
         let current_state = *self.state.lock().unwrap();
 
         match current_state {
@@ -183,31 +339,19 @@ impl Service {
                         IpcResponse::Recording
                     }
                     Err(e) => {
-                        println!("#{count} error: {e}");
+                        eprintln!(
+                            "{}#{count} error: {e}",
+                            crate::systemd::log_prefix(crate::systemd::ERR)
+                        );
+                        self.report_error(&e.to_string());
                         IpcResponse::Error(e.to_string())
                     }
                 }
             }
             ServiceState::Recording => {
-                // Stop recording and transcribe
-                *self.state.lock().unwrap() = ServiceState::Transcribing;
-                let count = *self.recording_counter.lock().unwrap();
-
-                println!("#{count} Transcribing...");
-
-                match self.stop_and_transcribe(count).await {
-                    Ok(_) => {
-                        *self.state.lock().unwrap() = ServiceState::Idle;
-                        println!(); // blank line between transcriptions
-                        IpcResponse::Success
-                    }
-                    Err(e) => {
-                        *self.state.lock().unwrap() = ServiceState::Idle;
-                        println!("#{count} error: {e}");
-                        println!();
-                        IpcResponse::Error(e.to_string())
-                    }
-                }
+                // Stop recording and transcribe in the background
+                self.spawn_transcription();
+                IpcResponse::Transcribing
             }
             ServiceState::Transcribing => {
                 // Already transcribing, ignore
@@ -235,36 +379,76 @@ impl Service {
                 println!("#{count} Recording...");
             }
             Err(e) => {
-                println!("#{count} error: {e}");
+                eprintln!(
+                    "{}#{count} error: {e}",
+                    crate::systemd::log_prefix(crate::systemd::ERR)
+                );
+                self.report_error(&e.to_string());
             }
         }
     }
 
     /// Handle hotkey release (stop recording) - push-to-talk mode
-    async fn handle_stop(&self) {
+    fn handle_stop(self: &Arc<Self>) {
         let current_state = *self.state.lock().unwrap();
 
         if current_state != ServiceState::Recording {
             return; // Only stop if currently recording
         }
 
-        // Stop recording and transcribe
-        *self.state.lock().unwrap() = ServiceState::Transcribing;
+        // Stop recording and transcribe in the background
+        self.spawn_transcription();
+    }
+
+    /// Stop recording and transcribe in a spawned task
+    ///
+    /// The state is `Transcribing` until the task finishes, so commands that
+    /// arrive meanwhile are rejected instead of queued.
+    fn spawn_transcription(self: &Arc<Self>) {
+        self.set_state(ServiceState::Transcribing);
         let count = *self.recording_counter.lock().unwrap();
 
         println!("#{count} Transcribing...");
 
-        match self.stop_and_transcribe(count).await {
-            Ok(_) => {
-                *self.state.lock().unwrap() = ServiceState::Idle;
-                println!(); // blank line between transcriptions
-            }
-            Err(e) => {
-                *self.state.lock().unwrap() = ServiceState::Idle;
-                println!("#{count} error: {e}");
-                println!();
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            match service.stop_and_transcribe(count).await {
+                Ok(_) => {
+                    service.set_state(ServiceState::Idle);
+                    println!(); // blank line between transcriptions
+                }
+                Err(e) => {
+                    service.set_state(ServiceState::Idle);
+                    eprintln!(
+                        "{}#{count} error: {e}",
+                        crate::systemd::log_prefix(crate::systemd::ERR)
+                    );
+                    service.report_error(&e.to_string());
+                    println!();
+                }
             }
+        });
+    }
+
+    /// Stop recording and discard the audio without transcribing
+    #[cfg(any(feature = "http-api", all(feature = "dbus", target_os = "linux")))]
+    fn handle_cancel(&self) -> IpcResponse {
+        let count = *self.recording_counter.lock().unwrap();
+
+        if let Some(mut recorder) = self.recorder.lock().unwrap().take() {
+            let _ = recorder.stop_recording();
+        }
+        if let Some(handle) = self.chunker_handle.lock().unwrap().take() {
+            handle.abort();
         }
+        if let Some(handle) = self.transcription_handle.lock().unwrap().take() {
+            handle.abort();
+        }
+
+        self.set_state(ServiceState::Idle);
+        println!("#{count} Cancelled.");
+        println!();
+        IpcResponse::Idle
     }
 
     /// Start recording audio with progressive transcription
@@ -276,9 +460,43 @@ impl Service {
 
         // Configure VAD from settings
         let settings = Settings::load();
+
+        // Presets can be switched from the desktop app between recordings
+        #[cfg(all(feature = "dbus", target_os = "linux"))]
+        if let Some(dbus) = self.dbus.get() {
+            dbus.set_active_preset(settings.ui.active_preset.as_deref());
+        }
+
+        // The active preset's Whisper tuning (merged with the settings for local models)
+        #[cfg(feature = "local-transcription")]
+        let whisper_overrides = settings
+            .ui
+            .active_preset
+            .as_deref()
+            .and_then(|name| Preset::load(name).ok())
+            .and_then(|(preset, _)| preset.whisper);
+
+        // Local models stream utterances as they are spoken when `local-streaming` is on
+        #[cfg(all(feature = "realtime", feature = "local-transcription"))]
+        let local_stream = match whis_core::LocalRealtimeProvider::for_provider(&self.provider) {
+            Some(engine) if settings.transcription.uses_realtime() => Some((
+                engine,
+                whis_core::LocalStreamConfig::from_settings(&settings, whisper_overrides.as_ref())?,
+            )),
+            _ => None,
+        };
+        #[cfg(all(feature = "realtime", feature = "local-transcription"))]
+        let streaming = local_stream.is_some();
+        #[cfg(not(all(feature = "realtime", feature = "local-transcription")))]
+        let streaming = false;
+
+        // Streaming segments speech itself, so the recorder's VAD stays off
         #[cfg(feature = "vad")]
         {
-            recorder.set_vad(settings.ui.vad.enabled, settings.ui.vad.threshold);
+            recorder.set_vad(
+                settings.ui.vad.enabled && !streaming,
+                settings.ui.vad.threshold,
+            );
         }
 
         // Start streaming recording with configured device
@@ -298,6 +516,43 @@ impl Service {
             }
         });
 
+        // Preload models in background (streaming and progressive alike)
+        #[cfg(feature = "local-transcription")]
+        {
+            match self.provider {
+                TranscriptionProvider::LocalWhisper => {
+                    if let Some(model_path) = settings.transcription.whisper_model_path() {
+                        whis_core::whisper_preload_model(&model_path);
+                    }
+                }
+                TranscriptionProvider::LocalParakeet => {
+                    if let Some(model_path) = settings.transcription.parakeet_model_path() {
+                        whis_core::preload_parakeet(&model_path);
+                    }
+                }
+                _ => {} // Cloud providers don't need preload
+            }
+        }
+
+        let provider = self.provider.clone();
+        let api_key = self.api_key.clone();
+        let language = self.language.clone();
+
+        #[cfg(all(feature = "realtime", feature = "local-transcription"))]
+        if let Some((engine, config)) = local_stream {
+            // No chunker: utterances go straight to the model (api_key is the model path)
+            let transcription_handle = tokio::spawn(async move {
+                engine
+                    .transcribe_local(&api_key, audio_rx_unbounded, language, config, None)
+                    .await
+            });
+
+            *self.recorder.lock().unwrap() = Some(recorder);
+            *self.transcription_handle.lock().unwrap() = Some(transcription_handle);
+            self.set_state(ServiceState::Recording);
+            return Ok(());
+        }
+
         // Create channels for progressive chunking
         let (chunk_tx, chunk_rx) = mpsc::unbounded_channel();
 
@@ -321,56 +576,57 @@ impl Service {
         });
 
         // Spawn transcription task based on provider
-        let provider = self.provider.clone();
-        let api_key = self.api_key.clone();
-        let language = self.language.clone();
+        let options = settings.transcription.provider_options();
+
+        // Whisper tuning for local models (settings merged with the active preset's)
+        #[cfg(feature = "local-transcription")]
+        let tuning = {
+            let tuning = settings
+                .transcription
+                .local_models
+                .whisper
+                .with_overrides(whisper_overrides.as_ref());
+            tuning.validate()?;
+            tuning
+        };
 
         let transcription_handle = tokio::spawn(async move {
             #[cfg(feature = "local-transcription")]
-            if provider == TranscriptionProvider::LocalParakeet {
-                // Local Parakeet progressive transcription
-                let model_path = Settings::load()
-                    .transcription
-                    .parakeet_model_path()
-                    .ok_or_else(|| anyhow::anyhow!("Parakeet model path not configured"))?;
-
-                return whis_core::progressive_transcribe_local(&model_path, chunk_rx, None).await;
+            if provider.is_local() {
+                // Local progressive transcription (api_key is the model path)
+                return whis_core::progressive_transcribe_local(
+                    &provider,
+                    &api_key,
+                    language.as_deref(),
+                    &tuning,
+                    chunk_rx,
+                    None,
+                )
+                .await;
             }
 
             // Cloud provider progressive transcription
-            whis_core::progressive_transcribe_cloud(
+            let text = whis_core::progressive_transcribe_cloud(
                 &provider,
+                &options,
                 &api_key,
                 language.as_deref(),
                 chunk_rx,
                 None,
             )
-            .await
+            .await?;
+            Ok(TranscriptionResult {
+                text,
+                language: None,
+                words: Vec::new(),
+            })
         });
 
-        // Preload models in background (same as before)
-        #[cfg(feature = "local-transcription")]
-        {
-            match self.provider {
-                TranscriptionProvider::LocalWhisper => {
-                    if let Some(model_path) = settings.transcription.whisper_model_path() {
-                        whis_core::whisper_preload_model(&model_path);
-                    }
-                }
-                TranscriptionProvider::LocalParakeet => {
-                    if let Some(model_path) = settings.transcription.parakeet_model_path() {
-                        whis_core::preload_parakeet(&model_path);
-                    }
-                }
-                _ => {} // Cloud providers don't need preload
-            }
-        }
-
         // Store recorder and task handles
         *self.recorder.lock().unwrap() = Some(recorder);
         *self.chunker_handle.lock().unwrap() = Some(chunker_handle);
         *self.transcription_handle.lock().unwrap() = Some(transcription_handle);
-        *self.state.lock().unwrap() = ServiceState::Recording;
+        self.set_state(ServiceState::Recording);
 
         Ok(())
     }
@@ -388,13 +644,8 @@ impl Service {
         // Stop recording (closes audio stream, signals chunker to finish)
         recorder.stop_recording()?;
 
-        // Get task handles
-        let chunker_handle = self
-            .chunker_handle
-            .lock()
-            .unwrap()
-            .take()
-            .context("No chunker task running")?;
+        // Get task handles (local streaming runs without a chunker)
+        let chunker_handle = self.chunker_handle.lock().unwrap().take();
 
         let transcription_handle = self
             .transcription_handle
@@ -404,52 +655,73 @@ impl Service {
             .context("No transcription task running")?;
 
         // Wait for chunker to finish processing all audio
-        chunker_handle
-            .await
-            .context("Failed to join chunker task")?
-            .map_err(|e| anyhow::anyhow!("Chunker task failed: {}", e))?;
+        if let Some(chunker_handle) = chunker_handle {
+            chunker_handle
+                .await
+                .context("Failed to join chunker task")?
+                .map_err(|e| anyhow::anyhow!("Chunker task failed: {}", e))?;
+        }
 
         // Wait for transcription to finish
-        let transcription = transcription_handle
+        let result = transcription_handle
             .await
             .context("Failed to join transcription task")??;
 
         // Print completion message immediately after transcription finishes
         println!("#{count} Done.");
+        if let Some(detected) = &result.language {
+            println!("#{count} Detected language: {detected}");
+        }
 
-        // Apply post-processing if enabled
+        // Apply the replacement dictionary (with the active preset's entries)
+        // and spoken formatting commands
         let settings = Settings::load();
-        let final_text = if settings.post_processing.enabled {
-            if let Some(post_processor_api_key) = settings
-                .post_processing
-                .api_key(&settings.transcription.api_keys)
-            {
-                println!("#{count} Post-processing...");
-
-                let prompt = settings
-                    .post_processing
-                    .prompt
-                    .as_deref()
-                    .unwrap_or(DEFAULT_POST_PROCESSING_PROMPT);
-
-                match post_process(
-                    &post_processor_api_key,
-                    &settings.post_processing.processor,
-                    &transcription,
-                    prompt,
-                    None,
-                )
-                .await
-                {
-                    Ok(processed) => processed,
-                    Err(_) => transcription, // Silently fallback in service mode
-                }
-            } else {
-                transcription
-            }
-        } else {
-            transcription
+        let config = ProcessingConfig {
+            enabled: settings.post_processing.enabled,
+            preset: settings
+                .ui
+                .active_preset
+                .as_deref()
+                .and_then(|name| Preset::load(name).ok())
+                .map(|(preset, _)| preset),
+            language: self.language.clone().or(result.language),
+            stream_to_stdout: false,
         };
+        let mut processed = record::correct(
+            record::TranscriptionResult {
+                text: result.text,
+                language: config.language.clone(),
+                words: result.words,
+            },
+            &config,
+        );
+
+        // Run the active preset's steps (or the configured prompt) like `whis`;
+        // a failing step keeps the text so far
+        if settings.post_processing.enabled
+            && settings.post_processing.processor != PostProcessor::None
+        {
+            println!("#{count} Post-processing...");
+            if let Err(e) = record::run_steps(&mut processed, &config, true).await {
+                eprintln!(
+                    "{}#{count} Post-processing failed: {e}",
+                    crate::systemd::log_prefix(crate::systemd::WARNING)
+                );
+            }
+        }
+        let final_text = processed.rendered_text();
+
+        // Publish to HTTP API clients (GET /transcript, SSE)
+        #[cfg(feature = "http-api")]
+        if let Some(api) = self.http_api.get() {
+            api.transcript_ready(&final_text);
+        }
+
+        // Publish to D-Bus clients (TranscriptReady signal)
+        #[cfg(all(feature = "dbus", target_os = "linux"))]
+        if let Some(dbus) = self.dbus.get() {
+            dbus.transcript_ready(&final_text);
+        }
 
         // Copy to clipboard (blocking operation)
         let clipboard_method = settings.ui.clipboard_backend.clone();
@@ -0,0 +1,181 @@
+//! Minimal systemd integration for the background service
+//!
+//! Implements the two pieces of the systemd protocol `whis start` needs when it
+//! runs as a user unit (see `whis service install`):
+//!
+//! - Socket activation: adopt the IPC socket systemd passed via `LISTEN_FDS`
+//! - Readiness notification: send `READY=1` to `$NOTIFY_SOCKET` (`Type=notify`)
+//! - Log priorities: `<N>` prefixes on stderr lines when stderr is the journal
+//!
+//! All are no-ops outside systemd, so `whis start` behaves the same in a terminal.
+//!
+//! Clients use [`socket_unit_active`] / [`service_unit_active`] to check the
+//! units without connecting: while systemd owns the socket, any connection
+//! starts the service. [`socket_unit_installed`] tells whether that can be the
+//! case without running `systemctl`.
+
+/// User unit running `whis start`
+pub const SERVICE_UNIT: &str = "whis.service";
+
+/// User unit owning the IPC socket
+pub const SOCKET_UNIT: &str = "whis.socket";
+
+/// First file descriptor passed by systemd (`SD_LISTEN_FDS_START`)
+#[cfg(unix)]
+const LISTEN_FDS_START: i32 = 3;
+
+/// Check whether systemd passed us a listening socket
+///
+/// Both `LISTEN_PID` (must match our PID) and `LISTEN_FDS` (at least one fd)
+/// have to be set, otherwise the variables were inherited from a parent.
+pub fn is_socket_activated() -> bool {
+    let pid_matches = std::env::var("LISTEN_PID")
+        .ok()
+        .and_then(|pid| pid.parse::<u32>().ok())
+        .is_some_and(|pid| pid == std::process::id());
+
+    let has_fds = std::env::var("LISTEN_FDS")
+        .ok()
+        .and_then(|fds| fds.parse::<u32>().ok())
+        .is_some_and(|fds| fds >= 1);
+
+    pid_matches && has_fds
+}
+
+/// Take ownership of the socket passed by systemd, if any
+///
+/// Must only be called once per process: the returned fd is closed on drop.
+#[cfg(unix)]
+pub fn take_listen_fd() -> Option<std::os::fd::OwnedFd> {
+    use std::os::fd::FromRawFd;
+
+    if !is_socket_activated() {
+        return None;
+    }
+
+    // SAFETY: systemd guarantees fd 3 is an open socket when LISTEN_PID matches
+    // our PID and LISTEN_FDS >= 1, and nothing else in the process owns it.
+    Some(unsafe { std::os::fd::OwnedFd::from_raw_fd(LISTEN_FDS_START) })
+}
+
+/// Check whether the process was started by systemd (stdout goes to the journal)
+pub fn is_under_systemd() -> bool {
+    std::env::var_os("INVOCATION_ID").is_some() || std::env::var_os("JOURNAL_STREAM").is_some()
+}
+
+/// Priority prefix of an error line (`sd-daemon(3)`)
+pub const ERR: &str = "<3>";
+
+/// Priority prefix of a warning line (`sd-daemon(3)`)
+pub const WARNING: &str = "<4>";
+
+/// Prefix for a stderr line logged with `priority` ([`ERR`], [`WARNING`])
+///
+/// journald strips the prefix and stores the line with that priority, so
+/// `journalctl --user -u whis -p warning` shows failures. Empty when stderr
+/// is not the journal.
+pub fn log_prefix(priority: &'static str) -> &'static str {
+    if stderr_is_journal() { priority } else { "" }
+}
+
+/// Check whether stderr is the stream `JOURNAL_STREAM` names (`<dev>:<ino>`)
+///
+/// The variable is inherited by child processes whose stderr was redirected,
+/// so the device and inode have to match.
+#[cfg(unix)]
+fn stderr_is_journal() -> bool {
+    use std::os::fd::AsFd;
+    use std::os::unix::fs::MetadataExt;
+    use std::sync::OnceLock;
+
+    static IS_JOURNAL: OnceLock<bool> = OnceLock::new();
+    *IS_JOURNAL.get_or_init(|| {
+        let Some((dev, ino)) = std::env::var("JOURNAL_STREAM").ok().and_then(|stream| {
+            let (dev, ino) = stream.split_once(':')?;
+            Some((dev.parse::<u64>().ok()?, ino.parse::<u64>().ok()?))
+        }) else {
+            return false;
+        };
+
+        std::io::stderr()
+            .as_fd()
+            .try_clone_to_owned()
+            .map(std::fs::File::from)
+            .and_then(|stderr| stderr.metadata())
+            .is_ok_and(|meta| meta.dev() == dev && meta.ino() == ino)
+    })
+}
+
+#[cfg(not(unix))]
+fn stderr_is_journal() -> bool {
+    false
+}
+
+/// Check whether `whis service install` wrote the socket unit
+pub fn socket_unit_installed() -> bool {
+    dirs::config_dir()
+        .is_some_and(|dir| dir.join("systemd").join("user").join(SOCKET_UNIT).exists())
+}
+
+/// Check whether systemd listens on the IPC socket (`whis.socket` is active)
+pub fn socket_unit_active() -> bool {
+    unit_active(SOCKET_UNIT)
+}
+
+/// Check whether the systemd service unit is running
+pub fn service_unit_active() -> bool {
+    unit_active(SERVICE_UNIT)
+}
+
+#[cfg(target_os = "linux")]
+fn unit_active(unit: &str) -> bool {
+    std::process::Command::new("systemctl")
+        .args(["--user", "is-active", "--quiet", unit])
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn unit_active(_unit: &str) -> bool {
+    false
+}
+
+/// Tell systemd the service finished starting up (`Type=notify`)
+pub fn notify_ready() {
+    notify("READY=1\nSTATUS=Listening");
+}
+
+/// Tell systemd the service is shutting down
+pub fn notify_stopping() {
+    notify("STOPPING=1");
+}
+
+/// Send a raw sd_notify message, ignoring errors (best effort like libsystemd)
+#[cfg(target_os = "linux")]
+fn notify(state: &str) {
+    use std::os::linux::net::SocketAddrExt;
+    use std::os::unix::net::{SocketAddr, UnixDatagram};
+
+    let Some(path) = std::env::var_os("NOTIFY_SOCKET") else {
+        return;
+    };
+    let path = path.to_string_lossy();
+
+    // Leading '@' denotes a socket in the abstract namespace
+    let addr = match path.strip_prefix('@') {
+        Some(name) => SocketAddr::from_abstract_name(name.as_bytes()),
+        None => SocketAddr::from_pathname(path.as_ref()),
+    };
+
+    let result = addr.and_then(|addr| {
+        let socket = UnixDatagram::unbound()?;
+        socket.send_to_addr(state.as_bytes(), &addr)
+    });
+
+    if let Err(e) = result {
+        whis_core::verbose!("sd_notify failed: {e}");
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn notify(_state: &str) {}
@@ -0,0 +1,212 @@
+//! Focused Window Detection
+//!
+//! Best-effort lookup of the focused application, used by the `{{active_app}}`
+//! prompt variable and per-app preset rules. Each backend shells out to the
+//! compositor's own tooling:
+//!
+//! - **Sway** - `swaymsg -t get_tree` (focused node's `app_id` or X11 class)
+//! - **Hyprland** - `hyprctl activewindow -j`
+//! - **X11** - `xprop` on the root window's `_NET_ACTIVE_WINDOW`
+//! - **GNOME (Wayland)** - `gdbus` calls to the
+//!   [Window Calls](https://extensions.gnome.org/extension/4724/window-calls/)
+//!   Shell extension, which must be installed
+//!
+//! Other compositors (KDE on Wayland) don't expose the focused window to
+//! clients, so detection returns `None` there. Async callers (hotkey handlers)
+//! use [`detect_active_window_async`], which can't stall on a hung query.
+
+use serde_json::Value;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::platform::{Compositor, detect_platform};
+
+/// The focused window
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveWindow {
+    /// Application identifier (Wayland app_id or X11 WM_CLASS class)
+    pub app: String,
+    /// Window title
+    pub title: String,
+}
+
+/// Detect the focused window, if the compositor allows it
+pub fn detect_active_window() -> Option<ActiveWindow> {
+    let platform = detect_platform();
+    let window = match platform.compositor {
+        Compositor::Sway => sway_active_window(),
+        Compositor::Hyprland => hyprland_active_window(),
+        _ if platform.platform.is_x11() => x11_active_window(),
+        Compositor::Gnome => gnome_active_window(),
+        _ => None,
+    };
+    crate::verbose!("Active window: {:?}", window);
+    window
+}
+
+/// Longest [`detect_active_window_async`] waits for the compositor
+const DETECT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// [`detect_active_window`] on a blocking thread, giving up after 500ms
+pub async fn detect_active_window_async() -> Option<ActiveWindow> {
+    let lookup = tokio::task::spawn_blocking(detect_active_window);
+    match tokio::time::timeout(DETECT_TIMEOUT, lookup).await {
+        Ok(window) => window.ok().flatten(),
+        Err(_) => {
+            crate::verbose!("Active window lookup timed out");
+            None
+        }
+    }
+}
+
+/// Run a command and return its stdout on success
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program)
+        .args(args)
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn sway_active_window() -> Option<ActiveWindow> {
+    let tree: Value =
+        serde_json::from_str(&command_output("swaymsg", &["-t", "get_tree"])?).ok()?;
+    let node = find_focused(&tree)?;
+
+    // Native Wayland windows have an app_id, XWayland windows a class
+    let app = node["app_id"]
+        .as_str()
+        .or_else(|| node["window_properties"]["class"].as_str())?;
+    Some(ActiveWindow {
+        app: app.to_string(),
+        title: node["name"].as_str().unwrap_or_default().to_string(),
+    })
+}
+
+/// Depth-first search for the focused leaf of a sway tree
+fn find_focused(node: &Value) -> Option<&Value> {
+    if node["focused"].as_bool() == Some(true) {
+        return Some(node);
+    }
+    ["nodes", "floating_nodes"]
+        .iter()
+        .filter_map(|key| node[key].as_array())
+        .flatten()
+        .find_map(find_focused)
+}
+
+fn hyprland_active_window() -> Option<ActiveWindow> {
+    let window: Value =
+        serde_json::from_str(&command_output("hyprctl", &["activewindow", "-j"])?).ok()?;
+    let app = window["class"].as_str().filter(|c| !c.is_empty())?;
+    Some(ActiveWindow {
+        app: app.to_string(),
+        title: window["title"].as_str().unwrap_or_default().to_string(),
+    })
+}
+
+fn x11_active_window() -> Option<ActiveWindow> {
+    // Output: "_NET_ACTIVE_WINDOW(WINDOW): window id # 0x3a00007"
+    let root = command_output("xprop", &["-root", "_NET_ACTIVE_WINDOW"])?;
+    let id = root.split_whitespace().last()?.trim_end_matches(',');
+    if id == "0x0" {
+        return None;
+    }
+
+    // Output: WM_CLASS(STRING) = "Navigator", "firefox"
+    //         _NET_WM_NAME(UTF8_STRING) = "Inbox - Mozilla Firefox"
+    let props = command_output("xprop", &["-id", id, "WM_CLASS", "_NET_WM_NAME"])?;
+    let mut app = None;
+    let mut title = String::new();
+    for line in props.lines() {
+        let Some((name, value)) = line.split_once(" = ") else {
+            continue;
+        };
+        let strings: Vec<&str> = value.split("\", \"").map(|s| s.trim_matches('"')).collect();
+        if name.starts_with("WM_CLASS") {
+            // Second string is the class (first is the instance)
+            app = strings.last().map(|s| s.to_string());
+        } else if name.starts_with("_NET_WM_NAME") {
+            title = strings.join("\", \"");
+        }
+    }
+
+    Some(ActiveWindow { app: app?, title })
+}
+
+/// D-Bus interface of the Window Calls GNOME Shell extension
+const GNOME_WINDOWS_INTERFACE: &str = "org.gnome.Shell.Extensions.Windows";
+
+/// Call a Window Calls method and return its single string result
+fn gnome_windows_call(method: &str, args: &[&str]) -> Option<String> {
+    let method = format!("{GNOME_WINDOWS_INTERFACE}.{method}");
+    let mut call = vec![
+        "call",
+        "--session",
+        "--dest",
+        "org.gnome.Shell",
+        "--object-path",
+        "/org/gnome/Shell/Extensions/Windows",
+        "--method",
+        &method,
+    ];
+    call.extend_from_slice(args);
+    parse_gvariant_string(&command_output("gdbus", &call)?)
+}
+
+fn gnome_active_window() -> Option<ActiveWindow> {
+    // List returns a JSON array: [{"id": 123, "wm_class": "firefox", "focus": true, ...}]
+    let windows: Value = serde_json::from_str(&gnome_windows_call("List", &[])?).ok()?;
+    let window = windows
+        .as_array()?
+        .iter()
+        .find(|w| w["focus"].as_bool() == Some(true))?;
+    let app = window["wm_class"].as_str().filter(|c| !c.is_empty())?;
+
+    // Older extension versions include the title, newer ones need GetTitle
+    let title = match window["title"].as_str() {
+        Some(title) => title.to_string(),
+        None => window["id"]
+            .as_u64()
+            .and_then(|id| gnome_windows_call("GetTitle", &[&id.to_string()]))
+            .unwrap_or_default(),
+    };
+    Some(ActiveWindow {
+        app: app.to_string(),
+        title,
+    })
+}
+
+/// Extract the string from gdbus output like `('text',)`
+///
+/// GVariant quotes with `'` (or `"` if the text contains `'`) and escapes
+/// backslashes, the quote character and control characters.
+fn parse_gvariant_string(output: &str) -> Option<String> {
+    let inner = output.trim().strip_prefix('(')?.strip_suffix(",)")?;
+    let quote = inner.chars().next().filter(|c| matches!(c, '\'' | '"'))?;
+    let inner = inner.strip_prefix(quote)?.strip_suffix(quote)?;
+
+    let mut text = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => text.push('\n'),
+            't' => text.push('\t'),
+            'r' => text.push('\r'),
+            'u' => {
+                let code: String = chars.by_ref().take(4).collect();
+                text.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+            }
+            other => text.push(other),
+        }
+    }
+    Some(text)
+}
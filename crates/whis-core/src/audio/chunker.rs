@@ -240,4 +240,26 @@ impl ProgressiveChunker {
 
         Ok(())
     }
+
+    /// Chunk a complete buffer of samples (e.g. a decoded audio file)
+    ///
+    /// Behaves like streaming the samples in one-second blocks without VAD
+    /// and then closing the stream, so all chunks (including the final one)
+    /// are sent before this returns.
+    pub fn consume_samples(&mut self, samples: &[f32]) -> Result<(), String> {
+        for block in samples.chunks(WHISPER_SAMPLE_RATE as usize) {
+            self.buffer.add_samples(block);
+
+            if self.should_chunk(None) {
+                let chunk = self.buffer.create_chunk();
+                self.chunk_tx.send(chunk).map_err(|e| e.to_string())?;
+            }
+        }
+
+        if let Some(final_chunk) = self.buffer.create_final_chunk() {
+            self.chunk_tx.send(final_chunk).map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
 }
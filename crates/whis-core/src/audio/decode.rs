@@ -0,0 +1,60 @@
+//! Decoding of audio files to 16kHz mono samples
+//!
+//! Local engines consume raw samples rather than encoded audio, so file
+//! transcription requests are decoded here before inference. The CLI decodes
+//! `--file` input here too.
+
+use anyhow::{Context, Result};
+use std::io::Cursor;
+
+use crate::resample::resample_to_16k;
+
+/// Decode an audio file to 16kHz mono f32 samples
+///
+/// Only WAV is supported (there is no general-purpose decoder for local
+/// engines). `mime_type` and `filename` are used to give a helpful error for
+/// other formats; callers can run [`ensure_wav`] first to fail before doing
+/// any other work.
+pub fn decode_to_16k(data: &[u8], mime_type: &str, filename: &str) -> Result<Vec<f32>> {
+    ensure_wav(data, mime_type, filename)?;
+    decode_wav(data)
+}
+
+/// Reject anything but a RIFF/WAVE file with a message naming the upload
+pub fn ensure_wav(data: &[u8], mime_type: &str, filename: &str) -> Result<()> {
+    let is_wav = data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WAVE";
+    if !is_wav {
+        anyhow::bail!(
+            "Unsupported audio format for local transcription: {} ({})\n\
+            Local engines only accept WAV files. Convert it first, e.g.:\n  \
+            ffmpeg -i {} -ar 16000 -ac 1 audio.wav",
+            filename,
+            mime_type,
+            filename
+        );
+    }
+    Ok(())
+}
+
+/// Decode WAV bytes and resample to 16kHz mono
+fn decode_wav(data: &[u8]) -> Result<Vec<f32>> {
+    let mut reader = hound::WavReader::new(Cursor::new(data)).context("Failed to read WAV data")?;
+
+    let spec = reader.spec();
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .samples::<f32>()
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to read float samples")?,
+        hound::SampleFormat::Int => {
+            let max_val = (1u32 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|v| v as f32 / max_val))
+                .collect::<Result<Vec<_>, _>>()
+                .context("Failed to read int samples")?
+        }
+    };
+
+    resample_to_16k(&samples, spec.sample_rate, spec.channels)
+}
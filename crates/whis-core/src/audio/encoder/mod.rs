@@ -1,7 +1,11 @@
-//! Audio encoding module providing MP3 encoding via embedded LAME encoder.
+//! Audio encoding module providing MP3 encoding via embedded LAME encoder,
+//! plus uncompressed WAV for providers that take raw PCM.
 
 #[cfg(feature = "embedded-encoder")]
 mod embedded;
+mod wav;
+
+pub use wav::encode_wav;
 
 use anyhow::Result;
 
@@ -34,6 +34,7 @@
 //! - **Linux**: ALSA stderr suppression via safe FFI wrapper
 
 pub mod chunker;
+pub mod decode;
 mod devices;
 mod encoder;
 pub mod error;
@@ -48,7 +49,7 @@ mod pulse;
 // Re-export public types
 pub use chunker::{AudioChunk as ProgressiveChunk, ChunkerConfig, ProgressiveChunker};
 pub use devices::list_audio_devices;
-pub use encoder::{AudioEncoder, create_encoder};
+pub use encoder::{AudioEncoder, create_encoder, encode_wav};
 pub use error::AudioError;
 pub use recorder::{AudioRecorder, AudioStreamSender, RecorderConfig, RecordingData};
 pub use types::AudioDeviceInfo;
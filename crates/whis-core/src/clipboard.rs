@@ -25,6 +25,10 @@
 //!
 //! // Force specific backend
 //! copy_to_clipboard("Hello", ClipboardMethod::Xclip)?;
+//!
+//! // Read back (used by the `{{clipboard}}`/`{{selection}}` prompt variables)
+//! let text = read_clipboard(ClipboardMethod::Auto)?;
+//! let selected = read_primary_selection()?;
 //! ```
 
 use anyhow::{Context, Result};
@@ -167,3 +171,59 @@ pub fn copy_to_clipboard(text: &str, method: ClipboardMethod) -> Result<()> {
         ClipboardMethod::Arboard => copy_via_arboard(text),
     }
 }
+
+/// Read text using a paste command (wl-paste, xclip -o)
+///
+/// An empty clipboard makes these tools exit non-zero, which is treated as
+/// empty text rather than an error.
+fn paste_via(program: &str, args: &[&str]) -> Result<String> {
+    let output = Command::new(program)
+        .args(args)
+        .stderr(Stdio::null())
+        .output()
+        .with_context(|| format!("Failed to run {program}"))?;
+
+    if !output.status.success() {
+        crate::verbose!("{program} exited with non-zero status (empty clipboard?)");
+        return Ok(String::new());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Read text from the clipboard using the specified method
+///
+/// Uses the same backend selection as [`copy_to_clipboard`].
+pub fn read_clipboard(method: ClipboardMethod) -> Result<String> {
+    let read_via_arboard = || -> Result<String> {
+        let mut clipboard = Clipboard::new().context("Failed to access clipboard")?;
+        match clipboard.get_text() {
+            Ok(text) => Ok(text),
+            Err(arboard::Error::ContentNotAvailable) => Ok(String::new()),
+            Err(e) => Err(e).context("Failed to read text from clipboard"),
+        }
+    };
+
+    match method {
+        ClipboardMethod::Auto if is_flatpak() => paste_via("wl-paste", &["--no-newline"]),
+        ClipboardMethod::Auto if session_type() == "x11" => {
+            paste_via("xclip", &["-selection", "clipboard", "-o"])
+        }
+        ClipboardMethod::Auto | ClipboardMethod::Arboard => read_via_arboard(),
+        ClipboardMethod::Xclip => paste_via("xclip", &["-selection", "clipboard", "-o"]),
+        ClipboardMethod::WlCopy => paste_via("wl-paste", &["--no-newline"]),
+    }
+}
+
+/// Read the primary selection (the currently highlighted text, Linux only)
+pub fn read_primary_selection() -> Result<String> {
+    if !cfg!(target_os = "linux") {
+        anyhow::bail!("The primary selection is only available on Linux");
+    }
+
+    if is_flatpak() || session_type() == "wayland" {
+        paste_via("wl-paste", &["--primary", "--no-newline"])
+    } else {
+        paste_via("xclip", &["-selection", "primary", "-o"])
+    }
+}
@@ -0,0 +1,215 @@
+//! Shareable preset bundles.
+//!
+//! A bundle packs several presets into one JSON file, so a team can
+//! distribute a standard set with `whis preset export` and import it on the
+//! CLI, desktop or mobile app. Presets are keyed by name and use the regular
+//! preset file format:
+//!
+//! ```json
+//! {
+//!   "presets": {
+//!     "email": { "description": "Format as an email", "prompt": "..." },
+//!     "standup": { "description": "Daily standup notes", "prompt": "..." }
+//!   }
+//! }
+//! ```
+//!
+//! # Conflicts
+//!
+//! When an imported name is already taken (by a user or built-in preset):
+//!
+//! - **skip** - keep the existing preset (default)
+//! - **overwrite** - replace it (a built-in gets a user copy that overrides it)
+//! - **rename** - import under the next free name (`email-2`, `email-3`, ...)
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use super::Preset;
+
+/// Longest preset name accepted by [`Preset::validate_name`]
+const MAX_NAME_LEN: usize = 50;
+
+/// A set of presets to export or import
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PresetBundle {
+    pub presets: BTreeMap<String, Preset>,
+}
+
+/// What to do when an imported preset's name is already taken
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictPolicy {
+    /// Import under the next free name
+    Rename,
+    /// Replace the existing preset
+    Overwrite,
+    /// Keep the existing preset
+    #[default]
+    Skip,
+}
+
+impl fmt::Display for ConflictPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConflictPolicy::Rename => write!(f, "rename"),
+            ConflictPolicy::Overwrite => write!(f, "overwrite"),
+            ConflictPolicy::Skip => write!(f, "skip"),
+        }
+    }
+}
+
+impl FromStr for ConflictPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "rename" => Ok(ConflictPolicy::Rename),
+            "overwrite" => Ok(ConflictPolicy::Overwrite),
+            "skip" => Ok(ConflictPolicy::Skip),
+            _ => Err(format!(
+                "Unknown conflict policy: {}. Use: rename, overwrite, skip",
+                s
+            )),
+        }
+    }
+}
+
+/// What happened to one preset during an import
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "lowercase")]
+pub enum ImportOutcome {
+    /// Imported under its own name
+    Added { name: String },
+    /// Imported under a new name because `from` was taken
+    Renamed { from: String, name: String },
+    /// Replaced an existing preset
+    Overwritten { name: String },
+    /// Not imported because the name was taken
+    Skipped { name: String },
+}
+
+impl fmt::Display for ImportOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportOutcome::Added { name } => write!(f, "Added '{}'", name),
+            ImportOutcome::Renamed { from, name } => {
+                write!(f, "Imported '{}' as '{}' (name taken)", from, name)
+            }
+            ImportOutcome::Overwritten { name } => write!(f, "Overwrote '{}'", name),
+            ImportOutcome::Skipped { name } => write!(f, "Skipped '{}' (already exists)", name),
+        }
+    }
+}
+
+/// Whether a preset name is taken in a presets directory (user file or built-in)
+fn is_taken(name: &str, presets_dir: &Path) -> bool {
+    Preset::is_builtin(name) || presets_dir.join(format!("{}.json", name)).exists()
+}
+
+/// Next free `name-N` in a presets directory
+fn free_name(name: &str, presets_dir: &Path) -> String {
+    (2..)
+        .map(|n| {
+            let suffix = format!("-{}", n);
+            let base: String = name
+                .chars()
+                .take(MAX_NAME_LEN.saturating_sub(suffix.len()))
+                .collect();
+            base + &suffix
+        })
+        .find(|candidate| !is_taken(candidate, presets_dir))
+        .expect("unbounded name search")
+}
+
+impl PresetBundle {
+    /// Bundle presets by name (user files take precedence over built-ins)
+    pub fn export(names: &[String]) -> Result<Self, String> {
+        Self::export_from(names, &Preset::presets_dir())
+    }
+
+    /// Bundle presets by name from a specific presets directory
+    pub fn export_from(names: &[String], presets_dir: &Path) -> Result<Self, String> {
+        let mut presets = BTreeMap::new();
+        for name in names {
+            let (preset, _) = Preset::load_from(name, presets_dir)?;
+            presets.insert(preset.name.clone(), preset);
+        }
+        Ok(Self { presets })
+    }
+
+    /// Parse and validate a bundle
+    ///
+    /// Names are trimmed. Fails without importing anything if any preset has
+    /// an invalid or duplicate name, unknown prompt variables or an unusable
+    /// output schema.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let bundle: Self =
+            serde_json::from_str(json).map_err(|e| format!("Invalid preset bundle: {}", e))?;
+
+        let mut presets = BTreeMap::new();
+        for (name, mut preset) in bundle.presets {
+            Preset::validate_name(&name, true).map_err(|e| format!("Preset '{}': {}", name, e))?;
+            let name = name.trim().to_string();
+            preset.name = name.clone();
+            preset.validate_prompts()?;
+            preset.validate_output()?;
+            if presets.insert(name.clone(), preset).is_some() {
+                return Err(format!("Preset '{}' appears more than once", name));
+            }
+        }
+        Ok(Self { presets })
+    }
+
+    /// Serialize the bundle as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Failed to serialize: {}", e))
+    }
+
+    /// Save the bundle's presets as user presets
+    pub fn import(&self, policy: ConflictPolicy) -> Result<Vec<ImportOutcome>, String> {
+        self.import_to(&Preset::presets_dir(), policy)
+    }
+
+    /// Save the bundle's presets to a specific presets directory
+    pub fn import_to(
+        &self,
+        presets_dir: &Path,
+        policy: ConflictPolicy,
+    ) -> Result<Vec<ImportOutcome>, String> {
+        let mut outcomes = Vec::with_capacity(self.presets.len());
+
+        for (name, preset) in &self.presets {
+            let outcome = match (is_taken(name, presets_dir), policy) {
+                (false, _) => ImportOutcome::Added { name: name.clone() },
+                (true, ConflictPolicy::Skip) => {
+                    outcomes.push(ImportOutcome::Skipped { name: name.clone() });
+                    continue;
+                }
+                (true, ConflictPolicy::Overwrite) => {
+                    ImportOutcome::Overwritten { name: name.clone() }
+                }
+                (true, ConflictPolicy::Rename) => ImportOutcome::Renamed {
+                    from: name.clone(),
+                    name: free_name(name, presets_dir),
+                },
+            };
+
+            let target = match &outcome {
+                ImportOutcome::Renamed { name, .. } => name,
+                _ => name,
+            };
+            Preset {
+                name: target.clone(),
+                ..preset.clone()
+            }
+            .save_to(presets_dir)?;
+            outcomes.push(outcome);
+        }
+
+        Ok(outcomes)
+    }
+}
@@ -152,3 +152,18 @@ pub const DEFAULT_KEEP_MODEL_LOADED: bool = true;
 /// - 10: Unload after 10 minutes of inactivity (default)
 /// - Higher values: For power users with plenty of RAM
 pub const DEFAULT_MODEL_UNLOAD_MINUTES: u32 = 10;
+
+// =============================================================================
+// HTTP API DEFAULTS
+// =============================================================================
+
+/// Whether the local HTTP API is enabled
+///
+/// Disabled by default: the API lets any local process holding the token
+/// start recordings, so it is opt-in via `whis config http-api true`.
+pub const DEFAULT_HTTP_API_ENABLED: bool = false;
+
+/// Default port for the local HTTP API (bound to 127.0.0.1 only)
+///
+/// 9447 spells "WHIS" on a phone keypad.
+pub const DEFAULT_HTTP_API_PORT: u16 = 9447;
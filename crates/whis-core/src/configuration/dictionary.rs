@@ -0,0 +1,225 @@
+//! Replacement dictionary for deterministic transcript fixes.
+//!
+//! Corrects words the transcription model keeps getting wrong ("whiz" →
+//! "whis", "kube cuddle" → "kubectl") and expands spoken snippets, without
+//! an LLM. The dictionary is applied to the raw transcript before any
+//! post-processing.
+//!
+//! # File Format
+//!
+//! Stored in `~/.config/whis/dictionary.json`:
+//!
+//! ```json
+//! {
+//!   "replacements": [
+//!     { "from": "whiz", "to": "whis", "whole_word": true, "preserve_case": true },
+//!     { "from": "kube cuddle", "to": "kubectl" },
+//!     { "from": "my (work )?email", "to": "me@example.com", "regex": true }
+//!   ]
+//! }
+//! ```
+//!
+//! # Options
+//!
+//! - **regex** - `from` is a regular expression; `to` may use `$1`/`${name}`
+//! - **whole_word** - only match at word boundaries ("whiz" but not "whizzing")
+//! - **preserve_case** - match case-insensitively and carry the case of the
+//!   matched text over to the replacement ("Whiz" → "Whis", "WHIZ" → "WHIS")
+//!
+//! Presets can carry their own replacements in a `dictionary` array, applied
+//! after the user dictionary.
+
+use regex::{Captures, Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::Preset;
+
+/// A single dictionary entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Replacement {
+    /// Text (or regex pattern) to find
+    pub from: String,
+
+    /// Replacement text
+    pub to: String,
+
+    /// Treat `from` as a regular expression
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub regex: bool,
+
+    /// Only match whole words
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub whole_word: bool,
+
+    /// Match case-insensitively and keep the matched text's case
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub preserve_case: bool,
+}
+
+impl Replacement {
+    /// Create a literal, case-sensitive replacement
+    pub fn literal(from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            regex: false,
+            whole_word: false,
+            preserve_case: false,
+        }
+    }
+
+    /// Build the matcher for this entry
+    fn compile(&self) -> Result<Regex, regex::Error> {
+        let pattern = if self.regex {
+            self.from.clone()
+        } else {
+            regex::escape(&self.from)
+        };
+        let pattern = if self.whole_word {
+            format!(r"\b(?:{pattern})\b")
+        } else {
+            pattern
+        };
+        RegexBuilder::new(&pattern)
+            .case_insensitive(self.preserve_case)
+            .build()
+    }
+
+    /// Check that the entry is usable (non-empty, valid regex)
+    pub fn validate(&self) -> Result<(), String> {
+        if self.from.is_empty() {
+            return Err("Replacement pattern cannot be empty".to_string());
+        }
+        self.compile()
+            .map(|_| ())
+            .map_err(|e| format!("Invalid pattern '{}': {}", self.from, e))
+    }
+
+    /// Apply this entry to a text
+    fn apply(&self, matcher: &Regex, text: &str) -> String {
+        matcher
+            .replace_all(text, |caps: &Captures| {
+                let mut replacement = String::new();
+                if self.regex {
+                    caps.expand(&self.to, &mut replacement);
+                } else {
+                    replacement.push_str(&self.to);
+                }
+                if self.preserve_case {
+                    match_case(&caps[0], &replacement)
+                } else {
+                    replacement
+                }
+            })
+            .into_owned()
+    }
+}
+
+/// Carry the case pattern of `matched` over to `replacement`
+///
+/// ALL CAPS stays all caps, a capitalized match capitalizes the replacement,
+/// anything else keeps the replacement as written.
+fn match_case(matched: &str, replacement: &str) -> String {
+    let has_letters = matched.chars().any(char::is_alphabetic);
+    if has_letters && !matched.chars().any(char::is_lowercase) && matched.chars().count() > 1 {
+        return replacement.to_uppercase();
+    }
+    if matched.chars().next().is_some_and(char::is_uppercase) {
+        let mut chars = replacement.chars();
+        return match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        };
+    }
+    replacement.to_string()
+}
+
+/// Apply replacements in order
+///
+/// Entries with an empty or invalid pattern are skipped.
+pub fn apply_replacements(text: &str, replacements: &[Replacement]) -> String {
+    replacements
+        .iter()
+        .filter(|r| !r.from.is_empty())
+        .fold(text.to_string(), |text, r| match r.compile() {
+            Ok(matcher) => r.apply(&matcher, &text),
+            Err(e) => {
+                crate::verbose!("Skipping dictionary entry '{}': {}", r.from, e);
+                text
+            }
+        })
+}
+
+/// The user's replacement dictionary
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Dictionary {
+    #[serde(default)]
+    pub replacements: Vec<Replacement>,
+}
+
+impl Dictionary {
+    /// Get the dictionary file path (~/.config/whis/dictionary.json)
+    pub fn path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("whis")
+            .join("dictionary.json")
+    }
+
+    /// Load the user dictionary (empty if missing or unreadable)
+    pub fn load() -> Self {
+        Self::load_from(&Self::path())
+    }
+
+    /// Load a dictionary from a specific file (empty if missing or unreadable)
+    pub fn load_from(path: &Path) -> Self {
+        match fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                eprintln!(
+                    "Warning: Failed to parse dictionary '{}': {}",
+                    path.display(),
+                    e
+                );
+                Self::default()
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(e) => {
+                eprintln!(
+                    "Warning: Failed to read dictionary '{}': {}",
+                    path.display(),
+                    e
+                );
+                Self::default()
+            }
+        }
+    }
+
+    /// Save the user dictionary
+    pub fn save(&self) -> Result<(), String> {
+        self.save_to(&Self::path())
+    }
+
+    /// Save the dictionary to a specific file
+    pub fn save_to(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize: {}", e))?;
+        fs::write(path, content).map_err(|e| format!("Failed to write dictionary: {}", e))
+    }
+
+    /// Apply the dictionary, then the preset's own entries (if any)
+    pub fn apply(&self, text: &str, preset: Option<&Preset>) -> String {
+        let text = apply_replacements(text, &self.replacements);
+        match preset {
+            Some(preset) => apply_replacements(&text, &preset.dictionary),
+            None => text,
+        }
+    }
+}
@@ -4,11 +4,23 @@
 //! - `TranscriptionProvider` enum (provider selection)
 //! - Default values for settings
 //! - Preset system for post-processing
+//! - Shareable preset bundles (export/import)
+//! - Replacement dictionary
+//! - Template variables for preset prompts
+//! - Structured (JSON) preset output
 
+mod bundle;
 mod defaults;
+mod dictionary;
 mod preset;
 mod provider;
+mod structured_output;
+mod template;
 
+pub use bundle::{ConflictPolicy, ImportOutcome, PresetBundle};
 pub use defaults::*;
-pub use preset::{Preset, PresetSource};
+pub use dictionary::{Dictionary, Replacement, apply_replacements};
+pub use preset::{Preset, PresetSource, PresetStep};
 pub use provider::TranscriptionProvider;
+pub use structured_output::StructuredOutput;
+pub use template::{PromptContext, expand_prompt, validate_prompt};
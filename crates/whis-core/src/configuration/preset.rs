@@ -20,11 +20,70 @@
 //! {
 //!   "description": "What this preset does",
 //!   "prompt": "System prompt for the LLM",
-//!   "post_processor": "openai",  // optional override
-//!   "model": "gpt-4"             // optional override
+//!   "post_processor": "openai",  // optional override (any post-processor, incl. "custom")
+//!   "model": "gpt-4",            // optional override (for anthropic: e.g. "claude-sonnet-4-5")
+//!   "whisper": { "beam_size": 5 } // optional local Whisper tuning override
 //! }
 //! ```
 //!
+//! # Multi-step Presets
+//!
+//! A preset may chain several steps instead of a single prompt. Steps run in
+//! order, each on the previous step's output:
+//!
+//! ```json
+//! {
+//!   "description": "Clean up, translate and bullet",
+//!   "steps": [
+//!     { "type": "replace", "replacements": [{ "from": "whiz", "to": "whis", "whole_word": true }] },
+//!     { "type": "llm", "prompt": "Fix grammar. Output only the text." },
+//!     { "type": "llm", "prompt": "Translate to German.", "post_processor": "ollama", "model": "qwen2.5:3b" },
+//!     { "type": "llm", "prompt": "Format as markdown bullets." }
+//!   ]
+//! }
+//! ```
+//!
+//! Presets without `steps` behave as a single `llm` step built from `prompt`,
+//! `post_processor` and `model`.
+//!
+//! # Dictionary
+//!
+//! A `dictionary` array adds replacement entries (see [`Dictionary`](super::Dictionary))
+//! that apply to the raw transcript whenever this preset is used, even without
+//! post-processing.
+//!
+//! # Template Variables
+//!
+//! Prompts may use `{{date}}`, `{{time}}`, `{{language}}`, `{{clipboard}}`,
+//! `{{selection}}`, `{{env.VAR}}` and `{{active_app}}`, expanded right before
+//! each LLM call (see [`expand_prompt`](super::expand_prompt)):
+//!
+//! ```json
+//! { "prompt": "Write my reply to this email:\n{{clipboard}}" }
+//! ```
+//!
+//! # Structured Output
+//!
+//! An `output` section with a JSON `schema` makes the last `llm` step reply
+//! in JSON, shown pretty-printed or through a `template` (see
+//! [`StructuredOutput`](super::StructuredOutput)). `replace` steps after it
+//! run on the rendered reply:
+//!
+//! ```json
+//! {
+//!   "prompt": "Extract the task from this voice memo.",
+//!   "output": {
+//!     "schema": { "type": "object", "properties": { "title": { "type": "string" } } },
+//!     "template": "TODO: {{title}}"
+//!   }
+//! }
+//! ```
+//!
+//! # Sharing
+//!
+//! Presets can be exported to and imported from a single bundle file (see
+//! [`PresetBundle`](super::PresetBundle)).
+//!
 //! # Usage
 //!
 //! ```ignore
@@ -43,6 +102,9 @@ use std::fs;
 use std::io;
 use std::path::PathBuf;
 
+use super::{Replacement, StructuredOutput, validate_prompt};
+use crate::settings::WhisperTuning;
+
 /// A preset for transcript post-processing
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Preset {
@@ -53,16 +115,63 @@ pub struct Preset {
     /// Human-readable description
     pub description: String,
 
-    /// The system prompt for the LLM
+    /// The system prompt for the LLM (unused when `steps` is set)
+    #[serde(default)]
     pub prompt: String,
 
-    /// Optional: Override the post-processor for this preset (openai, mistral)
+    /// Optional: Override the post-processor for this preset
+    /// (openai, mistral, groq, anthropic, ollama, custom)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub post_processor: Option<String>,
 
     /// Optional: Override the model for this preset
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub model: Option<String>,
+
+    /// Optional: Override local Whisper inference settings for this preset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub whisper: Option<WhisperTuning>,
+
+    /// Optional: Steps to run in order instead of the single prompt
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub steps: Vec<PresetStep>,
+
+    /// Optional: Replacement dictionary entries applied after the user dictionary
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dictionary: Vec<Replacement>,
+
+    /// Optional: Reply in JSON following a schema (applies to the last `llm` step)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<StructuredOutput>,
+}
+
+/// One step of a multi-step preset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PresetStep {
+    /// Run the text through an LLM post-processor
+    Llm {
+        /// The system prompt for this step
+        prompt: String,
+
+        /// Optional: Post-processor for this step (defaults to the preset's/configured one)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        post_processor: Option<String>,
+
+        /// Optional: Model for this step
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        model: Option<String>,
+    },
+    /// Deterministic find/replace, applied in order (same entries as the
+    /// replacement dictionary: literal by default, optionally regex/whole-word)
+    Replace { replacements: Vec<Replacement> },
+}
+
+impl PresetStep {
+    /// Whether this step calls an LLM
+    pub fn is_llm(&self) -> bool {
+        matches!(self, PresetStep::Llm { .. })
+    }
 }
 
 /// Where a preset was loaded from
@@ -105,6 +214,10 @@ impl Preset {
                     .to_string(),
                 post_processor: None,
                 model: None,
+                whisper: None,
+                steps: Vec::new(),
+                dictionary: Vec::new(),
+                output: None,
             },
             Preset {
                 name: "email".to_string(),
@@ -117,6 +230,10 @@ impl Preset {
                     .to_string(),
                 post_processor: None,
                 model: None,
+                whisper: None,
+                steps: Vec::new(),
+                dictionary: Vec::new(),
+                output: None,
             },
             Preset {
                 name: "default".to_string(),
@@ -129,6 +246,10 @@ impl Preset {
                     .to_string(),
                 post_processor: None,
                 model: None,
+                whisper: None,
+                steps: Vec::new(),
+                dictionary: Vec::new(),
+                output: None,
             },
         ]
     }
@@ -237,9 +358,75 @@ impl Preset {
             prompt: "Your system prompt here".to_string(),
             post_processor: None,
             model: None,
+            whisper: None,
+            steps: Vec::new(),
+            dictionary: Vec::new(),
+            output: None,
         }
     }
 
+    /// Check that all prompts only use known template variables
+    pub fn validate_prompts(&self) -> Result<(), String> {
+        let prompts = self.pipeline().into_iter().filter_map(|step| match step {
+            PresetStep::Llm { prompt, .. } => Some(prompt),
+            PresetStep::Replace { .. } => None,
+        });
+        for prompt in prompts {
+            validate_prompt(&prompt).map_err(|e| format!("Preset '{}': {}", self.name, e))?;
+        }
+        Ok(())
+    }
+
+    /// Check that the structured output (if any) can be produced
+    ///
+    /// The schema must be a JSON object and there must be an `llm` step to reply.
+    pub fn validate_output(&self) -> Result<(), String> {
+        let Some(output) = &self.output else {
+            return Ok(());
+        };
+        output
+            .validate()
+            .map_err(|e| format!("Preset '{}': {}", self.name, e))?;
+        if !self.pipeline().iter().any(PresetStep::is_llm) {
+            return Err(format!(
+                "Preset '{}': structured output requires an llm step",
+                self.name
+            ));
+        }
+        Ok(())
+    }
+
+    /// The steps to run for this preset
+    ///
+    /// Single-prompt presets yield one `llm` step. An `llm` step without its own
+    /// post-processor inherits the preset's post-processor and model overrides.
+    pub fn pipeline(&self) -> Vec<PresetStep> {
+        if self.steps.is_empty() {
+            return vec![PresetStep::Llm {
+                prompt: self.prompt.clone(),
+                post_processor: self.post_processor.clone(),
+                model: self.model.clone(),
+            }];
+        }
+
+        self.steps
+            .iter()
+            .cloned()
+            .map(|step| match step {
+                PresetStep::Llm {
+                    prompt,
+                    post_processor: None,
+                    model,
+                } => PresetStep::Llm {
+                    prompt,
+                    post_processor: self.post_processor.clone(),
+                    model: model.or_else(|| self.model.clone()),
+                },
+                step => step,
+            })
+            .collect()
+    }
+
     /// Check if a name is a built-in preset
     pub fn is_builtin(name: &str) -> bool {
         Self::builtins().iter().any(|p| p.name == name)
@@ -12,6 +12,17 @@
 //! - **Mistral** - EU-based alternative
 //! - **Groq** - Fast inference
 //! - **ElevenLabs** - High quality
+//! - **AssemblyAI** - Speaker labels, word timestamps, keyterm prompting
+//! - **AssemblyAI Realtime** - WebSocket streaming (v3 Universal-Streaming)
+//! - **Speechmatics** - Batch jobs API, EU or US region
+//! - **Speechmatics Realtime** - WebSocket streaming, EU or US region
+//! - **Gladia** - Pre-recorded API, EU-hosted
+//! - **Gladia Realtime** - Live WebSocket sessions, EU or US region
+//!
+//! # Network Providers (server address instead of API key)
+//!
+//! - **Wyoming** - Any Wyoming ASR server (e.g. wyoming-faster-whisper) over TCP
+//! - **Wyoming Realtime** - Streams audio to the server while recording
 //!
 //! # Local Providers (no API key, require model download)
 //!
@@ -24,6 +35,7 @@
 //! - `display_name()` - Human-readable name
 //! - `api_key_env_var()` - Environment variable for API key
 //! - `requires_api_key()` - Whether cloud API key is needed
+//! - `requires_pcm()` - Whether audio must be sent uncompressed
 //! - `is_local()` - Whether provider runs locally
 
 use serde::{Deserialize, Serialize};
@@ -42,6 +54,18 @@ pub enum TranscriptionProvider {
     #[serde(rename = "deepgram-realtime")]
     DeepgramRealtime,
     ElevenLabs,
+    AssemblyAI,
+    #[serde(rename = "assemblyai-realtime")]
+    AssemblyAIRealtime,
+    Speechmatics,
+    #[serde(rename = "speechmatics-realtime")]
+    SpeechmaticsRealtime,
+    Gladia,
+    #[serde(rename = "gladia-realtime")]
+    GladiaRealtime,
+    Wyoming,
+    #[serde(rename = "wyoming-realtime")]
+    WyomingRealtime,
     #[serde(rename = "local-whisper")]
     LocalWhisper,
     #[serde(rename = "local-parakeet")]
@@ -65,6 +89,14 @@ impl TranscriptionProvider {
             TranscriptionProvider::Deepgram => "deepgram",
             TranscriptionProvider::DeepgramRealtime => "deepgram-realtime",
             TranscriptionProvider::ElevenLabs => "elevenlabs",
+            TranscriptionProvider::AssemblyAI => "assemblyai",
+            TranscriptionProvider::AssemblyAIRealtime => "assemblyai-realtime",
+            TranscriptionProvider::Speechmatics => "speechmatics",
+            TranscriptionProvider::SpeechmaticsRealtime => "speechmatics-realtime",
+            TranscriptionProvider::Gladia => "gladia",
+            TranscriptionProvider::GladiaRealtime => "gladia-realtime",
+            TranscriptionProvider::Wyoming => "wyoming",
+            TranscriptionProvider::WyomingRealtime => "wyoming-realtime",
             TranscriptionProvider::LocalWhisper => "local-whisper",
             TranscriptionProvider::LocalParakeet => "local-parakeet",
         }
@@ -82,6 +114,18 @@ impl TranscriptionProvider {
                 "DEEPGRAM_API_KEY"
             }
             TranscriptionProvider::ElevenLabs => "ELEVENLABS_API_KEY",
+            TranscriptionProvider::AssemblyAI | TranscriptionProvider::AssemblyAIRealtime => {
+                "ASSEMBLYAI_API_KEY"
+            }
+            TranscriptionProvider::Speechmatics | TranscriptionProvider::SpeechmaticsRealtime => {
+                "SPEECHMATICS_API_KEY"
+            }
+            TranscriptionProvider::Gladia | TranscriptionProvider::GladiaRealtime => {
+                "GLADIA_API_KEY"
+            }
+            TranscriptionProvider::Wyoming | TranscriptionProvider::WyomingRealtime => {
+                "WYOMING_URL"
+            }
             TranscriptionProvider::LocalWhisper => "LOCAL_WHISPER_MODEL_PATH",
             TranscriptionProvider::LocalParakeet => "LOCAL_PARAKEET_MODEL_PATH",
         }
@@ -100,6 +144,14 @@ impl TranscriptionProvider {
             TranscriptionProvider::Mistral,
             TranscriptionProvider::Groq,
             TranscriptionProvider::ElevenLabs,
+            TranscriptionProvider::AssemblyAI,
+            TranscriptionProvider::AssemblyAIRealtime,
+            TranscriptionProvider::Speechmatics,
+            TranscriptionProvider::SpeechmaticsRealtime,
+            TranscriptionProvider::Gladia,
+            TranscriptionProvider::GladiaRealtime,
+            TranscriptionProvider::Wyoming,
+            TranscriptionProvider::WyomingRealtime,
             TranscriptionProvider::LocalWhisper,
             TranscriptionProvider::LocalParakeet,
         ]
@@ -107,12 +159,13 @@ impl TranscriptionProvider {
 
     /// List cloud providers for UI dropdowns (ordered by recommendation).
     ///
-    /// Excludes local providers and realtime variants (realtime is typically
-    /// toggled separately in the UI rather than shown as a separate provider).
+    /// Excludes providers without an API key (local models, Wyoming servers)
+    /// and realtime variants (realtime is typically toggled separately in the
+    /// UI rather than shown as a separate provider).
     pub fn cloud_providers() -> impl Iterator<Item = &'static TranscriptionProvider> {
         Self::all()
             .iter()
-            .filter(|p| !p.is_local() && !p.as_str().contains("realtime"))
+            .filter(|p| p.requires_api_key() && !p.as_str().contains("realtime"))
     }
 
     /// Human-readable display name for this provider
@@ -125,6 +178,14 @@ impl TranscriptionProvider {
             TranscriptionProvider::Deepgram => "Deepgram",
             TranscriptionProvider::DeepgramRealtime => "Deepgram Realtime",
             TranscriptionProvider::ElevenLabs => "ElevenLabs",
+            TranscriptionProvider::AssemblyAI => "AssemblyAI",
+            TranscriptionProvider::AssemblyAIRealtime => "AssemblyAI Realtime",
+            TranscriptionProvider::Speechmatics => "Speechmatics",
+            TranscriptionProvider::SpeechmaticsRealtime => "Speechmatics Realtime",
+            TranscriptionProvider::Gladia => "Gladia",
+            TranscriptionProvider::GladiaRealtime => "Gladia Realtime",
+            TranscriptionProvider::Wyoming => "Wyoming",
+            TranscriptionProvider::WyomingRealtime => "Wyoming Realtime",
             TranscriptionProvider::LocalWhisper => "Local Whisper",
             TranscriptionProvider::LocalParakeet => "Local Parakeet",
         }
@@ -134,7 +195,18 @@ impl TranscriptionProvider {
     pub fn requires_api_key(&self) -> bool {
         !matches!(
             self,
-            TranscriptionProvider::LocalWhisper | TranscriptionProvider::LocalParakeet
+            TranscriptionProvider::LocalWhisper
+                | TranscriptionProvider::LocalParakeet
+                | TranscriptionProvider::Wyoming
+                | TranscriptionProvider::WyomingRealtime
+        )
+    }
+
+    /// Whether this provider takes uncompressed audio (WAV) instead of MP3
+    pub fn requires_pcm(&self) -> bool {
+        matches!(
+            self,
+            TranscriptionProvider::Wyoming | TranscriptionProvider::WyomingRealtime
         )
     }
 
@@ -151,10 +223,18 @@ impl TranscriptionProvider {
     /// Realtime variants share API keys with their base providers:
     /// - `OpenAIRealtime` → "openai"
     /// - `DeepgramRealtime` → "deepgram"
+    /// - `AssemblyAIRealtime` → "assemblyai"
+    /// - `SpeechmaticsRealtime` → "speechmatics"
+    /// - `GladiaRealtime` → "gladia"
+    /// - `WyomingRealtime` → "wyoming"
     pub fn api_key_name(&self) -> &'static str {
         match self {
             Self::OpenAIRealtime => "openai",
             Self::DeepgramRealtime => "deepgram",
+            Self::AssemblyAIRealtime => "assemblyai",
+            Self::SpeechmaticsRealtime => "speechmatics",
+            Self::GladiaRealtime => "gladia",
+            Self::WyomingRealtime => "wyoming",
             _ => self.as_str(),
         }
     }
@@ -196,12 +276,24 @@ impl std::str::FromStr for TranscriptionProvider {
             "deepgram" => Ok(TranscriptionProvider::Deepgram),
             "deepgram-realtime" | "deepgramrealtime" => Ok(TranscriptionProvider::DeepgramRealtime),
             "elevenlabs" => Ok(TranscriptionProvider::ElevenLabs),
+            "assemblyai" => Ok(TranscriptionProvider::AssemblyAI),
+            "assemblyai-realtime" | "assemblyairealtime" => {
+                Ok(TranscriptionProvider::AssemblyAIRealtime)
+            }
+            "speechmatics" => Ok(TranscriptionProvider::Speechmatics),
+            "speechmatics-realtime" | "speechmaticsrealtime" => {
+                Ok(TranscriptionProvider::SpeechmaticsRealtime)
+            }
+            "gladia" => Ok(TranscriptionProvider::Gladia),
+            "gladia-realtime" | "gladiarealtime" => Ok(TranscriptionProvider::GladiaRealtime),
+            "wyoming" => Ok(TranscriptionProvider::Wyoming),
+            "wyoming-realtime" | "wyomingrealtime" => Ok(TranscriptionProvider::WyomingRealtime),
             "local-whisper" | "localwhisper" | "whisper" => Ok(TranscriptionProvider::LocalWhisper),
             "local-parakeet" | "localparakeet" | "parakeet" => {
                 Ok(TranscriptionProvider::LocalParakeet)
             }
             _ => Err(format!(
-                "Unknown provider: {}. Available: openai, openai-realtime, mistral, groq, deepgram, deepgram-realtime, elevenlabs, local-whisper, local-parakeet",
+                "Unknown provider: {}. Available: openai, openai-realtime, mistral, groq, deepgram, deepgram-realtime, elevenlabs, assemblyai, assemblyai-realtime, speechmatics, speechmatics-realtime, gladia, gladia-realtime, wyoming, wyoming-realtime, local-whisper, local-parakeet",
                 s
             )),
         }
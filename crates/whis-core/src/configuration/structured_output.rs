@@ -0,0 +1,97 @@
+//! Structured (JSON) output for presets.
+//!
+//! A preset with an `output` section asks the LLM for JSON following a
+//! schema instead of free text. The reply is validated (and requested once
+//! more if invalid), then shown as pretty-printed JSON or rendered through a
+//! template:
+//!
+//! ```json
+//! {
+//!   "description": "Turn a voice memo into a task",
+//!   "prompt": "Extract the task from this voice memo.",
+//!   "output": {
+//!     "schema": {
+//!       "type": "object",
+//!       "properties": {
+//!         "title": { "type": "string" },
+//!         "due": { "type": "string" },
+//!         "steps": { "type": "array", "items": { "type": "string" } }
+//!       },
+//!       "required": ["title", "steps"]
+//!     },
+//!     "template": "## {{title}} (due {{due}})\n{{steps}}"
+//!   }
+//! }
+//! ```
+//!
+//! # Templates
+//!
+//! `{{field}}` inserts a field of the reply; nested fields and array items
+//! use dots (`{{author.name}}`, `{{steps.0}}`). Strings are inserted as-is,
+//! arrays as a `- item` list, objects as JSON. Missing fields and `null`
+//! insert nothing.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::template::{Part, parts};
+
+/// Structured output settings of a preset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructuredOutput {
+    /// JSON schema the reply must follow
+    pub schema: Value,
+
+    /// Optional: Template for displaying the reply (pretty-printed JSON otherwise)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+impl StructuredOutput {
+    /// Check that the schema is a JSON object
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.schema.is_object() {
+            return Err("Output schema must be a JSON object".to_string());
+        }
+        Ok(())
+    }
+
+    /// Render a reply for output: through the template, or as pretty JSON
+    pub fn render(&self, value: &Value) -> String {
+        let Some(template) = &self.template else {
+            return serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string());
+        };
+
+        parts(template)
+            .into_iter()
+            .map(|part| match part {
+                Part::Literal(literal) => literal.to_string(),
+                Part::Variable(path) => lookup(value, path).map(display).unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+/// Find a dotted path (`author.name`, `steps.0`) in a value
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(value, |value, key| match value {
+        Value::Object(object) => object.get(key),
+        Value::Array(items) => key.parse::<usize>().ok().and_then(|i| items.get(i)),
+        _ => None,
+    })
+}
+
+/// Text for a value inserted into a template
+fn display(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| format!("- {}", display(item)))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Value::Object(_) => serde_json::to_string_pretty(value).unwrap_or_default(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,190 @@
+//! Template variables for preset prompts.
+//!
+//! Prompts may reference `{{variable}}` placeholders that are filled in right
+//! before the LLM call:
+//!
+//! | Variable         | Value                                                |
+//! |------------------|------------------------------------------------------|
+//! | `{{date}}`       | Local date, `YYYY-MM-DD`                             |
+//! | `{{time}}`       | Local time, `HH:MM`                                  |
+//! | `{{language}}`   | Transcription language code (`auto` when detecting)  |
+//! | `{{clipboard}}`  | Current clipboard contents                           |
+//! | `{{selection}}`  | Primary selection (highlighted text, Linux only)     |
+//! | `{{env.VAR}}`    | Environment variable `VAR` (error when unset)        |
+//! | `{{active_app}}` | Focused application (`unknown` if not detectable)    |
+//!
+//! `{{active_app}}` is the app focused when recording started if the caller
+//! detected it then (see [`PromptContext::active_app`]), else the one focused
+//! when the prompt is expanded.
+//!
+//! Whitespace inside the braces is ignored (`{{ date }}`). A `{{` without a
+//! closing `}}` is kept as literal text.
+//!
+//! # Usage
+//!
+//! ```ignore
+//! use whis_core::configuration::{PromptContext, expand_prompt};
+//!
+//! let context = PromptContext::from_settings(&settings);
+//! let prompt = expand_prompt("Reply to this email:\n{{clipboard}}", &context)?;
+//! ```
+
+use anyhow::{Result, anyhow};
+
+#[cfg(feature = "clipboard")]
+use crate::clipboard::ClipboardMethod;
+use crate::settings::Settings;
+
+/// Variable names listed in error messages
+const VARIABLES: &str = "date, time, language, clipboard, selection, env.VAR, active_app";
+
+/// Values available to prompt templates
+#[derive(Debug, Clone, Default)]
+pub struct PromptContext {
+    /// Transcription language (None = auto-detect)
+    pub language: Option<String>,
+    /// Focused application when recording started (None = look it up when
+    /// the prompt is expanded)
+    pub active_app: Option<String>,
+    /// Clipboard backend used to read `{{clipboard}}`
+    #[cfg(feature = "clipboard")]
+    pub clipboard_method: ClipboardMethod,
+}
+
+impl PromptContext {
+    /// Build a context from settings (language and clipboard backend)
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self {
+            language: settings.transcription.language.clone(),
+            active_app: None,
+            #[cfg(feature = "clipboard")]
+            clipboard_method: settings.ui.clipboard_backend.clone(),
+        }
+    }
+}
+
+/// A variable reference found in a prompt
+enum Variable<'a> {
+    Date,
+    Time,
+    Language,
+    Clipboard,
+    Selection,
+    Env(&'a str),
+    ActiveApp,
+}
+
+impl<'a> Variable<'a> {
+    fn parse(name: &'a str) -> Result<Self> {
+        Ok(match name {
+            "date" => Variable::Date,
+            "time" => Variable::Time,
+            "language" => Variable::Language,
+            "clipboard" => Variable::Clipboard,
+            "selection" => Variable::Selection,
+            "active_app" => Variable::ActiveApp,
+            _ => match name.strip_prefix("env.") {
+                Some(var) if !var.is_empty() => Variable::Env(var),
+                _ => {
+                    return Err(anyhow!(
+                        "Unknown template variable '{{{{{name}}}}}' in prompt\nAvailable: {VARIABLES}"
+                    ));
+                }
+            },
+        })
+    }
+
+    fn value(&self, context: &PromptContext) -> Result<String> {
+        Ok(match self {
+            Variable::Date => chrono::Local::now().format("%Y-%m-%d").to_string(),
+            Variable::Time => chrono::Local::now().format("%H:%M").to_string(),
+            Variable::Language => context
+                .language
+                .clone()
+                .unwrap_or_else(|| "auto".to_string()),
+            #[cfg(feature = "clipboard")]
+            Variable::Clipboard => {
+                crate::clipboard::read_clipboard(context.clipboard_method.clone())
+                    .map_err(|e| anyhow!("Failed to read {{{{clipboard}}}}: {e}"))?
+            }
+            #[cfg(feature = "clipboard")]
+            Variable::Selection => crate::clipboard::read_primary_selection()
+                .map_err(|e| anyhow!("Failed to read {{{{selection}}}}: {e}"))?,
+            #[cfg(not(feature = "clipboard"))]
+            Variable::Clipboard | Variable::Selection => {
+                return Err(anyhow!(
+                    "{{{{clipboard}}}} and {{{{selection}}}} are not available in this build"
+                ));
+            }
+            Variable::Env(var) => std::env::var(var).map_err(|_| {
+                anyhow!("Environment variable '{var}' used as {{{{env.{var}}}}} is not set")
+            })?,
+            Variable::ActiveApp => context
+                .active_app
+                .clone()
+                .or_else(|| crate::active_window::detect_active_window().map(|window| window.app))
+                .unwrap_or_else(|| "unknown".to_string()),
+        })
+    }
+}
+
+/// A piece of a template: literal text or a `{{variable}}` name
+pub(super) enum Part<'a> {
+    Literal(&'a str),
+    Variable(&'a str),
+}
+
+/// Split a template into literal text and variable names
+pub(super) fn parts(prompt: &str) -> Vec<Part<'_>> {
+    let mut parts = Vec::new();
+    let mut rest = prompt;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        parts.push(Part::Literal(&rest[..start]));
+        parts.push(Part::Variable(rest[start + 2..start + 2 + len].trim()));
+        rest = &rest[start + 2 + len + 2..];
+    }
+    parts.push(Part::Literal(rest));
+    parts
+}
+
+/// Check that a prompt only references known variables (without reading them)
+pub fn validate_prompt(prompt: &str) -> Result<()> {
+    for part in parts(prompt) {
+        if let Part::Variable(name) = part {
+            Variable::parse(name)?;
+        }
+    }
+    Ok(())
+}
+
+/// Expand the template variables in a prompt
+///
+/// Each distinct variable is read once, so `{{clipboard}}` used twice
+/// yields the same text.
+pub fn expand_prompt(prompt: &str, context: &PromptContext) -> Result<String> {
+    let mut expanded = String::with_capacity(prompt.len());
+    let mut cache: Vec<(&str, String)> = Vec::new();
+
+    for part in parts(prompt) {
+        let name = match part {
+            Part::Literal(literal) => {
+                expanded.push_str(literal);
+                continue;
+            }
+            Part::Variable(name) => name,
+        };
+
+        if let Some((_, value)) = cache.iter().find(|(cached, _)| *cached == name) {
+            expanded.push_str(value);
+            continue;
+        }
+        let value = Variable::parse(name)?.value(context)?;
+        expanded.push_str(&value);
+        cache.push((name, value));
+    }
+
+    Ok(expanded)
+}
@@ -0,0 +1,425 @@
+//! D-Bus recorder interface (Linux only)
+//!
+//! Exposes recording control on the session bus so GNOME Shell / KDE extensions
+//! and scripts can drive whis without spawning `whis toggle`. Served by the CLI
+//! background service and the desktop app.
+//!
+//! # Interface
+//!
+//! Bus name `ink.whis.Recorder`, object `/ink/whis/Recorder`, interface
+//! `ink.whis.Recorder`:
+//!
+//! | Member            | Kind     | Description                                   |
+//! |-------------------|----------|-----------------------------------------------|
+//! | `Toggle()`        | method   | Start recording, or stop and transcribe       |
+//! | `Start()`         | method   | Start recording                               |
+//! | `Stop()`          | method   | Stop recording and transcribe                 |
+//! | `Cancel()`        | method   | Stop recording and discard the audio          |
+//! | `State`           | property | `idle`, `recording` or `transcribing`         |
+//! | `ActivePreset`    | property | Name of the active preset (empty if none)     |
+//! | `TranscriptReady` | signal   | Emitted with the final text of each recording |
+//!
+//! Properties emit `PropertiesChanged`, so clients can watch them instead of
+//! polling. Methods return once the front end has handled the command; `Stop()`
+//! and `Toggle()` do not wait for the transcription (which can outlast the
+//! client's method call timeout), the text arrives via `TranscriptReady`.
+//! Example:
+//!
+//! ```bash
+//! busctl --user call ink.whis.Recorder /ink/whis/Recorder ink.whis.Recorder Toggle
+//! busctl --user get-property ink.whis.Recorder /ink/whis/Recorder ink.whis.Recorder State
+//! ```
+//!
+//! # Architecture
+//!
+//! Same shape as the HTTP API: the interface does not own the recorder. Method
+//! calls are forwarded to the front end as [`DbusRequest`]s, and the front end
+//! reports state and results back through [`DbusHandle`].
+
+use anyhow::{Context, Result};
+use tokio::sync::{mpsc, oneshot};
+use zbus::object_server::{InterfaceRef, SignalEmitter};
+use zbus::{Connection, connection, fdo, interface};
+
+use crate::state::{RecordingState, RemoteCommand};
+
+/// Well-known bus name requested on the session bus
+pub const BUS_NAME: &str = "ink.whis.Recorder";
+
+/// Object path of the recorder object
+pub const OBJECT_PATH: &str = "/ink/whis/Recorder";
+
+/// A control command with a reply channel for the outcome
+pub struct DbusRequest {
+    pub command: RemoteCommand,
+    pub reply: oneshot::Sender<Result<(), String>>,
+}
+
+/// Update pushed from the front end to the bus task
+enum Update {
+    State(RecordingState),
+    ActivePreset(String),
+    Transcript(String),
+}
+
+/// The `ink.whis.Recorder` object served on the bus
+struct Recorder {
+    state: RecordingState,
+    active_preset: String,
+    commands: mpsc::Sender<DbusRequest>,
+}
+
+impl Recorder {
+    /// Forward a command to the front end and wait for its reply
+    ///
+    /// Front ends reply once the command is handled (a stopped recording is
+    /// transcribed in the background), so a command queued behind another
+    /// one gets its own outcome rather than the other one's state change.
+    async fn dispatch(&self, command: RemoteCommand) -> fdo::Result<()> {
+        let (reply, outcome) = oneshot::channel();
+        self.commands
+            .send(DbusRequest { command, reply })
+            .await
+            .map_err(|_| fdo::Error::Failed("Recorder is shutting down".to_string()))?;
+
+        outcome
+            .await
+            .map_err(|_| fdo::Error::Failed("Recorder dropped the request".to_string()))?
+            .map_err(fdo::Error::Failed)
+    }
+}
+
+#[interface(name = "ink.whis.Recorder")]
+impl Recorder {
+    async fn toggle(&self) -> fdo::Result<()> {
+        self.dispatch(RemoteCommand::Toggle).await
+    }
+
+    async fn start(&self) -> fdo::Result<()> {
+        self.dispatch(RemoteCommand::Start).await
+    }
+
+    async fn stop(&self) -> fdo::Result<()> {
+        self.dispatch(RemoteCommand::Stop).await
+    }
+
+    async fn cancel(&self) -> fdo::Result<()> {
+        self.dispatch(RemoteCommand::Cancel).await
+    }
+
+    #[zbus(property)]
+    fn state(&self) -> String {
+        self.state.as_str().to_string()
+    }
+
+    #[zbus(property)]
+    fn active_preset(&self) -> String {
+        self.active_preset.clone()
+    }
+
+    #[zbus(signal)]
+    async fn transcript_ready(emitter: &SignalEmitter<'_>, text: &str) -> zbus::Result<()>;
+}
+
+/// Handle used by the front end to report state changes and results
+///
+/// Updates are queued and applied by a background task, so the methods can be
+/// called from synchronous code.
+#[derive(Clone)]
+pub struct DbusHandle {
+    updates: mpsc::UnboundedSender<Update>,
+}
+
+impl DbusHandle {
+    /// Report a recording state change (emits `PropertiesChanged`)
+    pub fn set_state(&self, state: RecordingState) {
+        let _ = self.updates.send(Update::State(state));
+    }
+
+    /// Report the active preset (emits `PropertiesChanged`)
+    pub fn set_active_preset(&self, preset: Option<&str>) {
+        let _ = self
+            .updates
+            .send(Update::ActivePreset(preset.unwrap_or_default().to_string()));
+    }
+
+    /// Report a completed transcript (emits `TranscriptReady`)
+    pub fn transcript_ready(&self, text: &str) {
+        let _ = self.updates.send(Update::Transcript(text.to_string()));
+    }
+}
+
+/// Serve the recorder object on the session bus
+///
+/// Must be called from within a Tokio runtime. Honors
+/// `DBUS_SESSION_BUS_ADDRESS`, so a private `dbus-daemon` can be used for
+/// testing. The connection stays open until the runtime shuts down.
+///
+/// # Errors
+///
+/// Returns an error if the session bus is unreachable or another instance
+/// already owns `ink.whis.Recorder`.
+pub async fn start_service(
+    active_preset: Option<&str>,
+) -> Result<(DbusHandle, mpsc::Receiver<DbusRequest>)> {
+    serve(connection::Builder::session()?, active_preset).await
+}
+
+/// Serve the recorder object on the bus `builder` connects to
+async fn serve(
+    builder: connection::Builder<'_>,
+    active_preset: Option<&str>,
+) -> Result<(DbusHandle, mpsc::Receiver<DbusRequest>)> {
+    let (commands_tx, commands_rx) = mpsc::channel(8);
+    let recorder = Recorder {
+        state: RecordingState::Idle,
+        active_preset: active_preset.unwrap_or_default().to_string(),
+        commands: commands_tx,
+    };
+
+    let connection = builder
+        .name(BUS_NAME)?
+        .serve_at(OBJECT_PATH, recorder)?
+        .build()
+        .await
+        .with_context(|| format!("Failed to acquire D-Bus name {BUS_NAME}"))?;
+
+    let recorder = connection
+        .object_server()
+        .interface::<_, Recorder>(OBJECT_PATH)
+        .await?;
+
+    let (updates_tx, updates_rx) = mpsc::unbounded_channel();
+    tokio::spawn(apply_updates(connection, recorder, updates_rx));
+
+    Ok((
+        DbusHandle {
+            updates: updates_tx,
+        },
+        commands_rx,
+    ))
+}
+
+/// Apply front end updates to the served object until all handles are dropped
+async fn apply_updates(
+    // Owned so the bus name stays claimed for the lifetime of the task
+    _connection: Connection,
+    recorder: InterfaceRef<Recorder>,
+    mut updates: mpsc::UnboundedReceiver<Update>,
+) {
+    while let Some(update) = updates.recv().await {
+        let emitter = recorder.signal_emitter();
+        let result = match update {
+            Update::State(state) => {
+                let mut object = recorder.get_mut().await;
+                if object.state == state {
+                    continue;
+                }
+                object.state = state;
+                object.state_changed(emitter).await
+            }
+            Update::ActivePreset(preset) => {
+                let mut object = recorder.get_mut().await;
+                if object.active_preset == preset {
+                    continue;
+                }
+                object.active_preset = preset;
+                object.active_preset_changed(emitter).await
+            }
+            Update::Transcript(text) => Recorder::transcript_ready(emitter, &text).await,
+        };
+
+        if let Err(e) = result {
+            crate::verbose!("D-Bus update failed: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+    use std::io::{BufRead, BufReader};
+    use std::process::{Child, Command, Stdio};
+    use std::time::Duration;
+    use zbus::proxy::CacheProperties;
+
+    /// Private session bus, stopped on drop
+    struct TestBus {
+        daemon: Child,
+        address: String,
+    }
+
+    impl TestBus {
+        /// Start a `dbus-daemon`, or `None` if it is not installed
+        fn start() -> Option<Self> {
+            let mut daemon = Command::new("dbus-daemon")
+                .args(["--session", "--nofork", "--print-address"])
+                .stdout(Stdio::piped())
+                .spawn()
+                .ok()?;
+            let mut address = String::new();
+            BufReader::new(daemon.stdout.take()?)
+                .read_line(&mut address)
+                .ok()?;
+            Some(Self {
+                daemon,
+                address: address.trim().to_string(),
+            })
+        }
+
+        fn builder(&self) -> connection::Builder<'_> {
+            connection::Builder::address(self.address.as_str()).unwrap()
+        }
+
+        /// Client proxy for the recorder object (uncached, so reads hit the bus)
+        async fn proxy(&self) -> zbus::Proxy<'static> {
+            let connection = self.builder().build().await.unwrap();
+            zbus::proxy::Builder::new(&connection)
+                .destination(BUS_NAME)
+                .unwrap()
+                .path(OBJECT_PATH)
+                .unwrap()
+                .interface(BUS_NAME)
+                .unwrap()
+                .cache_properties(CacheProperties::No)
+                .build()
+                .await
+                .unwrap()
+        }
+    }
+
+    impl Drop for TestBus {
+        fn drop(&mut self) {
+            let _ = self.daemon.kill();
+            let _ = self.daemon.wait();
+        }
+    }
+
+    macro_rules! test_bus {
+        () => {
+            match TestBus::start() {
+                Some(bus) => bus,
+                None => {
+                    eprintln!("dbus-daemon not found, skipping");
+                    return;
+                }
+            }
+        };
+    }
+
+    /// Front end stand-in: starts recordings right away, and replies to `Stop`
+    /// once the transcription is under way (it never finishes on its own)
+    fn spawn_front_end(handle: DbusHandle, mut requests: mpsc::Receiver<DbusRequest>) {
+        tokio::spawn(async move {
+            while let Some(request) = requests.recv().await {
+                match request.command {
+                    RemoteCommand::Start => {
+                        handle.set_state(RecordingState::Recording);
+                        let _ = request.reply.send(Ok(()));
+                    }
+                    RemoteCommand::Stop => {
+                        handle.set_state(RecordingState::Transcribing);
+                        let _ = request.reply.send(Ok(()));
+                    }
+                    RemoteCommand::Toggle | RemoteCommand::Cancel => {
+                        let _ = request.reply.send(Err("Not recording".to_string()));
+                    }
+                }
+            }
+        });
+    }
+
+    async fn wait_for_property(proxy: &zbus::Proxy<'_>, name: &str, expected: &str) {
+        for _ in 0..50 {
+            if proxy.get_property::<String>(name).await.unwrap() == expected {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("{name} never became '{expected}'");
+    }
+
+    #[tokio::test]
+    async fn stop_replies_before_transcription_finishes() {
+        let bus = test_bus!();
+        let (handle, requests) = serve(bus.builder(), None).await.unwrap();
+        spawn_front_end(handle.clone(), requests);
+
+        let proxy = bus.proxy().await;
+        let mut transcripts = proxy.receive_signal("TranscriptReady").await.unwrap();
+
+        proxy.call_method("Start", &()).await.unwrap();
+        wait_for_property(&proxy, "State", "recording").await;
+
+        tokio::time::timeout(Duration::from_secs(5), proxy.call_method("Stop", &()))
+            .await
+            .expect("Stop waited for the transcription")
+            .unwrap();
+        wait_for_property(&proxy, "State", "transcribing").await;
+
+        handle.transcript_ready("hello world");
+        handle.set_state(RecordingState::Idle);
+
+        let signal = tokio::time::timeout(Duration::from_secs(5), transcripts.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            signal.body().deserialize::<String>().unwrap(),
+            "hello world"
+        );
+        wait_for_property(&proxy, "State", "idle").await;
+    }
+
+    #[tokio::test]
+    async fn front_end_errors_are_returned() {
+        let bus = test_bus!();
+        let (handle, requests) = serve(bus.builder(), None).await.unwrap();
+        spawn_front_end(handle, requests);
+
+        let proxy = bus.proxy().await;
+        let error = proxy.call_method("Cancel", &()).await.unwrap_err();
+        assert!(error.to_string().contains("Not recording"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn replies_wait_for_the_command_to_be_handled() {
+        let bus = test_bus!();
+        let (handle, mut requests) = serve(bus.builder(), None).await.unwrap();
+
+        // A transcription finishes while `Start` waits in the queue; the
+        // front end then rejects it, and the state change is not the reply
+        tokio::spawn(async move {
+            let request = requests.recv().await.unwrap();
+            handle.set_state(RecordingState::Transcribing);
+            handle.set_state(RecordingState::Idle);
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            let _ = request
+                .reply
+                .send(Err("Transcription in progress".to_string()));
+        });
+
+        let proxy = bus.proxy().await;
+        let error = proxy.call_method("Start", &()).await.unwrap_err();
+        assert!(
+            error.to_string().contains("Transcription in progress"),
+            "{error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn active_preset_property_follows_updates() {
+        let bus = test_bus!();
+        let (handle, _requests) = serve(bus.builder(), Some("email")).await.unwrap();
+
+        let proxy = bus.proxy().await;
+        wait_for_property(&proxy, "ActivePreset", "email").await;
+
+        handle.set_active_preset(Some("notes"));
+        wait_for_property(&proxy, "ActivePreset", "notes").await;
+
+        handle.set_active_preset(None);
+        wait_for_property(&proxy, "ActivePreset", "").await;
+    }
+}
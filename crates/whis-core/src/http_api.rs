@@ -0,0 +1,625 @@
+//! Local HTTP/REST API for controlling recording
+//!
+//! Lets browser extensions and editor plugins trigger dictation without shelling
+//! out to `whis toggle`. Served by the CLI background service and the desktop app
+//! when `services.http_api.enabled` is set.
+//!
+//! # Security
+//!
+//! - Bound to `127.0.0.1` only
+//! - Every request must carry `Authorization: Bearer <token>` (from settings)
+//!
+//! # Endpoints
+//!
+//! | Method | Path          | Description                                        |
+//! |--------|---------------|----------------------------------------------------|
+//! | GET    | `/status`     | Current recording state                            |
+//! | POST   | `/start`      | Start recording                                    |
+//! | POST   | `/stop`       | Stop recording and transcribe                      |
+//! | POST   | `/cancel`     | Stop recording and discard the audio               |
+//! | GET    | `/transcript` | Last completed transcript                          |
+//! | GET    | `/events`     | Server-sent events (`state`, `transcript`, `error`)|
+//! | POST   | `/transcribe` | Transcribe the audio file in the request body      |
+//!
+//! # Architecture
+//!
+//! The server does not own the recorder. Control requests are forwarded to the
+//! front end through an [`ApiRequest`] channel, and the front end reports state
+//! changes and results back through the shared [`ApiHandle`]:
+//!
+//! ```text
+//! HTTP client ──► server ──ApiRequest──► front end (CLI service / desktop)
+//!                   ▲                          │
+//!                   └────────ApiHandle─────────┘
+//!                     (state, transcript, SSE events)
+//! ```
+
+use anyhow::{Context, Result};
+use axum::{
+    Json, Router,
+    body::Bytes,
+    extract::{DefaultBodyLimit, Query, Request, State},
+    http::{HeaderMap, StatusCode, header},
+    middleware::{self, Next},
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
+    routing::{get, post},
+};
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{broadcast, mpsc, oneshot};
+
+use crate::configuration::TranscriptionProvider;
+use crate::provider::{TranscriptionRequest, registry};
+use crate::settings::{HttpApiConfig, TranscriptionSettings};
+use crate::state::{RecordingState, RemoteCommand};
+
+/// Maximum upload size for `POST /transcribe` (cloud providers cap at ~25 MB)
+const MAX_UPLOAD_BYTES: usize = 50 * 1024 * 1024;
+
+/// Capacity of the SSE broadcast channel (slow clients skip older events)
+const EVENT_CHANNEL_CAPACITY: usize = 64;
+
+/// A control command with a reply channel for the outcome
+pub struct ApiRequest {
+    pub command: RemoteCommand,
+    pub reply: oneshot::Sender<Result<(), String>>,
+}
+
+/// Event published to `/events` subscribers
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ApiEvent {
+    /// Recording state changed
+    State { state: &'static str },
+    /// A transcription finished
+    Transcript { text: String },
+    /// Recording or transcription failed
+    Error { message: String },
+}
+
+impl ApiEvent {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::State { .. } => "state",
+            Self::Transcript { .. } => "transcript",
+            Self::Error { .. } => "error",
+        }
+    }
+}
+
+/// Shared state between the server and the front end
+struct Shared {
+    token: String,
+    /// Provider, language and keys used by `/transcribe`
+    transcription: Mutex<TranscriptionSettings>,
+    state: Mutex<RecordingState>,
+    last_transcript: Mutex<Option<String>>,
+    events: broadcast::Sender<ApiEvent>,
+    commands: mpsc::Sender<ApiRequest>,
+}
+
+/// Handle used by the front end to report state changes and results
+#[derive(Clone)]
+pub struct ApiHandle {
+    shared: Arc<Shared>,
+}
+
+impl ApiHandle {
+    /// Report a recording state change
+    pub fn set_state(&self, state: RecordingState) {
+        let changed = {
+            let mut current = self.shared.state.lock().unwrap();
+            let changed = *current != state;
+            *current = state;
+            changed
+        };
+
+        if changed {
+            let _ = self.shared.events.send(ApiEvent::State {
+                state: state.as_str(),
+            });
+        }
+    }
+
+    /// Report a completed transcript (stored for `GET /transcript`)
+    pub fn transcript_ready(&self, text: &str) {
+        *self.shared.last_transcript.lock().unwrap() = Some(text.to_string());
+        let _ = self.shared.events.send(ApiEvent::Transcript {
+            text: text.to_string(),
+        });
+    }
+
+    /// Replace the transcription settings used by `/transcribe`
+    ///
+    /// The server keeps the settings it was started with; front ends that let
+    /// users change settings at runtime push the new ones here.
+    pub fn set_transcription_settings(&self, settings: TranscriptionSettings) {
+        *self.shared.transcription.lock().unwrap() = settings;
+    }
+
+    /// Report a recording or transcription error
+    pub fn report_error(&self, message: &str) {
+        let _ = self.shared.events.send(ApiEvent::Error {
+            message: message.to_string(),
+        });
+    }
+}
+
+/// Start the HTTP API server on `127.0.0.1:<port>`
+///
+/// Must be called from within a Tokio runtime. `transcription` is used by
+/// `/transcribe` until replaced with [`ApiHandle::set_transcription_settings`].
+/// Returns the handle for reporting state and the receiver for control
+/// commands; the server runs until the runtime shuts down.
+///
+/// # Errors
+///
+/// Returns an error if no token is configured or the port is unavailable.
+pub async fn start_server(
+    config: &HttpApiConfig,
+    transcription: TranscriptionSettings,
+) -> Result<(ApiHandle, mpsc::Receiver<ApiRequest>)> {
+    let token = config
+        .token()
+        .context("HTTP API token not configured (set it with: whis config http-api-token <token>)")?
+        .to_string();
+
+    let (handle, commands_rx) = new_handle(token, transcription);
+    let app = router(handle.clone());
+
+    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, config.port));
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind HTTP API to {addr}"))?;
+
+    crate::verbose!("HTTP API listening on http://{addr}");
+
+    tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, app).await {
+            crate::error!("HTTP API server stopped: {e}");
+        }
+    });
+
+    Ok((handle, commands_rx))
+}
+
+fn new_handle(
+    token: String,
+    transcription: TranscriptionSettings,
+) -> (ApiHandle, mpsc::Receiver<ApiRequest>) {
+    let (commands_tx, commands_rx) = mpsc::channel(8);
+    let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+
+    let handle = ApiHandle {
+        shared: Arc::new(Shared {
+            token,
+            transcription: Mutex::new(transcription),
+            state: Mutex::new(RecordingState::Idle),
+            last_transcript: Mutex::new(None),
+            events,
+            commands: commands_tx,
+        }),
+    };
+    (handle, commands_rx)
+}
+
+fn router(handle: ApiHandle) -> Router {
+    Router::new()
+        .route("/status", get(status))
+        .route("/start", post(start))
+        .route("/stop", post(stop))
+        .route("/cancel", post(cancel))
+        .route("/transcript", get(transcript))
+        .route("/events", get(event_stream))
+        .route(
+            "/transcribe",
+            post(transcribe).layer(DefaultBodyLimit::max(MAX_UPLOAD_BYTES)),
+        )
+        .layer(middleware::from_fn_with_state(handle.clone(), authorize))
+        .with_state(handle)
+}
+
+fn error_response(status: StatusCode, message: impl Into<String>) -> Response {
+    let body = serde_json::json!({ "error": message.into() });
+    (status, Json(body)).into_response()
+}
+
+/// Reject requests without the configured bearer token
+async fn authorize(State(api): State<ApiHandle>, request: Request, next: Next) -> Response {
+    let provided = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match provided {
+        Some(token) if constant_time_eq(token.as_bytes(), api.shared.token.as_bytes()) => {
+            next.run(request).await
+        }
+        _ => error_response(StatusCode::UNAUTHORIZED, "Missing or invalid bearer token"),
+    }
+}
+
+/// Compare tokens without leaking the mismatch position through timing
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[derive(Serialize)]
+struct StatusResponse {
+    state: &'static str,
+}
+
+async fn status(State(api): State<ApiHandle>) -> Json<StatusResponse> {
+    let state = *api.shared.state.lock().unwrap();
+    Json(StatusResponse {
+        state: state.as_str(),
+    })
+}
+
+/// Forward a control command to the front end and wait for its outcome
+async fn send_command(api: &ApiHandle, command: RemoteCommand) -> Response {
+    let (reply, outcome) = oneshot::channel();
+    if api
+        .shared
+        .commands
+        .send(ApiRequest { command, reply })
+        .await
+        .is_err()
+    {
+        return error_response(StatusCode::SERVICE_UNAVAILABLE, "Recorder not available");
+    }
+
+    match outcome.await {
+        Ok(Ok(())) => status(State(api.clone())).await.into_response(),
+        Ok(Err(e)) => error_response(StatusCode::CONFLICT, e),
+        Err(_) => error_response(StatusCode::SERVICE_UNAVAILABLE, "Recorder not available"),
+    }
+}
+
+async fn start(State(api): State<ApiHandle>) -> Response {
+    send_command(&api, RemoteCommand::Start).await
+}
+
+async fn stop(State(api): State<ApiHandle>) -> Response {
+    send_command(&api, RemoteCommand::Stop).await
+}
+
+async fn cancel(State(api): State<ApiHandle>) -> Response {
+    send_command(&api, RemoteCommand::Cancel).await
+}
+
+#[derive(Serialize)]
+struct TranscriptResponse {
+    text: String,
+}
+
+async fn transcript(State(api): State<ApiHandle>) -> Response {
+    match api.shared.last_transcript.lock().unwrap().clone() {
+        Some(text) => Json(TranscriptResponse { text }).into_response(),
+        None => error_response(StatusCode::NOT_FOUND, "No transcript yet"),
+    }
+}
+
+async fn event_stream(
+    State(api): State<ApiHandle>,
+) -> Sse<impl futures_util::Stream<Item = Result<Event, Infallible>>> {
+    let rx = api.shared.events.subscribe();
+
+    let stream = futures_util::stream::unfold(rx, |mut rx| async move {
+        loop {
+            match rx.recv().await {
+                Ok(event) => {
+                    let sse = Event::default()
+                        .event(event.name())
+                        .json_data(&event)
+                        .unwrap_or_default();
+                    return Some((Ok(sse), rx));
+                }
+                // Slow client: skip the events it missed
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+#[derive(Deserialize)]
+struct TranscribeParams {
+    /// Language hint (overrides the configured language)
+    language: Option<String>,
+    /// Original filename, used by providers to detect the format
+    filename: Option<String>,
+}
+
+/// Transcribe an uploaded audio file with the configured provider
+async fn transcribe(
+    State(api): State<ApiHandle>,
+    Query(params): Query<TranscribeParams>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    if body.is_empty() {
+        return error_response(StatusCode::BAD_REQUEST, "Request body is empty");
+    }
+
+    let mime_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("application/octet-stream")
+        .to_string();
+    let filename = params
+        .filename
+        .unwrap_or_else(|| format!("audio.{}", extension_for_mime(&mime_type)));
+
+    let settings = api.shared.transcription.lock().unwrap().clone();
+    let provider = settings.provider.clone();
+    let language = params.language.or_else(|| settings.language.clone());
+
+    // Local providers receive their model path in place of an API key
+    let api_key = match provider {
+        TranscriptionProvider::LocalWhisper => settings.whisper_model_path(),
+        TranscriptionProvider::LocalParakeet => settings.parakeet_model_path(),
+        _ => settings.api_key_for(&provider),
+    };
+    let Some(api_key) = api_key else {
+        return error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            format!("{} is not configured", provider.display_name()),
+        );
+    };
+
+    let result = async {
+        let backend = registry().get_by_kind(&provider)?;
+        let client = crate::http::get_http_client()?;
+        backend
+            .transcribe_async(
+                client,
+                &api_key,
+                TranscriptionRequest {
+                    audio_data: body.to_vec(),
+                    language,
+                    filename,
+                    mime_type,
+                    progress: None,
+                    options: settings.provider_options(),
+                },
+            )
+            .await
+    }
+    .await;
+
+    match result {
+        Ok(result) => Json(TranscriptResponse { text: result.text }).into_response(),
+        Err(e) => error_response(StatusCode::UNPROCESSABLE_ENTITY, e.to_string()),
+    }
+}
+
+/// Guess a file extension for providers that detect the format from the filename
+fn extension_for_mime(mime_type: &str) -> &'static str {
+    match mime_type.split(';').next().unwrap_or_default().trim() {
+        "audio/wav" | "audio/x-wav" | "audio/wave" => "wav",
+        "audio/ogg" => "ogg",
+        "audio/webm" => "webm",
+        "audio/flac" => "flac",
+        "audio/mp4" | "audio/m4a" | "audio/x-m4a" => "m4a",
+        _ => "mp3",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::test_wyoming::WyomingServer;
+    use axum::body::Body;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    const TOKEN: &str = "secret";
+
+    fn app(transcription: TranscriptionSettings) -> (ApiHandle, Router) {
+        let (handle, _commands) = new_handle(TOKEN.to_string(), transcription);
+        let app = router(handle.clone());
+        (handle, app)
+    }
+
+    fn request(method: &str, uri: &str) -> axum::http::request::Builder {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::AUTHORIZATION, format!("Bearer {TOKEN}"))
+    }
+
+    async fn send(app: Router, request: Request) -> (StatusCode, Value) {
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    fn wyoming_settings(url: &str) -> TranscriptionSettings {
+        let mut settings = TranscriptionSettings {
+            provider: TranscriptionProvider::Wyoming,
+            language: Some("en".to_string()),
+            ..TranscriptionSettings::default()
+        };
+        settings.wyoming.url = Some(url.to_string());
+        settings
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_or_wrong_token() {
+        let (_, app) = app(TranscriptionSettings::default());
+
+        let missing = Request::get("/status").body(Body::empty()).unwrap();
+        let (status, body) = send(app.clone(), missing).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["error"], "Missing or invalid bearer token");
+
+        let wrong = Request::get("/status")
+            .header(header::AUTHORIZATION, "Bearer secreT")
+            .body(Body::empty())
+            .unwrap();
+        let (status, _) = send(app.clone(), wrong).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, body) =
+            send(app, request("GET", "/status").body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, json!({ "state": "idle" }));
+    }
+
+    #[tokio::test]
+    async fn transcript_is_not_found_until_reported() {
+        let (handle, app) = app(TranscriptionSettings::default());
+
+        let get = || request("GET", "/transcript").body(Body::empty()).unwrap();
+        let (status, _) = send(app.clone(), get()).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        handle.transcript_ready("hello");
+        let (status, body) = send(app, get()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, json!({ "text": "hello" }));
+    }
+
+    #[tokio::test]
+    async fn commands_fail_without_a_front_end() {
+        let (_, app) = app(TranscriptionSettings::default());
+
+        let (status, body) =
+            send(app, request("POST", "/start").body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["error"], "Recorder not available");
+    }
+
+    #[tokio::test]
+    async fn transcribe_rejects_empty_and_oversized_bodies() {
+        let (_, app) = app(TranscriptionSettings::default());
+
+        let empty = request("POST", "/transcribe").body(Body::empty()).unwrap();
+        let (status, body) = send(app.clone(), empty).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"], "Request body is empty");
+
+        let oversized = request("POST", "/transcribe")
+            .body(Body::from(vec![0u8; MAX_UPLOAD_BYTES + 1]))
+            .unwrap();
+        let (status, _) = send(app, oversized).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn transcribe_reports_unconfigured_provider() {
+        let (_, app) = app(wyoming_settings(""));
+
+        let upload = request("POST", "/transcribe")
+            .body(Body::from("RIFF"))
+            .unwrap();
+        let (status, body) = send(app, upload).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["error"], "Wyoming is not configured");
+    }
+
+    #[tokio::test]
+    async fn transcribe_uses_the_server_settings() {
+        let server = WyomingServer::start(vec![(
+            "audio-stop",
+            vec![("transcript", json!({ "text": " hallo " }))],
+        )])
+        .await;
+        let (handle, app) = app(wyoming_settings("tcp://127.0.0.1:1"));
+        handle.set_transcription_settings(wyoming_settings(&server.url));
+
+        let wav = crate::audio::encode_wav(&[0.25; 1600], 16000);
+        let upload = request("POST", "/transcribe?language=de")
+            .header(header::CONTENT_TYPE, "audio/wav")
+            .body(Body::from(wav))
+            .unwrap();
+        let (status, body) = send(app, upload).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, json!({ "text": "hallo" }));
+
+        let events = server.events();
+        assert_eq!(events[0].kind, "transcribe");
+        assert_eq!(events[0].data["language"], "de");
+    }
+
+    #[tokio::test]
+    async fn transcribe_names_the_upload_after_its_content_type() {
+        let (_, app) = app(wyoming_settings("tcp://127.0.0.1:1"));
+
+        let upload = request("POST", "/transcribe")
+            .header(header::CONTENT_TYPE, "audio/ogg; codecs=opus")
+            .body(Body::from("OggS"))
+            .unwrap();
+        let (status, body) = send(app, upload).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(
+            body["error"]
+                .as_str()
+                .unwrap()
+                .starts_with("Cannot send audio.ogg to Wyoming"),
+            "{body}"
+        );
+    }
+
+    #[cfg(feature = "local-transcription")]
+    #[tokio::test]
+    async fn transcribe_decodes_wav_uploads_for_local_whisper() {
+        let mut settings = TranscriptionSettings {
+            provider: TranscriptionProvider::LocalWhisper,
+            ..TranscriptionSettings::default()
+        };
+        settings.local_models.whisper_path = Some("/nonexistent/ggml-tiny.bin".to_string());
+        let (_, app) = app(settings);
+
+        // A WAV upload is decoded and reaches the model
+        let wav = crate::audio::encode_wav(&[0.25; 1600], 16000);
+        let upload = request("POST", "/transcribe")
+            .header(header::CONTENT_TYPE, "audio/wav")
+            .body(Body::from(wav))
+            .unwrap();
+        let (status, body) = send(app.clone(), upload).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(
+            body["error"]
+                .as_str()
+                .unwrap()
+                .starts_with("Whisper model not found at: /nonexistent/ggml-tiny.bin"),
+            "{body}"
+        );
+
+        // Other formats are rejected before the model is loaded
+        let upload = request("POST", "/transcribe")
+            .header(header::CONTENT_TYPE, "audio/ogg")
+            .body(Body::from("OggS"))
+            .unwrap();
+        let (status, body) = send(app, upload).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(
+            body["error"]
+                .as_str()
+                .unwrap()
+                .starts_with("Unsupported audio format for local transcription: audio.ogg"),
+            "{body}"
+        );
+    }
+
+    #[test]
+    fn extension_follows_mime_type() {
+        assert_eq!(extension_for_mime("audio/x-wav"), "wav");
+        assert_eq!(extension_for_mime("audio/webm;codecs=opus"), "webm");
+        assert_eq!(extension_for_mime("audio/x-m4a"), "m4a");
+        assert_eq!(extension_for_mime("application/octet-stream"), "mp3");
+    }
+}
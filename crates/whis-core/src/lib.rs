@@ -9,12 +9,17 @@ pub mod transcription;
 pub mod model;
 
 // Utility modules (cross-cutting concerns)
+pub mod active_window;
 #[cfg(feature = "clipboard")]
 pub mod clipboard;
+#[cfg(all(feature = "dbus", target_os = "linux"))]
+pub mod dbus;
 pub mod error;
 #[cfg(feature = "hotkey")]
 pub mod hotkey;
 pub mod http;
+#[cfg(feature = "http-api")]
+pub mod http_api;
 pub mod platform;
 pub mod resample;
 pub mod state;
@@ -32,22 +37,30 @@ pub use configuration::{
     DEFAULT_PROVIDER, DEFAULT_SHORTCUT, DEFAULT_SHORTCUT_MODE, DEFAULT_VAD_ENABLED,
     DEFAULT_VAD_THRESHOLD,
 };
-pub use configuration::{Preset, PresetSource, TranscriptionProvider};
+pub use configuration::{Dictionary, Preset, PresetSource, PresetStep, TranscriptionProvider};
 
 // Re-export transcription types
 #[cfg(feature = "local-transcription")]
 pub use transcription::progressive_transcribe_local;
 pub use transcription::{
-    DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessor, WarmupConfig,
-    clear_warmup_cache, post_process, preload_ollama, progressive_transcribe_cloud,
-    warmup_configured,
+    DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessDeltaSender, PostProcessor,
+    WarmupConfig, apply_voice_commands, clear_warmup_cache, post_process, post_process_streaming,
+    post_process_structured, preload_ollama, progressive_transcribe_cloud, warmup_configured,
 };
 
 // Re-export provider types
 #[cfg(feature = "realtime")]
+pub use provider::AssemblyAIRealtimeProvider;
+#[cfg(feature = "realtime")]
 pub use provider::DeepgramRealtimeProvider;
 #[cfg(feature = "realtime")]
+pub use provider::GladiaRealtimeProvider;
+#[cfg(feature = "realtime")]
 pub use provider::OpenAIRealtimeProvider;
+#[cfg(feature = "realtime")]
+pub use provider::SpeechmaticsRealtimeProvider;
+#[cfg(feature = "realtime")]
+pub use provider::WyomingRealtimeProvider;
 pub use provider::is_realtime_provider;
 #[cfg(feature = "local-transcription")]
 pub use provider::preload_parakeet;
@@ -56,11 +69,13 @@ pub use provider::transcribe_raw;
 #[cfg(feature = "local-transcription")]
 pub use provider::transcribe_raw_parakeet;
 pub use provider::{
-    DEFAULT_TIMEOUT_SECS, ProgressCallback, TranscriptionBackend, TranscriptionRequest,
-    TranscriptionResult, TranscriptionStage, registry,
+    DEFAULT_TIMEOUT_SECS, ProgressCallback, ProviderOptions, TranscriptionBackend,
+    TranscriptionRequest, TranscriptionResult, TranscriptionStage, WordTimestamp, registry,
 };
+#[cfg(all(feature = "realtime", feature = "local-transcription"))]
+pub use provider::{LocalRealtimeProvider, LocalStreamConfig};
 #[cfg(feature = "realtime")]
-pub use provider::{RealtimeTranscriptionBackend, get_realtime_backend};
+pub use provider::{PartialTranscriptSender, RealtimeTranscriptionBackend, get_realtime_backend};
 #[cfg(feature = "local-transcription")]
 pub use provider::{parakeet_set_keep_loaded, unload_parakeet};
 #[cfg(feature = "local-transcription")]
@@ -72,7 +87,7 @@ pub use clipboard::{ClipboardMethod, copy_to_clipboard};
 pub use error::{AudioError, ProviderError, Result, WhisError};
 pub use http::{get_http_client, is_http_client_ready, warmup_http_client};
 pub use settings::Settings;
-pub use state::RecordingState;
+pub use state::{RecordingState, RemoteAction, RemoteCommand};
 pub use verbose::set_verbose;
 
 #[cfg(feature = "hotkey")]
@@ -96,13 +111,18 @@ pub mod defaults {
 
 #[doc(hidden)]
 pub mod preset {
-    pub use crate::configuration::{Preset, PresetSource};
+    pub use crate::configuration::{
+        ConflictPolicy, Dictionary, ImportOutcome, Preset, PresetBundle, PresetSource, PresetStep,
+        PromptContext, Replacement, StructuredOutput, apply_replacements, expand_prompt,
+        validate_prompt,
+    };
 }
 
 #[doc(hidden)]
 pub mod post_processing {
     pub use crate::transcription::{
-        DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessor, post_process,
+        DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessDeltaSender, PostProcessor,
+        apply_voice_commands, post_process, post_process_streaming, post_process_structured,
     };
 }
 
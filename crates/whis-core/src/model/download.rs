@@ -1,19 +1,32 @@
 //! Shared download logic for models
 
-use super::types::ModelType;
+use super::types::{ModelInfo, ModelType};
 use anyhow::{Context, Result, anyhow};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::io::{self, Read, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// Checksums of downloaded models without pinned catalog values, stored in
+/// each model type's directory
+pub const CHECKSUMS_FILE: &str = "checksums.json";
+
+/// Exact size and SHA-256 of a model file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checksum {
+    pub size_bytes: u64,
+    /// Lowercase hex
+    pub sha256: String,
+}
 
 /// Download a model with default progress indication (prints to stderr)
 pub fn download<M: ModelType>(model_type: &M, model_name: &str, dest: &Path) -> Result<()> {
     download_with_progress(model_type, model_name, dest, |downloaded, total| {
-        let progress = if total > 0 {
-            (downloaded * 100 / total) as usize
-        } else {
-            0
-        };
+        let progress = (downloaded * 100).checked_div(total).unwrap_or(0) as usize;
 
         // Progress bar: [========          ] 45%
         let bar_width = 20;
@@ -39,6 +52,15 @@ pub fn download<M: ModelType>(model_type: &M, model_name: &str, dest: &Path) ->
 ///
 /// The callback receives (downloaded_bytes, total_bytes) and is called
 /// approximately every 1% of progress or every 500KB, whichever is more frequent.
+///
+/// Data is written to a `.part` file next to `dest`. If a previous download was
+/// interrupted, it resumes from the existing `.part` file with an HTTP Range
+/// request, sent with `If-Range` so a changed file on the server is downloaded
+/// again from the start (a partial file without a recorded validator is
+/// never resumed). The finished file is checked against the expected size and SHA-256
+/// (see [`expected_checksum`]) before it is moved into place (or extracted).
+/// Without a checksum, the file must at least match the catalog size or the
+/// size the server reported; otherwise the download fails.
 pub fn download_with_progress<M, F>(
     model_type: &M,
     model_name: &str,
@@ -49,7 +71,7 @@ where
     M: ModelType,
     F: Fn(u64, u64),
 {
-    let url = model_type.get_url(model_name).ok_or_else(|| {
+    let info = model_type.find(model_name).ok_or_else(|| {
         let available: Vec<_> = model_type.models().iter().map(|m| m.name).collect();
         anyhow!(
             "Unknown {} model: {}. Available: {}",
@@ -64,60 +86,113 @@ where
         fs::create_dir_all(parent).context("Failed to create models directory")?;
     }
 
+    let part_path = part_path(model_type, dest);
+    let validator_path = validator_path(&part_path);
+
+    // Look up the checksum up front (unpinned models ask the download server)
+    let expected = expected_checksum(model_type, info, true);
+
     // Download with progress
     let client = reqwest::blocking::Client::builder()
         .timeout(std::time::Duration::from_secs(600)) // 10 min timeout for large files
         .build()
         .context("Failed to create HTTP client")?;
 
-    let mut response = client.get(url).send().context("Failed to start download")?;
-
-    if !response.status().is_success() {
-        return Err(anyhow!("Download failed: HTTP {}", response.status()));
+    // Resume a previous partial download if there is one, as long as the
+    // server still has the same file
+    let validator = fs::read_to_string(&validator_path)
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty());
+    let resume_from = match validator {
+        Some(_) => fs::metadata(&part_path).map(|m| m.len()).unwrap_or(0),
+        None => 0,
+    };
+    let mut request = client.get(info.url);
+    if let Some(validator) = validator.as_deref()
+        && resume_from > 0
+    {
+        request = request
+            .header(reqwest::header::RANGE, format!("bytes={resume_from}-"))
+            .header(reqwest::header::IF_RANGE, validator);
     }
 
-    let total_size = response.content_length().unwrap_or(0);
+    let mut response = request.send().context("Failed to start download")?;
+    let status = response.status();
 
-    // Create temp file first, then rename on success
-    let temp_path = if model_type.needs_extraction() {
-        // For archives, use the download extension for temp file
-        dest.with_extension(format!("tmp{}", model_type.download_extension()))
+    let (mut file, mut downloaded, total_size) = if status == StatusCode::RANGE_NOT_SATISFIABLE {
+        // The partial file may already hold the whole model; its size is
+        // checked against the server's total below
+        (
+            None,
+            resume_from,
+            content_range_total(&response).unwrap_or(0),
+        )
+    } else if !status.is_success() {
+        return Err(anyhow!("Download failed: HTTP {}", status));
+    } else if status == StatusCode::PARTIAL_CONTENT {
+        eprintln!(
+            "[i] Resuming download at {:.1} MB",
+            resume_from as f64 / 1_000_000.0
+        );
+        let file = fs::OpenOptions::new()
+            .append(true)
+            .open(&part_path)
+            .context("Failed to open partial download")?;
+        let total = content_range_total(&response)
+            .or_else(|| response.content_length().map(|len| len + resume_from))
+            .unwrap_or(0);
+        (Some(file), resume_from, total)
     } else {
-        dest.with_extension(format!("{}tmp", model_type.download_extension()))
+        // Server ignored the range, the file changed, or nothing to resume:
+        // start over and remember which file this is for a later resume
+        let file = fs::File::create(&part_path).context("Failed to create temp file")?;
+        match resume_validator(&response) {
+            Some(validator) => fs::write(&validator_path, validator)
+                .context("Failed to record download validator")?,
+            None => {
+                fs::remove_file(&validator_path).ok();
+            }
+        }
+        (Some(file), 0, response.content_length().unwrap_or(0))
     };
 
-    let mut file = fs::File::create(&temp_path).context("Failed to create temp file")?;
-
-    let mut downloaded: u64 = 0;
-    let mut buffer = [0u8; 8192];
-    let mut last_callback_bytes: u64 = 0;
-
     // Emit initial progress
-    on_progress(0, total_size);
+    on_progress(downloaded, total_size);
 
-    loop {
-        let bytes_read = response.read(&mut buffer).context("Download interrupted")?;
-        if bytes_read == 0 {
-            break;
-        }
+    if let Some(file) = file.as_mut() {
+        let mut buffer = [0u8; 8192];
+        let mut last_callback_bytes = downloaded;
 
-        file.write_all(&buffer[..bytes_read])
-            .context("Failed to write to file")?;
-        downloaded += bytes_read as u64;
+        loop {
+            let bytes_read = response.read(&mut buffer).context("Download interrupted")?;
+            if bytes_read == 0 {
+                break;
+            }
 
-        // Emit progress every ~1% or 500KB, whichever is more frequent
-        let threshold = if total_size > 0 {
-            (total_size / 100).min(500_000)
-        } else {
-            500_000
-        };
+            file.write_all(&buffer[..bytes_read])
+                .context("Failed to write to file")?;
+            downloaded += bytes_read as u64;
+
+            // Emit progress every ~1% or 500KB, whichever is more frequent
+            let threshold = if total_size > 0 {
+                (total_size / 100).min(500_000)
+            } else {
+                500_000
+            };
 
-        if downloaded - last_callback_bytes >= threshold {
-            on_progress(downloaded, total_size);
-            last_callback_bytes = downloaded;
+            if downloaded - last_callback_bytes >= threshold {
+                on_progress(downloaded, total_size);
+                last_callback_bytes = downloaded;
+            }
         }
+
+        file.flush().context("Failed to write to file")?;
     }
 
+    // Complete: the partial file is either finalized or removed below
+    fs::remove_file(&validator_path).ok();
+
     // Final progress callback
     on_progress(downloaded, total_size);
 
@@ -126,25 +201,292 @@ where
         downloaded as f64 / 1_000_000.0
     );
 
+    // A corrupt partial file can't be resumed, so start from scratch next time
+    match &expected {
+        Some(expected) => {
+            if let Err(e) = check_checksum(info.name, expected, &part_path) {
+                fs::remove_file(&part_path).ok();
+                return Err(e.context("Downloaded model is corrupt, please retry"));
+            }
+        }
+        None => {
+            // Without a checksum, at least make sure the file is complete
+            let expected_size = info.size_bytes.or((total_size > 0).then_some(total_size));
+            if let Err(e) = check_size(info.name, expected_size, &part_path) {
+                fs::remove_file(&part_path).ok();
+                return Err(e.context("Download could not be verified, please retry"));
+            }
+            eprintln!(
+                "[!] No checksum available for {} {}, only the size was verified",
+                model_type.name(),
+                info.name
+            );
+        }
+    }
+
     // Handle extraction if needed
     if model_type.needs_extraction() {
         eprintln!("[i] Extracting...");
         if let Some(parent) = dest.parent() {
-            model_type.extract(&temp_path, parent)?;
+            model_type.extract(&part_path, parent)?;
         } else {
             return Err(anyhow!("No parent directory for extraction"));
         }
         // Remove temp archive after extraction
-        fs::remove_file(&temp_path).ok();
+        fs::remove_file(&part_path).ok();
         eprintln!("[+] Extraction complete!");
     } else {
         // Rename temp file to final destination
-        fs::rename(&temp_path, dest).context("Failed to finalize download")?;
+        fs::rename(&part_path, dest).context("Failed to finalize download")?;
+
+        // Keep the checksum so size checks and `verify` work offline (without
+        // a fetched one, that of the size-checked file)
+        if info.sha256.is_none() {
+            let checksum = match expected {
+                Some(expected) => expected,
+                None => checksum_file(dest)?,
+            };
+            record_checksum(model_type, info.name, &checksum)?;
+        }
     }
 
     Ok(())
 }
 
+/// Expected size and SHA-256 of a catalog model
+///
+/// Pinned catalog values come first, then the checksum recorded when the
+/// model was downloaded or imported, then (if `online`) the Hugging Face LFS
+/// metadata of its download URL.
+pub fn expected_checksum<M: ModelType>(
+    model_type: &M,
+    info: &ModelInfo,
+    online: bool,
+) -> Option<Checksum> {
+    if let (Some(size_bytes), Some(sha256)) = (info.size_bytes, info.sha256) {
+        return Some(Checksum {
+            size_bytes,
+            sha256: sha256.to_string(),
+        });
+    }
+
+    recorded_checksums(model_type)
+        .remove(info.name)
+        .or_else(|| online.then(|| remote_checksum(info.url)).flatten())
+}
+
+/// Look up size and SHA-256 from the Hugging Face LFS metadata of a URL
+///
+/// `resolve/` URLs answer with `X-Linked-Size` and `X-Linked-Etag` (the
+/// SHA-256 of the LFS object) before redirecting to the CDN. Returns `None`
+/// for other servers or when offline.
+pub fn remote_checksum(url: &str) -> Option<Checksum> {
+    let client = reqwest::blocking::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .timeout(std::time::Duration::from_secs(15))
+        .build()
+        .ok()?;
+    let response = client.head(url).send().ok()?;
+    let header = |name: &str| response.headers().get(name)?.to_str().ok();
+
+    let size_bytes = header("x-linked-size")?.parse().ok()?;
+    let sha256 = header("x-linked-etag")?
+        .trim_start_matches("W/")
+        .trim_matches('"')
+        .to_ascii_lowercase();
+    if sha256.len() != 64 || !sha256.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some(Checksum { size_bytes, sha256 })
+}
+
+/// Check a model file against its expected size and SHA-256
+///
+/// Fails if no checksum is known for the model. Hashing reads the whole
+/// file, so this is meant for after a download or an explicit verify, not for
+/// every startup.
+pub fn verify_checksum<M: ModelType>(model_type: &M, info: &ModelInfo, path: &Path) -> Result<()> {
+    let expected = expected_checksum(model_type, info, true).ok_or_else(|| {
+        anyhow!(
+            "No checksum known for {} (not pinned, and {} is unreachable)",
+            info.name,
+            info.url
+        )
+    })?;
+    check_checksum(info.name, &expected, path)
+}
+
+/// Check a file against an expected size and SHA-256
+pub fn check_checksum(name: &str, expected: &Checksum, path: &Path) -> Result<()> {
+    check_size(name, Some(expected.size_bytes), path)?;
+
+    let actual = sha256_file(path)?;
+    if !actual.eq_ignore_ascii_case(&expected.sha256) {
+        return Err(anyhow!(
+            "Checksum mismatch for {}: expected {}, got {}",
+            name,
+            expected.sha256,
+            actual
+        ));
+    }
+
+    Ok(())
+}
+
+/// Check a file against the size the catalog or download server reported
+///
+/// Fails when no size is known, since a truncated file could not be told
+/// apart from a complete one.
+fn check_size(name: &str, expected: Option<u64>, path: &Path) -> Result<()> {
+    let expected =
+        expected.ok_or_else(|| anyhow!("Neither a checksum nor a size is known for {}", name))?;
+    let size = fs::metadata(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?
+        .len();
+
+    if size != expected {
+        return Err(anyhow!(
+            "Size mismatch for {}: expected {} bytes, got {}",
+            name,
+            expected,
+            size
+        ));
+    }
+    Ok(())
+}
+
+/// Complete size of the resource from a `Content-Range` header
+/// (`bytes 100-199/200` or `bytes */200`)
+fn content_range_total(response: &reqwest::blocking::Response) -> Option<u64> {
+    response
+        .headers()
+        .get(reqwest::header::CONTENT_RANGE)?
+        .to_str()
+        .ok()?
+        .rsplit_once('/')?
+        .1
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Validator for resuming a download with `If-Range`
+///
+/// A strong `ETag`, else the `Last-Modified` date. Weak ETags can't be used
+/// with `If-Range`.
+fn resume_validator(response: &reqwest::blocking::Response) -> Option<String> {
+    let header = |name| response.headers().get(name)?.to_str().ok();
+
+    header(reqwest::header::ETAG)
+        .filter(|etag| !etag.starts_with("W/"))
+        .or_else(|| header(reqwest::header::LAST_MODIFIED))
+        .map(str::to_string)
+}
+
+/// Parsed checksum files, by models directory
+///
+/// Size checks run on hot paths (config checks, model lists), so each file
+/// is read once per process and kept current by [`record_checksum`] and
+/// [`forget_checksum`]. Models downloaded by another process meanwhile are
+/// only size-checked after a restart.
+fn checksum_cache() -> &'static Mutex<HashMap<PathBuf, BTreeMap<String, Checksum>>> {
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, BTreeMap<String, Checksum>>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+/// Checksums recorded for downloaded models without pinned values
+fn recorded_checksums<M: ModelType>(model_type: &M) -> BTreeMap<String, Checksum> {
+    let dir = model_type.default_dir();
+    checksum_cache()
+        .lock()
+        .unwrap()
+        .entry(dir)
+        .or_insert_with_key(|dir| {
+            fs::read_to_string(dir.join(CHECKSUMS_FILE))
+                .ok()
+                .and_then(|content| serde_json::from_str(&content).ok())
+                .unwrap_or_default()
+        })
+        .clone()
+}
+
+fn save_checksums<M: ModelType>(
+    model_type: &M,
+    checksums: BTreeMap<String, Checksum>,
+) -> Result<()> {
+    let dir = model_type.default_dir();
+    let path = dir.join(CHECKSUMS_FILE);
+    let content = serde_json::to_string_pretty(&checksums)?;
+    fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    checksum_cache().lock().unwrap().insert(dir, checksums);
+    Ok(())
+}
+
+/// Remember the checksum of an installed catalog model
+pub fn record_checksum<M: ModelType>(
+    model_type: &M,
+    name: &str,
+    checksum: &Checksum,
+) -> Result<()> {
+    let mut checksums = recorded_checksums(model_type);
+    checksums.insert(name.to_string(), checksum.clone());
+    save_checksums(model_type, checksums)
+}
+
+/// Drop the recorded checksum of a removed model
+pub fn forget_checksum<M: ModelType>(model_type: &M, name: &str) -> Result<()> {
+    let mut checksums = recorded_checksums(model_type);
+    if checksums.remove(name).is_some() {
+        save_checksums(model_type, checksums)?;
+    }
+    Ok(())
+}
+
+/// Size and SHA-256 of a file as it is
+pub fn checksum_file(path: &Path) -> Result<Checksum> {
+    let size_bytes = fs::metadata(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?
+        .len();
+    Ok(Checksum {
+        size_bytes,
+        sha256: sha256_file(path)?,
+    })
+}
+
+/// Compute the lowercase hex SHA-256 of a file
+fn sha256_file(path: &Path) -> Result<String> {
+    let mut file =
+        fs::File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher).context("Failed to read file for checksum")?;
+
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
+
+/// Path of the partial download for a model (e.g. `ggml-small.bin.part`)
+fn part_path<M: ModelType>(model_type: &M, dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    if model_type.needs_extraction() {
+        // Archives are extracted into `dest`, so the file itself is the archive
+        name.push(model_type.download_extension());
+    }
+    name.push(".part");
+    dest.with_file_name(name)
+}
+
+/// Path of the resume validator of a partial download (`<part>.etag`)
+fn validator_path(part_path: &Path) -> PathBuf {
+    let mut name = part_path.file_name().unwrap_or_default().to_os_string();
+    name.push(".etag");
+    part_path.with_file_name(name)
+}
+
 /// Ensure a model is available, downloading it if necessary
 pub fn ensure<M: ModelType>(model_type: &M, model_name: &str) -> Result<()> {
     let path = model_type.default_path(model_name);
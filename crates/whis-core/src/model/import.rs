@@ -0,0 +1,323 @@
+//! Offline model import
+//!
+//! Registers a model file or directory that was obtained without
+//! [`download`](super::download), e.g. copied onto an air-gapped machine.
+//!
+//! - Catalog models (e.g. `ggml-small.bin`) are installed at their default path
+//!   (copied, or symlinked on request) and checked against their size and
+//!   SHA-256, so they behave exactly like downloaded models.
+//! - Other models are recorded in `imported.json` in the model directory,
+//!   which makes them listable and selectable by name.
+
+use super::download::{check_checksum, checksum_file, expected_checksum, record_checksum};
+use super::types::ModelType;
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Registry of imported models, stored in each model type's directory
+pub const REGISTRY_FILE: &str = "imported.json";
+
+/// How an imported model is made available
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportMode {
+    /// Copy into the model directory
+    Copy,
+    /// Symlink from the model directory to the original location
+    Symlink,
+    /// Use the original location as-is (catalog models are copied instead,
+    /// since they are looked up at their default path)
+    Reference,
+}
+
+/// Metadata recorded for an imported model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportedModel {
+    /// Name used to select the model (`whis model use <engine> <name>`)
+    pub name: String,
+    /// Path transcription loads the model from
+    pub path: PathBuf,
+    /// Original location the model was imported from
+    pub source: PathBuf,
+    pub mode: ImportMode,
+    /// Detected format ("ggml", "gguf", "onnx")
+    pub format: String,
+    pub size_bytes: u64,
+    /// Unix timestamp of the import
+    pub imported_at: u64,
+}
+
+/// Result of an import
+pub enum Imported {
+    /// Installed as a catalog model at its default path
+    Catalog { name: String, path: PathBuf },
+    /// Recorded in the import registry
+    Custom(ImportedModel),
+}
+
+impl Imported {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Catalog { name, .. } => name,
+            Self::Custom(model) => &model.name,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Catalog { path, .. } => path,
+            Self::Custom(model) => &model.path,
+        }
+    }
+}
+
+/// Import a model from `source`
+///
+/// The name defaults to the file or directory name (without a `ggml-` prefix
+/// and extension). Fails if the model is invalid or the name is taken.
+pub fn import<M: ModelType>(
+    model_type: &M,
+    source: &Path,
+    name: Option<&str>,
+    mode: ImportMode,
+) -> Result<Imported> {
+    let source = source
+        .canonicalize()
+        .with_context(|| format!("Model not found: {}", source.display()))?;
+    let format = model_type.validate(&source)?;
+
+    let (name, catalog) = match name {
+        Some(name) => (name.to_string(), model_type.find(name)),
+        None => {
+            // Also recognize catalog models by their installed file/directory name
+            let catalog = model_type
+                .models()
+                .iter()
+                .find(|m| model_type.default_path(m.name).file_name() == source.file_name());
+            let name = match catalog {
+                Some(info) => info.name.to_string(),
+                None => default_name(&source)?,
+            };
+            (name.clone(), model_type.find(&name))
+        }
+    };
+
+    // Known model: install at its default path and verify it like a download
+    if let Some(info) = catalog {
+        let dest = model_type.default_path(info.name);
+        if dest.exists() {
+            return Err(anyhow!(
+                "{} model '{}' is already installed at {}",
+                model_type.name(),
+                info.name,
+                dest.display()
+            ));
+        }
+
+        // Air-gapped machines only know pinned checksums; the format was
+        // validated above either way
+        let checksum = if model_type.needs_extraction() {
+            None
+        } else {
+            expected_checksum(model_type, info, true)
+        };
+        match &checksum {
+            Some(checksum) => check_checksum(info.name, checksum, &source)?,
+            None if !model_type.needs_extraction() => eprintln!(
+                "[!] No checksum known for {} {}, recording the imported file's",
+                model_type.name(),
+                info.name
+            ),
+            None => {}
+        }
+
+        let mode = match mode {
+            ImportMode::Reference => ImportMode::Copy,
+            mode => mode,
+        };
+        place(&source, &dest, mode)?;
+        if info.sha256.is_none() && !model_type.needs_extraction() {
+            let checksum = match checksum {
+                Some(checksum) => checksum,
+                None => checksum_file(&dest)?,
+            };
+            record_checksum(model_type, info.name, &checksum)?;
+        }
+
+        return Ok(Imported::Catalog {
+            name: info.name.to_string(),
+            path: dest,
+        });
+    }
+
+    if find(model_type, &name).is_some() {
+        return Err(anyhow!(
+            "An imported {} model named '{}' already exists. Choose another name with --name",
+            model_type.name(),
+            name
+        ));
+    }
+
+    let path = match mode {
+        ImportMode::Reference => source.clone(),
+        ImportMode::Copy | ImportMode::Symlink => {
+            let file_name = source
+                .file_name()
+                .ok_or_else(|| anyhow!("Invalid model path: {}", source.display()))?;
+            let dest = model_type.default_dir().join(file_name);
+            if dest.exists() {
+                return Err(anyhow!("{} already exists", dest.display()));
+            }
+            place(&source, &dest, mode)?;
+            dest
+        }
+    };
+
+    let model = ImportedModel {
+        name,
+        size_bytes: super::storage::disk_usage(&source),
+        path,
+        source,
+        mode,
+        format: format.to_string(),
+        imported_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    };
+
+    let mut models = list(model_type);
+    models.push(model.clone());
+    save(model_type, &models)?;
+
+    Ok(Imported::Custom(model))
+}
+
+/// All imported models of a type
+pub fn list<M: ModelType>(model_type: &M) -> Vec<ImportedModel> {
+    fs::read_to_string(registry_path(model_type))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Look up an imported model by name
+pub fn find<M: ModelType>(model_type: &M, name: &str) -> Option<ImportedModel> {
+    list(model_type).into_iter().find(|m| m.name == name)
+}
+
+/// Remove an imported model, returning the number of bytes freed
+///
+/// Copies and symlinks are deleted; referenced originals are left alone.
+pub fn remove<M: ModelType>(model_type: &M, name: &str) -> Result<u64> {
+    let mut models = list(model_type);
+    let index = models
+        .iter()
+        .position(|m| m.name == name)
+        .ok_or_else(|| anyhow!("No imported {} model named '{}'", model_type.name(), name))?;
+    let model = models.remove(index);
+
+    let freed = match model.mode {
+        ImportMode::Copy => {
+            let size = super::storage::disk_usage(&model.path);
+            if model.path.is_dir() {
+                fs::remove_dir_all(&model.path)
+            } else {
+                fs::remove_file(&model.path)
+            }
+            .with_context(|| format!("Failed to remove {}", model.path.display()))?;
+            size
+        }
+        ImportMode::Symlink => {
+            fs::remove_file(&model.path)
+                .with_context(|| format!("Failed to remove {}", model.path.display()))?;
+            0
+        }
+        ImportMode::Reference => 0,
+    };
+
+    save(model_type, &models)?;
+    Ok(freed)
+}
+
+/// Path of the import registry for a model type
+pub fn registry_path<M: ModelType>(model_type: &M) -> PathBuf {
+    model_type.default_dir().join(REGISTRY_FILE)
+}
+
+fn save<M: ModelType>(model_type: &M, models: &[ImportedModel]) -> Result<()> {
+    let path = registry_path(model_type);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context("Failed to create models directory")?;
+    }
+
+    let content = serde_json::to_string_pretty(models)?;
+    fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Derive a model name from its path (`ggml-small.en.bin` -> `small.en`)
+fn default_name(path: &Path) -> Result<String> {
+    let file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| anyhow!("Invalid model path: {}", path.display()))?;
+
+    let name = file_name
+        .strip_suffix(".bin")
+        .or_else(|| file_name.strip_suffix(".gguf"))
+        .unwrap_or(file_name);
+    let name = name.strip_prefix("ggml-").unwrap_or(name);
+
+    Ok(name.to_string())
+}
+
+/// Copy or symlink a model into place
+fn place(source: &Path, dest: &Path, mode: ImportMode) -> Result<()> {
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent).context("Failed to create models directory")?;
+    }
+
+    match mode {
+        ImportMode::Copy if source.is_dir() => copy_dir(source, dest),
+        ImportMode::Copy => fs::copy(source, dest).map(|_| ()),
+        ImportMode::Symlink => symlink(source, dest),
+        ImportMode::Reference => Ok(()),
+    }
+    .with_context(|| {
+        format!(
+            "Failed to import {} to {}",
+            source.display(),
+            dest.display()
+        )
+    })
+}
+
+fn copy_dir(source: &Path, dest: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let target = dest.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn symlink(source: &Path, dest: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(source, dest)
+}
+
+#[cfg(windows)]
+fn symlink(source: &Path, dest: &Path) -> std::io::Result<()> {
+    if source.is_dir() {
+        std::os::windows::fs::symlink_dir(source, dest)
+    } else {
+        std::os::windows::fs::symlink_file(source, dest)
+    }
+}
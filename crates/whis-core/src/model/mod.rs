@@ -14,6 +14,17 @@
 //!   ├── download()     - Download with progress
 //!   ├── ensure()       - Download if missing
 //!   └── verify()       - Check if valid
+//!
+//! Recommendation
+//!   └── recommend_whisper() - Pick a model for the machine's RAM / cores
+//!
+//! Import
+//!   └── import()       - Register a model copied in without a download
+//!
+//! Storage
+//!   ├── disk_usage()   - Size of an installed model
+//!   ├── remove()       - Delete an installed model
+//!   └── find_orphans() - Unknown files in a model directory
 //! ```
 //!
 //! # Usage
@@ -31,11 +42,15 @@
 //! ```
 
 pub mod download;
+pub mod import;
 pub mod parakeet;
+pub mod recommend;
+pub mod storage;
 pub mod types;
 pub mod whisper;
 
 // Re-export commonly used types
+pub use recommend::{SystemResources, recommend_whisper};
 pub use types::{ModelInfo, ModelType};
 pub use whisper::WhisperModel;
 
@@ -0,0 +1,88 @@
+//! Hardware-based model recommendation
+//!
+//! Picks a whisper model that transcribes at a comfortable speed on the current
+//! machine. whisper.cpp needs roughly the model size in RAM plus working
+//! buffers, and runs on CPU cores unless a GPU backend is available, so the
+//! heuristic only looks at total RAM and core count.
+
+/// Hardware resources relevant for local transcription
+#[derive(Debug, Clone, Copy)]
+pub struct SystemResources {
+    /// Total physical memory in MB, if it could be determined
+    pub total_ram_mb: Option<u64>,
+    /// Logical CPU cores available to the process
+    pub cpu_cores: usize,
+}
+
+impl SystemResources {
+    /// Detect resources of the current machine
+    pub fn detect() -> Self {
+        Self {
+            total_ram_mb: total_ram_mb(),
+            cpu_cores: std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
+        }
+    }
+}
+
+/// Recommend a whisper model for the given hardware
+///
+/// `english_only` prefers `.en` / distilled models, which are more accurate
+/// for English at the same size. Unknown RAM is treated as 8 GB.
+pub fn recommend_whisper(resources: &SystemResources, english_only: bool) -> &'static str {
+    let ram_mb = resources.total_ram_mb.unwrap_or(8 * 1024);
+    let cores = resources.cpu_cores;
+
+    if ram_mb < 4 * 1024 || cores <= 2 {
+        // Low-end: anything above base is too slow for dictation
+        if english_only { "base.en" } else { "base-q8_0" }
+    } else if ram_mb < 8 * 1024 || cores <= 4 {
+        // Typical laptop: quantized small is fast and accurate enough
+        if english_only {
+            "small.en-q8_0"
+        } else {
+            "small-q8_0"
+        }
+    } else if ram_mb < 16 * 1024 || cores <= 8 {
+        // Capable laptop: quantized turbo gives near large-v3 accuracy
+        if english_only {
+            "distil-large-v3"
+        } else {
+            "large-v3-turbo-q5_0"
+        }
+    } else if english_only {
+        "distil-large-v3"
+    } else {
+        "large-v3-turbo"
+    }
+}
+
+/// Read total physical memory in MB
+#[cfg(target_os = "linux")]
+fn total_ram_mb() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let line = meminfo.lines().find(|l| l.starts_with("MemTotal:"))?;
+    let kb: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb / 1024)
+}
+
+/// Read total physical memory in MB
+#[cfg(target_os = "macos")]
+fn total_ram_mb() -> Option<u64> {
+    let output = std::process::Command::new("sysctl")
+        .args(["-n", "hw.memsize"])
+        .output()
+        .ok()?;
+    let bytes: u64 = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .ok()?;
+    Some(bytes / (1024 * 1024))
+}
+
+/// Read total physical memory in MB
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn total_ram_mb() -> Option<u64> {
+    None
+}
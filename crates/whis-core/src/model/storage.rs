@@ -0,0 +1,92 @@
+//! Disk usage and housekeeping for installed models
+
+use super::types::ModelType;
+use anyhow::{Context, Result, anyhow};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Total size in bytes of a model file or directory (0 if missing)
+pub fn disk_usage(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| disk_usage(&entry.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+/// Delete an installed model, returning the number of bytes freed
+pub fn remove<M: ModelType>(model_type: &M, model_name: &str) -> Result<u64> {
+    if model_type.find(model_name).is_none() {
+        return Err(anyhow!(
+            "Unknown {} model: {}",
+            model_type.name(),
+            model_name
+        ));
+    }
+
+    let path = model_type.default_path(model_name);
+    if !path.exists() {
+        return Err(anyhow!(
+            "{} model '{}' is not installed",
+            model_type.name(),
+            model_name
+        ));
+    }
+
+    let size = disk_usage(&path);
+    if path.is_dir() {
+        fs::remove_dir_all(&path)
+    } else {
+        fs::remove_file(&path)
+    }
+    .with_context(|| format!("Failed to remove {}", path.display()))?;
+    super::download::forget_checksum(model_type, model_name)?;
+
+    Ok(size)
+}
+
+/// Find entries in the model directory that don't belong to any known model
+///
+/// Catches interrupted downloads, leftover archives and models dropped from the
+/// catalog. Imported models and their registry are not orphans. Subdirectories
+/// are skipped for file-based model types, since the Whisper directory also
+/// hosts the Parakeet directory.
+pub fn find_orphans<M: ModelType>(model_type: &M) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(model_type.default_dir()) else {
+        return Vec::new();
+    };
+
+    let known: Vec<PathBuf> = model_type
+        .models()
+        .iter()
+        .map(|m| model_type.default_path(m.name))
+        .chain(super::import::list(model_type).into_iter().map(|m| m.path))
+        .chain(std::iter::once(super::import::registry_path(model_type)))
+        .chain(std::iter::once(
+            model_type
+                .default_dir()
+                .join(super::download::CHECKSUMS_FILE),
+        ))
+        .collect();
+
+    let mut orphans: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| !known.contains(path))
+        .filter(|path| model_type.needs_extraction() || !path.is_dir())
+        .collect();
+
+    orphans.sort();
+    orphans
+}
@@ -10,6 +10,14 @@ pub struct ModelInfo {
     pub url: &'static str,
     pub description: &'static str,
     pub size_mb: Option<u64>,
+    /// Exact size of the downloaded file in bytes (for truncation checks)
+    pub size_bytes: Option<u64>,
+    /// Expected SHA-256 of the downloaded file (lowercase hex)
+    pub sha256: Option<&'static str>,
+    /// Model family the variant belongs to (e.g. "large-v3-turbo")
+    pub family: &'static str,
+    /// Weight quantization (e.g. "q5_0"), None for full precision
+    pub quantization: Option<&'static str>,
 }
 
 /// Trait defining operations for a model type (Whisper, Parakeet, etc.)
@@ -31,11 +39,36 @@ pub trait ModelType: Send + Sync {
     fn default_path(&self, model_name: &str) -> PathBuf;
 
     /// Check if a model exists and is valid at the given path
+    ///
+    /// Called on hot paths (config checks, model lists), so this only looks at
+    /// file metadata. The SHA-256 is checked after a download or import and by
+    /// [`download::verify_checksum`](super::download::verify_checksum).
     fn verify(&self, path: &Path) -> bool;
 
+    /// Validate a user-provided model (e.g. for import), returning its format
+    ///
+    /// Unlike [`verify`](Self::verify), this inspects the content and explains
+    /// what is wrong.
+    fn validate(&self, path: &Path) -> Result<&'static str> {
+        if self.verify(path) {
+            Ok(self.name())
+        } else {
+            Err(anyhow::anyhow!(
+                "{} is not a valid {} model",
+                path.display(),
+                self.name()
+            ))
+        }
+    }
+
+    /// Look up a model by name
+    fn find(&self, name: &str) -> Option<&ModelInfo> {
+        self.models().iter().find(|m| m.name == name)
+    }
+
     /// Get download URL for a model by name
     fn get_url(&self, name: &str) -> Option<&'static str> {
-        self.models().iter().find(|m| m.name == name).map(|m| m.url)
+        self.find(name).map(|m| m.url)
     }
 
     /// Whether this model type needs extraction after download
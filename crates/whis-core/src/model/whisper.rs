@@ -1,33 +1,278 @@
 //! Whisper model type implementation
 
 use super::types::{ModelInfo, ModelType};
+use anyhow::{Context, Result, anyhow};
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
+/// Magic of legacy whisper.cpp GGML files (`0x67676d6c` little-endian)
+const GGML_MAGIC: &[u8; 4] = b"lmgg";
+
+/// Magic of GGUF files
+const GGUF_MAGIC: &[u8; 4] = b"GGUF";
+
 /// Available whisper models
+///
+/// Grouped by family, full precision (f16) first, then quantized variants
+/// (`q5_x` = 5-bit, `q8_0` = 8-bit; smaller and faster on CPU, slightly less
+/// accurate). `.en` models are English-only and more accurate for English.
+///
+/// Exact size and SHA-256 are only pinned for the original multilingual
+/// full-precision models so far (`cargo test -- --ignored catalog_pins`
+/// lists the values still missing). The others are checked against the
+/// Hugging Face LFS metadata when downloaded, and the result is recorded next
+/// to the models (see [`download::expected_checksum`](super::download::expected_checksum)).
+/// Without the metadata, a download must match the size the server reported
+/// and an import is taken as is; the checksum of the file is recorded instead.
+/// An unpinned model without a recorded checksum (e.g. copied into the models
+/// directory by hand) is not treated as installed by [`ModelType::verify`].
 const MODELS: &[ModelInfo] = &[
+    // tiny
     ModelInfo {
         name: "tiny",
         url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-tiny.bin",
         description: "75 MB",
         size_mb: Some(75),
+        size_bytes: Some(77691713),
+        sha256: Some("be07e048e1e599ad46341c8d2a135645097a538221678b7acdd1b1919c6e1b21"),
+        family: "tiny",
+        quantization: None,
+    },
+    ModelInfo {
+        name: "tiny.en",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-tiny.en.bin",
+        description: "75 MB, English-only",
+        size_mb: Some(75),
+        size_bytes: None,
+        sha256: None,
+        family: "tiny",
+        quantization: None,
+    },
+    ModelInfo {
+        name: "tiny-q5_1",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-tiny-q5_1.bin",
+        description: "32 MB",
+        size_mb: Some(32),
+        size_bytes: None,
+        sha256: None,
+        family: "tiny",
+        quantization: Some("q5_1"),
     },
+    ModelInfo {
+        name: "tiny-q8_0",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-tiny-q8_0.bin",
+        description: "44 MB",
+        size_mb: Some(44),
+        size_bytes: None,
+        sha256: None,
+        family: "tiny",
+        quantization: Some("q8_0"),
+    },
+    // base
     ModelInfo {
         name: "base",
         url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-base.bin",
         description: "142 MB",
         size_mb: Some(142),
+        size_bytes: Some(147951465),
+        sha256: Some("60ed5bc3dd14eea856493d334349b405782ddcaf0028d4b5df4088345fba2efe"),
+        family: "base",
+        quantization: None,
+    },
+    ModelInfo {
+        name: "base.en",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-base.en.bin",
+        description: "142 MB, English-only",
+        size_mb: Some(142),
+        size_bytes: None,
+        sha256: None,
+        family: "base",
+        quantization: None,
     },
+    ModelInfo {
+        name: "base-q5_1",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-base-q5_1.bin",
+        description: "60 MB",
+        size_mb: Some(60),
+        size_bytes: None,
+        sha256: None,
+        family: "base",
+        quantization: Some("q5_1"),
+    },
+    ModelInfo {
+        name: "base-q8_0",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-base-q8_0.bin",
+        description: "82 MB",
+        size_mb: Some(82),
+        size_bytes: None,
+        sha256: None,
+        family: "base",
+        quantization: Some("q8_0"),
+    },
+    // small
     ModelInfo {
         name: "small",
         url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-small.bin",
         description: "466 MB",
         size_mb: Some(466),
+        size_bytes: Some(487601967),
+        sha256: Some("1be3a9b2063867b937e64e2ec7483364a79917e157fa98c5d94b5c1fffea987b"),
+        family: "small",
+        quantization: None,
+    },
+    ModelInfo {
+        name: "small.en",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-small.en.bin",
+        description: "466 MB, English-only",
+        size_mb: Some(466),
+        size_bytes: None,
+        sha256: None,
+        family: "small",
+        quantization: None,
+    },
+    ModelInfo {
+        name: "small-q5_1",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-small-q5_1.bin",
+        description: "190 MB",
+        size_mb: Some(190),
+        size_bytes: None,
+        sha256: None,
+        family: "small",
+        quantization: Some("q5_1"),
+    },
+    ModelInfo {
+        name: "small-q8_0",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-small-q8_0.bin",
+        description: "264 MB",
+        size_mb: Some(264),
+        size_bytes: None,
+        sha256: None,
+        family: "small",
+        quantization: Some("q8_0"),
+    },
+    ModelInfo {
+        name: "small.en-q5_1",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-small.en-q5_1.bin",
+        description: "190 MB, English-only",
+        size_mb: Some(190),
+        size_bytes: None,
+        sha256: None,
+        family: "small",
+        quantization: Some("q5_1"),
     },
+    ModelInfo {
+        name: "small.en-q8_0",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-small.en-q8_0.bin",
+        description: "264 MB, English-only",
+        size_mb: Some(264),
+        size_bytes: None,
+        sha256: None,
+        family: "small",
+        quantization: Some("q8_0"),
+    },
+    // medium
     ModelInfo {
         name: "medium",
         url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-medium.bin",
         description: "1.5 GB",
         size_mb: Some(1500),
+        size_bytes: Some(1533763059),
+        sha256: Some("6c14d5adee5f86394037b4e4e8b59f1673b6cee10e3cf0b11bbdbee79c156208"),
+        family: "medium",
+        quantization: None,
+    },
+    ModelInfo {
+        name: "medium.en",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-medium.en.bin",
+        description: "1.5 GB, English-only",
+        size_mb: Some(1500),
+        size_bytes: None,
+        sha256: None,
+        family: "medium",
+        quantization: None,
+    },
+    ModelInfo {
+        name: "medium-q5_0",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-medium-q5_0.bin",
+        description: "539 MB",
+        size_mb: Some(539),
+        size_bytes: None,
+        sha256: None,
+        family: "medium",
+        quantization: Some("q5_0"),
+    },
+    ModelInfo {
+        name: "medium-q8_0",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-medium-q8_0.bin",
+        description: "823 MB",
+        size_mb: Some(823),
+        size_bytes: None,
+        sha256: None,
+        family: "medium",
+        quantization: Some("q8_0"),
+    },
+    // large-v3
+    ModelInfo {
+        name: "large-v3",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-large-v3.bin",
+        description: "3.1 GB",
+        size_mb: Some(3100),
+        size_bytes: None,
+        sha256: None,
+        family: "large-v3",
+        quantization: None,
+    },
+    ModelInfo {
+        name: "large-v3-q5_0",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-large-v3-q5_0.bin",
+        description: "1.1 GB",
+        size_mb: Some(1080),
+        size_bytes: None,
+        sha256: None,
+        family: "large-v3",
+        quantization: Some("q5_0"),
+    },
+    // large-v3-turbo
+    ModelInfo {
+        name: "large-v3-turbo",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-large-v3-turbo.bin",
+        description: "1.6 GB, near large-v3 accuracy at ~6x speed",
+        size_mb: Some(1620),
+        size_bytes: None,
+        sha256: None,
+        family: "large-v3-turbo",
+        quantization: None,
+    },
+    ModelInfo {
+        name: "large-v3-turbo-q5_0",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-large-v3-turbo-q5_0.bin",
+        description: "574 MB, best quality per MB on CPU",
+        size_mb: Some(574),
+        size_bytes: None,
+        sha256: None,
+        family: "large-v3-turbo",
+        quantization: Some("q5_0"),
+    },
+    ModelInfo {
+        name: "large-v3-turbo-q8_0",
+        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-large-v3-turbo-q8_0.bin",
+        description: "874 MB",
+        size_mb: Some(874),
+        size_bytes: None,
+        sha256: None,
+        family: "large-v3-turbo",
+        quantization: Some("q8_0"),
+    },
+    // distil-large-v3
+    ModelInfo {
+        name: "distil-large-v3",
+        url: "https://huggingface.co/distil-whisper/distil-large-v3-ggml/resolve/main/ggml-distil-large-v3.bin",
+        description: "1.5 GB, English-only, fast large-v3 distillation",
+        size_mb: Some(1520),
+        size_bytes: None,
+        sha256: None,
+        family: "distil-large-v3",
+        quantization: None,
     },
 ];
 
@@ -58,10 +303,83 @@ impl ModelType for WhisperModel {
     }
 
     fn verify(&self, path: &Path) -> bool {
-        path.exists() && path.is_file()
+        if !path.is_file() {
+            return false;
+        }
+
+        // Custom paths only need to exist
+        let Some(info) = self
+            .models()
+            .iter()
+            .find(|m| self.default_path(m.name) == path)
+        else {
+            return true;
+        };
+
+        // Catalog models must have their exact size; a truncated download
+        // would otherwise crash the model loader. Without a pinned or recorded
+        // checksum there is nothing to compare against, so the file can't be
+        // trusted.
+        super::download::expected_checksum(self, info, false).is_some_and(|checksum| {
+            path.metadata()
+                .is_ok_and(|meta| meta.len() == checksum.size_bytes)
+        })
+    }
+
+    fn validate(&self, path: &Path) -> Result<&'static str> {
+        if !path.is_file() {
+            return Err(anyhow!(
+                "{} is not a file (whisper models are single .bin/.gguf files)",
+                path.display()
+            ));
+        }
+
+        let mut magic = [0u8; 4];
+        std::fs::File::open(path)
+            .and_then(|mut file| file.read_exact(&mut magic))
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+
+        match &magic {
+            GGML_MAGIC => Ok("ggml"),
+            GGUF_MAGIC => Ok("gguf"),
+            _ => Err(anyhow!(
+                "{} is not a whisper.cpp model (expected GGML or GGUF header)",
+                path.display()
+            )),
+        }
     }
 
     fn download_extension(&self) -> &'static str {
         ".bin"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every catalog entry must be pinned, and match the Hugging Face LFS
+    /// metadata. Needs network access: `cargo test -- --ignored catalog_pins`.
+    /// Fails with the values to paste for entries that are not pinned yet.
+    #[test]
+    #[ignore = "needs network access"]
+    fn catalog_pins_match_hugging_face() {
+        let mut problems = Vec::new();
+
+        for info in MODELS {
+            let Some(remote) = crate::model::download::remote_checksum(info.url) else {
+                problems.push(format!("{}: no LFS metadata at {}", info.name, info.url));
+                continue;
+            };
+            let pinned = (info.size_bytes, info.sha256);
+            if pinned != (Some(remote.size_bytes), Some(remote.sha256.as_str())) {
+                problems.push(format!(
+                    "{}: size_bytes: Some({}), sha256: Some(\"{}\") (pinned: {:?})",
+                    info.name, remote.size_bytes, remote.sha256, pinned
+                ));
+            }
+        }
+
+        assert!(problems.is_empty(), "\n{}", problems.join("\n"));
+    }
+}
@@ -0,0 +1,496 @@
+//! AssemblyAI transcription provider
+//!
+//! AssemblyAI's pre-recorded API is asynchronous:
+//! 1. Upload the raw audio bytes to `/v2/upload` (returns a private URL)
+//! 2. Submit a transcript job referencing that URL
+//! 3. Poll the job until it is `completed` or `error`
+//!
+//! Jobs return word timestamps, and speaker labels when
+//! `transcription.assemblyai.speaker_labels` is enabled.
+//!
+//! The API base URL is a parameter of `transcribe_at` and
+//! `transcribe_blocking_at`, so tests can point them at a local stand-in server.
+
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+use std::time::{Duration, Instant};
+
+use super::{
+    DEFAULT_TIMEOUT_SECS, TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
+    TranscriptionStage, WordTimestamp,
+};
+use crate::settings::AssemblyAIConfig;
+
+const API_URL: &str = "https://api.assemblyai.com/v2";
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Deserialize)]
+struct UploadResponse {
+    upload_url: String,
+}
+
+#[derive(Deserialize)]
+struct Transcript {
+    id: String,
+    status: String,
+    #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(default)]
+    language_code: Option<String>,
+    #[serde(default)]
+    words: Option<Vec<Word>>,
+    #[serde(default)]
+    utterances: Option<Vec<Utterance>>,
+}
+
+#[derive(Deserialize)]
+struct Word {
+    text: String,
+    start: u64,
+    end: u64,
+    #[serde(default)]
+    speaker: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Utterance {
+    speaker: String,
+    text: String,
+}
+
+/// AssemblyAI transcription provider
+///
+/// Uses AssemblyAI's Universal model via the upload + poll REST API.
+/// Supports word timestamps, speaker labels and keyterm prompting.
+#[derive(Debug, Default, Clone)]
+pub struct AssemblyAIProvider;
+
+/// Build the transcript job request body
+fn job_body(
+    upload_url: &str,
+    language: Option<&str>,
+    options: &AssemblyAIConfig,
+) -> serde_json::Value {
+    let mut body = json!({
+        "audio_url": upload_url,
+        "speaker_labels": options.speaker_labels,
+    });
+    match language {
+        Some(lang) => body["language_code"] = json!(lang),
+        None => body["language_detection"] = json!(true),
+    }
+    if !options.keyterms.is_empty() {
+        body["keyterms_prompt"] = json!(options.keyterms);
+    }
+    body
+}
+
+/// Return the transcript once the job has finished, `None` while it is still running
+fn finished(transcript: Transcript) -> Result<Option<TranscriptionResult>> {
+    match transcript.status.as_str() {
+        "completed" => Ok(Some(into_result(transcript))),
+        "error" => Err(anyhow!(
+            "AssemblyAI transcription failed: {}",
+            transcript.error.as_deref().unwrap_or("unknown error")
+        )),
+        _ => Ok(None),
+    }
+}
+
+fn into_result(transcript: Transcript) -> TranscriptionResult {
+    // With speaker labels, render one "Speaker X: ..." line per utterance
+    let text = match transcript.utterances {
+        Some(utterances) if !utterances.is_empty() => utterances
+            .iter()
+            .map(|u| format!("Speaker {}: {}", u.speaker, u.text.trim()))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => transcript.text.unwrap_or_default(),
+    };
+
+    let words = transcript
+        .words
+        .unwrap_or_default()
+        .into_iter()
+        .map(|w| WordTimestamp {
+            text: w.text,
+            start_ms: w.start,
+            end_ms: w.end,
+            speaker: w.speaker,
+        })
+        .collect();
+
+    TranscriptionResult {
+        text,
+        language: transcript.language_code,
+        words,
+    }
+}
+
+/// Fail with the response body when AssemblyAI returns a non-success status
+fn api_error(what: &str, status: reqwest::StatusCode, body: &str) -> anyhow::Error {
+    anyhow!("AssemblyAI {what} failed ({status}): {body}")
+}
+
+impl AssemblyAIProvider {
+    /// Blocking upload + poll against `api_url` (the `/v2` API base)
+    pub(crate) fn transcribe_blocking_at(
+        api_url: &str,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        // 1. Upload audio
+        request.report(TranscriptionStage::Uploading);
+        let response = client
+            .post(format!("{api_url}/upload"))
+            .header("authorization", api_key)
+            .body(request.audio_data.clone())
+            .send()
+            .context("Failed to upload audio to AssemblyAI")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().unwrap_or_default();
+            return Err(api_error("upload", status, &body));
+        }
+        let upload: UploadResponse = response
+            .json()
+            .context("Failed to parse AssemblyAI upload response")?;
+
+        // 2. Submit the transcript job
+        request.report(TranscriptionStage::Transcribing);
+        let response = client
+            .post(format!("{api_url}/transcript"))
+            .header("authorization", api_key)
+            .json(&job_body(
+                &upload.upload_url,
+                request.language.as_deref(),
+                &request.options.assemblyai,
+            ))
+            .send()
+            .context("Failed to submit AssemblyAI transcript job")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().unwrap_or_default();
+            return Err(api_error("transcript request", status, &body));
+        }
+        let job: Transcript = response
+            .json()
+            .context("Failed to parse AssemblyAI transcript response")?;
+        let job_url = format!("{api_url}/transcript/{}", job.id);
+
+        // 3. Poll until the job finishes
+        let deadline = Instant::now() + Duration::from_secs(DEFAULT_TIMEOUT_SECS);
+        let mut transcript = job;
+        loop {
+            if let Some(result) = finished(transcript)? {
+                return Ok(result);
+            }
+            if Instant::now() >= deadline {
+                anyhow::bail!("Timed out waiting for AssemblyAI transcript");
+            }
+            std::thread::sleep(POLL_INTERVAL);
+
+            let response = client
+                .get(&job_url)
+                .header("authorization", api_key)
+                .send()
+                .context("Failed to poll AssemblyAI transcript")?;
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().unwrap_or_default();
+                return Err(api_error("poll", status, &body));
+            }
+            transcript = response
+                .json()
+                .context("Failed to parse AssemblyAI transcript response")?;
+        }
+    }
+
+    /// Upload + poll against `api_url` (the `/v2` API base)
+    pub(crate) async fn transcribe_at(
+        client: &reqwest::Client,
+        api_url: &str,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        // 1. Upload audio
+        request.report(TranscriptionStage::Uploading);
+        let response = client
+            .post(format!("{api_url}/upload"))
+            .header("authorization", api_key)
+            .body(request.audio_data.clone())
+            .send()
+            .await
+            .context("Failed to upload audio to AssemblyAI")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(api_error("upload", status, &body));
+        }
+        let upload: UploadResponse = response
+            .json()
+            .await
+            .context("Failed to parse AssemblyAI upload response")?;
+
+        // 2. Submit the transcript job
+        request.report(TranscriptionStage::Transcribing);
+        let response = client
+            .post(format!("{api_url}/transcript"))
+            .header("authorization", api_key)
+            .json(&job_body(
+                &upload.upload_url,
+                request.language.as_deref(),
+                &request.options.assemblyai,
+            ))
+            .send()
+            .await
+            .context("Failed to submit AssemblyAI transcript job")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(api_error("transcript request", status, &body));
+        }
+        let job: Transcript = response
+            .json()
+            .await
+            .context("Failed to parse AssemblyAI transcript response")?;
+        let job_url = format!("{api_url}/transcript/{}", job.id);
+
+        // 3. Poll until the job finishes
+        let deadline = Instant::now() + Duration::from_secs(DEFAULT_TIMEOUT_SECS);
+        let mut transcript = job;
+        loop {
+            if let Some(result) = finished(transcript)? {
+                return Ok(result);
+            }
+            if Instant::now() >= deadline {
+                anyhow::bail!("Timed out waiting for AssemblyAI transcript");
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+
+            let response = client
+                .get(&job_url)
+                .header("authorization", api_key)
+                .send()
+                .await
+                .context("Failed to poll AssemblyAI transcript")?;
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(api_error("poll", status, &body));
+            }
+            transcript = response
+                .json()
+                .await
+                .context("Failed to parse AssemblyAI transcript response")?;
+        }
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for AssemblyAIProvider {
+    fn name(&self) -> &'static str {
+        "assemblyai"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "AssemblyAI"
+    }
+
+    fn transcribe_sync(
+        &self,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        Self::transcribe_blocking_at(API_URL, api_key, request)
+    }
+
+    async fn transcribe_async(
+        &self,
+        client: &reqwest::Client,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        Self::transcribe_at(client, API_URL, api_key, request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::ProviderOptions;
+    use crate::provider::test_http::TestServer;
+
+    const UPLOADED: &str = r#"{"upload_url":"https://cdn.example/audio"}"#;
+
+    fn request(language: Option<&str>, assemblyai: AssemblyAIConfig) -> TranscriptionRequest {
+        TranscriptionRequest::new(vec![1, 2, 3, 4], language.map(str::to_string)).with_options(
+            ProviderOptions {
+                assemblyai,
+                ..ProviderOptions::default()
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn uploads_submits_and_polls_until_completed() {
+        let server = TestServer::start(vec![
+            (200, UPLOADED),
+            (200, r#"{"id":"job1","status":"queued"}"#),
+            (200, r#"{"id":"job1","status":"processing"}"#),
+            (
+                200,
+                r#"{"id":"job1","status":"completed","text":"hello world","language_code":"en",
+                    "words":[{"text":"hello","start":0,"end":400},{"text":"world","start":450,"end":900}]}"#,
+            ),
+        ])
+        .await;
+        let options = AssemblyAIConfig {
+            speaker_labels: false,
+            keyterms: vec!["Whis".to_string()],
+        };
+
+        let result = AssemblyAIProvider::transcribe_at(
+            &reqwest::Client::new(),
+            &server.url,
+            "secret",
+            request(None, options),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.text, "hello world");
+        assert_eq!(result.language.as_deref(), Some("en"));
+        assert_eq!(result.words.len(), 2);
+        assert_eq!(result.words[1].start_ms, 450);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(
+            (requests[0].method.as_str(), requests[0].path.as_str()),
+            ("POST", "/upload")
+        );
+        assert_eq!(requests[0].header("authorization"), Some("secret"));
+        assert_eq!(requests[0].body, vec![1, 2, 3, 4]);
+
+        assert_eq!(
+            (requests[1].method.as_str(), requests[1].path.as_str()),
+            ("POST", "/transcript")
+        );
+        let job = requests[1].json();
+        assert_eq!(job["audio_url"], "https://cdn.example/audio");
+        assert_eq!(job["language_detection"], true);
+        assert_eq!(job["keyterms_prompt"], serde_json::json!(["Whis"]));
+
+        for poll in &requests[2..] {
+            assert_eq!(
+                (poll.method.as_str(), poll.path.as_str()),
+                ("GET", "/transcript/job1")
+            );
+            assert_eq!(poll.header("authorization"), Some("secret"));
+        }
+    }
+
+    #[tokio::test]
+    async fn upload_errors_include_status_and_body() {
+        let server = TestServer::start(vec![(401, r#"{"error":"Invalid API key"}"#)]).await;
+
+        let error = AssemblyAIProvider::transcribe_at(
+            &reqwest::Client::new(),
+            &server.url,
+            "wrong",
+            request(None, AssemblyAIConfig::default()),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+
+        assert!(error.contains("upload failed (401"), "{error}");
+        assert!(error.contains("Invalid API key"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn failed_jobs_report_the_error() {
+        let server = TestServer::start(vec![
+            (200, UPLOADED),
+            (
+                200,
+                r#"{"id":"job1","status":"error","error":"Audio duration is too short"}"#,
+            ),
+        ])
+        .await;
+
+        let error = AssemblyAIProvider::transcribe_at(
+            &reqwest::Client::new(),
+            &server.url,
+            "secret",
+            request(None, AssemblyAIConfig::default()),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+
+        assert!(error.contains("Audio duration is too short"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn poll_errors_include_status() {
+        let server = TestServer::start(vec![
+            (200, UPLOADED),
+            (200, r#"{"id":"job1","status":"queued"}"#),
+            (500, r#"{"error":"Internal error"}"#),
+        ])
+        .await;
+
+        let error = AssemblyAIProvider::transcribe_at(
+            &reqwest::Client::new(),
+            &server.url,
+            "secret",
+            request(None, AssemblyAIConfig::default()),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+
+        assert!(error.contains("poll failed (500"), "{error}");
+    }
+
+    #[test]
+    fn blocking_path_renders_speaker_labels() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let server = runtime.block_on(TestServer::start(vec![
+            (200, UPLOADED),
+            (
+                200,
+                r#"{"id":"job1","status":"completed","text":"hi hello",
+                    "utterances":[{"speaker":"A","text":"hi"},{"speaker":"B","text":" hello "}]}"#,
+            ),
+        ]));
+        let options = AssemblyAIConfig {
+            speaker_labels: true,
+            keyterms: Vec::new(),
+        };
+
+        let result = AssemblyAIProvider::transcribe_blocking_at(
+            &server.url,
+            "secret",
+            request(Some("de"), options),
+        )
+        .unwrap();
+
+        assert_eq!(result.text, "Speaker A: hi\nSpeaker B: hello");
+        let job = server.requests()[1].json();
+        assert_eq!(job["speaker_labels"], true);
+        assert_eq!(job["language_code"], "de");
+        assert!(job.get("language_detection").is_none());
+    }
+}
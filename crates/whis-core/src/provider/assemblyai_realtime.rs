@@ -0,0 +1,550 @@
+//! AssemblyAI Universal-Streaming (v3) transcription provider
+//!
+//! Streams raw PCM16 over a WebSocket and receives `Turn` events. Each turn is
+//! re-sent as it grows (interim), then once more with `end_of_turn` and, since
+//! we request `format_turns`, a final punctuated copy with `turn_is_formatted`.
+//!
+//! ```text
+//! Begin → Turn (interim)* → Turn (end_of_turn) → Turn (formatted) → ... → Termination
+//! ```
+//!
+//! Only formatted turns are kept; they are ordered by `turn_order`.
+//!
+//! The WebSocket URL is a parameter of `stream_session`, so tests can point it
+//! at a local stand-in server.
+
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+use futures_util::{SinkExt, Stream, StreamExt};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::{Duration, timeout};
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{
+        Message,
+        client::IntoClientRequest,
+        http::header::{AUTHORIZATION, HeaderValue},
+        protocol::frame::coding::CloseCode,
+    },
+};
+
+use super::realtime_resume::{ResumableSession, SessionProgress, transcribe_resumable};
+use super::{
+    AssemblyAIProvider, PartialTranscriptSender, ProviderOptions, RealtimeTranscriptionBackend,
+    TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
+};
+use crate::config::TranscriptionProvider;
+use crate::settings::AssemblyAIConfig;
+
+const WS_URL: &str = "wss://streaming.assemblyai.com/v3/ws";
+const SAMPLE_RATE: u32 = 16000;
+/// Model used for languages other than English
+const MULTILINGUAL_MODEL: &str = "universal-streaming-multilingual";
+
+/// AssemblyAI Universal-Streaming provider
+///
+/// Streams audio via WebSocket for real-time transcription with
+/// punctuated, formatted turns. Uses the same API key as batch AssemblyAI.
+#[derive(Debug, Default, Clone)]
+pub struct AssemblyAIRealtimeProvider {
+    options: ProviderOptions,
+}
+
+#[derive(Deserialize, Debug)]
+struct StreamingEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    #[serde(default)]
+    turn_order: u32,
+    #[serde(default)]
+    end_of_turn: bool,
+    #[serde(default)]
+    turn_is_formatted: bool,
+    #[serde(default)]
+    transcript: String,
+    #[serde(default)]
+    words: Vec<StreamingWord>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct StreamingWord {
+    /// End of the word from the start of the session (milliseconds)
+    end: u64,
+}
+
+impl AssemblyAIRealtimeProvider {
+    /// Create a provider that streams with the caller's AssemblyAI options
+    pub fn new(options: ProviderOptions) -> Self {
+        Self { options }
+    }
+
+    /// Build the session URL: audio format, turn formatting, model and keyterms
+    fn session_url(
+        ws_url: &str,
+        language: Option<&str>,
+        options: &AssemblyAIConfig,
+    ) -> Result<reqwest::Url> {
+        let mut url = reqwest::Url::parse(ws_url).context("Failed to parse AssemblyAI URL")?;
+        url.query_pairs_mut()
+            .append_pair("sample_rate", &SAMPLE_RATE.to_string())
+            .append_pair("encoding", "pcm_s16le")
+            .append_pair("format_turns", "true");
+
+        if let Some(lang) = language
+            && lang != "en"
+        {
+            url.query_pairs_mut()
+                .append_pair("speech_model", MULTILINGUAL_MODEL);
+        }
+        if !options.keyterms.is_empty() {
+            url.query_pairs_mut().append_pair(
+                "keyterms_prompt",
+                &serde_json::to_string(&options.keyterms)?,
+            );
+        }
+        Ok(url)
+    }
+
+    /// Stream audio over a single WebSocket connection to `ws_url`
+    async fn stream_session(
+        ws_url: &str,
+        options: &AssemblyAIConfig,
+        api_key: &str,
+        mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+        progress: SessionProgress,
+    ) -> Result<String> {
+        // 1. Connect with the API key in the Authorization header
+        let mut request = Self::session_url(ws_url, language.as_deref(), options)?
+            .as_str()
+            .into_client_request()
+            .context("Failed to build AssemblyAI request")?;
+        request.headers_mut().insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(api_key).context("Invalid AssemblyAI API key")?,
+        );
+
+        let (ws_stream, _response) = timeout(Duration::from_secs(30), connect_async(request))
+            .await
+            .map_err(|_| anyhow!("Connection timeout"))?
+            .context("Failed to connect to AssemblyAI")?;
+
+        let (mut write, read) = ws_stream.split();
+
+        // 2. Collect turns while audio streams
+        let (done_tx, done_rx) = oneshot::channel::<()>();
+        let read_handle =
+            tokio::spawn(
+                async move { collect_transcripts(read, done_rx, partial_tx, progress).await },
+            );
+
+        // 3. Stream audio chunks as binary PCM16 frames
+        let mut total_samples = 0;
+        while let Some(samples) = audio_rx.recv().await {
+            if samples.is_empty() {
+                continue;
+            }
+            total_samples += samples.len();
+
+            let bytes: Vec<u8> = samples
+                .iter()
+                .map(|&s| (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+                .flat_map(|s| s.to_le_bytes())
+                .collect();
+
+            write
+                .send(Message::Binary(bytes.into()))
+                .await
+                .context("Failed to send audio chunk")?;
+        }
+
+        if crate::verbose::is_verbose() {
+            eprintln!("[assemblyai-realtime] Sent {total_samples} samples, terminating session");
+        }
+
+        // 4. Terminate: the server flushes the last turn, then sends Termination
+        write
+            .send(Message::Text(r#"{"type":"Terminate"}"#.to_string().into()))
+            .await
+            .context("Failed to send Terminate message")?;
+        let _ = done_tx.send(());
+
+        let transcript_result = timeout(Duration::from_secs(30), read_handle).await;
+        let _ = write.send(Message::Close(None)).await;
+
+        match transcript_result {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => Err(anyhow!("Read task panicked: {e}")),
+            Err(_) => Err(anyhow!("Timeout waiting for transcription result")),
+        }
+    }
+}
+
+/// Collect formatted turns until the server sends `Termination`
+///
+/// Interim turns are forwarded to `partial_tx` after the formatted text so far;
+/// formatted turns are committed to `progress` up to their last word.
+async fn collect_transcripts<S>(
+    mut read: S,
+    mut done_rx: oneshot::Receiver<()>,
+    partial_tx: Option<PartialTranscriptSender>,
+    progress: SessionProgress,
+) -> Result<String>
+where
+    S: Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+{
+    let mut turns: BTreeMap<u32, String> = BTreeMap::new();
+    let mut terminating = false;
+    let joined = |turns: &BTreeMap<u32, String>| {
+        turns
+            .values()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    loop {
+        let msg = tokio::select! {
+            _ = &mut done_rx, if !terminating => {
+                terminating = true;
+                continue;
+            }
+            msg = read.next() => msg,
+        };
+
+        let text = match msg {
+            Some(Ok(Message::Text(text))) => text,
+            Some(Ok(Message::Close(frame))) => {
+                if crate::verbose::is_verbose() {
+                    eprintln!("[assemblyai-realtime] WebSocket closed: {:?}", frame);
+                }
+                // Anything but a normal close before Termination is a failure
+                if let Some(frame) = frame
+                    && frame.code != CloseCode::Normal
+                {
+                    return Err(anyhow!(
+                        "AssemblyAI closed the connection ({}): {}",
+                        u16::from(frame.code),
+                        frame.reason
+                    ));
+                }
+                if !terminating {
+                    return Err(anyhow!("AssemblyAI closed the connection unexpectedly"));
+                }
+                return Ok(joined(&turns));
+            }
+            Some(Ok(_)) => continue,
+            Some(Err(e)) => return Err(anyhow!("WebSocket error: {e}")),
+            None if terminating => return Ok(joined(&turns)),
+            None => return Err(anyhow!("AssemblyAI connection ended unexpectedly")),
+        };
+
+        let event: StreamingEvent =
+            serde_json::from_str(&text).context("Failed to parse AssemblyAI event")?;
+
+        match event.event_type.as_str() {
+            "Begin" => {
+                if crate::verbose::is_verbose() {
+                    eprintln!("[assemblyai-realtime] Session started");
+                }
+            }
+            "Turn" => {
+                if event.end_of_turn && event.turn_is_formatted {
+                    if !event.transcript.is_empty() {
+                        let end_ms = event.words.last().map(|w| w.end).unwrap_or(0);
+                        progress.commit(
+                            &event.transcript,
+                            (end_ms * SAMPLE_RATE as u64 / 1000) as usize,
+                        );
+                        turns.insert(event.turn_order, event.transcript);
+                    }
+                    if let Some(tx) = &partial_tx {
+                        let _ = tx.send(joined(&turns));
+                    }
+                } else if !event.transcript.is_empty()
+                    && let Some(tx) = &partial_tx
+                {
+                    let text = format!("{} {}", joined(&turns), event.transcript);
+                    let _ = tx.send(text.trim().to_string());
+                }
+            }
+            "Termination" => {
+                if crate::verbose::is_verbose() {
+                    eprintln!("[assemblyai-realtime] Session terminated");
+                }
+                return Ok(joined(&turns));
+            }
+            _ => {
+                if let Some(error) = event.error {
+                    return Err(anyhow!("AssemblyAI error: {error}"));
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ResumableSession for AssemblyAIRealtimeProvider {
+    fn batch_provider(&self) -> TranscriptionProvider {
+        TranscriptionProvider::AssemblyAIRealtime
+    }
+
+    fn batch_options(&self) -> ProviderOptions {
+        self.options.clone()
+    }
+
+    async fn run_session(
+        &self,
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+        progress: SessionProgress,
+    ) -> Result<String> {
+        Self::stream_session(
+            WS_URL,
+            &self.options.assemblyai,
+            api_key,
+            audio_rx,
+            language,
+            partial_tx,
+            progress,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl RealtimeTranscriptionBackend for AssemblyAIRealtimeProvider {
+    async fn transcribe_stream(
+        &self,
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+    ) -> Result<String> {
+        transcribe_resumable(self, api_key, audio_rx, language, partial_tx).await
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for AssemblyAIRealtimeProvider {
+    fn name(&self) -> &'static str {
+        "assemblyai-realtime"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "AssemblyAI Realtime"
+    }
+
+    /// For file input, fall back to the regular AssemblyAI API
+    fn transcribe_sync(
+        &self,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        AssemblyAIProvider.transcribe_sync(api_key, request)
+    }
+
+    /// For async file input, fall back to the regular AssemblyAI API
+    async fn transcribe_async(
+        &self,
+        client: &reqwest::Client,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        AssemblyAIProvider
+            .transcribe_async(client, api_key, request)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::stream;
+    use std::sync::{Arc, Mutex};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+    use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+
+    type Event = Result<Message, tokio_tungstenite::tungstenite::Error>;
+
+    fn text(json: &str) -> Event {
+        Ok(Message::Text(json.to_string().into()))
+    }
+
+    /// Run `collect_transcripts` over canned events, returning the result and partials
+    async fn collect(events: Vec<Event>) -> (Result<String>, Vec<String>) {
+        let (_done_tx, done_rx) = oneshot::channel();
+        let (partial_tx, mut partial_rx) = mpsc::unbounded_channel();
+        let result = collect_transcripts(
+            stream::iter(events),
+            done_rx,
+            Some(partial_tx),
+            SessionProgress::default(),
+        )
+        .await;
+
+        let mut partials = Vec::new();
+        while let Ok(partial) = partial_rx.try_recv() {
+            partials.push(partial);
+        }
+        (result, partials)
+    }
+
+    #[tokio::test]
+    async fn keeps_formatted_turns_and_forwards_interim_ones() {
+        let (result, partials) = collect(vec![
+            text(r#"{"type":"Begin","id":"s1"}"#),
+            text(r#"{"type":"Turn","turn_order":0,"transcript":"hel"}"#),
+            text(r#"{"type":"Turn","turn_order":0,"end_of_turn":true,"transcript":"hello world"}"#),
+            text(
+                r#"{"type":"Turn","turn_order":0,"end_of_turn":true,"turn_is_formatted":true,
+                    "transcript":"Hello world.","words":[{"end":900}]}"#,
+            ),
+            text(r#"{"type":"Turn","turn_order":1,"transcript":"how"}"#),
+            text(
+                r#"{"type":"Turn","turn_order":1,"end_of_turn":true,"turn_is_formatted":true,
+                    "transcript":"How are you?","words":[{"end":2100}]}"#,
+            ),
+            text(r#"{"type":"Termination","audio_duration_seconds":3}"#),
+        ])
+        .await;
+
+        assert_eq!(result.unwrap(), "Hello world. How are you?");
+        assert_eq!(
+            partials,
+            [
+                "hel",
+                "hello world",
+                "Hello world.",
+                "Hello world. how",
+                "Hello world. How are you?"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn error_events_fail_the_session() {
+        let (result, _) =
+            collect(vec![text(r#"{"type":"Error","error":"Invalid API key"}"#)]).await;
+
+        let error = result.unwrap_err().to_string();
+        assert!(
+            error.contains("AssemblyAI error: Invalid API key"),
+            "{error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn abnormal_close_fails_with_code_and_reason() {
+        let (result, _) = collect(vec![Ok(Message::Close(Some(CloseFrame {
+            code: CloseCode::Policy,
+            reason: "Unauthorized".into(),
+        })))])
+        .await;
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("(1008): Unauthorized"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn stream_ending_before_termination_is_an_error() {
+        let (result, _) = collect(vec![text(r#"{"type":"Begin","id":"s1"}"#)]).await;
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("ended unexpectedly"), "{error}");
+    }
+
+    // The handshake callback's error type is fixed by tungstenite
+    #[allow(clippy::result_large_err)]
+    #[tokio::test]
+    async fn streams_audio_and_terminates_against_a_local_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ws_url = format!("ws://{}/v3/ws", listener.local_addr().unwrap());
+        let handshake = Arc::new(Mutex::new(None));
+
+        // Stand-in server: count audio frames, answer Terminate with a turn
+        let seen = Arc::clone(&handshake);
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_hdr_async(
+                stream,
+                |request: &Request, response: Response| {
+                    let auth = request.headers()["authorization"]
+                        .to_str()
+                        .unwrap()
+                        .to_string();
+                    *seen.lock().unwrap() = Some((request.uri().to_string(), auth));
+                    Ok(response)
+                },
+            )
+            .await
+            .unwrap();
+
+            let mut audio_bytes = 0;
+            while let Some(Ok(message)) = ws.next().await {
+                match message {
+                    Message::Binary(bytes) => audio_bytes += bytes.len(),
+                    Message::Text(text) if text.contains("Terminate") => {
+                        let turn = r#"{"type":"Turn","turn_order":0,"end_of_turn":true,
+                            "turn_is_formatted":true,"transcript":"Hallo Welt.","words":[{"end":500}]}"#;
+                        ws.send(Message::Text(turn.into())).await.unwrap();
+                        ws.send(Message::Text(r#"{"type":"Termination"}"#.into()))
+                            .await
+                            .unwrap();
+                    }
+                    _ => {}
+                }
+            }
+            audio_bytes
+        });
+
+        let (audio_tx, audio_rx) = mpsc::unbounded_channel();
+        audio_tx.send(vec![0.0; 1600]).unwrap();
+        audio_tx.send(vec![0.5; 1600]).unwrap();
+        drop(audio_tx);
+
+        let options = AssemblyAIConfig {
+            speaker_labels: false,
+            keyterms: vec!["Whis".to_string()],
+        };
+        let text = AssemblyAIRealtimeProvider::stream_session(
+            &ws_url,
+            &options,
+            "secret",
+            audio_rx,
+            Some("de".to_string()),
+            None,
+            SessionProgress::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(text, "Hallo Welt.");
+        // Two chunks of 1600 PCM16 samples
+        assert_eq!(server.await.unwrap(), 2 * 1600 * 2);
+
+        let (uri, auth) = handshake.lock().unwrap().clone().unwrap();
+        assert_eq!(auth, "secret");
+        assert!(uri.starts_with("/v3/ws?"), "{uri}");
+        assert!(uri.contains("sample_rate=16000"), "{uri}");
+        assert!(uri.contains("encoding=pcm_s16le"), "{uri}");
+        assert!(uri.contains("format_turns=true"), "{uri}");
+        assert!(
+            uri.contains(&format!("speech_model={MULTILINGUAL_MODEL}")),
+            "{uri}"
+        );
+        assert!(uri.contains("keyterms_prompt="), "{uri}");
+    }
+}
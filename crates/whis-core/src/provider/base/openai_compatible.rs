@@ -83,7 +83,11 @@ pub(crate) fn openai_compatible_transcribe_sync(
                     let text = response.text().context("Failed to get response text")?;
                     let resp: OpenAICompatibleResponse =
                         serde_json::from_str(&text).context("Failed to parse API response")?;
-                    return Ok(TranscriptionResult { text: resp.text });
+                    return Ok(TranscriptionResult {
+                        text: resp.text,
+                        language: None,
+                        words: Vec::new(),
+                    });
                 }
 
                 // Check if error is retryable
@@ -188,7 +192,11 @@ pub(crate) async fn openai_compatible_transcribe_async(
                         .context("Failed to get response text")?;
                     let resp: OpenAICompatibleResponse =
                         serde_json::from_str(&text).context("Failed to parse API response")?;
-                    return Ok(TranscriptionResult { text: resp.text });
+                    return Ok(TranscriptionResult {
+                        text: resp.text,
+                        language: None,
+                        words: Vec::new(),
+                    });
                 }
 
                 // Check if error is retryable
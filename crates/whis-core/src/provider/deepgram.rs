@@ -112,7 +112,11 @@ impl TranscriptionBackend for DeepgramProvider {
                                 )
                             })?;
 
-                        return Ok(TranscriptionResult { text: transcript });
+                        return Ok(TranscriptionResult {
+                            text: transcript,
+                            language: None,
+                            words: Vec::new(),
+                        });
                     }
 
                     // Check if error is retryable
@@ -215,7 +219,11 @@ impl TranscriptionBackend for DeepgramProvider {
                                 )
                             })?;
 
-                        return Ok(TranscriptionResult { text: transcript });
+                        return Ok(TranscriptionResult {
+                            text: transcript,
+                            language: None,
+                            words: Vec::new(),
+                        });
                     }
 
                     // Check if error is retryable
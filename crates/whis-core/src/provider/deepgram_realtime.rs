@@ -26,10 +26,12 @@ use tokio_tungstenite::{
     },
 };
 
+use super::realtime_resume::{ResumableSession, SessionProgress, transcribe_resumable};
 use super::{
-    DeepgramProvider, RealtimeTranscriptionBackend, TranscriptionBackend, TranscriptionRequest,
-    TranscriptionResult,
+    DeepgramProvider, PartialTranscriptSender, RealtimeTranscriptionBackend, TranscriptionBackend,
+    TranscriptionRequest, TranscriptionResult,
 };
+use crate::config::TranscriptionProvider;
 
 const WS_URL: &str = "wss://api.deepgram.com/v1/listen";
 const MODEL: &str = "nova-3";
@@ -62,6 +64,12 @@ struct DeepgramEvent {
     /// Set to true when this result is from a Finalize message
     #[serde(default)]
     from_finalize: bool,
+    /// Offset of this result from the start of the stream (seconds)
+    #[serde(default)]
+    start: f64,
+    /// Audio duration covered by this result (seconds)
+    #[serde(default)]
+    duration: f64,
 }
 
 #[derive(Deserialize, Debug)]
@@ -76,19 +84,30 @@ struct Alternative {
     confidence: f64,
 }
 
-/// Retry configuration for WebSocket connections
-const MAX_RETRIES: u32 = 3;
-const BASE_BACKOFF_SECS: u64 = 1;
-
 impl DeepgramRealtimeProvider {
     /// Transcribe audio from a channel of f32 samples (16kHz mono)
     ///
-    /// Connects to Deepgram Live Streaming API via WebSocket and streams audio chunks
-    /// as they arrive. Returns the final transcript when the channel closes.
+    /// Streams over a WebSocket session, reconnecting and replaying the
+    /// unacknowledged audio if the connection drops (see `realtime_resume`).
     async fn transcribe_stream_impl(
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+    ) -> Result<String> {
+        transcribe_resumable(&Self, api_key, audio_rx, language, partial_tx).await
+    }
+
+    /// Stream audio over a single WebSocket connection
+    ///
+    /// Connects to the Deepgram Live Streaming API and streams audio chunks as they arrive.
+    /// Returns the final transcript when the channel closes.
+    async fn stream_session(
         api_key: &str,
         mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+        progress: SessionProgress,
     ) -> Result<String> {
         // 1. Build WebSocket URL with query params
         let mut url = format!(
@@ -100,66 +119,20 @@ impl DeepgramRealtimeProvider {
             url.push_str(&format!("&language={}", lang));
         }
 
-        // 2. Connect to WebSocket with retry logic
-        let ws_stream = {
-            let mut last_error = None;
-
-            let result = 'retry: {
-                for attempt in 0..MAX_RETRIES {
-                    // Build request fresh each attempt
-                    let mut request = match url.clone().into_client_request() {
-                        Ok(r) => r,
-                        Err(e) => {
-                            last_error = Some(format!("Failed to build request: {}", e));
-                            continue;
-                        }
-                    };
-
-                    match HeaderValue::from_str(&format!("Token {api_key}")) {
-                        Ok(header_value) => {
-                            request.headers_mut().insert(AUTHORIZATION, header_value);
-                        }
-                        Err(e) => {
-                            last_error = Some(format!("Failed to set auth header: {}", e));
-                            continue;
-                        }
-                    }
-
-                    // Attempt connection with timeout
-                    match timeout(Duration::from_secs(30), connect_async(request)).await {
-                        Ok(Ok((stream, _response))) => break 'retry Ok(stream),
-                        Ok(Err(e)) => {
-                            last_error = Some(format!("Connection failed: {}", e));
-                        }
-                        Err(_) => {
-                            last_error = Some("Connection timeout".to_string());
-                        }
-                    }
-
-                    // Retry with exponential backoff (1s, 2s, 4s)
-                    if attempt < MAX_RETRIES - 1 {
-                        let backoff = BASE_BACKOFF_SECS * (1 << attempt);
-                        if crate::verbose::is_verbose() {
-                            eprintln!(
-                                "[deepgram-realtime] Attempt {} failed: {}. Retrying in {}s...",
-                                attempt + 1,
-                                last_error.as_deref().unwrap_or("unknown"),
-                                backoff
-                            );
-                        }
-                        tokio::time::sleep(Duration::from_secs(backoff)).await;
-                    }
-                }
-
-                Err(anyhow!(
-                    "Failed to connect after {} attempts: {}",
-                    MAX_RETRIES,
-                    last_error.unwrap_or_else(|| "unknown error".to_string())
-                ))
-            };
+        // 2. Connect to WebSocket (reconnects are handled by `transcribe_resumable`)
+        let mut request = url
+            .into_client_request()
+            .context("Failed to build Deepgram request")?;
+        request.headers_mut().insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Token {api_key}"))
+                .context("Invalid Deepgram API key")?,
+        );
 
-            result?
-        };
+        let (ws_stream, _response) = timeout(Duration::from_secs(30), connect_async(request))
+            .await
+            .map_err(|_| anyhow!("Connection timeout"))?
+            .context("Failed to connect to Deepgram")?;
 
         let (write, read) = ws_stream.split();
         let write = Arc::new(Mutex::new(write));
@@ -168,7 +141,10 @@ impl DeepgramRealtimeProvider {
         let (done_tx, done_rx) = oneshot::channel::<usize>();
 
         // 5. Spawn read task to collect transcripts
-        let read_handle = tokio::spawn(async move { collect_transcripts(read, done_rx).await });
+        let read_handle =
+            tokio::spawn(
+                async move { collect_transcripts(read, done_rx, partial_tx, progress).await },
+            );
 
         // 6. Spawn keepalive task
         let (keepalive_cancel_tx, keepalive_cancel_rx) = oneshot::channel();
@@ -252,8 +228,9 @@ impl DeepgramRealtimeProvider {
         api_key: &str,
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
     ) -> Result<String> {
-        Self::transcribe_stream_impl(api_key, audio_rx, language).await
+        Self::transcribe_stream_impl(api_key, audio_rx, language, partial_tx).await
     }
 }
 
@@ -302,9 +279,14 @@ where
 ///
 /// The done_rx channel receives total_samples to calculate dynamic timeout.
 /// Longer recordings need more processing time after CloseStream.
+///
+/// Interim results are forwarded to `partial_tx` (appended to the final text so far),
+/// final ones are committed to `progress` so a reconnect can resume after them.
 async fn collect_transcripts<S>(
     mut read: S,
     mut done_rx: oneshot::Receiver<usize>,
+    partial_tx: Option<PartialTranscriptSender>,
+    progress: SessionProgress,
 ) -> Result<String>
 where
     S: Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
@@ -328,7 +310,7 @@ where
 
             // Process WebSocket messages
             msg = read.next() => {
-                if let Some(result) = process_message(msg, &mut final_transcript)? {
+                if let Some(result) = process_message(msg, &mut final_transcript, partial_tx.as_ref(), &progress)? {
                     return Ok(result);
                 }
             }
@@ -371,7 +353,7 @@ where
             }
 
             msg = read.next() => {
-                if let Some(result) = process_message(msg, &mut final_transcript)? {
+                if let Some(result) = process_message(msg, &mut final_transcript, partial_tx.as_ref(), &progress)? {
                     return Ok(result);
                 }
                 // Continue waiting - don't reset the deadline, just process more messages
@@ -386,6 +368,8 @@ where
 fn process_message(
     msg: Option<Result<Message, tokio_tungstenite::tungstenite::Error>>,
     final_transcript: &mut String,
+    partial_tx: Option<&PartialTranscriptSender>,
+    progress: &SessionProgress,
 ) -> Result<Option<String>> {
     match msg {
         Some(Ok(Message::Text(text))) => {
@@ -401,14 +385,25 @@ fn process_message(
 
             match event.event_type.as_str() {
                 "Results" => {
-                    // Only collect final results (ignore interim results where is_final=false)
-                    if event.is_final
-                        && let Some(channel) = event.channel
+                    if let Some(channel) = event.channel
                         && let Some(alt) = channel.alternatives.first()
                         && !alt.transcript.is_empty()
                     {
-                        final_transcript.push_str(&alt.transcript);
-                        final_transcript.push(' ');
+                        // Only final results are kept; interim ones are just shown
+                        let interim = if event.is_final {
+                            final_transcript.push_str(&alt.transcript);
+                            final_transcript.push(' ');
+                            let end = (event.start + event.duration) * SAMPLE_RATE as f64;
+                            progress.commit(&alt.transcript, end as usize);
+                            ""
+                        } else {
+                            alt.transcript.as_str()
+                        };
+
+                        if let Some(tx) = partial_tx {
+                            let _ =
+                                tx.send(format!("{final_transcript}{interim}").trim().to_string());
+                        }
                     }
 
                     // Note: Don't return immediately on from_finalize.
@@ -461,6 +456,24 @@ fn process_message(
     }
 }
 
+#[async_trait]
+impl ResumableSession for DeepgramRealtimeProvider {
+    fn batch_provider(&self) -> TranscriptionProvider {
+        TranscriptionProvider::DeepgramRealtime
+    }
+
+    async fn run_session(
+        &self,
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+        progress: SessionProgress,
+    ) -> Result<String> {
+        Self::stream_session(api_key, audio_rx, language, partial_tx, progress).await
+    }
+}
+
 #[async_trait]
 impl RealtimeTranscriptionBackend for DeepgramRealtimeProvider {
     async fn transcribe_stream(
@@ -468,8 +481,9 @@ impl RealtimeTranscriptionBackend for DeepgramRealtimeProvider {
         api_key: &str,
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
     ) -> Result<String> {
-        Self::transcribe_stream_impl(api_key, audio_rx, language).await
+        Self::transcribe_stream_impl(api_key, audio_rx, language, partial_tx).await
     }
 
     fn sample_rate(&self) -> u32 {
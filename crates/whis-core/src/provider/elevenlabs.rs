@@ -86,7 +86,11 @@ impl TranscriptionBackend for ElevenLabsProvider {
                         let text = response.text().context("Failed to get response text")?;
                         let resp: Response = serde_json::from_str(&text)
                             .context("Failed to parse ElevenLabs API response")?;
-                        return Ok(TranscriptionResult { text: resp.text });
+                        return Ok(TranscriptionResult {
+                            text: resp.text,
+                            language: None,
+                            words: Vec::new(),
+                        });
                     }
 
                     // Check if error is retryable
@@ -179,7 +183,11 @@ impl TranscriptionBackend for ElevenLabsProvider {
                             .context("Failed to get response text")?;
                         let resp: Response = serde_json::from_str(&text)
                             .context("Failed to parse ElevenLabs API response")?;
-                        return Ok(TranscriptionResult { text: resp.text });
+                        return Ok(TranscriptionResult {
+                            text: resp.text,
+                            language: None,
+                            words: Vec::new(),
+                        });
                     }
 
                     // Check if error is retryable
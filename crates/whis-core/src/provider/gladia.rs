@@ -0,0 +1,314 @@
+//! Gladia pre-recorded transcription provider
+//!
+//! Gladia's pre-recorded API is asynchronous:
+//! 1. Upload the audio to `/v2/upload` (returns a private URL)
+//! 2. Start a job on `/v2/pre-recorded` referencing that URL
+//! 3. Poll the job until it is `done` or `error`
+//!
+//! Authentication uses the `x-gladia-key` header.
+//!
+//! Pre-recorded jobs run in the EU. With `transcription.regions.gladia = "us"`
+//! they are refused, so audio never leaves the chosen region (this includes the
+//! batch fallback of `gladia-realtime`).
+
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+use std::time::{Duration, Instant};
+
+use super::{
+    DEFAULT_TIMEOUT_SECS, TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
+    TranscriptionStage, WordTimestamp,
+};
+use crate::settings::DataRegion;
+
+const API_URL: &str = "https://api.gladia.io/v2";
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Deserialize)]
+struct UploadResponse {
+    audio_url: String,
+}
+
+#[derive(Deserialize)]
+struct CreatedJob {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct Job {
+    status: String,
+    #[serde(default)]
+    error_code: Option<u16>,
+    #[serde(default)]
+    result: Option<JobResult>,
+}
+
+#[derive(Deserialize)]
+struct JobResult {
+    transcription: Transcription,
+}
+
+#[derive(Deserialize)]
+struct Transcription {
+    full_transcript: String,
+    #[serde(default)]
+    languages: Vec<String>,
+    #[serde(default)]
+    utterances: Vec<Utterance>,
+}
+
+#[derive(Deserialize)]
+struct Utterance {
+    #[serde(default)]
+    words: Vec<Word>,
+}
+
+#[derive(Deserialize)]
+struct Word {
+    word: String,
+    /// Seconds from the start of the audio
+    start: f64,
+    end: f64,
+}
+
+/// Gladia pre-recorded transcription provider
+///
+/// Uses Gladia's v2 pre-recorded API (Whisper-based, EU-hosted).
+#[derive(Debug, Default, Clone)]
+pub struct GladiaProvider;
+
+/// Refuse regions the pre-recorded API does not run in
+fn check_region(region: DataRegion) -> Result<()> {
+    if region != DataRegion::Eu {
+        anyhow::bail!(
+            "Gladia pre-recorded transcription only runs in the EU, but the Gladia region is \
+             '{region}'. Use gladia-realtime, or set it to eu: whis config gladia-region eu"
+        );
+    }
+    Ok(())
+}
+
+/// Build the job request body
+fn job_body(audio_url: &str, language: Option<&str>) -> serde_json::Value {
+    let mut body = json!({ "audio_url": audio_url });
+    if let Some(lang) = language {
+        body["language_config"] = json!({ "languages": [lang], "code_switching": false });
+    }
+    body
+}
+
+/// Return the transcript once the job has finished, `None` while it is still running
+fn finished(job: Job) -> Result<Option<TranscriptionResult>> {
+    match job.status.as_str() {
+        "done" => {
+            let transcription = job
+                .result
+                .ok_or_else(|| anyhow!("Gladia job finished without a result"))?
+                .transcription;
+            Ok(Some(into_result(transcription)))
+        }
+        "error" => Err(anyhow!(
+            "Gladia transcription failed (error code {})",
+            job.error_code
+                .map(|c| c.to_string())
+                .unwrap_or_else(|| "unknown".to_string())
+        )),
+        _ => Ok(None),
+    }
+}
+
+fn into_result(transcription: Transcription) -> TranscriptionResult {
+    let words = transcription
+        .utterances
+        .into_iter()
+        .flat_map(|u| u.words)
+        .map(|w| WordTimestamp {
+            text: w.word.trim().to_string(),
+            start_ms: (w.start * 1000.0) as u64,
+            end_ms: (w.end * 1000.0) as u64,
+            speaker: None,
+        })
+        .collect();
+
+    TranscriptionResult {
+        text: transcription.full_transcript,
+        language: transcription.languages.into_iter().next(),
+        words,
+    }
+}
+
+/// Fail with the response body when Gladia returns a non-success status
+fn api_error(what: &str, status: reqwest::StatusCode, body: &str) -> anyhow::Error {
+    anyhow!("Gladia {what} failed ({status}): {body}")
+}
+
+#[async_trait]
+impl TranscriptionBackend for GladiaProvider {
+    fn name(&self) -> &'static str {
+        "gladia"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Gladia"
+    }
+
+    fn transcribe_sync(
+        &self,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        check_region(request.options.regions.gladia)?;
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        // 1. Upload audio
+        request.report(TranscriptionStage::Uploading);
+        let form = reqwest::blocking::multipart::Form::new().part(
+            "audio",
+            reqwest::blocking::multipart::Part::bytes(request.audio_data.clone())
+                .file_name(request.filename.clone())
+                .mime_str(&request.mime_type)?,
+        );
+        let response = client
+            .post(format!("{API_URL}/upload"))
+            .header("x-gladia-key", api_key)
+            .multipart(form)
+            .send()
+            .context("Failed to upload audio to Gladia")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().unwrap_or_default();
+            return Err(api_error("upload", status, &body));
+        }
+        let upload: UploadResponse = response
+            .json()
+            .context("Failed to parse Gladia upload response")?;
+
+        // 2. Start the transcription job
+        request.report(TranscriptionStage::Transcribing);
+        let response = client
+            .post(format!("{API_URL}/pre-recorded"))
+            .header("x-gladia-key", api_key)
+            .json(&job_body(&upload.audio_url, request.language.as_deref()))
+            .send()
+            .context("Failed to start Gladia transcription")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().unwrap_or_default();
+            return Err(api_error("transcription request", status, &body));
+        }
+        let job: CreatedJob = response
+            .json()
+            .context("Failed to parse Gladia job response")?;
+        let job_url = format!("{API_URL}/pre-recorded/{}", job.id);
+
+        // 3. Poll until the job finishes
+        let deadline = Instant::now() + Duration::from_secs(DEFAULT_TIMEOUT_SECS);
+        loop {
+            let response = client
+                .get(&job_url)
+                .header("x-gladia-key", api_key)
+                .send()
+                .context("Failed to poll Gladia job")?;
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().unwrap_or_default();
+                return Err(api_error("poll", status, &body));
+            }
+            let job: Job = response.json().context("Failed to parse Gladia job")?;
+            if let Some(result) = finished(job)? {
+                return Ok(result);
+            }
+            if Instant::now() >= deadline {
+                anyhow::bail!("Timed out waiting for Gladia transcript");
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    async fn transcribe_async(
+        &self,
+        client: &reqwest::Client,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        check_region(request.options.regions.gladia)?;
+
+        // 1. Upload audio
+        request.report(TranscriptionStage::Uploading);
+        let form = reqwest::multipart::Form::new().part(
+            "audio",
+            reqwest::multipart::Part::bytes(request.audio_data.clone())
+                .file_name(request.filename.clone())
+                .mime_str(&request.mime_type)?,
+        );
+        let response = client
+            .post(format!("{API_URL}/upload"))
+            .header("x-gladia-key", api_key)
+            .multipart(form)
+            .send()
+            .await
+            .context("Failed to upload audio to Gladia")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(api_error("upload", status, &body));
+        }
+        let upload: UploadResponse = response
+            .json()
+            .await
+            .context("Failed to parse Gladia upload response")?;
+
+        // 2. Start the transcription job
+        request.report(TranscriptionStage::Transcribing);
+        let response = client
+            .post(format!("{API_URL}/pre-recorded"))
+            .header("x-gladia-key", api_key)
+            .json(&job_body(&upload.audio_url, request.language.as_deref()))
+            .send()
+            .await
+            .context("Failed to start Gladia transcription")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(api_error("transcription request", status, &body));
+        }
+        let job: CreatedJob = response
+            .json()
+            .await
+            .context("Failed to parse Gladia job response")?;
+        let job_url = format!("{API_URL}/pre-recorded/{}", job.id);
+
+        // 3. Poll until the job finishes
+        let deadline = Instant::now() + Duration::from_secs(DEFAULT_TIMEOUT_SECS);
+        loop {
+            let response = client
+                .get(&job_url)
+                .header("x-gladia-key", api_key)
+                .send()
+                .await
+                .context("Failed to poll Gladia job")?;
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(api_error("poll", status, &body));
+            }
+            let job: Job = response
+                .json()
+                .await
+                .context("Failed to parse Gladia job")?;
+            if let Some(result) = finished(job)? {
+                return Ok(result);
+            }
+            if Instant::now() >= deadline {
+                anyhow::bail!("Timed out waiting for Gladia transcript");
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+}
@@ -0,0 +1,355 @@
+//! Gladia live transcription provider
+//!
+//! A live session is initiated over HTTP, then streamed over WebSocket:
+//!
+//! ```text
+//! POST /v2/live?region=…  → { id, url }   (session config: encoding, language)
+//! WS url: binary PCM16*   ← transcript (is_final=false | true)*
+//!         stop_recording  ← transcript* → end_session, close
+//! ```
+//!
+//! The session runs in the region selected by `transcription.regions.gladia`
+//! (EU by default). The session URL carries its own token, so only the init
+//! request is authenticated.
+
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+use futures_util::{SinkExt, Stream, StreamExt};
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::{Duration, timeout};
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{Message, protocol::frame::coding::CloseCode},
+};
+
+use super::realtime_resume::{ResumableSession, SessionProgress, transcribe_resumable};
+use super::{
+    GladiaProvider, PartialTranscriptSender, ProviderOptions, RealtimeTranscriptionBackend,
+    TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
+};
+use crate::config::TranscriptionProvider;
+use crate::settings::DataRegion;
+
+const LIVE_URL: &str = "https://api.gladia.io/v2/live";
+const SAMPLE_RATE: u32 = 16000;
+
+/// Gladia live provider
+///
+/// Streams audio via WebSocket for real-time transcription.
+/// Uses the same API key as Gladia pre-recorded (GLADIA_API_KEY).
+#[derive(Debug, Default, Clone)]
+pub struct GladiaRealtimeProvider {
+    options: ProviderOptions,
+}
+
+#[derive(Deserialize)]
+struct LiveSession {
+    url: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct LiveMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    data: Option<TranscriptData>,
+}
+
+#[derive(Deserialize, Debug)]
+struct TranscriptData {
+    is_final: bool,
+    utterance: Utterance,
+}
+
+#[derive(Deserialize, Debug)]
+struct Utterance {
+    text: String,
+    /// End of the utterance from the start of the session (seconds)
+    end: f64,
+}
+
+fn region_param(region: DataRegion) -> &'static str {
+    match region {
+        DataRegion::Eu => "eu-west",
+        DataRegion::Us => "us-west",
+    }
+}
+
+impl GladiaRealtimeProvider {
+    /// Create a provider that streams from the caller's Gladia region
+    pub fn new(options: ProviderOptions) -> Self {
+        Self { options }
+    }
+
+    /// Initiate a live session in `region` and return its WebSocket URL
+    async fn init_session(
+        region: DataRegion,
+        api_key: &str,
+        language: Option<&str>,
+    ) -> Result<String> {
+        let mut body = json!({
+            "encoding": "wav/pcm",
+            "bit_depth": 16,
+            "sample_rate": SAMPLE_RATE,
+            "channels": 1,
+            "messages_config": { "receive_partial_transcripts": true },
+        });
+        if let Some(lang) = language {
+            body["language_config"] = json!({ "languages": [lang], "code_switching": false });
+        }
+
+        let response = crate::http::get_http_client()?
+            .post(format!("{LIVE_URL}?region={}", region_param(region)))
+            .header("x-gladia-key", api_key)
+            .json(&body)
+            .send()
+            .await
+            .context("Failed to start Gladia live session")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow!("Gladia live session failed ({status}): {body}"));
+        }
+        let session: LiveSession = response
+            .json()
+            .await
+            .context("Failed to parse Gladia live session")?;
+        Ok(session.url)
+    }
+
+    /// Stream audio over a single live session in `region`
+    async fn stream_session(
+        region: DataRegion,
+        api_key: &str,
+        mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+        progress: SessionProgress,
+    ) -> Result<String> {
+        // 1. Initiate the session, then connect to its WebSocket
+        let url = Self::init_session(region, api_key, language.as_deref()).await?;
+        let (ws_stream, _response) = timeout(Duration::from_secs(30), connect_async(url))
+            .await
+            .map_err(|_| anyhow!("Connection timeout"))?
+            .context("Failed to connect to Gladia")?;
+        let (mut write, read) = ws_stream.split();
+
+        // 2. Collect transcripts while audio streams
+        let (done_tx, done_rx) = oneshot::channel::<()>();
+        let read_handle =
+            tokio::spawn(
+                async move { collect_transcripts(read, done_rx, partial_tx, progress).await },
+            );
+
+        // 3. Stream audio chunks as binary PCM16 frames
+        let mut total_samples = 0;
+        while let Some(samples) = audio_rx.recv().await {
+            if samples.is_empty() {
+                continue;
+            }
+            total_samples += samples.len();
+
+            let bytes: Vec<u8> = samples
+                .iter()
+                .map(|&s| (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+                .flat_map(|s| s.to_le_bytes())
+                .collect();
+
+            write
+                .send(Message::Binary(bytes.into()))
+                .await
+                .context("Failed to send audio chunk")?;
+        }
+
+        if crate::verbose::is_verbose() {
+            eprintln!("[gladia-realtime] Sent {total_samples} samples, stopping recording");
+        }
+
+        // 4. Stop: the server finalizes, sends end_session and closes
+        write
+            .send(Message::Text(
+                r#"{"type":"stop_recording"}"#.to_string().into(),
+            ))
+            .await
+            .context("Failed to send stop_recording")?;
+        let _ = done_tx.send(());
+
+        let transcript_result = timeout(Duration::from_secs(30), read_handle).await;
+        let _ = write.send(Message::Close(None)).await;
+
+        match transcript_result {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => Err(anyhow!("Read task panicked: {e}")),
+            Err(_) => Err(anyhow!("Timeout waiting for transcription result")),
+        }
+    }
+}
+
+/// Collect final utterances until the session ends
+///
+/// Partial utterances are forwarded to `partial_tx` after the final text so far;
+/// final ones are committed to `progress` up to their end time.
+async fn collect_transcripts<S>(
+    mut read: S,
+    mut done_rx: oneshot::Receiver<()>,
+    partial_tx: Option<PartialTranscriptSender>,
+    progress: SessionProgress,
+) -> Result<String>
+where
+    S: Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+{
+    let mut finals: Vec<String> = Vec::new();
+    let mut stopping = false;
+
+    loop {
+        let msg = tokio::select! {
+            _ = &mut done_rx, if !stopping => {
+                stopping = true;
+                continue;
+            }
+            msg = read.next() => msg,
+        };
+
+        let text = match msg {
+            Some(Ok(Message::Text(text))) => text,
+            Some(Ok(Message::Close(frame))) => {
+                if crate::verbose::is_verbose() {
+                    eprintln!("[gladia-realtime] WebSocket closed: {:?}", frame);
+                }
+                if let Some(frame) = frame
+                    && frame.code != CloseCode::Normal
+                {
+                    return Err(anyhow!(
+                        "Gladia closed the connection ({}): {}",
+                        u16::from(frame.code),
+                        frame.reason
+                    ));
+                }
+                if !stopping {
+                    return Err(anyhow!("Gladia closed the connection unexpectedly"));
+                }
+                return Ok(finals.join(" "));
+            }
+            Some(Ok(_)) => continue,
+            Some(Err(e)) => return Err(anyhow!("WebSocket error: {e}")),
+            None if stopping => return Ok(finals.join(" ")),
+            None => return Err(anyhow!("Gladia connection ended unexpectedly")),
+        };
+
+        let event: LiveMessage =
+            serde_json::from_str(&text).context("Failed to parse Gladia message")?;
+
+        match event.kind.as_str() {
+            "transcript" => {
+                let Some(data) = event.data else { continue };
+                let utterance = data.utterance.text.trim();
+                if utterance.is_empty() {
+                    continue;
+                }
+                if data.is_final {
+                    progress.commit(
+                        utterance,
+                        (data.utterance.end * SAMPLE_RATE as f64) as usize,
+                    );
+                    finals.push(utterance.to_string());
+                    if let Some(tx) = &partial_tx {
+                        let _ = tx.send(finals.join(" "));
+                    }
+                } else if let Some(tx) = &partial_tx {
+                    let text = format!("{} {utterance}", finals.join(" "));
+                    let _ = tx.send(text.trim().to_string());
+                }
+            }
+            "end_session" => {
+                if crate::verbose::is_verbose() {
+                    eprintln!("[gladia-realtime] Session ended");
+                }
+                return Ok(finals.join(" "));
+            }
+            _ => {
+                // Lifecycle and acknowledgment messages: nothing to do
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ResumableSession for GladiaRealtimeProvider {
+    fn batch_provider(&self) -> TranscriptionProvider {
+        TranscriptionProvider::GladiaRealtime
+    }
+
+    fn batch_options(&self) -> ProviderOptions {
+        self.options.clone()
+    }
+
+    async fn run_session(
+        &self,
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+        progress: SessionProgress,
+    ) -> Result<String> {
+        Self::stream_session(
+            self.options.regions.gladia,
+            api_key,
+            audio_rx,
+            language,
+            partial_tx,
+            progress,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl RealtimeTranscriptionBackend for GladiaRealtimeProvider {
+    async fn transcribe_stream(
+        &self,
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+    ) -> Result<String> {
+        transcribe_resumable(self, api_key, audio_rx, language, partial_tx).await
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for GladiaRealtimeProvider {
+    fn name(&self) -> &'static str {
+        "gladia-realtime"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Gladia Realtime"
+    }
+
+    /// For file input, fall back to the Gladia pre-recorded API
+    fn transcribe_sync(
+        &self,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        GladiaProvider.transcribe_sync(api_key, request)
+    }
+
+    /// For async file input, fall back to the Gladia pre-recorded API
+    async fn transcribe_async(
+        &self,
+        client: &reqwest::Client,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        GladiaProvider
+            .transcribe_async(client, api_key, request)
+            .await
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! This provider enables offline transcription using Parakeet models.
 //! Requires a Parakeet model directory containing ONNX files.
+//! Handles microphone samples and WAV files; other file formats are rejected
+//! before the model is loaded.
 //!
 //! Parakeet models offer high accuracy and speed for speech-to-text.
 
@@ -10,7 +12,7 @@ use async_trait::async_trait;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, OnceLock};
 
-use super::{TranscriptionBackend, TranscriptionRequest, TranscriptionResult};
+use super::{TranscriptionBackend, TranscriptionRequest, TranscriptionResult, TranscriptionStage};
 
 /// Local Parakeet transcription provider
 #[derive(Debug, Default, Clone)]
@@ -28,24 +30,114 @@ impl TranscriptionBackend for LocalParakeetProvider {
 
     fn transcribe_sync(
         &self,
-        _model_path: &str,
-        _request: TranscriptionRequest,
+        model_path: &str,
+        request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        anyhow::bail!(
-            "File transcription not supported. Use microphone recording with transcribe_raw()."
-        )
+        ensure_wav(&request)?;
+        transcribe_file(model_path, &request)
     }
 
     async fn transcribe_async(
         &self,
         _client: &reqwest::Client,
-        _model_path: &str,
-        _request: TranscriptionRequest,
+        model_path: &str,
+        request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        anyhow::bail!(
-            "File transcription not supported. Use microphone recording with transcribe_raw()."
-        )
+        ensure_wav(&request)?;
+
+        // Decoding and inference are CPU-bound
+        let model_path = model_path.to_string();
+        tokio::task::spawn_blocking(move || transcribe_file(&model_path, &request))
+            .await
+            .map_err(|e| anyhow::anyhow!("Transcription task panicked: {}", e))?
+    }
+}
+
+/// Fail fast on uploads Parakeet cannot decode (only WAV is supported)
+fn ensure_wav(request: &TranscriptionRequest) -> Result<()> {
+    crate::audio::decode::ensure_wav(&request.audio_data, &request.mime_type, &request.filename)
+}
+
+/// Transcribe an encoded audio file (e.g. an HTTP API upload)
+///
+/// Decodes the file to 16kHz mono samples and hands them to
+/// [`transcribe_chunked`].
+fn transcribe_file(
+    model_path: &str,
+    request: &TranscriptionRequest,
+) -> Result<TranscriptionResult> {
+    let samples = crate::audio::decode::decode_to_16k(
+        &request.audio_data,
+        &request.mime_type,
+        &request.filename,
+    )?;
+
+    request.report(TranscriptionStage::Transcribing);
+    transcribe_chunked(model_path, samples)
+}
+
+/// Transcribe a complete recording or file of 16kHz mono samples.
+///
+/// Splits the audio with [`ProgressiveChunker`], the same way microphone
+/// recordings are chunked, so overlapping chunk boundaries are merged without
+/// duplicated words.
+///
+/// # Arguments
+/// * `model_path` - Path to the Parakeet model directory
+/// * `samples` - Raw f32 audio samples (must be 16kHz mono)
+pub fn transcribe_chunked(model_path: &str, samples: Vec<f32>) -> Result<TranscriptionResult> {
+    use crate::audio::{ChunkerConfig, ProgressiveChunker};
+    use crate::transcription::{ChunkTranscription, merge_transcriptions};
+    use transcribe_rs::engines::parakeet::{ParakeetInferenceParams, TimestampGranularity};
+
+    // Keep chunks (including the 2s overlap) within Parakeet's ~90s limit
+    let config = ChunkerConfig {
+        target_duration_secs: 85,
+        vad_aware: false,
+        ..ChunkerConfig::default()
+    };
+    let (chunk_tx, mut chunk_rx) = tokio::sync::mpsc::unbounded_channel();
+    ProgressiveChunker::new(config, chunk_tx)
+        .consume_samples(&samples)
+        .map_err(|e| anyhow::anyhow!("Failed to chunk audio: {}", e))?;
+    drop(samples);
+
+    // Load once and reuse the engine for every chunk
+    get_or_load_engine(model_path)?;
+    let mut cache = get_cache().lock().unwrap();
+    let cached = cache.as_mut().ok_or_else(|| {
+        anyhow::anyhow!("Parakeet engine not loaded (cache empty after get_or_load_engine)")
+    })?;
+
+    let params = ParakeetInferenceParams {
+        timestamp_granularity: TimestampGranularity::Segment,
+    };
+
+    let mut transcriptions = Vec::new();
+    while let Ok(chunk) = chunk_rx.try_recv() {
+        crate::verbose!(
+            "Transcribing chunk {} ({:.1}s)...",
+            chunk.index + 1,
+            chunk.samples.len() as f32 / 16000.0
+        );
+
+        let result = transcribe_chunk_with_engine(&mut cached.engine, chunk.samples, &params)?;
+        transcriptions.push(ChunkTranscription {
+            index: chunk.index,
+            text: result.text,
+            has_leading_overlap: chunk.has_leading_overlap,
+        });
     }
+
+    // Release the lock before maybe_unload
+    drop(cache);
+    maybe_unload();
+
+    Ok(TranscriptionResult {
+        text: merge_transcriptions(transcriptions),
+        language: None,
+        words: Vec::new(),
+    })
 }
 
 /// Transcribe raw f32 samples directly.
@@ -116,6 +208,8 @@ fn transcribe_samples(model_path: &str, samples: Vec<f32>) -> Result<Transcripti
         // Concatenate chunk results with space separator
         TranscriptionResult {
             text: results.join(" "),
+            language: None,
+            words: Vec::new(),
         }
     };
 
@@ -130,8 +224,9 @@ fn transcribe_samples(model_path: &str, samples: Vec<f32>) -> Result<Transcripti
 
 /// Transcribe a single chunk of audio using an already-loaded engine
 ///
-/// This function is used internally by `transcribe_samples()` to reuse the same
-/// engine instance across multiple chunks, avoiding repeated model loading.
+/// This function is used internally by `transcribe_samples()` and
+/// `transcribe_chunked()` to reuse the same engine instance across multiple
+/// chunks, avoiding repeated model loading.
 fn transcribe_chunk_with_engine(
     engine: &mut transcribe_rs::engines::parakeet::ParakeetEngine,
     samples: Vec<f32>,
@@ -147,6 +242,8 @@ fn transcribe_chunk_with_engine(
 
     Ok(TranscriptionResult {
         text: result.text.trim().to_string(),
+        language: None,
+        words: Vec::new(),
     })
 }
 
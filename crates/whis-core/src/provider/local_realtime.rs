@@ -0,0 +1,251 @@
+//! Realtime transcription for local models (Whisper + Parakeet)
+//!
+//! Segments the microphone stream into utterances with [`VadProcessor`] and
+//! transcribes each utterance as soon as the speaker pauses, so the text is
+//! ready shortly after recording stops, fully offline.
+//!
+//! # Architecture
+//!
+//! ```text
+//! audio_rx → VadProcessor → utterance buffer
+//!                               ↓ speech → silence (commit point)
+//!                           utterance queue → worker (spawn_blocking per utterance)
+//!                                                 ↓
+//!                                       texts joined (and sent as partials)
+//! ```
+//!
+//! With VAD turned off (`ui.vad.enabled`, `--no-vad`), without the `vad`
+//! feature, or if the VAD model fails to load, utterances are committed every
+//! [`MAX_UTTERANCE_SECS`] instead.
+//!
+//! Local providers have no [`RealtimeTranscriptionBackend`](super::RealtimeTranscriptionBackend):
+//! front ends build a [`LocalStreamConfig`] (settings merged with the
+//! recording's preset) and call [`LocalRealtimeProvider::transcribe_local`],
+//! which also reports the detected language.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+use super::{PartialTranscriptSender, TranscriptionResult};
+use crate::audio::VadProcessor;
+use crate::config::TranscriptionProvider;
+use crate::resample::WHISPER_SAMPLE_RATE;
+use crate::settings::{Settings, WhisperTuning};
+
+/// Longest utterance before a commit is forced (Whisper's context window)
+const MAX_UTTERANCE_SECS: usize = 30;
+
+/// Utterances shorter than this are dropped (clicks, breaths)
+const MIN_UTTERANCE_MS: usize = 250;
+
+/// Local engine used for realtime transcription
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalRealtimeProvider {
+    Whisper,
+    Parakeet,
+}
+
+/// Per-recording options for local streaming
+#[derive(Debug, Clone)]
+pub struct LocalStreamConfig {
+    /// Whisper inference parameters (settings merged with preset overrides)
+    pub tuning: WhisperTuning,
+    /// Commit utterances at speech pauses (otherwise every [`MAX_UTTERANCE_SECS`])
+    pub vad: bool,
+    /// VAD speech probability threshold
+    pub vad_threshold: f32,
+}
+
+impl LocalStreamConfig {
+    /// Build from settings, with the preset's `whisper` section on top
+    pub fn from_settings(settings: &Settings, overrides: Option<&WhisperTuning>) -> Result<Self> {
+        let tuning = settings
+            .transcription
+            .local_models
+            .whisper
+            .with_overrides(overrides);
+        tuning.validate()?;
+
+        Ok(Self {
+            tuning,
+            vad: settings.ui.vad.enabled,
+            vad_threshold: settings.ui.vad.threshold,
+        })
+    }
+}
+
+impl LocalRealtimeProvider {
+    /// Local engine of a provider (`None` for cloud and network providers)
+    pub fn for_provider(provider: &TranscriptionProvider) -> Option<Self> {
+        match provider {
+            TranscriptionProvider::LocalWhisper => Some(Self::Whisper),
+            TranscriptionProvider::LocalParakeet => Some(Self::Parakeet),
+            _ => None,
+        }
+    }
+
+    fn transcribe(
+        self,
+        model_path: &str,
+        samples: Vec<f32>,
+        language: Option<&str>,
+        tuning: &WhisperTuning,
+    ) -> Result<TranscriptionResult> {
+        match self {
+            Self::Whisper => {
+                super::local_whisper::transcribe_raw(model_path, &samples, language, tuning)
+            }
+            Self::Parakeet => super::local_parakeet::transcribe_raw(model_path, samples),
+        }
+    }
+
+    fn should_keep_loaded(self) -> bool {
+        match self {
+            Self::Whisper => super::local_whisper::should_keep_loaded(),
+            Self::Parakeet => super::local_parakeet::should_keep_loaded(),
+        }
+    }
+
+    fn set_keep_loaded(self, keep: bool) {
+        match self {
+            Self::Whisper => super::local_whisper::set_keep_loaded(keep),
+            Self::Parakeet => super::local_parakeet::set_keep_loaded(keep),
+        }
+    }
+
+    fn unload(self) {
+        match self {
+            Self::Whisper => super::local_whisper::unload_model(),
+            Self::Parakeet => super::local_parakeet::unload_parakeet(),
+        }
+    }
+}
+
+/// Keeps the model loaded between utterances, restoring the previous
+/// keep-loaded setting (and unloading if it was off) when dropped
+struct KeepLoadedGuard {
+    engine: LocalRealtimeProvider,
+    previous: bool,
+}
+
+impl KeepLoadedGuard {
+    fn new(engine: LocalRealtimeProvider) -> Self {
+        let previous = engine.should_keep_loaded();
+        engine.set_keep_loaded(true);
+        Self { engine, previous }
+    }
+}
+
+impl Drop for KeepLoadedGuard {
+    fn drop(&mut self) {
+        self.engine.set_keep_loaded(self.previous);
+        if !self.previous {
+            self.engine.unload();
+        }
+    }
+}
+
+impl LocalRealtimeProvider {
+    /// Transcribe the microphone stream utterance by utterance
+    ///
+    /// `model_path` is the model file (Whisper) or directory (Parakeet). When
+    /// `language` is `None`, the result reports the language Whisper detected
+    /// for most utterances.
+    pub async fn transcribe_local(
+        &self,
+        model_path: &str,
+        mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        config: LocalStreamConfig,
+        partial_tx: Option<PartialTranscriptSender>,
+    ) -> Result<TranscriptionResult> {
+        let engine = *self;
+        let LocalStreamConfig {
+            tuning,
+            vad,
+            vad_threshold,
+        } = config;
+        let mut vad = VadProcessor::new(vad, vad_threshold)
+            .or_else(|e| {
+                crate::warn!("VAD unavailable, committing every {MAX_UTTERANCE_SECS}s: {e}");
+                VadProcessor::disabled()
+            })
+            .context("Failed to create VAD processor")?;
+        let segment_at_pauses = vad.is_enabled();
+
+        let keep_loaded = KeepLoadedGuard::new(engine);
+
+        // Transcribe committed utterances sequentially while recording continues
+        let (utterance_tx, mut utterance_rx) = mpsc::unbounded_channel::<Vec<f32>>();
+        let model_path = model_path.to_string();
+        let worker = tokio::spawn(async move {
+            let mut texts = Vec::new();
+            let mut languages: HashMap<String, usize> = HashMap::new();
+            while let Some(samples) = utterance_rx.recv().await {
+                let model_path = model_path.clone();
+                let language = language.clone();
+                let tuning = tuning.clone();
+                let seconds = samples.len() as f32 / WHISPER_SAMPLE_RATE as f32;
+
+                let result = tokio::task::spawn_blocking(move || {
+                    engine.transcribe(&model_path, samples, language.as_deref(), &tuning)
+                })
+                .await
+                .context("Transcription task panicked")??;
+
+                crate::verbose!("Utterance ({:.1}s): {}", seconds, result.text);
+                if let Some(detected) = result.language {
+                    *languages.entry(detected).or_default() += 1;
+                }
+                if !result.text.is_empty() {
+                    texts.push(result.text);
+                    if let Some(tx) = &partial_tx {
+                        let _ = tx.send(texts.join(" "));
+                    }
+                }
+            }
+
+            let language = languages
+                .into_iter()
+                .max_by_key(|(_, count)| *count)
+                .map(|(language, _)| language);
+            anyhow::Ok(TranscriptionResult {
+                text: texts.join(" "),
+                language,
+                words: Vec::new(),
+            })
+        });
+
+        let max_samples = MAX_UTTERANCE_SECS * WHISPER_SAMPLE_RATE as usize;
+        let mut utterance = Vec::new();
+
+        while let Some(samples) = audio_rx.recv().await {
+            utterance.extend(vad.process(&samples));
+
+            // Commit at the speech → silence transition, or when too long
+            let pause = segment_at_pauses && vad.is_silence();
+            if !utterance.is_empty() && (pause || utterance.len() >= max_samples) {
+                commit(&utterance_tx, std::mem::take(&mut utterance));
+            }
+        }
+
+        // Stream closed: commit whatever is left
+        utterance.extend(vad.flush());
+        commit(&utterance_tx, utterance);
+        drop(utterance_tx);
+
+        let result = worker.await.context("Transcription worker panicked")??;
+        drop(keep_loaded);
+        Ok(result)
+    }
+}
+
+/// Queue an utterance for transcription, dropping ones too short to contain words
+fn commit(utterance_tx: &mpsc::UnboundedSender<Vec<f32>>, samples: Vec<f32>) {
+    let min_samples = MIN_UTTERANCE_MS * WHISPER_SAMPLE_RATE as usize / 1000;
+    if samples.len() < min_samples {
+        return;
+    }
+    let _ = utterance_tx.send(samples);
+}
@@ -1,7 +1,13 @@
-//! Local transcription using Whisper via transcribe-rs
+//! Local transcription using Whisper via whisper.cpp
 //!
 //! This provider enables offline transcription without API calls.
 //! Requires a whisper.cpp model file (e.g., ggml-small.bin).
+//! Handles microphone samples and WAV files; other file formats are rejected
+//! before the model is loaded.
+//!
+//! Inference is tunable through [`WhisperTuning`] (threads, beam size,
+//! temperature fallback, no-speech threshold), and the auto-detected language
+//! is reported back when no language is configured.
 //!
 //! Uses engine-level caching to avoid reloading the model on every
 //! transcription (saves 200ms-2s per call in listen mode).
@@ -14,7 +20,8 @@ use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, OnceLock};
 
-use super::{TranscriptionBackend, TranscriptionRequest, TranscriptionResult};
+use super::{TranscriptionBackend, TranscriptionRequest, TranscriptionResult, TranscriptionStage};
+use crate::settings::WhisperTuning;
 
 // ============================================================================
 // stderr Suppression for GGML Vulkan Output
@@ -114,7 +121,7 @@ mod stderr_suppression {
     }
 }
 
-/// Local Whisper transcription provider using transcribe-rs
+/// Local Whisper transcription provider using whisper.cpp
 #[derive(Debug, Default, Clone)]
 pub struct LocalWhisperProvider;
 
@@ -130,40 +137,75 @@ impl TranscriptionBackend for LocalWhisperProvider {
 
     fn transcribe_sync(
         &self,
-        _model_path: &str,
-        _request: TranscriptionRequest,
+        model_path: &str,
+        request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        anyhow::bail!(
-            "File transcription not supported. Use microphone recording with transcribe_raw()."
-        )
+        ensure_wav(&request)?;
+        transcribe_file(model_path, &request)
     }
 
     async fn transcribe_async(
         &self,
         _client: &reqwest::Client,
-        _model_path: &str,
-        _request: TranscriptionRequest,
+        model_path: &str,
+        request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        anyhow::bail!(
-            "File transcription not supported. Use microphone recording with transcribe_raw()."
-        )
+        ensure_wav(&request)?;
+
+        // Decoding and inference are CPU-bound
+        let model_path = model_path.to_string();
+        tokio::task::spawn_blocking(move || transcribe_file(&model_path, &request))
+            .await
+            .map_err(|e| anyhow::anyhow!("Transcription task panicked: {}", e))?
     }
 }
 
+/// Fail fast on uploads Whisper cannot decode (only WAV is supported)
+fn ensure_wav(request: &TranscriptionRequest) -> Result<()> {
+    crate::audio::decode::ensure_wav(&request.audio_data, &request.mime_type, &request.filename)
+}
+
+/// Transcribe an encoded audio file (e.g. an HTTP API upload)
+///
+/// Decodes the file to 16kHz mono samples and hands them to
+/// [`transcribe_raw`] with the request's Whisper tuning.
+fn transcribe_file(
+    model_path: &str,
+    request: &TranscriptionRequest,
+) -> Result<TranscriptionResult> {
+    let samples = crate::audio::decode::decode_to_16k(
+        &request.audio_data,
+        &request.mime_type,
+        &request.filename,
+    )?;
+
+    request.report(TranscriptionStage::Transcribing);
+    transcribe_raw(
+        model_path,
+        &samples,
+        request.language.as_deref(),
+        &request.options.whisper,
+    )
+}
+
 /// Transcribe raw f32 samples directly.
 ///
 /// Use this for local recordings where samples are already 16kHz mono.
+/// When `language` is `None`, the language is auto-detected and reported in
+/// [`TranscriptionResult::language`].
 ///
 /// # Arguments
 /// * `model_path` - Path to the whisper.cpp model file (.bin)
 /// * `samples` - Raw f32 audio samples (must be 16kHz mono)
 /// * `language` - Optional language code (e.g., "en", "de")
+/// * `tuning` - Inference parameters (settings merged with preset overrides)
 pub fn transcribe_raw(
     model_path: &str,
     samples: &[f32],
     language: Option<&str>,
+    tuning: &WhisperTuning,
 ) -> Result<TranscriptionResult> {
-    transcribe_samples(model_path, samples, language)
+    transcribe_samples(model_path, samples, language, tuning)
 }
 
 // ============================================================================
@@ -173,8 +215,11 @@ pub fn transcribe_raw(
 static WHISPER_ENGINE: OnceLock<Mutex<Option<CachedWhisperEngine>>> = OnceLock::new();
 static KEEP_LOADED: AtomicBool = AtomicBool::new(false);
 
+/// Loaded whisper.cpp model with a reusable decoding state
 struct CachedWhisperEngine {
-    engine: transcribe_rs::engines::whisper::WhisperEngine,
+    // Keeps the model alive for `state`
+    _context: whisper_rs::WhisperContext,
+    state: whisper_rs::WhisperState,
     path: String,
 }
 
@@ -182,8 +227,10 @@ fn get_cache() -> &'static Mutex<Option<CachedWhisperEngine>> {
     WHISPER_ENGINE.get_or_init(|| Mutex::new(None))
 }
 
-/// Get or load the WhisperEngine, caching it for future use.
+/// Get or load the whisper.cpp model, caching it for future use.
 fn get_or_load_engine(model_path: &str) -> Result<()> {
+    use whisper_rs::{WhisperContext, WhisperContextParameters};
+
     let mut cache = get_cache().lock().unwrap();
 
     // Check if already loaded with same path
@@ -210,16 +257,14 @@ fn get_or_load_engine(model_path: &str) -> Result<()> {
 
     crate::verbose!("Loading whisper model from: {}", model_path);
 
-    // Create and load engine
-    use transcribe_rs::TranscriptionEngine;
-    let mut engine = transcribe_rs::engines::whisper::WhisperEngine::new();
-
     // Suppress stderr during model loading to hide whisper.cpp noise.
     // The guard automatically restores stderr when dropped (RAII pattern).
     let _stderr_guard = stderr_suppression::suppress();
 
-    engine
-        .load_model(Path::new(model_path))
+    let context = WhisperContext::new_with_params(model_path, WhisperContextParameters::default())
+        .map_err(|e| anyhow::anyhow!("Failed to load whisper model: {}", e))?;
+    let state = context
+        .create_state()
         .map_err(|e| anyhow::anyhow!("Failed to load whisper model: {}", e))?;
 
     // Explicitly drop the guard to restore stderr before any subsequent logging
@@ -228,65 +273,125 @@ fn get_or_load_engine(model_path: &str) -> Result<()> {
     crate::verbose!("Whisper model loaded successfully");
 
     *cache = Some(CachedWhisperEngine {
-        engine,
+        _context: context,
+        state,
         path: model_path.to_string(),
     });
 
     Ok(())
 }
 
-/// Internal function to transcribe PCM samples using cached WhisperEngine
+/// Build whisper.cpp decoding parameters from the tuning settings
+fn full_params<'a>(
+    language: Option<&'a str>,
+    tuning: &WhisperTuning,
+) -> whisper_rs::FullParams<'a, 'a> {
+    use whisper_rs::{FullParams, SamplingStrategy};
+
+    let beam_size = tuning.beam_size.unwrap_or(WhisperTuning::DEFAULT_BEAM_SIZE);
+    let strategy = if beam_size <= 1 {
+        SamplingStrategy::Greedy { best_of: 1 }
+    } else {
+        SamplingStrategy::BeamSearch {
+            beam_size: beam_size as i32,
+            patience: -1.0,
+        }
+    };
+
+    let mut params = FullParams::new(strategy);
+    // None asks whisper.cpp to auto-detect the language
+    params.set_language(language);
+    params.set_translate(false);
+    params.set_print_special(false);
+    params.set_print_progress(false);
+    params.set_print_realtime(false);
+    params.set_print_timestamps(false);
+    params.set_suppress_non_speech_tokens(true);
+    params.set_suppress_blank(tuning.suppress_blank.unwrap_or(true));
+    params.set_temperature(
+        tuning
+            .temperature
+            .unwrap_or(WhisperTuning::DEFAULT_TEMPERATURE),
+    );
+    params.set_temperature_inc(
+        tuning
+            .temperature_increment
+            .unwrap_or(WhisperTuning::DEFAULT_TEMPERATURE_INCREMENT),
+    );
+    params.set_no_speech_thold(
+        tuning
+            .no_speech_threshold
+            .unwrap_or(WhisperTuning::DEFAULT_NO_SPEECH_THRESHOLD),
+    );
+    if let Some(threads) = tuning.threads {
+        params.set_n_threads(threads as i32);
+    }
+
+    params
+}
+
+/// Internal function to transcribe PCM samples using the cached model
 fn transcribe_samples(
     model_path: &str,
     samples: &[f32],
     language: Option<&str>,
+    tuning: &WhisperTuning,
 ) -> Result<TranscriptionResult> {
-    use transcribe_rs::TranscriptionEngine;
-    use transcribe_rs::engines::whisper::WhisperInferenceParams;
-
     // Get or load engine
     get_or_load_engine(model_path)?;
 
     // Perform transcription with locked access to engine
-    let text = {
+    let (text, detected_language) = {
         let mut cache = get_cache().lock().unwrap();
         let cached = cache
             .as_mut()
             .ok_or_else(|| anyhow::anyhow!("Engine not loaded"))?;
-
-        // Configure inference parameters
-        let params = WhisperInferenceParams {
-            language: language.map(|s| s.to_string()),
-            translate: false,
-            print_special: false,
-            print_progress: false,
-            print_realtime: false,
-            print_timestamps: false,
-            suppress_blank: true,
-            suppress_non_speech_tokens: true,
-            no_speech_thold: 0.2,
-            initial_prompt: None,
-        };
+        let state = &mut cached.state;
 
         // Suppress stderr during transcription to hide whisper.cpp noise
         let _stderr_guard = stderr_suppression::suppress();
 
-        // Transcribe (transcribe-rs requires Vec<f32>, not &[f32])
-        let result = cached
-            .engine
-            .transcribe_samples(samples.to_vec(), Some(params))
+        state
+            .full(full_params(language, tuning), samples)
             .map_err(|e| anyhow::anyhow!("Transcription failed: {}", e))?;
 
         drop(_stderr_guard);
 
-        result.text
+        let segments = state
+            .full_n_segments()
+            .map_err(|e| anyhow::anyhow!("Transcription failed: {}", e))?;
+        let mut text = String::new();
+        for i in 0..segments {
+            let segment = state
+                .full_get_segment_text(i)
+                .map_err(|e| anyhow::anyhow!("Transcription failed: {}", e))?;
+            text.push_str(&segment);
+        }
+
+        // Only report the language when whisper.cpp chose it
+        let detected_language = match language {
+            Some(_) => None,
+            None => state
+                .full_lang_id_from_state()
+                .ok()
+                .and_then(whisper_rs::get_lang_str)
+                .map(str::to_string),
+        };
+
+        (text, detected_language)
     };
 
+    if let Some(ref lang) = detected_language {
+        crate::verbose!("Detected language: {}", lang);
+    }
+
     // Conditionally unload based on KEEP_LOADED flag
     maybe_unload();
 
     Ok(TranscriptionResult {
         text: text.trim().to_string(),
+        language: detected_language,
+        words: Vec::new(),
     })
 }
 
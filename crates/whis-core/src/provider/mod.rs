@@ -10,7 +10,9 @@
 //!   ├── Registry     - Provider lookup and lifecycle
 //!   ├── Base         - Shared HTTP logic (OpenAI-compatible APIs)
 //!   └── Providers    - Individual provider implementations
-//!       ├── Cloud    - OpenAI, Mistral, Groq, Deepgram, ElevenLabs
+//!       ├── Cloud    - OpenAI, Mistral, Groq, Deepgram, ElevenLabs, AssemblyAI,
+//!       │              Speechmatics, Gladia
+//!       ├── Network  - Wyoming (any Wyoming ASR server over TCP)
 //!       └── Local    - Whisper, Parakeet
 //! ```
 //!
@@ -24,6 +26,12 @@
 //! **Cloud Providers** (Custom format):
 //! - Deepgram Nova API
 //! - ElevenLabs API
+//! - AssemblyAI API (upload + poll)
+//! - Speechmatics jobs API (EU/US region)
+//! - Gladia pre-recorded API
+//!
+//! **Network Providers** (server address instead of API key):
+//! - Wyoming protocol (TCP, raw PCM)
 //!
 //! **Local Providers** (No API key required):
 //! - Local Whisper (via transcribe-rs)
@@ -39,7 +47,7 @@
 //!
 //! For OpenAI-compatible APIs, use the shared helpers from `base::openai_compatible`.
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::sync::{Arc, OnceLock};
@@ -78,15 +86,23 @@ impl TranscriptionStage {
 /// Progress callback type for reporting transcription stages
 pub type ProgressCallback = Arc<dyn Fn(TranscriptionStage) + Send + Sync>;
 
+mod assemblyai;
+#[cfg(feature = "realtime")]
+mod assemblyai_realtime;
 mod base;
 mod deepgram;
 #[cfg(feature = "realtime")]
 mod deepgram_realtime;
 mod elevenlabs;
 pub mod error;
+mod gladia;
+#[cfg(feature = "realtime")]
+mod gladia_realtime;
 mod groq;
 #[cfg(feature = "local-transcription")]
 mod local_parakeet;
+#[cfg(all(feature = "realtime", feature = "local-transcription"))]
+mod local_realtime;
 #[cfg(feature = "local-transcription")]
 pub mod local_whisper;
 mod mistral;
@@ -95,24 +111,46 @@ mod openai;
 mod openai_realtime;
 #[cfg(feature = "realtime")]
 mod realtime;
+#[cfg(feature = "realtime")]
+mod realtime_resume;
+mod speechmatics;
+#[cfg(feature = "realtime")]
+mod speechmatics_realtime;
+#[cfg(test)]
+mod test_http;
+#[cfg(test)]
+pub(crate) mod test_wyoming;
+mod wyoming;
+#[cfg(feature = "realtime")]
+mod wyoming_realtime;
 
 /// Default timeout for API requests (5 minutes)
 pub const DEFAULT_TIMEOUT_SECS: u64 = 300;
 
+pub use assemblyai::AssemblyAIProvider;
+#[cfg(feature = "realtime")]
+pub use assemblyai_realtime::AssemblyAIRealtimeProvider;
 pub use deepgram::DeepgramProvider;
 #[cfg(feature = "realtime")]
 pub use deepgram_realtime::DeepgramRealtimeProvider;
 pub use elevenlabs::ElevenLabsProvider;
 pub use error::ProviderError;
+pub use gladia::GladiaProvider;
+#[cfg(feature = "realtime")]
+pub use gladia_realtime::GladiaRealtimeProvider;
 pub use groq::GroqProvider;
 #[cfg(feature = "local-transcription")]
 pub use local_parakeet::LocalParakeetProvider;
 #[cfg(feature = "local-transcription")]
 pub use local_parakeet::preload_parakeet;
 #[cfg(feature = "local-transcription")]
+pub use local_parakeet::transcribe_chunked as transcribe_chunked_parakeet;
+#[cfg(feature = "local-transcription")]
 pub use local_parakeet::transcribe_raw as transcribe_raw_parakeet;
 #[cfg(feature = "local-transcription")]
 pub use local_parakeet::{set_keep_loaded as parakeet_set_keep_loaded, unload_parakeet};
+#[cfg(all(feature = "realtime", feature = "local-transcription"))]
+pub use local_realtime::{LocalRealtimeProvider, LocalStreamConfig};
 #[cfg(feature = "local-transcription")]
 pub use local_whisper::LocalWhisperProvider;
 #[cfg(feature = "local-transcription")]
@@ -127,9 +165,17 @@ pub use openai::OpenAIProvider;
 #[cfg(feature = "realtime")]
 pub use openai_realtime::OpenAIRealtimeProvider;
 #[cfg(feature = "realtime")]
-pub use realtime::RealtimeTranscriptionBackend;
+pub use realtime::{PartialTranscriptSender, RealtimeTranscriptionBackend};
+pub use speechmatics::SpeechmaticsProvider;
+#[cfg(feature = "realtime")]
+pub use speechmatics_realtime::SpeechmaticsRealtimeProvider;
+pub use wyoming::WyomingProvider;
+pub(crate) use wyoming::describe as wyoming_describe;
+#[cfg(feature = "realtime")]
+pub use wyoming_realtime::WyomingRealtimeProvider;
 
 use crate::config::TranscriptionProvider;
+use crate::settings::{AssemblyAIConfig, RegionConfig, WhisperTuning};
 
 /// Request data for transcription
 #[derive(Clone)]
@@ -140,6 +186,23 @@ pub struct TranscriptionRequest {
     pub mime_type: String,
     /// Optional progress callback for status updates
     pub progress: Option<ProgressCallback>,
+    /// Provider options from the caller's settings
+    pub options: ProviderOptions,
+}
+
+/// Provider options that travel with a request
+///
+/// Front ends fill these in from their own settings (see
+/// `TranscriptionSettings::provider_options`); providers never load settings
+/// themselves.
+#[derive(Debug, Clone, Default)]
+pub struct ProviderOptions {
+    /// Inference tuning for local Whisper
+    pub whisper: WhisperTuning,
+    /// Speaker labels and keyterms for AssemblyAI (batch and realtime)
+    pub assemblyai: AssemblyAIConfig,
+    /// Data regions for Speechmatics and Gladia
+    pub regions: RegionConfig,
 }
 
 impl TranscriptionRequest {
@@ -151,15 +214,56 @@ impl TranscriptionRequest {
             filename: "audio.mp3".to_string(),
             mime_type: "audio/mpeg".to_string(),
             progress: None,
+            options: ProviderOptions::default(),
         }
     }
 
+    /// Encode 16kHz mono samples for a provider: MP3 for cloud APIs,
+    /// WAV for providers that take raw PCM (see `requires_pcm`)
+    ///
+    /// `stem` is the filename without extension.
+    pub fn from_samples(
+        provider: &TranscriptionProvider,
+        samples: &[f32],
+        language: Option<String>,
+        stem: &str,
+    ) -> Result<Self> {
+        let sample_rate = crate::resample::WHISPER_SAMPLE_RATE;
+        let (audio_data, extension, mime_type) = if provider.requires_pcm() {
+            (
+                crate::audio::encode_wav(samples, sample_rate),
+                "wav",
+                "audio/wav",
+            )
+        } else {
+            let mp3 = crate::audio::create_encoder()
+                .encode_samples(samples, sample_rate)
+                .context("Failed to encode audio to MP3")?;
+            (mp3, "mp3", "audio/mpeg")
+        };
+
+        Ok(Self {
+            audio_data,
+            language,
+            filename: format!("{stem}.{extension}"),
+            mime_type: mime_type.to_string(),
+            progress: None,
+            options: ProviderOptions::default(),
+        })
+    }
+
     /// Set the progress callback
     pub fn with_progress(mut self, callback: ProgressCallback) -> Self {
         self.progress = Some(callback);
         self
     }
 
+    /// Set the provider options
+    pub fn with_options(mut self, options: ProviderOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// Report progress if callback is set
     pub fn report(&self, stage: TranscriptionStage) {
         if let Some(cb) = &self.progress {
@@ -169,8 +273,25 @@ impl TranscriptionRequest {
 }
 
 /// Result of a transcription
+#[derive(Debug)]
 pub struct TranscriptionResult {
     pub text: String,
+    /// Language detected by the provider (ISO-639-1), when it reports one
+    pub language: Option<String>,
+    /// Word-level timings, when the provider reports them (empty otherwise)
+    pub words: Vec<WordTimestamp>,
+}
+
+/// A transcribed word with its position in the audio
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordTimestamp {
+    pub text: String,
+    /// Start offset in milliseconds
+    pub start_ms: u64,
+    /// End offset in milliseconds
+    pub end_ms: u64,
+    /// Speaker label (e.g. "A"), when speaker diarization is enabled
+    pub speaker: Option<String>,
 }
 
 // Import shared helpers from base module
@@ -222,6 +343,27 @@ impl ProviderRegistry {
         #[cfg(feature = "realtime")]
         providers.insert("deepgram-realtime", Arc::new(DeepgramRealtimeProvider));
         providers.insert("elevenlabs", Arc::new(ElevenLabsProvider));
+        providers.insert("assemblyai", Arc::new(AssemblyAIProvider));
+        #[cfg(feature = "realtime")]
+        providers.insert(
+            "assemblyai-realtime",
+            Arc::new(AssemblyAIRealtimeProvider::default()),
+        );
+        providers.insert("speechmatics", Arc::new(SpeechmaticsProvider));
+        #[cfg(feature = "realtime")]
+        providers.insert(
+            "speechmatics-realtime",
+            Arc::new(SpeechmaticsRealtimeProvider::default()),
+        );
+        providers.insert("gladia", Arc::new(GladiaProvider));
+        #[cfg(feature = "realtime")]
+        providers.insert(
+            "gladia-realtime",
+            Arc::new(GladiaRealtimeProvider::default()),
+        );
+        providers.insert("wyoming", Arc::new(WyomingProvider));
+        #[cfg(feature = "realtime")]
+        providers.insert("wyoming-realtime", Arc::new(WyomingRealtimeProvider));
         #[cfg(feature = "local-transcription")]
         providers.insert("local-whisper", Arc::new(LocalWhisperProvider));
         #[cfg(feature = "local-transcription")]
@@ -272,25 +414,34 @@ pub fn registry() -> &'static ProviderRegistry {
 
 /// Check if a provider supports realtime WebSocket streaming
 ///
-/// Returns true for providers that implement RealtimeTranscriptionBackend
-/// and should bypass the chunking pipeline for lower latency.
+/// Returns true for cloud and network providers that implement RealtimeTranscriptionBackend
+/// and should bypass the chunking pipeline for lower latency. Local providers
+/// stream depending on settings; see `TranscriptionSettings::uses_realtime`.
 pub fn is_realtime_provider(provider: &TranscriptionProvider) -> bool {
     matches!(
         provider,
-        TranscriptionProvider::OpenAIRealtime | TranscriptionProvider::DeepgramRealtime
+        TranscriptionProvider::OpenAIRealtime
+            | TranscriptionProvider::DeepgramRealtime
+            | TranscriptionProvider::AssemblyAIRealtime
+            | TranscriptionProvider::SpeechmaticsRealtime
+            | TranscriptionProvider::GladiaRealtime
+            | TranscriptionProvider::WyomingRealtime
     )
 }
 
 /// Get the realtime backend for a provider
 ///
 /// Returns a trait object implementing RealtimeTranscriptionBackend for
-/// providers that support WebSocket streaming.
+/// providers that support WebSocket streaming. `options` configures the
+/// providers that take settings (and their batch fallback). Local providers
+/// stream through [`LocalRealtimeProvider::transcribe_local`] instead.
 ///
 /// # Errors
 /// Returns an error if the provider does not support realtime streaming.
 #[cfg(feature = "realtime")]
 pub fn get_realtime_backend(
     provider: &TranscriptionProvider,
+    options: &ProviderOptions,
 ) -> anyhow::Result<std::sync::Arc<dyn RealtimeTranscriptionBackend>> {
     match provider {
         TranscriptionProvider::OpenAIRealtime => Ok(std::sync::Arc::new(OpenAIRealtimeProvider)
@@ -299,9 +450,25 @@ pub fn get_realtime_backend(
             Ok(std::sync::Arc::new(DeepgramRealtimeProvider)
                 as std::sync::Arc<dyn RealtimeTranscriptionBackend>)
         }
+        TranscriptionProvider::AssemblyAIRealtime => Ok(std::sync::Arc::new(
+            AssemblyAIRealtimeProvider::new(options.clone()),
+        )
+            as std::sync::Arc<dyn RealtimeTranscriptionBackend>),
+        TranscriptionProvider::SpeechmaticsRealtime => Ok(std::sync::Arc::new(
+            SpeechmaticsRealtimeProvider::new(options.clone()),
+        )
+            as std::sync::Arc<dyn RealtimeTranscriptionBackend>),
+        TranscriptionProvider::GladiaRealtime => Ok(std::sync::Arc::new(
+            GladiaRealtimeProvider::new(options.clone()),
+        )
+            as std::sync::Arc<dyn RealtimeTranscriptionBackend>),
+        TranscriptionProvider::WyomingRealtime => Ok(std::sync::Arc::new(WyomingRealtimeProvider)
+            as std::sync::Arc<dyn RealtimeTranscriptionBackend>),
         _ => Err(anyhow::anyhow!(
             "Provider '{}' does not support realtime streaming. \
-             Available realtime providers: openai-realtime, deepgram-realtime",
+             Available realtime providers: openai-realtime, deepgram-realtime, \
+             assemblyai-realtime, speechmatics-realtime, \
+             gladia-realtime, wyoming-realtime",
             provider.as_str()
         )),
     }
@@ -21,12 +21,15 @@ use tokio_tungstenite::{
     },
 };
 
+use super::realtime_resume::{ResumableSession, SessionProgress, transcribe_resumable};
 use super::{
-    OpenAIProvider, RealtimeTranscriptionBackend, TranscriptionBackend, TranscriptionRequest,
-    TranscriptionResult,
+    OpenAIProvider, PartialTranscriptSender, RealtimeTranscriptionBackend, TranscriptionBackend,
+    TranscriptionRequest, TranscriptionResult,
 };
+use crate::config::TranscriptionProvider;
 
 const WS_URL: &str = "wss://api.openai.com/v1/realtime?intent=transcription";
+const DELTA_EVENT: &str = "conversation.item.input_audio_transcription.delta";
 const REALTIME_SAMPLE_RATE: u32 = 24000;
 
 /// OpenAI Realtime transcription provider
@@ -106,6 +109,9 @@ struct RealtimeEvent {
     event_type: String,
     #[serde(default)]
     transcript: Option<String>,
+    /// Incremental text for `...input_audio_transcription.delta` events
+    #[serde(default)]
+    delta: Option<String>,
     #[serde(default)]
     error: Option<RealtimeError>,
 }
@@ -118,80 +124,45 @@ struct RealtimeError {
     code: Option<String>,
 }
 
-/// Retry configuration for WebSocket connections
-const MAX_RETRIES: u32 = 3;
-const BASE_BACKOFF_SECS: u64 = 1;
-
 impl OpenAIRealtimeProvider {
     /// Transcribe audio from a channel of f32 samples (16kHz mono)
     ///
-    /// Connects to OpenAI Realtime API via WebSocket and streams audio chunks
-    /// as they arrive. Returns the final transcript when the channel closes.
+    /// Streams over a WebSocket session, reconnecting and replaying the
+    /// unacknowledged audio if the connection drops (see `realtime_resume`).
     async fn transcribe_stream_impl(
         api_key: &str,
-        mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
     ) -> Result<String> {
-        // 1. Connect to WebSocket with retry logic
-        let ws_stream = {
-            let mut last_error = None;
-
-            let result = 'retry: {
-                for attempt in 0..MAX_RETRIES {
-                    // Build request fresh each attempt
-                    let mut request = match WS_URL.into_client_request() {
-                        Ok(r) => r,
-                        Err(e) => {
-                            last_error = Some(format!("Failed to build request: {}", e));
-                            continue;
-                        }
-                    };
-
-                    match HeaderValue::from_str(&format!("Bearer {api_key}")) {
-                        Ok(header_value) => {
-                            request.headers_mut().insert(AUTHORIZATION, header_value);
-                        }
-                        Err(e) => {
-                            last_error = Some(format!("Failed to set auth header: {}", e));
-                            continue;
-                        }
-                    }
-
-                    // Attempt connection with timeout
-                    match timeout(Duration::from_secs(30), connect_async(request)).await {
-                        Ok(Ok((stream, _response))) => break 'retry Ok(stream),
-                        Ok(Err(e)) => {
-                            last_error = Some(format!("Connection failed: {}", e));
-                        }
-                        Err(_) => {
-                            last_error = Some("Connection timeout".to_string());
-                        }
-                    }
-
-                    // Retry with exponential backoff (1s, 2s, 4s)
-                    if attempt < MAX_RETRIES - 1 {
-                        let backoff = BASE_BACKOFF_SECS * (1 << attempt);
-                        if crate::verbose::is_verbose() {
-                            eprintln!(
-                                "[openai-realtime] Attempt {} failed: {}. Retrying in {}s...",
-                                attempt + 1,
-                                last_error.as_deref().unwrap_or("unknown"),
-                                backoff
-                            );
-                        }
-                        tokio::time::sleep(Duration::from_secs(backoff)).await;
-                    }
-                }
+        transcribe_resumable(&Self, api_key, audio_rx, language, partial_tx).await
+    }
 
-                Err(anyhow!(
-                    "Failed to connect after {} attempts: {}",
-                    MAX_RETRIES,
-                    last_error.unwrap_or_else(|| "unknown error".to_string())
-                ))
-            };
+    /// Stream audio over a single WebSocket connection
+    ///
+    /// Connects to the OpenAI Realtime API and streams audio chunks as they arrive.
+    /// Returns the final transcript when the channel closes.
+    async fn stream_session(
+        api_key: &str,
+        mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+        _progress: SessionProgress,
+    ) -> Result<String> {
+        // 1. Connect to WebSocket (reconnects are handled by `transcribe_resumable`)
+        let mut request = WS_URL
+            .into_client_request()
+            .context("Failed to build OpenAI request")?;
+        request.headers_mut().insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {api_key}"))
+                .context("Invalid OpenAI API key")?,
+        );
 
-            result?
-        };
+        let (ws_stream, _response) = timeout(Duration::from_secs(30), connect_async(request))
+            .await
+            .map_err(|_| anyhow!("Connection timeout"))?
+            .context("Failed to connect to OpenAI")?;
 
         let (mut write, mut read) = ws_stream.split();
 
@@ -271,7 +242,9 @@ impl OpenAIRealtimeProvider {
         let (done_tx, done_rx) = oneshot::channel::<usize>(); // Now sends total_samples
 
         let read_handle =
-            tokio::spawn(async move { collect_transcripts(read, error_tx, done_rx).await });
+            tokio::spawn(
+                async move { collect_transcripts(read, error_tx, done_rx, partial_tx).await },
+            );
 
         // 5. (No keepalive needed for OpenAI)
 
@@ -371,8 +344,9 @@ impl OpenAIRealtimeProvider {
         api_key: &str,
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
     ) -> Result<String> {
-        Self::transcribe_stream_impl(api_key, audio_rx, language).await
+        Self::transcribe_stream_impl(api_key, audio_rx, language, partial_tx).await
     }
 }
 
@@ -387,16 +361,21 @@ impl OpenAIRealtimeProvider {
 ///
 /// The done_rx channel receives total_samples to calculate a dynamic timeout for Phase 2.
 /// Longer recordings need more processing time after Finalize.
+///
+/// `delta` events are accumulated and forwarded to `partial_tx` in both phases.
 async fn collect_transcripts<S>(
     mut read: S,
     error_tx: oneshot::Sender<anyhow::Error>,
     mut done_rx: oneshot::Receiver<usize>,
+    partial_tx: Option<PartialTranscriptSender>,
 ) -> Result<String>
 where
     S: futures_util::Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
 {
     // Store transcript if received early (during Phase 1)
     let mut early_transcript: Option<String> = None;
+    // Interim text built from delta events
+    let mut partial = String::new();
 
     // Phase 1: Monitor for errors AND collect transcripts during audio streaming
     // total_samples will be received when streaming completes
@@ -440,6 +419,10 @@ where
                             return Err(err);
                         }
 
+                        if event.event_type == DELTA_EVENT {
+                            forward_delta(&mut partial, event.delta.as_deref(), partial_tx.as_ref());
+                        }
+
                         // Capture transcript if it arrives early (short recordings)
                         if event.event_type == "conversation.item.input_audio_transcription.completed"
                             && let Some(transcript) = event.transcript
@@ -528,6 +511,9 @@ where
                                     return Err(anyhow!("OpenAI Realtime error: {}", err.message));
                                 }
                             }
+                            DELTA_EVENT => {
+                                forward_delta(&mut partial, event.delta.as_deref(), partial_tx.as_ref());
+                            }
                             "conversation.item.input_audio_transcription.completed" => {
                                 if let Some(transcript) = event.transcript {
                                     return Ok(transcript);
@@ -538,17 +524,14 @@ where
                         }
                     }
                     Some(Ok(Message::Close(_))) => {
-                        // Graceful degradation: connection closed, return empty string
-                        eprintln!("[openai-realtime] WARNING: WebSocket closed before receiving transcription");
-                        return Ok(String::new());
+                        // Error (not empty text) so the session is resumed on a new connection
+                        return Err(anyhow!("WebSocket closed before receiving transcription"));
                     }
                     Some(Err(e)) => {
                         return Err(anyhow!("WebSocket error: {e}"));
                     }
                     None => {
-                        // Graceful degradation: connection ended, return empty string
-                        eprintln!("[openai-realtime] WARNING: Connection ended before receiving transcription");
-                        return Ok(String::new());
+                        return Err(anyhow!("Connection ended before receiving transcription"));
                     }
                     _ => {} // Ignore Ping, Pong, Binary
                 }
@@ -557,6 +540,22 @@ where
     }
 }
 
+/// Append a transcription delta and forward the accumulated text as a partial
+fn forward_delta(
+    partial: &mut String,
+    delta: Option<&str>,
+    partial_tx: Option<&PartialTranscriptSender>,
+) {
+    if let Some(delta) = delta
+        && !delta.is_empty()
+    {
+        partial.push_str(delta);
+        if let Some(tx) = partial_tx {
+            let _ = tx.send(partial.trim().to_string());
+        }
+    }
+}
+
 /// Simple linear interpolation to resample from 16kHz to 24kHz
 ///
 /// For each output sample, we interpolate between two input samples.
@@ -583,6 +582,26 @@ fn resample_16k_to_24k(samples: &[f32]) -> Vec<f32> {
     output
 }
 
+#[async_trait]
+impl ResumableSession for OpenAIRealtimeProvider {
+    fn batch_provider(&self) -> TranscriptionProvider {
+        TranscriptionProvider::OpenAIRealtime
+    }
+
+    /// OpenAI only finalizes on commit, so nothing is acknowledged mid-session
+    /// and a reconnect replays all of the session's audio.
+    async fn run_session(
+        &self,
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+        progress: SessionProgress,
+    ) -> Result<String> {
+        Self::stream_session(api_key, audio_rx, language, partial_tx, progress).await
+    }
+}
+
 #[async_trait]
 impl RealtimeTranscriptionBackend for OpenAIRealtimeProvider {
     async fn transcribe_stream(
@@ -590,8 +609,9 @@ impl RealtimeTranscriptionBackend for OpenAIRealtimeProvider {
         api_key: &str,
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
     ) -> Result<String> {
-        Self::transcribe_stream_impl(api_key, audio_rx, language).await
+        Self::transcribe_stream_impl(api_key, audio_rx, language, partial_tx).await
     }
 
     fn sample_rate(&self) -> u32 {
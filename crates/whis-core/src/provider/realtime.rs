@@ -21,6 +21,11 @@
 //! 10. Close connection gracefully
 //! ```
 //!
+//! Cloud providers run these steps per connection and wrap them with
+//! `realtime_resume::transcribe_resumable`, which reconnects with backoff when a
+//! session fails, replays the audio not yet covered by finalized text, and falls
+//! back to the provider's batch `TranscriptionBackend` if reconnecting keeps failing.
+//!
 //! # Read Task Pattern (collect_transcripts)
 //!
 //! The read task should have two phases:
@@ -33,20 +38,33 @@
 //!
 //! # Provider Differences
 //!
-//! | Aspect | OpenAI Realtime | Deepgram Realtime |
-//! |--------|-----------------|-------------------|
-//! | Sample Rate | 24kHz (resampling needed) | 16kHz (native) |
-//! | Audio Format | Base64-encoded PCM16 | Raw binary PCM16 |
-//! | Auth Header | `Authorization: Bearer {key}` | `Authorization: Token {key}` |
-//! | Session Config | `session.update` message | Query parameters |
-//! | Finalize | `commit` + `response.create` | `{"type":"Finalize"}` |
-//! | KeepAlive | Not needed | Required (every 5s) |
-//! | Final Event | `conversation.item.input_audio_transcription.completed` | `Results` with `from_finalize=true` |
+//! | Aspect | OpenAI Realtime | Deepgram Realtime | AssemblyAI Realtime | Speechmatics Realtime | Gladia Realtime | Wyoming |
+//! |--------|-----------------|-------------------|---------------------|-----------------------|-----------------|---------|
+//! | Sample Rate | 24kHz (resampling needed) | 16kHz (native) | 16kHz (native) | 16kHz (native) | 16kHz (native) | 16kHz (native) |
+//! | Audio Format | Base64-encoded PCM16 | Raw binary PCM16 | Raw binary PCM16 | Raw binary PCM16 | Raw binary PCM16 | `audio-chunk` events over TCP |
+//! | Auth Header | `Authorization: Bearer {key}` | `Authorization: Token {key}` | `Authorization: {key}` | `Authorization: Bearer {key}` | `x-gladia-key` on the HTTP init only | None (LAN server address) |
+//! | Session Config | `session.update` message | Query parameters | Query parameters | `StartRecognition` message | HTTP `POST /v2/live` | `transcribe` + `audio-start` events |
+//! | Finalize | `commit` + `response.create` | `{"type":"Finalize"}` | `{"type":"Terminate"}` | `EndOfStream` with `last_seq_no` | `{"type":"stop_recording"}` | `audio-stop` |
+//! | KeepAlive | Not needed | Required (every 5s) | Not needed | Not needed | Not needed | Not needed |
+//! | Final Event | `conversation.item.input_audio_transcription.completed` | `Results` with `from_finalize=true` | `Turn` with `turn_is_formatted=true` | `AddTranscript` | `transcript` with `is_final=true` | `transcript` |
+//! | Partial Event | `conversation.item.input_audio_transcription.delta` | `Results` with `is_final=false` | `Turn` with `end_of_turn=false` | `AddPartialTranscript` | `transcript` with `is_final=false` | `transcript-chunk` (streaming servers) |
+//!
+//! # Partial Transcripts
+//!
+//! Callers that want live feedback pass a [`PartialTranscriptSender`]. Each
+//! message is the whole transcript so far (finalized text followed by the
+//! current interim hypothesis), so receivers just replace what they display.
+//! Partials are best-effort: the returned transcript is always authoritative.
 
 use anyhow::Result;
 use async_trait::async_trait;
 use tokio::sync::mpsc;
 
+/// Channel receiving interim transcripts while audio is still streaming.
+///
+/// Each message is the full transcript so far, replacing the previous one.
+pub type PartialTranscriptSender = mpsc::UnboundedSender<String>;
+
 /// Trait for realtime (WebSocket-based) transcription providers.
 ///
 /// Realtime providers stream audio during recording rather than buffering
@@ -68,6 +86,7 @@ pub trait RealtimeTranscriptionBackend: Send + Sync {
     /// * `audio_rx` - Unbounded channel receiving audio chunks as f32 samples at 16kHz.
     ///   Unbounded channels are used to avoid dropping audio when the network is slow.
     /// * `language` - Optional language code (e.g., "en", "es")
+    /// * `partial_tx` - Optional channel for interim transcripts (see module docs)
     ///
     /// # Returns
     ///
@@ -84,6 +103,7 @@ pub trait RealtimeTranscriptionBackend: Send + Sync {
         api_key: &str,
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
     ) -> Result<String>;
 
     /// Required sample rate for this provider's WebSocket API.
@@ -0,0 +1,400 @@
+//! Reconnect/resume for realtime WebSocket providers
+//!
+//! Wraps a provider's single-connection session so that a dropped socket
+//! (Wi-Fi blip, server close) does not lose the recording:
+//!
+//! ```text
+//! audio_rx → capture (unacknowledged samples) → current session's audio channel
+//!                                         ↓ session error
+//!            finalized text kept, unacknowledged tail replayed on a new session
+//!                                         ↓ reconnects exhausted
+//!            batch transcription of the tail via the provider's TranscriptionBackend
+//! ```
+//!
+//! Sessions report finalized text together with the audio position it covers
+//! through [`SessionProgress`]. Providers without per-segment acknowledgements
+//! (OpenAI) never commit, so the whole session's audio is replayed.
+
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+use tokio::time::Duration;
+
+use super::{PartialTranscriptSender, ProviderOptions};
+use crate::audio::{ChunkerConfig, ProgressiveChunker};
+use crate::config::TranscriptionProvider;
+use crate::resample::WHISPER_SAMPLE_RATE;
+use crate::transcription::progressive_transcribe_cloud;
+
+/// Consecutive failed sessions before falling back to batch transcription
+const MAX_RECONNECTS: u32 = 3;
+const BASE_BACKOFF_SECS: u64 = 1;
+
+/// Finalized text of the current session and the audio it covers
+#[derive(Clone, Default)]
+pub(crate) struct SessionProgress(Arc<Mutex<Acknowledged>>);
+
+#[derive(Default)]
+struct Acknowledged {
+    text: String,
+    samples: usize,
+}
+
+impl SessionProgress {
+    /// Record finalized text covering audio up to `end_sample`
+    /// (16kHz samples counted from the start of the session)
+    pub(crate) fn commit(&self, text: &str, end_sample: usize) {
+        let mut ack = self.0.lock().unwrap();
+        if !text.is_empty() {
+            if !ack.text.is_empty() {
+                ack.text.push(' ');
+            }
+            ack.text.push_str(text.trim());
+        }
+        ack.samples = ack.samples.max(end_sample);
+    }
+
+    fn take(&self) -> Acknowledged {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+/// A single realtime connection that can be restarted from a replayed tail
+#[async_trait]
+pub(crate) trait ResumableSession: Send + Sync {
+    /// Provider whose `TranscriptionBackend` handles the batch fallback
+    fn batch_provider(&self) -> TranscriptionProvider;
+
+    /// Options for the batch fallback requests
+    fn batch_options(&self) -> ProviderOptions {
+        ProviderOptions::default()
+    }
+
+    /// Stream `audio_rx` over one connection and return its transcript,
+    /// committing finalized segments to `progress` as they arrive
+    async fn run_session(
+        &self,
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+        progress: SessionProgress,
+    ) -> Result<String>;
+}
+
+/// Unacknowledged audio plus the sender feeding the active session
+#[derive(Default)]
+struct Capture {
+    samples: Vec<f32>,
+    session_tx: Option<mpsc::UnboundedSender<Vec<f32>>>,
+    closed: bool,
+}
+
+/// Run realtime sessions until one completes, reconnecting after failures
+pub(crate) async fn transcribe_resumable<S: ResumableSession>(
+    session: &S,
+    api_key: &str,
+    mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+    language: Option<String>,
+    partial_tx: Option<PartialTranscriptSender>,
+) -> Result<String> {
+    let capture = Arc::new(Mutex::new(Capture::default()));
+
+    // Keep samples for replay/fallback and feed the active session
+    let forwarder = tokio::spawn({
+        let capture = Arc::clone(&capture);
+        async move {
+            while let Some(samples) = audio_rx.recv().await {
+                let mut capture = capture.lock().unwrap();
+                capture.samples.extend_from_slice(&samples);
+                if let Some(tx) = &capture.session_tx
+                    && tx.send(samples).is_err()
+                {
+                    capture.session_tx = None;
+                }
+            }
+            let mut capture = capture.lock().unwrap();
+            capture.closed = true;
+            capture.session_tx = None;
+        }
+    });
+
+    let mut segments: Vec<String> = Vec::new();
+    let mut failures = 0;
+
+    loop {
+        // Replay the unacknowledged tail, then continue with live audio
+        let (session_tx, session_rx) = mpsc::unbounded_channel();
+        {
+            let mut capture = capture.lock().unwrap();
+            for block in capture.samples.chunks(WHISPER_SAMPLE_RATE as usize) {
+                let _ = session_tx.send(block.to_vec());
+            }
+            // A finished recording ends the session once the replay is read
+            capture.session_tx = (!capture.closed).then_some(session_tx);
+        }
+
+        let progress = SessionProgress::default();
+        let session_partials = partial_tx
+            .as_ref()
+            .map(|tx| prefix_partials(tx.clone(), segments.join(" ")));
+
+        let result = session
+            .run_session(
+                api_key,
+                session_rx,
+                language.clone(),
+                session_partials,
+                progress.clone(),
+            )
+            .await;
+
+        let error = match result {
+            Ok(text) => {
+                segments.push(text);
+                break;
+            }
+            Err(e) => e,
+        };
+
+        // Keep what the failed session finalized and drop the audio it covers
+        let acknowledged = progress.take();
+        {
+            let mut capture = capture.lock().unwrap();
+            capture.session_tx = None;
+            let covered = acknowledged.samples.min(capture.samples.len());
+            capture.samples.drain(..covered);
+        }
+        if !acknowledged.text.is_empty() {
+            segments.push(acknowledged.text);
+        }
+        if acknowledged.samples > 0 {
+            failures = 0;
+        }
+        failures += 1;
+
+        if failures >= MAX_RECONNECTS {
+            crate::warn!(
+                "Realtime connection failed {} times ({}), falling back to batch transcription",
+                failures,
+                error
+            );
+
+            // Let the recording finish before transcribing what is left
+            forwarder
+                .await
+                .map_err(|e| anyhow!("Audio capture task panicked: {e}"))?;
+            let tail = std::mem::take(&mut capture.lock().unwrap().samples);
+            let text = transcribe_batch(
+                &session.batch_provider(),
+                &session.batch_options(),
+                api_key,
+                language.as_deref(),
+                &tail,
+            )
+            .await?;
+            segments.push(text);
+            break;
+        }
+
+        let backoff = BASE_BACKOFF_SECS * (1 << (failures - 1));
+        crate::warn!(
+            "Realtime connection lost ({}), reconnecting in {}s...",
+            error,
+            backoff
+        );
+        tokio::time::sleep(Duration::from_secs(backoff)).await;
+    }
+
+    Ok(segments
+        .iter()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" "))
+}
+
+/// Forward a session's partials with the text of earlier sessions prepended
+fn prefix_partials(partial_tx: PartialTranscriptSender, prefix: String) -> PartialTranscriptSender {
+    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    tokio::spawn(async move {
+        while let Some(partial) = rx.recv().await {
+            let text = format!("{prefix} {partial}").trim().to_string();
+            if partial_tx.send(text).is_err() {
+                break;
+            }
+        }
+    });
+    tx
+}
+
+/// Transcribe captured audio through the provider's batch API
+async fn transcribe_batch(
+    provider: &TranscriptionProvider,
+    options: &ProviderOptions,
+    api_key: &str,
+    language: Option<&str>,
+    samples: &[f32],
+) -> Result<String> {
+    let (chunk_tx, chunk_rx) = mpsc::unbounded_channel();
+    let config = ChunkerConfig {
+        vad_aware: false,
+        ..ChunkerConfig::default()
+    };
+    ProgressiveChunker::new(config, chunk_tx)
+        .consume_samples(samples)
+        .map_err(|e| anyhow!(e))?;
+
+    progressive_transcribe_cloud(provider, options, api_key, language, chunk_rx, None).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::test_wyoming::WyomingServer;
+    use serde_json::json;
+    use std::collections::VecDeque;
+
+    /// How a scripted session ends
+    enum Outcome {
+        /// Commit `text` for the first `samples` samples received, then fail
+        Fail { samples: usize, text: &'static str },
+        /// Read until the recording ends and return `text`
+        Complete(&'static str),
+    }
+
+    /// Session stand-in recording the audio each connection received
+    struct FakeSession {
+        script: Mutex<VecDeque<Outcome>>,
+        received: Mutex<Vec<Vec<f32>>>,
+    }
+
+    impl FakeSession {
+        fn new(script: Vec<Outcome>) -> Self {
+            Self {
+                script: Mutex::new(script.into()),
+                received: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn received(&self) -> Vec<Vec<f32>> {
+            self.received.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl ResumableSession for FakeSession {
+        fn batch_provider(&self) -> TranscriptionProvider {
+            TranscriptionProvider::Wyoming
+        }
+
+        async fn run_session(
+            &self,
+            _api_key: &str,
+            mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+            _language: Option<String>,
+            _partial_tx: Option<PartialTranscriptSender>,
+            progress: SessionProgress,
+        ) -> Result<String> {
+            let outcome = self.script.lock().unwrap().pop_front().unwrap();
+            let limit = match outcome {
+                Outcome::Fail { samples, .. } => samples,
+                Outcome::Complete(_) => usize::MAX,
+            };
+
+            let mut received = Vec::new();
+            while received.len() < limit
+                && let Some(samples) = audio_rx.recv().await
+            {
+                received.extend(samples);
+            }
+            self.received.lock().unwrap().push(received);
+
+            match outcome {
+                Outcome::Fail { samples, text } => {
+                    progress.commit(text, samples);
+                    Err(anyhow!("connection reset"))
+                }
+                Outcome::Complete(text) => Ok(text.to_string()),
+            }
+        }
+    }
+
+    /// A finished recording of `len` samples whose values are their indices
+    fn recording(len: usize) -> mpsc::UnboundedReceiver<Vec<f32>> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let samples: Vec<f32> = (0..len).map(|i| i as f32).collect();
+        for block in samples.chunks(4000) {
+            tx.send(block.to_vec()).unwrap();
+        }
+        rx
+    }
+
+    #[tokio::test]
+    async fn replays_only_the_unacknowledged_tail() {
+        let session = FakeSession::new(vec![
+            Outcome::Fail {
+                samples: 16000,
+                text: "one",
+            },
+            Outcome::Fail {
+                samples: 8000,
+                text: " two ",
+            },
+            Outcome::Complete("three"),
+        ]);
+
+        let text = transcribe_resumable(&session, "key", recording(48000), None, None)
+            .await
+            .unwrap();
+        assert_eq!(text, "one two three");
+
+        let received = session.received();
+        assert_eq!(received.len(), 3);
+        assert_eq!(received[0][0], 0.0);
+        // The second session starts where the first one's acknowledgement ended
+        assert_eq!(received[1][0], 16000.0);
+        assert_eq!(received[2][0], 24000.0);
+        assert_eq!(received[2].len(), 24000);
+        assert_eq!(received[2].last(), Some(&47999.0));
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_batch_after_failures_without_progress() {
+        let server = WyomingServer::start(vec![(
+            "audio-stop",
+            vec![("transcript", json!({ "text": "rest" }))],
+        )])
+        .await;
+        let session = FakeSession::new(vec![
+            Outcome::Fail {
+                samples: 8000,
+                text: "start",
+            },
+            Outcome::Fail {
+                samples: 0,
+                text: "",
+            },
+            Outcome::Fail {
+                samples: 0,
+                text: "",
+            },
+        ]);
+
+        let text = transcribe_resumable(&session, &server.url, recording(16000), None, None)
+            .await
+            .unwrap();
+        assert_eq!(text, "start rest");
+        assert_eq!(session.received().len(), MAX_RECONNECTS as usize);
+
+        // Only the tail after the acknowledged 8000 samples is batch transcribed
+        let audio_bytes: usize = server
+            .events()
+            .iter()
+            .filter(|event| event.kind == "audio-chunk")
+            .map(|event| event.payload.len())
+            .sum();
+        assert_eq!(audio_bytes, 8000 * 2);
+    }
+}
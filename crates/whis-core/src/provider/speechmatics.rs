@@ -0,0 +1,334 @@
+//! Speechmatics batch transcription provider
+//!
+//! Speechmatics' batch API is job-based:
+//! 1. Submit a multipart job (`data_file` + JSON `config`)
+//! 2. Poll the job until it is `done` (or `rejected`)
+//! 3. Fetch the `json-v2` transcript (words and punctuation with timings)
+//!
+//! Jobs run in the region selected by `transcription.regions.speechmatics`
+//! (EU by default).
+
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+use std::time::{Duration, Instant};
+
+use super::{
+    DEFAULT_TIMEOUT_SECS, TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
+    TranscriptionStage, WordTimestamp,
+};
+use crate::settings::DataRegion;
+
+const EU_API_URL: &str = "https://eu1.asr.api.speechmatics.com/v2";
+const US_API_URL: &str = "https://us1.asr.api.speechmatics.com/v2";
+const OPERATING_POINT: &str = "enhanced";
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Deserialize)]
+struct CreatedJob {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct JobStatus {
+    job: Job,
+}
+
+#[derive(Deserialize)]
+struct Job {
+    status: String,
+}
+
+#[derive(Deserialize)]
+struct Transcript {
+    results: Vec<ResultItem>,
+    #[serde(default)]
+    metadata: Option<Metadata>,
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    #[serde(default)]
+    transcription_config: Option<TranscriptionConfig>,
+}
+
+#[derive(Deserialize)]
+struct TranscriptionConfig {
+    #[serde(default)]
+    language: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ResultItem {
+    #[serde(rename = "type")]
+    kind: String,
+    start_time: f64,
+    end_time: f64,
+    alternatives: Vec<Alternative>,
+}
+
+#[derive(Deserialize)]
+struct Alternative {
+    content: String,
+    #[serde(default)]
+    language: Option<String>,
+}
+
+/// Speechmatics batch transcription provider
+///
+/// Uses the Speechmatics jobs API with the enhanced operating point.
+/// Audio is processed in the EU or US depending on the configured region.
+#[derive(Debug, Default, Clone)]
+pub struct SpeechmaticsProvider;
+
+/// Base URL of the batch API for a region
+fn api_url(region: DataRegion) -> &'static str {
+    match region {
+        DataRegion::Eu => EU_API_URL,
+        DataRegion::Us => US_API_URL,
+    }
+}
+
+/// Job config: language identification when no language is set
+fn job_config(language: Option<&str>) -> String {
+    json!({
+        "type": "transcription",
+        "transcription_config": {
+            "language": language.unwrap_or("auto"),
+            "operating_point": OPERATING_POINT,
+        },
+    })
+    .to_string()
+}
+
+/// Whether the job is finished, failing if Speechmatics rejected it
+fn is_done(status: &str) -> Result<bool> {
+    match status {
+        "done" => Ok(true),
+        "rejected" | "deleted" | "expired" => {
+            Err(anyhow!("Speechmatics transcription job {status}"))
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Join words and punctuation into text, keeping word timings
+fn into_result(transcript: Transcript) -> TranscriptionResult {
+    let mut text = String::new();
+    let mut words = Vec::new();
+    let mut detected = None;
+
+    for item in transcript.results {
+        let Some(alt) = item.alternatives.into_iter().next() else {
+            continue;
+        };
+        if item.kind == "punctuation" {
+            text.push_str(&alt.content);
+            continue;
+        }
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(&alt.content);
+        detected = detected.or(alt.language);
+        words.push(WordTimestamp {
+            text: alt.content,
+            start_ms: (item.start_time * 1000.0) as u64,
+            end_ms: (item.end_time * 1000.0) as u64,
+            speaker: None,
+        });
+    }
+
+    let configured = transcript
+        .metadata
+        .and_then(|m| m.transcription_config)
+        .and_then(|c| c.language)
+        .filter(|l| l != "auto");
+
+    TranscriptionResult {
+        text,
+        language: detected.or(configured),
+        words,
+    }
+}
+
+/// Fail with the response body when Speechmatics returns a non-success status
+fn api_error(what: &str, status: reqwest::StatusCode, body: &str) -> anyhow::Error {
+    anyhow!("Speechmatics {what} failed ({status}): {body}")
+}
+
+#[async_trait]
+impl TranscriptionBackend for SpeechmaticsProvider {
+    fn name(&self) -> &'static str {
+        "speechmatics"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Speechmatics"
+    }
+
+    fn transcribe_sync(
+        &self,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        let base = api_url(request.options.regions.speechmatics);
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        // 1. Submit the job with the audio attached
+        request.report(TranscriptionStage::Uploading);
+        let form = reqwest::blocking::multipart::Form::new()
+            .text("config", job_config(request.language.as_deref()))
+            .part(
+                "data_file",
+                reqwest::blocking::multipart::Part::bytes(request.audio_data.clone())
+                    .file_name(request.filename.clone())
+                    .mime_str(&request.mime_type)?,
+            );
+        let response = client
+            .post(format!("{base}/jobs"))
+            .bearer_auth(api_key)
+            .multipart(form)
+            .send()
+            .context("Failed to submit Speechmatics job")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().unwrap_or_default();
+            return Err(api_error("job request", status, &body));
+        }
+        let job: CreatedJob = response
+            .json()
+            .context("Failed to parse Speechmatics job response")?;
+
+        // 2. Poll until the job is done
+        request.report(TranscriptionStage::Transcribing);
+        let deadline = Instant::now() + Duration::from_secs(DEFAULT_TIMEOUT_SECS);
+        loop {
+            let response = client
+                .get(format!("{base}/jobs/{}", job.id))
+                .bearer_auth(api_key)
+                .send()
+                .context("Failed to poll Speechmatics job")?;
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().unwrap_or_default();
+                return Err(api_error("poll", status, &body));
+            }
+            let job_status: JobStatus = response
+                .json()
+                .context("Failed to parse Speechmatics job status")?;
+            if is_done(&job_status.job.status)? {
+                break;
+            }
+            if Instant::now() >= deadline {
+                anyhow::bail!("Timed out waiting for Speechmatics transcript");
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+
+        // 3. Fetch the transcript
+        let response = client
+            .get(format!("{base}/jobs/{}/transcript?format=json-v2", job.id))
+            .bearer_auth(api_key)
+            .send()
+            .context("Failed to fetch Speechmatics transcript")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().unwrap_or_default();
+            return Err(api_error("transcript request", status, &body));
+        }
+        let transcript: Transcript = response
+            .json()
+            .context("Failed to parse Speechmatics transcript")?;
+
+        Ok(into_result(transcript))
+    }
+
+    async fn transcribe_async(
+        &self,
+        client: &reqwest::Client,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        let base = api_url(request.options.regions.speechmatics);
+
+        // 1. Submit the job with the audio attached
+        request.report(TranscriptionStage::Uploading);
+        let form = reqwest::multipart::Form::new()
+            .text("config", job_config(request.language.as_deref()))
+            .part(
+                "data_file",
+                reqwest::multipart::Part::bytes(request.audio_data.clone())
+                    .file_name(request.filename.clone())
+                    .mime_str(&request.mime_type)?,
+            );
+        let response = client
+            .post(format!("{base}/jobs"))
+            .bearer_auth(api_key)
+            .multipart(form)
+            .send()
+            .await
+            .context("Failed to submit Speechmatics job")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(api_error("job request", status, &body));
+        }
+        let job: CreatedJob = response
+            .json()
+            .await
+            .context("Failed to parse Speechmatics job response")?;
+
+        // 2. Poll until the job is done
+        request.report(TranscriptionStage::Transcribing);
+        let deadline = Instant::now() + Duration::from_secs(DEFAULT_TIMEOUT_SECS);
+        loop {
+            let response = client
+                .get(format!("{base}/jobs/{}", job.id))
+                .bearer_auth(api_key)
+                .send()
+                .await
+                .context("Failed to poll Speechmatics job")?;
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(api_error("poll", status, &body));
+            }
+            let job_status: JobStatus = response
+                .json()
+                .await
+                .context("Failed to parse Speechmatics job status")?;
+            if is_done(&job_status.job.status)? {
+                break;
+            }
+            if Instant::now() >= deadline {
+                anyhow::bail!("Timed out waiting for Speechmatics transcript");
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+
+        // 3. Fetch the transcript
+        let response = client
+            .get(format!("{base}/jobs/{}/transcript?format=json-v2", job.id))
+            .bearer_auth(api_key)
+            .send()
+            .await
+            .context("Failed to fetch Speechmatics transcript")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(api_error("transcript request", status, &body));
+        }
+        let transcript: Transcript = response
+            .json()
+            .await
+            .context("Failed to parse Speechmatics transcript")?;
+
+        Ok(into_result(transcript))
+    }
+}
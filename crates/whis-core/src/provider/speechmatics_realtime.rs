@@ -0,0 +1,368 @@
+//! Speechmatics Realtime transcription provider
+//!
+//! Speaks the Speechmatics realtime WebSocket protocol:
+//!
+//! ```text
+//! → StartRecognition            ← RecognitionStarted
+//! → binary PCM16 (AddAudio)*    ← AudioAdded / AddPartialTranscript / AddTranscript
+//! → EndOfStream(last_seq_no)    ← AddTranscript* → EndOfTranscript
+//! ```
+//!
+//! Sessions connect to the region selected by `transcription.regions.speechmatics`
+//! (EU by default).
+
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+use futures_util::{SinkExt, Stream, StreamExt};
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::{Duration, timeout};
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{
+        Message,
+        client::IntoClientRequest,
+        http::header::{AUTHORIZATION, HeaderValue},
+    },
+};
+
+use super::realtime_resume::{ResumableSession, SessionProgress, transcribe_resumable};
+use super::{
+    PartialTranscriptSender, ProviderOptions, RealtimeTranscriptionBackend, SpeechmaticsProvider,
+    TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
+};
+use crate::config::TranscriptionProvider;
+use crate::settings::DataRegion;
+
+const EU_WS_URL: &str = "wss://eu2.rt.speechmatics.com/v2";
+const US_WS_URL: &str = "wss://us2.rt.speechmatics.com/v2";
+const SAMPLE_RATE: u32 = 16000;
+/// Realtime sessions need an explicit language (no identification)
+const DEFAULT_LANGUAGE: &str = "en";
+
+/// Speechmatics Realtime provider
+///
+/// Streams audio via WebSocket for real-time transcription.
+/// Uses the same API key as batch Speechmatics (SPEECHMATICS_API_KEY).
+#[derive(Debug, Default, Clone)]
+pub struct SpeechmaticsRealtimeProvider {
+    options: ProviderOptions,
+}
+
+#[derive(Deserialize, Debug)]
+struct ServerMessage {
+    message: String,
+    #[serde(default)]
+    metadata: Option<TranscriptMetadata>,
+    #[serde(default, rename = "type")]
+    error_type: Option<String>,
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct TranscriptMetadata {
+    transcript: String,
+    /// End of the segment from the start of the session (seconds)
+    end_time: f64,
+}
+
+fn ws_url(region: DataRegion) -> &'static str {
+    match region {
+        DataRegion::Eu => EU_WS_URL,
+        DataRegion::Us => US_WS_URL,
+    }
+}
+
+impl SpeechmaticsRealtimeProvider {
+    /// Create a provider that streams to the caller's Speechmatics region
+    pub fn new(options: ProviderOptions) -> Self {
+        Self { options }
+    }
+
+    /// Stream audio over a single WebSocket connection to `region`
+    async fn stream_session(
+        region: DataRegion,
+        api_key: &str,
+        mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+        progress: SessionProgress,
+    ) -> Result<String> {
+        // 1. Connect to the configured region
+        let mut request = ws_url(region)
+            .into_client_request()
+            .context("Failed to build Speechmatics request")?;
+        request.headers_mut().insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {api_key}"))
+                .context("Invalid Speechmatics API key")?,
+        );
+
+        let (ws_stream, _response) = timeout(Duration::from_secs(30), connect_async(request))
+            .await
+            .map_err(|_| anyhow!("Connection timeout"))?
+            .context("Failed to connect to Speechmatics")?;
+        let (mut write, mut read) = ws_stream.split();
+
+        // 2. Start recognition and wait until the server is ready for audio
+        let start = json!({
+            "message": "StartRecognition",
+            "audio_format": {
+                "type": "raw",
+                "encoding": "pcm_s16le",
+                "sample_rate": SAMPLE_RATE,
+            },
+            "transcription_config": {
+                "language": language.as_deref().unwrap_or(DEFAULT_LANGUAGE),
+                "operating_point": "enhanced",
+                "enable_partials": true,
+            },
+        });
+        write
+            .send(Message::Text(start.to_string().into()))
+            .await
+            .context("Failed to send StartRecognition")?;
+
+        timeout(Duration::from_secs(30), async {
+            while let Some(msg) = read.next().await {
+                if let Message::Text(text) = msg? {
+                    let event: ServerMessage = serde_json::from_str(&text)
+                        .context("Failed to parse Speechmatics message")?;
+                    match event.message.as_str() {
+                        "RecognitionStarted" => return Ok(()),
+                        "Error" => return Err(server_error(&event)),
+                        _ => {}
+                    }
+                }
+            }
+            Err(anyhow!(
+                "Speechmatics closed the connection before starting"
+            ))
+        })
+        .await
+        .map_err(|_| anyhow!("Timeout waiting for RecognitionStarted"))??;
+
+        // 3. Collect transcripts while audio streams
+        let (done_tx, done_rx) = oneshot::channel::<()>();
+        let read_handle =
+            tokio::spawn(
+                async move { collect_transcripts(read, done_rx, partial_tx, progress).await },
+            );
+
+        // 4. Stream audio chunks as binary PCM16 frames (each one is an AddAudio)
+        let mut seq_no: u64 = 0;
+        while let Some(samples) = audio_rx.recv().await {
+            if samples.is_empty() {
+                continue;
+            }
+            seq_no += 1;
+
+            let bytes: Vec<u8> = samples
+                .iter()
+                .map(|&s| (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+                .flat_map(|s| s.to_le_bytes())
+                .collect();
+
+            write
+                .send(Message::Binary(bytes.into()))
+                .await
+                .context("Failed to send audio chunk")?;
+        }
+
+        if crate::verbose::is_verbose() {
+            eprintln!("[speechmatics-realtime] Sent {seq_no} chunks, ending stream");
+        }
+
+        // 5. EndOfStream: the server finalizes and replies with EndOfTranscript
+        let end = json!({ "message": "EndOfStream", "last_seq_no": seq_no });
+        write
+            .send(Message::Text(end.to_string().into()))
+            .await
+            .context("Failed to send EndOfStream")?;
+        let _ = done_tx.send(());
+
+        let transcript_result = timeout(Duration::from_secs(30), read_handle).await;
+        let _ = write.send(Message::Close(None)).await;
+
+        match transcript_result {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => Err(anyhow!("Read task panicked: {e}")),
+            Err(_) => Err(anyhow!("Timeout waiting for transcription result")),
+        }
+    }
+}
+
+fn server_error(event: &ServerMessage) -> anyhow::Error {
+    anyhow!(
+        "Speechmatics error ({}): {}",
+        event.error_type.as_deref().unwrap_or("unknown"),
+        event.reason.as_deref().unwrap_or("no reason given")
+    )
+}
+
+/// Collect final transcripts until the server sends `EndOfTranscript`
+///
+/// Partial transcripts are forwarded to `partial_tx` after the final text so far;
+/// final ones are committed to `progress` up to their end time.
+async fn collect_transcripts<S>(
+    mut read: S,
+    mut done_rx: oneshot::Receiver<()>,
+    partial_tx: Option<PartialTranscriptSender>,
+    progress: SessionProgress,
+) -> Result<String>
+where
+    S: Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+{
+    let mut final_transcript = String::new();
+    let mut ending = false;
+
+    loop {
+        let msg = tokio::select! {
+            _ = &mut done_rx, if !ending => {
+                ending = true;
+                continue;
+            }
+            msg = read.next() => msg,
+        };
+
+        let text = match msg {
+            Some(Ok(Message::Text(text))) => text,
+            Some(Ok(Message::Close(frame))) => {
+                if crate::verbose::is_verbose() {
+                    eprintln!("[speechmatics-realtime] WebSocket closed: {:?}", frame);
+                }
+                return Err(anyhow!(
+                    "Speechmatics closed the connection before EndOfTranscript"
+                ));
+            }
+            Some(Ok(_)) => continue,
+            Some(Err(e)) => return Err(anyhow!("WebSocket error: {e}")),
+            None => return Err(anyhow!("Speechmatics connection ended unexpectedly")),
+        };
+
+        let event: ServerMessage =
+            serde_json::from_str(&text).context("Failed to parse Speechmatics message")?;
+
+        match event.message.as_str() {
+            "AddTranscript" => {
+                if let Some(meta) = event.metadata
+                    && !meta.transcript.trim().is_empty()
+                {
+                    final_transcript.push_str(&meta.transcript);
+                    progress.commit(
+                        meta.transcript.trim(),
+                        (meta.end_time * SAMPLE_RATE as f64) as usize,
+                    );
+                    if let Some(tx) = &partial_tx {
+                        let _ = tx.send(final_transcript.trim().to_string());
+                    }
+                }
+            }
+            "AddPartialTranscript" => {
+                if let Some(meta) = event.metadata
+                    && let Some(tx) = &partial_tx
+                {
+                    let text = format!("{final_transcript}{}", meta.transcript);
+                    let _ = tx.send(text.trim().to_string());
+                }
+            }
+            "EndOfTranscript" => {
+                if crate::verbose::is_verbose() {
+                    eprintln!("[speechmatics-realtime] EndOfTranscript received");
+                }
+                return Ok(final_transcript.trim().to_string());
+            }
+            "Error" => return Err(server_error(&event)),
+            "Warning" if crate::verbose::is_verbose() => {
+                eprintln!(
+                    "[speechmatics-realtime] Warning: {}",
+                    event.reason.as_deref().unwrap_or("")
+                );
+            }
+            _ => {
+                // AudioAdded, Info, Warning: nothing to do
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ResumableSession for SpeechmaticsRealtimeProvider {
+    fn batch_provider(&self) -> TranscriptionProvider {
+        TranscriptionProvider::SpeechmaticsRealtime
+    }
+
+    fn batch_options(&self) -> ProviderOptions {
+        self.options.clone()
+    }
+
+    async fn run_session(
+        &self,
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+        progress: SessionProgress,
+    ) -> Result<String> {
+        Self::stream_session(
+            self.options.regions.speechmatics,
+            api_key,
+            audio_rx,
+            language,
+            partial_tx,
+            progress,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl RealtimeTranscriptionBackend for SpeechmaticsRealtimeProvider {
+    async fn transcribe_stream(
+        &self,
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+    ) -> Result<String> {
+        transcribe_resumable(self, api_key, audio_rx, language, partial_tx).await
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for SpeechmaticsRealtimeProvider {
+    fn name(&self) -> &'static str {
+        "speechmatics-realtime"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Speechmatics Realtime"
+    }
+
+    /// For file input, fall back to the Speechmatics batch API
+    fn transcribe_sync(
+        &self,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        SpeechmaticsProvider.transcribe_sync(api_key, request)
+    }
+
+    /// For async file input, fall back to the Speechmatics batch API
+    async fn transcribe_async(
+        &self,
+        client: &reqwest::Client,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        SpeechmaticsProvider
+            .transcribe_async(client, api_key, request)
+            .await
+    }
+}
@@ -0,0 +1,106 @@
+//! Minimal Wyoming stand-in server for provider tests
+//!
+//! Serves a single connection: records every event it receives and, the first
+//! time an event of a scripted kind arrives, replies with the scripted events.
+//! Once the last scripted reply is sent the server closes its side of the
+//! connection (but keeps recording until the client closes too).
+
+use serde_json::Value;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+use super::wyoming::encode_event;
+
+/// An event received by [`WyomingServer`]
+#[derive(Debug, Clone)]
+pub(crate) struct Received {
+    pub kind: String,
+    pub data: Value,
+    pub payload: Vec<u8>,
+}
+
+/// Reply events sent when an event of the given kind is first received
+pub(crate) type Script = Vec<(&'static str, Vec<(&'static str, Value)>)>;
+
+/// Local Wyoming server replaying scripted events
+pub(crate) struct WyomingServer {
+    /// Server address as configured by users, e.g. `tcp://127.0.0.1:40000`
+    pub url: String,
+    events: Arc<Mutex<Vec<Received>>>,
+}
+
+impl WyomingServer {
+    /// Start serving one connection with `script` on the current runtime
+    pub async fn start(mut script: Script) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("tcp://{}", listener.local_addr().unwrap());
+        let events = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = Arc::clone(&events);
+        tokio::spawn(async move {
+            let Ok((stream, _)) = listener.accept().await else {
+                return;
+            };
+            let (read, mut write) = stream.into_split();
+            let mut reader = BufReader::new(read);
+
+            while let Some(event) = read_event(&mut reader).await {
+                let kind = event.kind.clone();
+                recorded.lock().unwrap().push(event);
+
+                let Some(index) = script.iter().position(|(trigger, _)| *trigger == kind) else {
+                    continue;
+                };
+                let (_, replies) = script.remove(index);
+                for (reply, data) in replies {
+                    let _ = write.write_all(&encode_event(reply, data, &[])).await;
+                }
+                if script.is_empty() {
+                    let _ = write.shutdown().await;
+                }
+            }
+        });
+
+        Self { url, events }
+    }
+
+    /// Events received so far, in order
+    pub fn events(&self) -> Vec<Received> {
+        self.events.lock().unwrap().clone()
+    }
+
+    /// Kinds of the events received so far, in order
+    pub fn kinds(&self) -> Vec<String> {
+        self.events().into_iter().map(|event| event.kind).collect()
+    }
+}
+
+/// Read one event, keeping its payload
+async fn read_event<R: AsyncBufReadExt + Unpin>(reader: &mut R) -> Option<Received> {
+    let mut line = String::new();
+    if reader.read_line(&mut line).await.ok()? == 0 {
+        return None;
+    }
+    let header: Value = serde_json::from_str(line.trim()).ok()?;
+    let length = |field: &str| header.get(field).and_then(Value::as_u64).unwrap_or(0) as usize;
+
+    let mut extra = vec![0; length("data_length")];
+    reader.read_exact(&mut extra).await.ok()?;
+    let mut payload = vec![0; length("payload_length")];
+    reader.read_exact(&mut payload).await.ok()?;
+
+    let mut data = header.get("data").cloned().unwrap_or(Value::Null);
+    if let (Some(data), Ok(Value::Object(extra))) = (
+        data.as_object_mut(),
+        serde_json::from_slice::<Value>(&extra),
+    ) {
+        data.extend(extra);
+    }
+
+    Some(Received {
+        kind: header["type"].as_str()?.to_string(),
+        data,
+        payload,
+    })
+}
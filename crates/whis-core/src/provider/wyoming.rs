@@ -0,0 +1,567 @@
+//! Wyoming protocol transcription provider
+//!
+//! Talks to any Wyoming ASR server (wyoming-faster-whisper, wyoming-vosk,
+//! Home Assistant add-ons, ...) over plain TCP. Each event is a JSON header
+//! line, optionally followed by extra data and a binary payload:
+//!
+//! ```text
+//! {"type": "...", "data": {...}, "data_length": N, "payload_length": M}\n
+//! <N bytes of JSON data><M bytes of payload>
+//! ```
+//!
+//! A transcription is one connection:
+//!
+//! ```text
+//! → transcribe {language}
+//! → audio-start {rate, width, channels}
+//! → audio-chunk {rate, width, channels} + PCM payload*
+//! → audio-stop                          ← (transcript-start/chunk/stop)* → transcript {text}
+//! ```
+//!
+//! The server address is passed in place of an API key
+//! (`tcp://host:port`, `host:port` or `host`).
+
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt};
+
+use super::{
+    DEFAULT_TIMEOUT_SECS, TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
+};
+
+/// Port used by Wyoming ASR servers unless configured otherwise
+pub(crate) const DEFAULT_PORT: u16 = 10300;
+pub(crate) const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Audio per `audio-chunk` event (100ms at 16kHz)
+pub(crate) const SAMPLES_PER_CHUNK: usize = 1600;
+
+/// Wyoming provider (batch)
+///
+/// Sends the whole recording, then waits for the transcript.
+#[derive(Debug, Default, Clone)]
+pub struct WyomingProvider;
+
+/// PCM format announced in `audio-start` and `audio-chunk` events
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AudioFormat {
+    pub rate: u32,
+    /// Bytes per sample
+    pub width: u16,
+    pub channels: u16,
+}
+
+impl AudioFormat {
+    /// 16kHz mono 16-bit, as recorded by whis
+    pub const WHISPER: Self = Self {
+        rate: 16000,
+        width: 2,
+        channels: 1,
+    };
+
+    fn to_json(self) -> Value {
+        json!({ "rate": self.rate, "width": self.width, "channels": self.channels })
+    }
+
+    fn bytes_per_chunk(self) -> usize {
+        SAMPLES_PER_CHUNK * self.width as usize * self.channels as usize
+    }
+}
+
+/// A received event (payload is read and discarded)
+#[derive(Debug)]
+pub(crate) struct Event {
+    pub kind: String,
+    pub data: Value,
+}
+
+impl Event {
+    /// String field of the event data
+    pub fn text(&self, field: &str) -> Option<&str> {
+        self.data.get(field).and_then(Value::as_str)
+    }
+}
+
+#[derive(Deserialize)]
+struct Header {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    data: Option<Value>,
+    #[serde(default)]
+    data_length: usize,
+    #[serde(default)]
+    payload_length: usize,
+}
+
+impl Header {
+    /// Merge inline data with the separately sent data block
+    fn into_event(self, extra: &[u8]) -> Result<Event> {
+        let mut data = self.data.unwrap_or_else(|| json!({}));
+        if !extra.is_empty() {
+            let extra: Value =
+                serde_json::from_slice(extra).context("Invalid Wyoming event data")?;
+            if let (Some(data), Value::Object(extra)) = (data.as_object_mut(), extra) {
+                data.extend(extra);
+            }
+        }
+        Ok(Event {
+            kind: self.kind,
+            data,
+        })
+    }
+}
+
+/// Normalize a server address to `host:port`
+pub(crate) fn socket_address(url: &str) -> String {
+    let address = url
+        .trim()
+        .trim_start_matches("tcp://")
+        .trim_end_matches('/');
+    let has_port = match address.rfind(']') {
+        // IPv6 literal: [::1] or [::1]:10300
+        Some(end) => address[end..].contains(':'),
+        None => address.contains(':'),
+    };
+    if has_port {
+        address.to_string()
+    } else {
+        format!("{address}:{DEFAULT_PORT}")
+    }
+}
+
+/// Serialize an event (data inline in the header, understood by all versions)
+pub(crate) fn encode_event(kind: &str, data: Value, payload: &[u8]) -> Vec<u8> {
+    let mut header = json!({ "type": kind, "data": data });
+    if !payload.is_empty() {
+        header["payload_length"] = json!(payload.len());
+    }
+    let mut bytes = header.to_string().into_bytes();
+    bytes.push(b'\n');
+    bytes.extend_from_slice(payload);
+    bytes
+}
+
+/// `transcribe` event: language hint, if any
+pub(crate) fn transcribe_event(language: Option<&str>) -> Vec<u8> {
+    let data = match language {
+        Some(lang) => json!({ "language": lang }),
+        None => json!({}),
+    };
+    encode_event("transcribe", data, &[])
+}
+
+pub(crate) fn audio_start_event(format: AudioFormat) -> Vec<u8> {
+    encode_event("audio-start", format.to_json(), &[])
+}
+
+pub(crate) fn audio_chunk_event(format: AudioFormat, pcm: &[u8]) -> Vec<u8> {
+    encode_event("audio-chunk", format.to_json(), pcm)
+}
+
+pub(crate) fn audio_stop_event() -> Vec<u8> {
+    encode_event("audio-stop", json!({}), &[])
+}
+
+/// Read the next event, or `None` when the server closed the connection
+pub(crate) async fn read_event<R: AsyncBufRead + Unpin>(reader: &mut R) -> Result<Option<Event>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line).await? == 0 {
+        return Ok(None);
+    }
+    let header: Header =
+        serde_json::from_str(line.trim()).context("Invalid Wyoming event header")?;
+
+    let mut extra = vec![0; header.data_length];
+    reader.read_exact(&mut extra).await?;
+    let mut payload = vec![0; header.payload_length];
+    reader.read_exact(&mut payload).await?;
+
+    header.into_event(&extra).map(Some)
+}
+
+/// Blocking variant of [`read_event`]
+fn read_event_sync<R: BufRead>(reader: &mut R) -> Result<Option<Event>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    let header: Header =
+        serde_json::from_str(line.trim()).context("Invalid Wyoming event header")?;
+
+    let mut extra = vec![0; header.data_length];
+    reader.read_exact(&mut extra)?;
+    let mut payload = vec![0; header.payload_length];
+    reader.read_exact(&mut payload)?;
+
+    header.into_event(&extra).map(Some)
+}
+
+/// Error reported by the server in an `error` event
+pub(crate) fn server_error(event: &Event) -> anyhow::Error {
+    anyhow!(
+        "Wyoming server error: {}",
+        event.text("text").unwrap_or("unknown error")
+    )
+}
+
+/// Ask the server to describe itself and wait for its `info` reply
+///
+/// Used as a connection check (warmup); the info itself is not needed.
+pub(crate) async fn describe(url: &str) -> Result<()> {
+    let address = socket_address(url);
+    let stream = tokio::time::timeout(CONNECT_TIMEOUT, tokio::net::TcpStream::connect(&address))
+        .await
+        .map_err(|_| anyhow!("Timed out connecting to Wyoming server at {address}"))?
+        .with_context(|| format!("Failed to connect to Wyoming server at {address}"))?;
+    let (read, mut write) = stream.into_split();
+
+    write
+        .write_all(&encode_event("describe", json!({}), &[]))
+        .await?;
+
+    let mut reader = tokio::io::BufReader::new(read);
+    while let Some(event) = read_event(&mut reader).await? {
+        if event.kind == "info" {
+            return Ok(());
+        }
+    }
+    Err(anyhow!("Wyoming server closed the connection without info"))
+}
+
+/// Extract the PCM data and its format from a WAV file
+fn wav_pcm(data: &[u8]) -> Result<(AudioFormat, &[u8])> {
+    if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"WAVE" {
+        anyhow::bail!("Wyoming needs uncompressed WAV audio");
+    }
+
+    let mut format = None;
+    let mut pos = 12;
+    while pos + 8 <= data.len() {
+        let id = &data[pos..pos + 4];
+        let size = u32::from_le_bytes(data[pos + 4..pos + 8].try_into()?) as usize;
+        let body = &data[pos + 8..(pos + 8 + size).min(data.len())];
+
+        match id {
+            b"fmt " if body.len() >= 16 => {
+                let tag = u16::from_le_bytes([body[0], body[1]]);
+                let bits = u16::from_le_bytes([body[14], body[15]]);
+                // 1 = PCM, 0xFFFE = extensible (PCM subformat for integer samples)
+                if (tag != 1 && tag != 0xFFFE) || !bits.is_multiple_of(8) {
+                    anyhow::bail!("Wyoming needs integer PCM WAV audio");
+                }
+                format = Some(AudioFormat {
+                    rate: u32::from_le_bytes(body[4..8].try_into()?),
+                    width: bits / 8,
+                    channels: u16::from_le_bytes([body[2], body[3]]),
+                });
+            }
+            b"data" => {
+                let format = format.ok_or_else(|| anyhow!("WAV data before format chunk"))?;
+                return Ok((format, body));
+            }
+            _ => {}
+        }
+
+        // Chunks are padded to an even size
+        pos += 8 + size + (size & 1);
+    }
+
+    Err(anyhow!("WAV file has no audio data"))
+}
+
+/// Events for a whole transcription, up to and including `audio-stop`
+fn request_events(request: &TranscriptionRequest) -> Result<Vec<u8>> {
+    let (format, pcm) = wav_pcm(&request.audio_data)
+        .with_context(|| format!("Cannot send {} to Wyoming", request.filename))?;
+
+    let mut bytes = transcribe_event(request.language.as_deref());
+    bytes.extend(audio_start_event(format));
+    for chunk in pcm.chunks(format.bytes_per_chunk()) {
+        bytes.extend(audio_chunk_event(format, chunk));
+    }
+    bytes.extend(audio_stop_event());
+    Ok(bytes)
+}
+
+fn into_result(event: &Event) -> TranscriptionResult {
+    TranscriptionResult {
+        text: event.text("text").unwrap_or_default().trim().to_string(),
+        language: event.text("language").map(str::to_string),
+        words: Vec::new(),
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for WyomingProvider {
+    fn name(&self) -> &'static str {
+        "wyoming"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Wyoming"
+    }
+
+    fn transcribe_sync(
+        &self,
+        url: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        let events = request_events(&request)?;
+
+        let address = socket_address(url);
+        let socket = address
+            .to_socket_addrs()
+            .with_context(|| format!("Invalid Wyoming server address: {address}"))?
+            .next()
+            .ok_or_else(|| anyhow!("Could not resolve Wyoming server: {address}"))?;
+        let mut stream = TcpStream::connect_timeout(&socket, CONNECT_TIMEOUT)
+            .with_context(|| format!("Failed to connect to Wyoming server at {address}"))?;
+        stream.set_read_timeout(Some(Duration::from_secs(DEFAULT_TIMEOUT_SECS)))?;
+
+        request.report(super::TranscriptionStage::Uploading);
+        stream
+            .write_all(&events)
+            .context("Failed to send audio to Wyoming server")?;
+
+        request.report(super::TranscriptionStage::Transcribing);
+        let mut reader = BufReader::new(stream);
+        while let Some(event) = read_event_sync(&mut reader)? {
+            match event.kind.as_str() {
+                "transcript" => return Ok(into_result(&event)),
+                "error" => return Err(server_error(&event)),
+                _ => {}
+            }
+        }
+        Err(anyhow!(
+            "Wyoming server closed the connection without a transcript"
+        ))
+    }
+
+    async fn transcribe_async(
+        &self,
+        _client: &reqwest::Client,
+        url: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        let events = request_events(&request)?;
+
+        let address = socket_address(url);
+        let stream =
+            tokio::time::timeout(CONNECT_TIMEOUT, tokio::net::TcpStream::connect(&address))
+                .await
+                .map_err(|_| anyhow!("Timed out connecting to Wyoming server at {address}"))?
+                .with_context(|| format!("Failed to connect to Wyoming server at {address}"))?;
+        let (read, mut write) = stream.into_split();
+
+        request.report(super::TranscriptionStage::Uploading);
+        write
+            .write_all(&events)
+            .await
+            .context("Failed to send audio to Wyoming server")?;
+
+        request.report(super::TranscriptionStage::Transcribing);
+        let mut reader = tokio::io::BufReader::new(read);
+        tokio::time::timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS), async {
+            while let Some(event) = read_event(&mut reader).await? {
+                match event.kind.as_str() {
+                    "transcript" => return Ok(into_result(&event)),
+                    "error" => return Err(server_error(&event)),
+                    _ => {}
+                }
+            }
+            Err(anyhow!(
+                "Wyoming server closed the connection without a transcript"
+            ))
+        })
+        .await
+        .map_err(|_| anyhow!("Timed out waiting for Wyoming transcript"))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::test_wyoming::WyomingServer;
+
+    /// 0.25s of 16kHz audio as a WAV request
+    fn request(language: Option<&str>) -> TranscriptionRequest {
+        let samples = vec![0.25; 4000];
+        let mut request = TranscriptionRequest::new(
+            crate::audio::encode_wav(&samples, 16000),
+            language.map(str::to_string),
+        );
+        request.filename = "audio.wav".to_string();
+        request
+    }
+
+    fn transcript(text: &str) -> Vec<(&'static str, Value)> {
+        vec![
+            ("transcript-start", json!({})),
+            ("transcript", json!({ "text": text, "language": "de" })),
+        ]
+    }
+
+    #[test]
+    fn socket_address_adds_default_port() {
+        assert_eq!(socket_address("localhost"), "localhost:10300");
+        assert_eq!(socket_address(" tcp://asr.lan:1234/ "), "asr.lan:1234");
+        assert_eq!(socket_address("tcp://10.0.0.2"), "10.0.0.2:10300");
+        assert_eq!(socket_address("[::1]"), "[::1]:10300");
+        assert_eq!(socket_address("[::1]:1234"), "[::1]:1234");
+    }
+
+    #[test]
+    fn wav_pcm_reads_format_and_skips_other_chunks() {
+        let wav = crate::audio::encode_wav(&[0.5, -0.5], 16000);
+        let (format, pcm) = wav_pcm(&wav).unwrap();
+        assert_eq!((format.rate, format.width, format.channels), (16000, 2, 1));
+        assert_eq!(pcm.len(), 4);
+
+        // An odd-sized chunk between fmt and data is padded to an even size
+        let mut padded = wav[..36].to_vec();
+        padded.extend_from_slice(b"LIST");
+        padded.extend_from_slice(&3u32.to_le_bytes());
+        padded.extend_from_slice(b"abc\0");
+        padded.extend_from_slice(&wav[36..]);
+        let (_, pcm) = wav_pcm(&padded).unwrap();
+        assert_eq!(pcm, &wav[44..]);
+    }
+
+    #[test]
+    fn wav_pcm_rejects_compressed_and_float_audio() {
+        let error = wav_pcm(b"ID3\x04 not a wav file").unwrap_err();
+        assert!(error.to_string().contains("uncompressed WAV"));
+
+        let mut float = crate::audio::encode_wav(&[0.5], 16000);
+        float[20..22].copy_from_slice(&3u16.to_le_bytes());
+        let error = wav_pcm(&float).unwrap_err();
+        assert!(error.to_string().contains("integer PCM"));
+
+        let header_only = crate::audio::encode_wav(&[], 16000);
+        let error = wav_pcm(&header_only[..36]).unwrap_err();
+        assert!(error.to_string().contains("no audio data"));
+    }
+
+    #[tokio::test]
+    async fn read_event_merges_separate_data_and_skips_payload() {
+        let mut bytes =
+            br#"{"type":"transcript","data":{"language":"en"},"data_length":16,"payload_length":3}"#
+                .to_vec();
+        bytes.push(b'\n');
+        bytes.extend_from_slice(br#"{"text":"hello"}"#);
+        bytes.extend_from_slice(b"xyz");
+        bytes.extend(encode_event("transcript-stop", json!({}), &[]));
+
+        let mut reader = &bytes[..];
+        let event = read_event(&mut reader).await.unwrap().unwrap();
+        assert_eq!(event.kind, "transcript");
+        assert_eq!(event.text("text"), Some("hello"));
+        assert_eq!(event.text("language"), Some("en"));
+
+        let event = read_event(&mut reader).await.unwrap().unwrap();
+        assert_eq!(event.kind, "transcript-stop");
+        assert!(read_event(&mut reader).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn describe_waits_for_info() {
+        let server =
+            WyomingServer::start(vec![("describe", vec![("info", json!({ "asr": [] }))])]).await;
+
+        describe(&server.url).await.unwrap();
+        assert_eq!(server.kinds(), ["describe"]);
+    }
+
+    #[tokio::test]
+    async fn batch_sends_audio_and_returns_transcript() {
+        let server = WyomingServer::start(vec![("audio-stop", transcript(" Hallo Welt "))]).await;
+
+        let result = WyomingProvider
+            .transcribe_async(&reqwest::Client::new(), &server.url, request(Some("de")))
+            .await
+            .unwrap();
+        assert_eq!(result.text, "Hallo Welt");
+        assert_eq!(result.language.as_deref(), Some("de"));
+
+        let events = server.events();
+        let kinds: Vec<&str> = events.iter().map(|event| event.kind.as_str()).collect();
+        // 4000 samples: two full 1600-sample chunks and one of 800
+        assert_eq!(
+            kinds,
+            [
+                "transcribe",
+                "audio-start",
+                "audio-chunk",
+                "audio-chunk",
+                "audio-chunk",
+                "audio-stop"
+            ]
+        );
+        assert_eq!(events[0].data["language"], "de");
+        assert_eq!(
+            events[1].data,
+            json!({ "rate": 16000, "width": 2, "channels": 1 })
+        );
+        let sizes: Vec<usize> = events[2..5].iter().map(|e| e.payload.len()).collect();
+        assert_eq!(sizes, [3200, 3200, 1600]);
+        assert_eq!(events[2].data["rate"], 16000);
+    }
+
+    #[tokio::test]
+    async fn batch_sync_returns_transcript() {
+        let server = WyomingServer::start(vec![("audio-stop", transcript("hello"))]).await;
+
+        let url = server.url.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            WyomingProvider.transcribe_sync(&url, request(None))
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(result.text, "hello");
+        assert_eq!(server.events()[0].data, json!({}));
+    }
+
+    #[tokio::test]
+    async fn batch_reports_server_errors() {
+        let server = WyomingServer::start(vec![(
+            "audio-stop",
+            vec![("error", json!({ "text": "model not loaded" }))],
+        )])
+        .await;
+
+        let error = WyomingProvider
+            .transcribe_async(&reqwest::Client::new(), &server.url, request(None))
+            .await
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Wyoming server error: model not loaded");
+    }
+
+    #[tokio::test]
+    async fn batch_fails_when_server_closes_without_transcript() {
+        let server = WyomingServer::start(vec![("audio-stop", Vec::new())]).await;
+
+        let error = WyomingProvider
+            .transcribe_async(&reqwest::Client::new(), &server.url, request(None))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("without a transcript"));
+    }
+
+    #[tokio::test]
+    async fn batch_rejects_non_wav_audio_before_connecting() {
+        let mut mp3 = TranscriptionRequest::new(b"ID3 not a wav".to_vec(), None);
+        mp3.filename = "audio.mp3".to_string();
+
+        let error = WyomingProvider
+            .transcribe_async(&reqwest::Client::new(), "tcp://127.0.0.1:9", mp3)
+            .await
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Cannot send audio.mp3 to Wyoming");
+    }
+}
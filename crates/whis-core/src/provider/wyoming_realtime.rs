@@ -0,0 +1,355 @@
+//! Wyoming protocol streaming provider
+//!
+//! Opens the Wyoming connection when recording starts and forwards audio as
+//! `audio-chunk` events while the user speaks, so the server has the whole
+//! recording as soon as `audio-stop` is sent:
+//!
+//! ```text
+//! → transcribe, audio-start
+//! → audio-chunk*                ← transcript-start, transcript-chunk* (streaming servers)
+//! → audio-stop                  ← transcript-stop, transcript {text}
+//! ```
+//!
+//! Servers that stream text (`transcript-chunk`) produce live partials; others
+//! only reply once `audio-stop` is received. Wyoming has no per-segment
+//! acknowledgements, so a dropped session replays all of its audio.
+
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use tokio::time::{Duration, timeout};
+
+use super::realtime_resume::{ResumableSession, SessionProgress, transcribe_resumable};
+use super::wyoming::{
+    AudioFormat, CONNECT_TIMEOUT, SAMPLES_PER_CHUNK, audio_chunk_event, audio_start_event,
+    audio_stop_event, read_event, server_error, socket_address, transcribe_event,
+};
+use super::{
+    DEFAULT_TIMEOUT_SECS, PartialTranscriptSender, RealtimeTranscriptionBackend,
+    TranscriptionBackend, TranscriptionRequest, TranscriptionResult, WyomingProvider,
+};
+use crate::config::TranscriptionProvider;
+
+const SAMPLE_RATE: u32 = 16000;
+
+/// Wyoming streaming provider
+///
+/// Streams audio over TCP while recording. Uses the same server address as
+/// batch Wyoming (WYOMING_URL).
+#[derive(Debug, Default, Clone)]
+pub struct WyomingRealtimeProvider;
+
+impl WyomingRealtimeProvider {
+    /// Stream audio over a single TCP connection
+    async fn stream_session(
+        url: &str,
+        mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+    ) -> Result<String> {
+        // 1. Connect and announce the audio format
+        let address = socket_address(url);
+        let stream = timeout(CONNECT_TIMEOUT, tokio::net::TcpStream::connect(&address))
+            .await
+            .map_err(|_| anyhow!("Connection timeout"))?
+            .with_context(|| format!("Failed to connect to Wyoming server at {address}"))?;
+        let (read, mut write) = stream.into_split();
+
+        let format = AudioFormat::WHISPER;
+        let mut start = transcribe_event(language.as_deref());
+        start.extend(audio_start_event(format));
+        write
+            .write_all(&start)
+            .await
+            .context("Failed to start Wyoming transcription")?;
+
+        // 2. Collect the transcript while audio streams
+        let read_handle = tokio::spawn(collect_transcript(
+            tokio::io::BufReader::new(read),
+            partial_tx,
+        ));
+
+        // 3. Stream audio as PCM16 audio-chunk events
+        let mut total_samples = 0;
+        while let Some(samples) = audio_rx.recv().await {
+            if samples.is_empty() {
+                continue;
+            }
+            total_samples += samples.len();
+
+            for chunk in samples.chunks(SAMPLES_PER_CHUNK) {
+                let pcm: Vec<u8> = chunk
+                    .iter()
+                    .map(|&s| (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+                    .flat_map(|s| s.to_le_bytes())
+                    .collect();
+
+                write
+                    .write_all(&audio_chunk_event(format, &pcm))
+                    .await
+                    .context("Failed to send audio chunk")?;
+            }
+        }
+
+        if crate::verbose::is_verbose() {
+            eprintln!("[wyoming-realtime] Sent {total_samples} samples, stopping audio");
+        }
+
+        // 4. audio-stop: non-streaming servers only start transcribing now
+        write
+            .write_all(&audio_stop_event())
+            .await
+            .context("Failed to send audio-stop")?;
+
+        let transcript_result =
+            timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS), read_handle).await;
+        let _ = write.shutdown().await;
+
+        match transcript_result {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => Err(anyhow!("Read task panicked: {e}")),
+            Err(_) => Err(anyhow!("Timeout waiting for transcription result")),
+        }
+    }
+
+    /// Transcribe audio from a channel of f32 samples (16kHz mono)
+    ///
+    /// Reconnects and replays the audio if the connection drops
+    /// (see `realtime_resume`).
+    pub async fn transcribe_stream(
+        url: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+    ) -> Result<String> {
+        transcribe_resumable(&Self, url, audio_rx, language, partial_tx).await
+    }
+}
+
+/// Wait for the final `transcript` event
+///
+/// Text from `transcript-chunk` events (streaming servers) is forwarded to
+/// `partial_tx` as it arrives.
+async fn collect_transcript(
+    mut reader: tokio::io::BufReader<tokio::net::tcp::OwnedReadHalf>,
+    partial_tx: Option<PartialTranscriptSender>,
+) -> Result<String> {
+    let mut streamed = String::new();
+
+    while let Some(event) = read_event(&mut reader).await? {
+        match event.kind.as_str() {
+            "transcript-chunk" => {
+                if let Some(text) = event.text("text") {
+                    streamed.push_str(text);
+                    if let Some(tx) = &partial_tx {
+                        let _ = tx.send(streamed.trim().to_string());
+                    }
+                }
+            }
+            "transcript" => {
+                if crate::verbose::is_verbose() {
+                    eprintln!("[wyoming-realtime] Transcript received");
+                }
+                return Ok(event.text("text").unwrap_or_default().trim().to_string());
+            }
+            "error" => return Err(server_error(&event)),
+            _ => {
+                // transcript-start, transcript-stop, info: nothing to do
+            }
+        }
+    }
+
+    Err(anyhow!(
+        "Wyoming server closed the connection without a transcript"
+    ))
+}
+
+#[async_trait]
+impl ResumableSession for WyomingRealtimeProvider {
+    fn batch_provider(&self) -> TranscriptionProvider {
+        TranscriptionProvider::WyomingRealtime
+    }
+
+    async fn run_session(
+        &self,
+        url: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+        _progress: SessionProgress,
+    ) -> Result<String> {
+        Self::stream_session(url, audio_rx, language, partial_tx).await
+    }
+}
+
+#[async_trait]
+impl RealtimeTranscriptionBackend for WyomingRealtimeProvider {
+    async fn transcribe_stream(
+        &self,
+        url: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        partial_tx: Option<PartialTranscriptSender>,
+    ) -> Result<String> {
+        transcribe_resumable(self, url, audio_rx, language, partial_tx).await
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for WyomingRealtimeProvider {
+    fn name(&self) -> &'static str {
+        "wyoming-realtime"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Wyoming Realtime"
+    }
+
+    /// For file input, fall back to a batch Wyoming transcription
+    fn transcribe_sync(
+        &self,
+        url: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        WyomingProvider.transcribe_sync(url, request)
+    }
+
+    /// For async file input, fall back to a batch Wyoming transcription
+    async fn transcribe_async(
+        &self,
+        client: &reqwest::Client,
+        url: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        WyomingProvider.transcribe_async(client, url, request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::test_wyoming::WyomingServer;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn streams_audio_and_forwards_transcript_chunks_as_partials() {
+        let server = WyomingServer::start(vec![
+            (
+                "audio-chunk",
+                vec![
+                    ("transcript-start", json!({})),
+                    ("transcript-chunk", json!({ "text": "hello" })),
+                    ("transcript-chunk", json!({ "text": " wor" })),
+                ],
+            ),
+            (
+                "audio-stop",
+                vec![
+                    ("transcript-chunk", json!({ "text": "ld" })),
+                    ("transcript-stop", json!({})),
+                    ("transcript", json!({ "text": " hello world " })),
+                ],
+            ),
+        ])
+        .await;
+        let (audio_tx, audio_rx) = mpsc::unbounded_channel();
+        let (partial_tx, mut partial_rx) = mpsc::unbounded_channel();
+
+        let url = server.url.clone();
+        let session = tokio::spawn(async move {
+            WyomingRealtimeProvider::stream_session(
+                &url,
+                audio_rx,
+                Some("en".to_string()),
+                Some(partial_tx),
+            )
+            .await
+        });
+
+        // Partials arrive while audio is still being recorded
+        audio_tx.send(vec![0.5; 2000]).unwrap();
+        assert_eq!(partial_rx.recv().await.unwrap(), "hello");
+        assert_eq!(partial_rx.recv().await.unwrap(), "hello wor");
+        audio_tx.send(Vec::new()).unwrap();
+        audio_tx.send(vec![-0.5; 1000]).unwrap();
+        drop(audio_tx);
+
+        assert_eq!(session.await.unwrap().unwrap(), "hello world");
+        assert_eq!(partial_rx.recv().await.unwrap(), "hello world");
+
+        let events = server.events();
+        let kinds: Vec<&str> = events.iter().map(|event| event.kind.as_str()).collect();
+        // 2000 samples become chunks of 1600 and 400; the empty batch is skipped
+        assert_eq!(
+            kinds,
+            [
+                "transcribe",
+                "audio-start",
+                "audio-chunk",
+                "audio-chunk",
+                "audio-chunk",
+                "audio-stop"
+            ]
+        );
+        assert_eq!(events[0].data["language"], "en");
+        let sizes: Vec<usize> = events[2..5].iter().map(|e| e.payload.len()).collect();
+        assert_eq!(sizes, [3200, 800, 2000]);
+        assert_eq!(&events[2].payload[..2], &16383i16.to_le_bytes());
+        assert_eq!(&events[4].payload[..2], &(-16383i16).to_le_bytes());
+    }
+
+    #[tokio::test]
+    async fn non_streaming_server_replies_after_audio_stop() {
+        let server = WyomingServer::start(vec![(
+            "audio-stop",
+            vec![("transcript", json!({ "text": "done" }))],
+        )])
+        .await;
+        let (audio_tx, audio_rx) = mpsc::unbounded_channel();
+        let (partial_tx, mut partial_rx) = mpsc::unbounded_channel();
+        audio_tx.send(vec![0.0; 100]).unwrap();
+        drop(audio_tx);
+
+        let text =
+            WyomingRealtimeProvider::stream_session(&server.url, audio_rx, None, Some(partial_tx))
+                .await
+                .unwrap();
+
+        assert_eq!(text, "done");
+        assert!(partial_rx.try_recv().is_err());
+        assert_eq!(server.events()[0].data, json!({}));
+    }
+
+    #[tokio::test]
+    async fn reports_server_errors() {
+        let server = WyomingServer::start(vec![(
+            "audio-start",
+            vec![("error", json!({ "text": "unsupported rate" }))],
+        )])
+        .await;
+        let (audio_tx, audio_rx) = mpsc::unbounded_channel();
+        drop(audio_tx);
+
+        let error = WyomingRealtimeProvider::stream_session(&server.url, audio_rx, None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Wyoming server error: unsupported rate");
+    }
+
+    #[tokio::test]
+    async fn fails_when_server_closes_without_transcript() {
+        let server = WyomingServer::start(vec![("audio-stop", Vec::new())]).await;
+        let (audio_tx, audio_rx) = mpsc::unbounded_channel();
+        drop(audio_tx);
+
+        let error = WyomingRealtimeProvider::stream_session(&server.url, audio_rx, None, None)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("without a transcript"));
+    }
+}
@@ -8,7 +8,7 @@
 //! Settings (Aggregate Root)
 //!   ├── Transcription  - Provider, API keys, local models
 //!   ├── PostProcessing - LLM processor, prompts
-//!   ├── Services       - Ollama, external services
+//!   ├── Services       - Ollama, local HTTP API, external services
 //!   ├── Shortcuts      - CLI and Desktop keyboard shortcuts
 //!   └── UI             - Clipboard, microphone, VAD, presets, bubble
 //! ```
@@ -44,11 +44,16 @@ mod store_adapter;
 mod transcription;
 mod ui;
 
-pub use post_processing::PostProcessingSettings;
-pub use services::{OllamaConfig, ServicesSettings};
+pub use post_processing::{AnthropicConfig, CustomEndpointConfig, PostProcessingSettings};
+pub use services::{HttpApiConfig, OllamaConfig, ServicesSettings};
 pub use shortcuts::{CliShortcutMode, ShortcutsSettings};
-pub use transcription::{LocalModelsConfig, TranscriptionSettings};
-pub use ui::{BubblePosition, BubbleSettings, ModelMemorySettings, UiSettings, VadSettings};
+pub use transcription::{
+    AssemblyAIConfig, DataRegion, LocalModelsConfig, RegionConfig, TranscriptionSettings,
+    WhisperTuning, WyomingConfig,
+};
+pub use ui::{
+    BubblePosition, BubbleSettings, ModelMemorySettings, PresetRule, UiSettings, VadSettings,
+};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -2,7 +2,6 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::config::TranscriptionProvider;
 use crate::post_processing::PostProcessor;
 
 /// Settings for post-processing transcripts with LLMs.
@@ -12,6 +11,11 @@ pub struct PostProcessingSettings {
     #[serde(default)]
     pub enabled: bool,
 
+    /// Interpret spoken formatting commands ("comma", "new line", "scratch that")
+    /// before any LLM step (works without an LLM)
+    #[serde(default)]
+    pub voice_commands: bool,
+
     /// LLM provider for post-processing (grammar, punctuation, filler word removal)
     #[serde(default = "default_processor")]
     pub processor: PostProcessor,
@@ -19,6 +23,81 @@ pub struct PostProcessingSettings {
     /// Custom prompt for post-processing (uses default if None)
     #[serde(default)]
     pub prompt: Option<String>,
+
+    /// OpenAI-compatible endpoint used by the custom post-processor
+    #[serde(default)]
+    pub custom: CustomEndpointConfig,
+
+    /// Request options for the Anthropic post-processor
+    #[serde(default)]
+    pub anthropic: AnthropicConfig,
+}
+
+/// Request options for Claude via the Anthropic Messages API.
+///
+/// Unset fields use the built-in defaults. A preset's `model` takes
+/// precedence over `model` here.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnthropicConfig {
+    /// Model name (e.g., claude-haiku-4-5, claude-sonnet-4-5)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    /// Maximum number of tokens in the reply
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+
+    /// Sampling temperature (0.0 - 1.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+}
+
+impl AnthropicConfig {
+    /// Check the options are within the ranges the API accepts
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.max_tokens == Some(0) {
+            anyhow::bail!("max_tokens must be at least 1");
+        }
+        if let Some(t) = self.temperature
+            && !(0.0..=1.0).contains(&t)
+        {
+            anyhow::bail!("temperature must be between 0.0 and 1.0");
+        }
+        Ok(())
+    }
+}
+
+/// An OpenAI-compatible chat completions server (OpenRouter, LM Studio,
+/// llama.cpp server, vLLM, ...).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CustomEndpointConfig {
+    /// Base URL of the API, e.g. `http://localhost:1234/v1`
+    /// (`/chat/completions` is appended)
+    #[serde(default)]
+    pub base_url: Option<String>,
+
+    /// Model name sent with each request (presets can override it)
+    #[serde(default)]
+    pub model: Option<String>,
+
+    /// API key sent as a Bearer token (None for servers without auth)
+    #[serde(default)]
+    pub api_key: Option<String>,
+
+    /// Extra fields merged into the request body (e.g. temperature, top_p)
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub params: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CustomEndpointConfig {
+    /// Full chat completions URL, if a base URL is configured
+    pub fn chat_url(&self) -> Option<String> {
+        self.base_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(|url| format!("{}/chat/completions", url.trim_end_matches('/')))
+    }
 }
 
 fn default_processor() -> PostProcessor {
@@ -29,8 +108,11 @@ impl Default for PostProcessingSettings {
     fn default() -> Self {
         Self {
             enabled: false,
+            voice_commands: false,
             processor: crate::configuration::DEFAULT_POST_PROCESSOR,
             prompt: Some(crate::transcription::DEFAULT_POST_PROCESSING_PROMPT.to_string()),
+            custom: CustomEndpointConfig::default(),
+            anthropic: AnthropicConfig::default(),
         }
     }
 }
@@ -39,6 +121,7 @@ impl PostProcessingSettings {
     /// Get the API key for the post-processor, falling back to environment variables.
     ///
     /// Returns None for local post-processor (Ollama uses URL instead).
+    /// Custom endpoints without a key return an empty string once a base URL is set.
     pub fn api_key(
         &self,
         transcription_api_keys: &std::collections::HashMap<String, String>,
@@ -49,15 +132,9 @@ impl PostProcessingSettings {
         }
 
         // Fall back to environment variable
-        match &self.processor {
-            PostProcessor::None | PostProcessor::Ollama => None,
-            PostProcessor::OpenAI => {
-                std::env::var(TranscriptionProvider::OpenAI.api_key_env_var()).ok()
-            }
-            PostProcessor::Mistral => {
-                std::env::var(TranscriptionProvider::Mistral.api_key_env_var()).ok()
-            }
-        }
+        self.processor
+            .api_key_env_var()
+            .and_then(|var| std::env::var(var).ok())
     }
 
     /// Get the API key for the post-processor from settings only (no env var fallback).
@@ -68,9 +145,15 @@ impl PostProcessingSettings {
         transcription_api_keys: &std::collections::HashMap<String, String>,
     ) -> Option<String> {
         match &self.processor {
-            PostProcessor::None | PostProcessor::Ollama => None,
-            PostProcessor::OpenAI => transcription_api_keys.get("openai").cloned(),
-            PostProcessor::Mistral => transcription_api_keys.get("mistral").cloned(),
+            PostProcessor::Custom => self
+                .custom
+                .chat_url()
+                .map(|_| self.custom.api_key.clone().unwrap_or_default()),
+            processor => processor
+                .api_key_name()
+                .and_then(|name| transcription_api_keys.get(name))
+                .filter(|key| !key.is_empty())
+                .cloned(),
         }
     }
 
@@ -82,9 +165,7 @@ impl PostProcessingSettings {
         match &self.processor {
             PostProcessor::None => true,   // No post-processing always valid
             PostProcessor::Ollama => true, // Ollama URL checked in services
-            PostProcessor::OpenAI | PostProcessor::Mistral => {
-                self.api_key(transcription_api_keys).is_some()
-            }
+            _ => self.api_key(transcription_api_keys).is_some(),
         }
     }
 
@@ -94,13 +175,12 @@ impl PostProcessingSettings {
         transcription_api_keys: &std::collections::HashMap<String, String>,
     ) -> anyhow::Result<()> {
         if !self.is_configured(transcription_api_keys) {
+            if self.processor == PostProcessor::Custom {
+                anyhow::bail!("Custom post-processor requires a base URL. Please configure it.");
+            }
             anyhow::bail!(
                 "Post-processor '{}' requires an API key. Please configure it.",
-                match self.processor {
-                    PostProcessor::OpenAI => "OpenAI",
-                    PostProcessor::Mistral => "Mistral",
-                    _ => "unknown",
-                }
+                self.processor.display_name()
             );
         }
         Ok(())
@@ -1,4 +1,4 @@
-//! External service configuration (Ollama, etc.).
+//! External service configuration (Ollama, local HTTP API, etc.).
 
 use serde::{Deserialize, Serialize};
 
@@ -8,6 +8,10 @@ pub struct ServicesSettings {
     /// Ollama configuration for local LLM post-processing
     #[serde(default)]
     pub ollama: OllamaConfig,
+
+    /// Local HTTP API for browser extensions and editor plugins
+    #[serde(default)]
+    pub http_api: HttpApiConfig,
 }
 
 /// Configuration for Ollama local LLM service.
@@ -77,3 +81,55 @@ impl OllamaConfig {
         }
     }
 }
+
+/// Configuration for the local HTTP API (127.0.0.1 only).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpApiConfig {
+    /// Whether the background service / desktop app serves the API
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Port to listen on (default: 9447)
+    #[serde(default = "default_http_api_port")]
+    pub port: u16,
+
+    /// Bearer token clients must send in the `Authorization` header
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+fn default_http_api_port() -> u16 {
+    crate::configuration::DEFAULT_HTTP_API_PORT
+}
+
+impl Default for HttpApiConfig {
+    fn default() -> Self {
+        Self {
+            enabled: crate::configuration::DEFAULT_HTTP_API_ENABLED,
+            port: crate::configuration::DEFAULT_HTTP_API_PORT,
+            token: None,
+        }
+    }
+}
+
+impl HttpApiConfig {
+    /// Get the configured token, ignoring empty strings.
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref().filter(|t| !t.is_empty())
+    }
+
+    /// Generate a token if none is configured, returning the active token.
+    pub fn ensure_token(&mut self) -> anyhow::Result<&str> {
+        if self.token().is_none() {
+            self.token = Some(generate_token()?);
+        }
+        Ok(self.token.as_deref().unwrap_or_default())
+    }
+}
+
+/// Generate a random 128-bit hex token from the OS random source.
+fn generate_token() -> anyhow::Result<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes).map_err(|e| anyhow::anyhow!("Failed to generate token: {e}"))?;
+    Ok(bytes.iter().map(|b| format!("{b:02x}")).collect())
+}
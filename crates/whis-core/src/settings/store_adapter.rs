@@ -16,7 +16,13 @@
 //! groq_api_key          → transcription.api_keys["groq"]
 //! deepgram_api_key      → transcription.api_keys["deepgram"]
 //! elevenlabs_api_key    → transcription.api_keys["elevenlabs"]
+//! assemblyai_api_key    → transcription.api_keys["assemblyai"]
+//! speechmatics_api_key  → transcription.api_keys["speechmatics"]
+//! gladia_api_key        → transcription.api_keys["gladia"]
+//! anthropic_api_key     → transcription.api_keys["anthropic"]
+//! wyoming_url           → transcription.wyoming.url
 //! post_processor        → post_processing.processor
+//! voice_commands        → post_processing.voice_commands
 //! active_preset         → ui.active_preset
 //! ollama_url            → services.ollama.url
 //! ollama_model          → services.ollama.model
@@ -55,6 +61,10 @@ const API_KEY_MAPPINGS: &[(&str, &str)] = &[
     ("groq_api_key", "groq"),
     ("deepgram_api_key", "deepgram"),
     ("elevenlabs_api_key", "elevenlabs"),
+    ("assemblyai_api_key", "assemblyai"),
+    ("speechmatics_api_key", "speechmatics"),
+    ("gladia_api_key", "gladia"),
+    ("anthropic_api_key", "anthropic"),
 ];
 
 impl Settings {
@@ -92,6 +102,12 @@ impl Settings {
             }
         }
 
+        if let Some(Value::String(url)) = map.get("wyoming_url")
+            && !url.is_empty()
+        {
+            settings.transcription.wyoming.url = Some(url.clone());
+        }
+
         // Post-processing settings
         if let Some(Value::Bool(enabled)) = map.get("post_processing_enabled") {
             settings.post_processing.enabled = *enabled;
@@ -103,6 +119,10 @@ impl Settings {
             settings.post_processing.processor = p;
         }
 
+        if let Some(Value::Bool(voice_commands)) = map.get("voice_commands") {
+            settings.post_processing.voice_commands = *voice_commands;
+        }
+
         // UI settings
         if let Some(Value::String(preset)) = map.get("active_preset")
             && !preset.is_empty()
@@ -181,6 +201,10 @@ impl Settings {
             }
         }
 
+        if let Some(ref url) = self.transcription.wyoming.url {
+            map.insert("wyoming_url".to_string(), Value::String(url.clone()));
+        }
+
         // Post-processing settings
         map.insert(
             "post_processing_enabled".to_string(),
@@ -190,6 +214,10 @@ impl Settings {
             "post_processor".to_string(),
             Value::String(self.post_processing.processor.to_string()),
         );
+        map.insert(
+            "voice_commands".to_string(),
+            Value::Bool(self.post_processing.voice_commands),
+        );
 
         // UI settings
         if let Some(ref preset) = self.ui.active_preset {
@@ -242,6 +270,12 @@ impl Settings {
             "openai"
         } else if provider == "deepgram-realtime" {
             "deepgram"
+        } else if provider == "assemblyai-realtime" {
+            "assemblyai"
+        } else if provider == "speechmatics-realtime" {
+            "speechmatics"
+        } else if provider == "gladia-realtime" {
+            "gladia"
         } else {
             provider
         };
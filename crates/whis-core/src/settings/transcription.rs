@@ -4,9 +4,11 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::config::TranscriptionProvider;
+use crate::model::{ModelType, WhisperModel};
+use crate::provider::ProviderOptions;
 
 #[cfg(feature = "local-transcription")]
-use crate::model::{ModelType, ParakeetModel};
+use crate::model::ParakeetModel;
 
 /// Settings for transcription providers and models.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +29,18 @@ pub struct TranscriptionSettings {
     /// Local model configuration
     #[serde(default)]
     pub local_models: LocalModelsConfig,
+
+    /// AssemblyAI options (batch and streaming)
+    #[serde(default)]
+    pub assemblyai: AssemblyAIConfig,
+
+    /// Processing region for providers with regional endpoints
+    #[serde(default)]
+    pub regions: RegionConfig,
+
+    /// Wyoming ASR server (wyoming and wyoming-realtime providers)
+    #[serde(default)]
+    pub wyoming: WyomingConfig,
 }
 
 impl Default for TranscriptionSettings {
@@ -36,12 +50,15 @@ impl Default for TranscriptionSettings {
             language: crate::configuration::DEFAULT_LANGUAGE.map(String::from),
             api_keys: HashMap::new(),
             local_models: LocalModelsConfig::default(),
+            assemblyai: AssemblyAIConfig::default(),
+            regions: RegionConfig::default(),
+            wyoming: WyomingConfig::default(),
         }
     }
 }
 
 /// Configuration for local transcription models.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LocalModelsConfig {
     /// Path to whisper.cpp model file for local transcription
     /// (e.g., ~/.local/share/whis/models/ggml-small.bin)
@@ -52,6 +69,223 @@ pub struct LocalModelsConfig {
     /// (e.g., ~/.local/share/whis/models/parakeet/parakeet-tdt-0.6b-v3-int8)
     #[serde(default)]
     pub parakeet_path: Option<String>,
+
+    /// Inference tuning for local Whisper (presets can override individual fields)
+    #[serde(default)]
+    pub whisper: WhisperTuning,
+
+    /// Transcribe microphone recordings utterance by utterance at speech pauses
+    /// (realtime) instead of in fixed-length chunks. Off by default; enable
+    /// with `whis config local-streaming true`.
+    #[serde(default)]
+    pub streaming: bool,
+}
+
+/// Inference parameters for local Whisper.
+///
+/// Every field is optional: unset fields use the defaults below, and a preset's
+/// `whisper` section only overrides the fields it sets.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WhisperTuning {
+    /// CPU threads used for inference (default: whisper.cpp's choice, up to 4)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threads: Option<u32>,
+
+    /// Beam search width; 1 uses greedy decoding (default: 1, larger is slower)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beam_size: Option<u32>,
+
+    /// Initial sampling temperature (default: 0.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+
+    /// Temperature increase when decoding fails; 0 disables fallback (default: 0.2)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature_increment: Option<f32>,
+
+    /// Probability above which a segment is treated as silence (default: 0.2)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_speech_threshold: Option<f32>,
+
+    /// Suppress blank output at the start of segments (default: true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suppress_blank: Option<bool>,
+}
+
+impl WhisperTuning {
+    /// Default beam search width (greedy; beam search is opt-in)
+    pub const DEFAULT_BEAM_SIZE: u32 = 1;
+    /// Default initial temperature
+    pub const DEFAULT_TEMPERATURE: f32 = 0.0;
+    /// Default temperature fallback increment
+    pub const DEFAULT_TEMPERATURE_INCREMENT: f32 = 0.2;
+    /// Default no-speech probability threshold
+    pub const DEFAULT_NO_SPEECH_THRESHOLD: f32 = 0.2;
+
+    /// Apply `overrides` on top of these settings (set fields win).
+    pub fn with_overrides(&self, overrides: Option<&WhisperTuning>) -> WhisperTuning {
+        let Some(o) = overrides else {
+            return self.clone();
+        };
+
+        WhisperTuning {
+            threads: o.threads.or(self.threads),
+            beam_size: o.beam_size.or(self.beam_size),
+            temperature: o.temperature.or(self.temperature),
+            temperature_increment: o.temperature_increment.or(self.temperature_increment),
+            no_speech_threshold: o.no_speech_threshold.or(self.no_speech_threshold),
+            suppress_blank: o.suppress_blank.or(self.suppress_blank),
+        }
+    }
+
+    /// Validate value ranges.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.threads == Some(0) {
+            anyhow::bail!("whisper.threads must be at least 1");
+        }
+        if self.beam_size == Some(0) {
+            anyhow::bail!("whisper.beam_size must be at least 1 (1 = greedy)");
+        }
+        if let Some(t) = self.temperature
+            && !(0.0..=1.0).contains(&t)
+        {
+            anyhow::bail!("whisper.temperature must be between 0.0 and 1.0");
+        }
+        if let Some(t) = self.temperature_increment
+            && !(0.0..=1.0).contains(&t)
+        {
+            anyhow::bail!("whisper.temperature_increment must be between 0.0 and 1.0");
+        }
+        if let Some(t) = self.no_speech_threshold
+            && !(0.0..=1.0).contains(&t)
+        {
+            anyhow::bail!("whisper.no_speech_threshold must be between 0.0 and 1.0");
+        }
+        Ok(())
+    }
+}
+
+/// AssemblyAI transcription options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AssemblyAIConfig {
+    /// Label speakers ("Speaker A: ...") in batch transcripts
+    #[serde(default)]
+    pub speaker_labels: bool,
+
+    /// Words and phrases to boost (names, jargon), sent as keyterm prompts
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keyterms: Vec<String>,
+}
+
+impl AssemblyAIConfig {
+    /// Most keyterms accepted by the streaming API (batch accepts more)
+    pub const MAX_KEYTERMS: usize = 100;
+
+    /// Longest keyterm phrase accepted by AssemblyAI, in words
+    pub const MAX_KEYTERM_WORDS: usize = 6;
+
+    /// Check the keyterms against AssemblyAI's limits
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.keyterms.len() > Self::MAX_KEYTERMS {
+            anyhow::bail!(
+                "assemblyai.keyterms has {} entries (max {})",
+                self.keyterms.len(),
+                Self::MAX_KEYTERMS
+            );
+        }
+        for term in &self.keyterms {
+            let words = term.split_whitespace().count();
+            if words == 0 {
+                anyhow::bail!("assemblyai.keyterms contains an empty entry");
+            }
+            if words > Self::MAX_KEYTERM_WORDS {
+                anyhow::bail!(
+                    "assemblyai keyterm '{}' is too long (max {} words)",
+                    term,
+                    Self::MAX_KEYTERM_WORDS
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Where a provider processes (and stores) audio.
+///
+/// Defaults to the EU so recordings stay in the EU unless the user opts out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataRegion {
+    #[default]
+    Eu,
+    Us,
+}
+
+impl DataRegion {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Eu => "eu",
+            Self::Us => "us",
+        }
+    }
+}
+
+impl std::fmt::Display for DataRegion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for DataRegion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "eu" => Ok(Self::Eu),
+            "us" => Ok(Self::Us),
+            _ => Err(format!("Unknown region: {s}. Available: eu, us")),
+        }
+    }
+}
+
+/// Region selection for providers with regional endpoints.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RegionConfig {
+    /// Speechmatics batch jobs and realtime sessions
+    #[serde(default)]
+    pub speechmatics: DataRegion,
+    /// Gladia live sessions (pre-recorded jobs only run in the EU and refuse `us`)
+    #[serde(default)]
+    pub gladia: DataRegion,
+}
+
+impl RegionConfig {
+    /// Region for a provider, or `None` if it has no region choice
+    pub fn get(&self, provider: &TranscriptionProvider) -> Option<DataRegion> {
+        match provider.api_key_name() {
+            "speechmatics" => Some(self.speechmatics),
+            "gladia" => Some(self.gladia),
+            _ => None,
+        }
+    }
+
+    /// Set the region for a provider (ignored if it has no region choice)
+    pub fn set(&mut self, provider: &TranscriptionProvider, region: DataRegion) {
+        match provider.api_key_name() {
+            "speechmatics" => self.speechmatics = region,
+            "gladia" => self.gladia = region,
+            _ => {}
+        }
+    }
+}
+
+/// Configuration for a Wyoming ASR server on the network.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WyomingConfig {
+    /// Server address (e.g., tcp://homeassistant.local:10300).
+    /// Falls back to the WYOMING_URL environment variable.
+    #[serde(default)]
+    pub url: Option<String>,
 }
 
 impl TranscriptionSettings {
@@ -83,7 +317,12 @@ impl TranscriptionSettings {
     }
 
     /// Get the API key for a specific provider from settings only (no env var fallback).
+    ///
+    /// Wyoming providers receive the server address in place of an API key.
     pub fn api_key_from_settings_for(&self, provider: &TranscriptionProvider) -> Option<String> {
+        if provider.api_key_name() == "wyoming" {
+            return self.wyoming.url.clone().filter(|u| !u.is_empty());
+        }
         self.api_keys
             .get(provider.api_key_name())
             .filter(|k| !k.is_empty())
@@ -92,19 +331,21 @@ impl TranscriptionSettings {
 
     /// Check if an API key is explicitly configured in settings (not just in environment).
     ///
-    /// Returns true only if the key exists in the api_keys HashMap.
+    /// Returns true only if the key exists in the api_keys HashMap
+    /// (for Wyoming: if the server address is set).
     /// Use this to distinguish between:
     /// - [configured]: Key in settings.json
     /// - [available]: Key only in environment variable
     pub fn has_configured_api_key(&self, provider: &TranscriptionProvider) -> bool {
-        self.api_keys
-            .get(provider.api_key_name())
-            .map(|k| !k.is_empty())
-            .unwrap_or(false)
+        self.api_key_from_settings_for(provider).is_some()
     }
 
-    /// Set the API key for a provider.
+    /// Set the API key for a provider (for Wyoming: the server address).
     pub fn set_api_key(&mut self, provider: &TranscriptionProvider, key: String) {
+        if provider.api_key_name() == "wyoming" {
+            self.wyoming.url = Some(key);
+            return;
+        }
         self.api_keys
             .insert(provider.api_key_name().to_string(), key);
     }
@@ -117,13 +358,15 @@ impl TranscriptionSettings {
     /// Check if the current provider is properly configured.
     ///
     /// For cloud providers: checks for API key
-    /// For LocalWhisper: checks for model path AND that file exists
+    /// For Wyoming: checks for server address
+    /// For LocalWhisper: checks for model path AND that the file is complete
+    /// (exact catalog size, so a truncated download doesn't count)
     /// For LocalParakeet: checks for model directory AND it's valid
     pub fn is_configured(&self) -> bool {
         match self.provider {
             TranscriptionProvider::LocalWhisper => self
                 .whisper_model_path()
-                .map(|p| std::path::Path::new(&p).exists())
+                .map(|p| WhisperModel.verify(std::path::Path::new(&p)))
                 .unwrap_or(false),
             #[cfg(feature = "local-transcription")]
             TranscriptionProvider::LocalParakeet => self
@@ -136,6 +379,27 @@ impl TranscriptionSettings {
         }
     }
 
+    /// Whether microphone recordings should stream through a realtime backend.
+    ///
+    /// True for realtime cloud providers, and for local providers when
+    /// `local_models.streaming` is enabled (and the build supports it).
+    pub fn uses_realtime(&self) -> bool {
+        if self.provider.is_local() {
+            return self.local_models.streaming
+                && cfg!(all(feature = "realtime", feature = "local-transcription"));
+        }
+        crate::provider::is_realtime_provider(&self.provider)
+    }
+
+    /// Provider options to send with transcription requests.
+    pub fn provider_options(&self) -> ProviderOptions {
+        ProviderOptions {
+            whisper: self.local_models.whisper.clone(),
+            assemblyai: self.assemblyai.clone(),
+            regions: self.regions.clone(),
+        }
+    }
+
     /// Get the whisper model path, falling back to environment variable.
     pub fn whisper_model_path(&self) -> Option<String> {
         self.local_models
@@ -160,6 +424,8 @@ impl TranscriptionSettings {
                 self.provider
             );
         }
+        self.local_models.whisper.validate()?;
+        self.assemblyai.validate()?;
         Ok(())
     }
 }
@@ -2,13 +2,14 @@
 //!
 //! This module contains settings for:
 //! - Audio recording configuration (microphone, VAD, chunking)
-//! - Output handling (clipboard backend, presets)
+//! - Output handling (clipboard backend, presets, per-app preset rules)
 //! - Desktop-specific features (floating bubble overlay)
 //!
 //! Note: Keyboard shortcuts are now in the `shortcuts` module.
 
 use serde::{Deserialize, Serialize};
 
+use crate::active_window::ActiveWindow;
 #[cfg(feature = "clipboard")]
 use crate::clipboard::ClipboardMethod;
 
@@ -56,6 +57,16 @@ pub struct UiSettings {
     #[serde(default)]
     pub active_preset: Option<String>,
 
+    /// Presets picked automatically for the focused application.
+    ///
+    /// Checked in order when a recording starts; the first rule matching
+    /// the focused window wins. Without a match (or when the focused window
+    /// can't be detected) the active preset is used.
+    ///
+    /// Example: `[{ "app": "thunderbird", "preset": "email" }]`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub preset_rules: Vec<PresetRule>,
+
     /// Audio chunk duration for progressive transcription (seconds).
     ///
     /// During recording, audio is split into chunks and transcribed
@@ -84,6 +95,38 @@ pub struct UiSettings {
     pub model_memory: ModelMemorySettings,
 }
 
+/// Maps a focused application or window title to a preset.
+///
+/// `app` and `title` match case-insensitive substrings of the window's
+/// application id (Wayland app_id or X11 class) and title. When both are set,
+/// both must match; a rule with neither never matches.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PresetRule {
+    /// Application to match (e.g. "thunderbird", "code", "kitty")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<String>,
+
+    /// Window title to match (e.g. "Inbox")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Preset to use when the rule matches
+    pub preset: String,
+}
+
+impl PresetRule {
+    /// Whether this rule applies to a window
+    pub fn matches(&self, window: &ActiveWindow) -> bool {
+        let contains = |pattern: &Option<String>, value: &str| match pattern.as_deref() {
+            Some(pattern) => value.to_lowercase().contains(&pattern.to_lowercase()),
+            None => true,
+        };
+        (self.app.is_some() || self.title.is_some())
+            && contains(&self.app, &window.app)
+            && contains(&self.title, &window.title)
+    }
+}
+
 fn default_chunk_duration() -> u64 {
     crate::configuration::DEFAULT_CHUNK_DURATION_SECS
 }
@@ -215,6 +258,16 @@ impl Default for ModelMemorySettings {
     }
 }
 
+impl UiSettings {
+    /// Preset for a window: the first matching rule, else the active preset
+    pub fn preset_for_window(&self, window: Option<&ActiveWindow>) -> Option<String> {
+        window
+            .and_then(|window| self.preset_rules.iter().find(|rule| rule.matches(window)))
+            .map(|rule| rule.preset.clone())
+            .or_else(|| self.active_preset.clone())
+    }
+}
+
 impl Default for UiSettings {
     fn default() -> Self {
         Self {
@@ -223,6 +276,7 @@ impl Default for UiSettings {
             microphone_device: None,
             vad: VadSettings::default(),
             active_preset: None,
+            preset_rules: Vec::new(),
             chunk_duration_secs: crate::configuration::DEFAULT_CHUNK_DURATION_SECS,
             bubble: BubbleSettings::default(),
             model_memory: ModelMemorySettings::default(),
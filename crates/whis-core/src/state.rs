@@ -7,3 +7,94 @@ pub enum RecordingState {
     Recording,
     Transcribing,
 }
+
+impl RecordingState {
+    /// Lowercase name used by external interfaces (HTTP API, D-Bus)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::Recording => "recording",
+            Self::Transcribing => "transcribing",
+        }
+    }
+}
+
+/// Recording control command received from an external interface (HTTP API, D-Bus)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteCommand {
+    /// Start recording, or stop and transcribe if already recording
+    Toggle,
+    /// Start recording
+    Start,
+    /// Stop recording and transcribe
+    Stop,
+    /// Stop recording and discard the audio
+    Cancel,
+}
+
+/// What a front end does with an accepted [`RemoteCommand`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteAction {
+    /// Start or stop recording, as a shortcut press would
+    Toggle,
+    /// Stop recording and discard the audio
+    Cancel,
+}
+
+impl RemoteCommand {
+    /// Decide how to handle the command in `state`, or why it is rejected
+    ///
+    /// Shared by every front end so the HTTP API and D-Bus behave the same.
+    pub fn resolve(self, state: RecordingState) -> Result<RemoteAction, String> {
+        match (self, state) {
+            (_, RecordingState::Transcribing) => Err("Transcription in progress".to_string()),
+            (Self::Toggle, _)
+            | (Self::Start, RecordingState::Idle)
+            | (Self::Stop, RecordingState::Recording) => Ok(RemoteAction::Toggle),
+            (Self::Cancel, RecordingState::Recording) => Ok(RemoteAction::Cancel),
+            (Self::Start, _) => Err("Already recording".to_string()),
+            (_, _) => Err("Not recording".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands_resolve_against_the_current_state() {
+        use RecordingState::*;
+
+        assert_eq!(RemoteCommand::Start.resolve(Idle), Ok(RemoteAction::Toggle));
+        assert_eq!(
+            RemoteCommand::Stop.resolve(Recording),
+            Ok(RemoteAction::Toggle)
+        );
+        assert_eq!(
+            RemoteCommand::Toggle.resolve(Recording),
+            Ok(RemoteAction::Toggle)
+        );
+        assert_eq!(
+            RemoteCommand::Cancel.resolve(Recording),
+            Ok(RemoteAction::Cancel)
+        );
+
+        assert_eq!(
+            RemoteCommand::Start.resolve(Recording),
+            Err("Already recording".to_string())
+        );
+        assert_eq!(
+            RemoteCommand::Stop.resolve(Idle),
+            Err("Not recording".to_string())
+        );
+        assert_eq!(
+            RemoteCommand::Cancel.resolve(Idle),
+            Err("Not recording".to_string())
+        );
+        assert_eq!(
+            RemoteCommand::Toggle.resolve(Transcribing),
+            Err("Transcription in progress".to_string())
+        );
+    }
+}
@@ -0,0 +1,60 @@
+//! Line readers for streamed LLM responses
+//!
+//! Chat APIs stream replies either as Server-Sent Events (OpenAI-compatible
+//! servers, Anthropic) or as newline-delimited JSON (Ollama). Both are line
+//! based, so the response body is split into lines and handed to a callback:
+//!
+//! ```text
+//! SSE:    data: {"choices":[{"delta":{"content":"Hel"}}]}
+//!         data: [DONE]
+//! NDJSON: {"message":{"content":"Hel"},"done":false}
+//! ```
+
+use anyhow::Result;
+use futures_util::StreamExt;
+
+/// Whether to keep reading after a line
+pub(crate) enum Flow {
+    Continue,
+    Stop,
+}
+
+/// Feed each line of a streamed response body to `on_line`
+///
+/// Lines are passed without their trailing `\r\n`/`\n`; empty lines are
+/// skipped. A final line without a newline is still delivered.
+pub(crate) async fn read_lines(
+    response: reqwest::Response,
+    mut on_line: impl FnMut(&str) -> Result<Flow>,
+) -> Result<()> {
+    let mut stream = response.bytes_stream();
+    let mut buffer: Vec<u8> = Vec::new();
+
+    while let Some(chunk) = stream.next().await {
+        buffer.extend_from_slice(&chunk?);
+
+        while let Some(pos) = buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\r', '\n']);
+            if line.is_empty() {
+                continue;
+            }
+            if let Flow::Stop = on_line(line)? {
+                return Ok(());
+            }
+        }
+    }
+
+    let rest = String::from_utf8_lossy(&buffer);
+    let rest = rest.trim();
+    if !rest.is_empty() {
+        on_line(rest)?;
+    }
+    Ok(())
+}
+
+/// Payload of an SSE `data:` line (None for `event:`, `id:` and comments)
+pub(crate) fn sse_data(line: &str) -> Option<&str> {
+    line.strip_prefix("data:").map(str::trim_start)
+}
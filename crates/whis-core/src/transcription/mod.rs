@@ -4,12 +4,17 @@
 //! - Progressive transcription functions (cloud and local)
 //! - Ollama integration for local LLM
 //! - Post-processing with LLM cleanup
+//! - Structured (JSON schema) post-processing replies
+//! - Spoken punctuation/formatting commands
 //! - Connection warmup utilities
 
+mod llm_stream;
 mod ollama;
 mod ollama_manager;
 mod post_processing;
+mod structured_output;
 mod transcribe;
+mod voice_commands;
 mod warmup;
 
 pub use ollama::{
@@ -19,9 +24,12 @@ pub use ollama::{
 };
 pub use ollama_manager::{clear_warmup_cache, preload_ollama};
 pub use post_processing::{
-    DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessor, post_process,
+    DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessDeltaSender, PostProcessor,
+    post_process, post_process_streaming, post_process_structured,
 };
 pub use transcribe::progressive_transcribe_cloud;
 #[cfg(feature = "local-transcription")]
 pub use transcribe::progressive_transcribe_local;
+pub(crate) use transcribe::{ChunkTranscription, merge_transcriptions};
+pub use voice_commands::apply_voice_commands;
 pub use warmup::{WarmupConfig, warmup_configured};
@@ -7,31 +7,51 @@
 //!
 //! - **OpenAI** - GPT models via chat completions API
 //! - **Mistral** - Mistral models via chat completions API
+//! - **Groq** - Fast open-weight models via Groq's OpenAI-compatible API
+//! - **Anthropic** - Claude models via the native Messages API
 //! - **Ollama** - Local LLMs (no API key required, just server URL)
+//! - **Custom** - Any OpenAI-compatible server (OpenRouter, LM Studio,
+//!   llama.cpp server, vLLM), configured in `post_processing.custom`
 //! - **None** - Pass through without processing
 //!
 //! # Usage
 //!
 //! ```ignore
-//! use whis_core::post_processing::{post_process, PostProcessor};
+//! use whis_core::post_processing::{post_process, PostProcessConfig, PostProcessor};
 //!
-//! let cleaned = post_process(
-//!     "um so like I was thinking...",
-//!     &PostProcessor::OpenAI,
-//!     "sk-...",
-//!     "Clean up this transcript",
-//!     None,
-//! ).await?;
+//! let config = PostProcessConfig {
+//!     processor: PostProcessor::OpenAI,
+//!     prompt: "Clean up this transcript".to_string(),
+//!     api_key_or_url: "sk-...".to_string(),
+//!     model: None,
+//!     ollama_keep_alive: String::new(),
+//!     custom: Default::default(),
+//!     anthropic: Default::default(),
+//! };
+//! let cleaned = post_process("um so like I was thinking...", &config).await?;
 //! ```
+//!
+//! `post_process_streaming` returns the same result but also sends the reply
+//! text as it is generated (SSE for cloud and custom servers, NDJSON for Ollama).
+//!
+//! `post_process_structured` asks for a JSON reply matching a schema (see
+//! `structured_output`) and returns the parsed value.
 
 use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use tokio::sync::mpsc;
 
+use super::llm_stream::{Flow, read_lines, sse_data};
+use super::structured_output;
 use crate::http::get_http_client;
+use crate::settings::{AnthropicConfig, CustomEndpointConfig};
 
 const OPENAI_CHAT_URL: &str = "https://api.openai.com/v1/chat/completions";
 const MISTRAL_CHAT_URL: &str = "https://api.mistral.ai/v1/chat/completions";
+const GROQ_CHAT_URL: &str = "https://api.groq.com/openai/v1/chat/completions";
+const ANTHROPIC_MESSAGES_URL: &str = "https://api.anthropic.com/v1/messages";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
 const DEFAULT_TIMEOUT_SECS: u64 = 60;
 
 pub const DEFAULT_POST_PROCESSING_PROMPT: &str = "Clean up this voice transcript. \
@@ -46,7 +66,10 @@ pub enum PostProcessor {
     None,
     OpenAI,
     Mistral,
+    Groq,
+    Anthropic,
     Ollama,
+    Custom,
 }
 
 impl Default for PostProcessor {
@@ -61,7 +84,10 @@ impl fmt::Display for PostProcessor {
             PostProcessor::None => write!(f, "none"),
             PostProcessor::OpenAI => write!(f, "openai"),
             PostProcessor::Mistral => write!(f, "mistral"),
+            PostProcessor::Groq => write!(f, "groq"),
+            PostProcessor::Anthropic => write!(f, "anthropic"),
             PostProcessor::Ollama => write!(f, "ollama"),
+            PostProcessor::Custom => write!(f, "custom"),
         }
     }
 }
@@ -74,9 +100,12 @@ impl std::str::FromStr for PostProcessor {
             "none" => Ok(PostProcessor::None),
             "openai" => Ok(PostProcessor::OpenAI),
             "mistral" => Ok(PostProcessor::Mistral),
+            "groq" => Ok(PostProcessor::Groq),
+            "anthropic" => Ok(PostProcessor::Anthropic),
             "ollama" => Ok(PostProcessor::Ollama),
+            "custom" => Ok(PostProcessor::Custom),
             _ => Err(format!(
-                "Unknown post-processor: {}. Use 'none', 'openai', 'mistral', or 'ollama'",
+                "Unknown post-processor: {}. Use 'none', 'openai', 'mistral', 'groq', 'anthropic', 'ollama', or 'custom'",
                 s
             )),
         }
@@ -85,8 +114,47 @@ impl std::str::FromStr for PostProcessor {
 
 impl PostProcessor {
     /// Returns true if this post-processor requires an API key (cloud providers)
+    ///
+    /// Custom endpoints may or may not need a key, so they are not included.
     pub fn requires_api_key(&self) -> bool {
-        matches!(self, PostProcessor::OpenAI | PostProcessor::Mistral)
+        self.api_key_name().is_some()
+    }
+
+    /// Human-readable name for display
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PostProcessor::None => "None",
+            PostProcessor::OpenAI => "OpenAI",
+            PostProcessor::Mistral => "Mistral",
+            PostProcessor::Groq => "Groq",
+            PostProcessor::Anthropic => "Anthropic",
+            PostProcessor::Ollama => "Ollama",
+            PostProcessor::Custom => "Custom (OpenAI-compatible)",
+        }
+    }
+
+    /// Key in `TranscriptionSettings.api_keys` holding this post-processor's key
+    ///
+    /// OpenAI, Mistral and Groq share their key with transcription.
+    pub fn api_key_name(&self) -> Option<&'static str> {
+        match self {
+            PostProcessor::OpenAI => Some("openai"),
+            PostProcessor::Mistral => Some("mistral"),
+            PostProcessor::Groq => Some("groq"),
+            PostProcessor::Anthropic => Some("anthropic"),
+            PostProcessor::None | PostProcessor::Ollama | PostProcessor::Custom => None,
+        }
+    }
+
+    /// Environment variable used as a fallback for the API key
+    pub fn api_key_env_var(&self) -> Option<&'static str> {
+        match self {
+            PostProcessor::OpenAI => Some("OPENAI_API_KEY"),
+            PostProcessor::Mistral => Some("MISTRAL_API_KEY"),
+            PostProcessor::Groq => Some("GROQ_API_KEY"),
+            PostProcessor::Anthropic => Some("ANTHROPIC_API_KEY"),
+            PostProcessor::None | PostProcessor::Ollama | PostProcessor::Custom => None,
+        }
     }
 }
 
@@ -96,16 +164,22 @@ impl PostProcessor {
 /// replacing ad-hoc tuples for better readability and maintainability.
 #[derive(Debug, Clone)]
 pub struct PostProcessConfig {
-    /// Which post-processor to use (OpenAI, Mistral, Ollama, None)
+    /// Which post-processor to use (OpenAI, Mistral, Groq, Anthropic, Ollama, Custom, None)
     pub processor: PostProcessor,
     /// System prompt for the LLM
     pub prompt: String,
-    /// API key (for OpenAI/Mistral) or server URL (for Ollama)
+    /// API key (for cloud and custom providers, may be empty for custom) or
+    /// server URL (for Ollama)
     pub api_key_or_url: String,
-    /// Ollama model name (only used when processor is Ollama)
-    pub ollama_model: Option<String>,
+    /// Model override (Ollama: the model to run; others: falls back to the
+    /// provider default when None)
+    pub model: Option<String>,
     /// Ollama keep_alive duration (only used when processor is Ollama)
     pub ollama_keep_alive: String,
+    /// Endpoint of the Custom post-processor (`post_processing.custom` in settings)
+    pub custom: CustomEndpointConfig,
+    /// Anthropic model, max_tokens and temperature (`post_processing.anthropic`)
+    pub anthropic: AnthropicConfig,
 }
 
 #[derive(Debug, Deserialize)]
@@ -123,97 +197,471 @@ struct Message {
     content: String,
 }
 
-/// Post-process (clean up) a transcript using the specified LLM provider
+/// Channel receiving post-processing output as it streams in
+///
+/// Each message is the next piece of text (a delta, not the text so far).
+pub type PostProcessDeltaSender = mpsc::UnboundedSender<String>;
+
+/// Accumulates streamed text and forwards each piece to the delta channel
+struct StreamedText<'a> {
+    text: String,
+    delta_tx: &'a PostProcessDeltaSender,
+}
+
+impl<'a> StreamedText<'a> {
+    fn new(delta_tx: &'a PostProcessDeltaSender) -> Self {
+        Self {
+            text: String::new(),
+            delta_tx,
+        }
+    }
+
+    fn push(&mut self, delta: &str) {
+        if delta.is_empty() {
+            return;
+        }
+        self.text.push_str(delta);
+        let _ = self.delta_tx.send(delta.to_string());
+    }
+}
+
+/// Post-process (clean up) a transcript using the configured LLM provider
 ///
-/// For cloud providers (OpenAI, Mistral), `api_key_or_url` is the API key.
-/// For Ollama, `api_key_or_url` is the server URL (e.g., http://localhost:11434).
-pub async fn post_process(
+/// `config.prompt` is the system prompt. For cloud providers (OpenAI, Mistral,
+/// Groq, Anthropic), `config.api_key_or_url` is the API key. For Ollama it is
+/// the server URL (e.g., http://localhost:11434). For Custom it is the API key
+/// (empty for servers without auth) and the endpoint is `config.custom`.
+/// Anthropic request options come from `config.anthropic`.
+pub async fn post_process(text: &str, config: &PostProcessConfig) -> Result<String> {
+    run(text, config, &config.prompt, None, None).await
+}
+
+/// Post-process a transcript, sending the reply to `delta_tx` as it streams in
+///
+/// Takes the same arguments as [`post_process`] and returns the complete text
+/// once the reply has finished. With `PostProcessor::None` the input text is
+/// returned without sending anything.
+pub async fn post_process_streaming(
     text: &str,
-    post_processor: &PostProcessor,
-    api_key_or_url: &str,
+    config: &PostProcessConfig,
+    delta_tx: &PostProcessDeltaSender,
+) -> Result<String> {
+    run(text, config, &config.prompt, None, Some(delta_tx)).await
+}
+
+/// Post-process a transcript into JSON matching `schema`
+///
+/// Takes the same arguments as [`post_process`]. Providers with a JSON mode
+/// are asked to follow the schema; the reply is validated and, if it is not
+/// valid JSON for the schema, requested once more with the problem explained.
+pub async fn post_process_structured(
+    text: &str,
+    config: &PostProcessConfig,
+    schema: &serde_json::Value,
+) -> Result<serde_json::Value> {
+    if config.processor == PostProcessor::None {
+        return Err(anyhow!("Structured output requires a post-processor"));
+    }
+
+    let prompt = format!(
+        "{}\n\n{}",
+        config.prompt,
+        structured_output::instructions(schema)
+    );
+    let reply = run(text, config, &prompt, Some(schema), None).await?;
+
+    let problem = match structured_output::parse_reply(&reply, schema) {
+        Ok(value) => return Ok(value),
+        Err(problem) => problem,
+    };
+    crate::verbose!("Structured reply rejected ({problem}), retrying once");
+
+    let retry_prompt = format!(
+        "{prompt}\n\nYour previous reply was rejected: {problem}. \
+        Reply again with only the corrected JSON."
+    );
+    let reply = run(text, config, &retry_prompt, Some(schema), None).await?;
+    structured_output::parse_reply(&reply, schema).map_err(|problem| {
+        anyhow!(
+            "{} did not return valid structured output: {}",
+            config.processor.display_name(),
+            problem
+        )
+    })
+}
+
+/// Dispatch to the provider with `prompt`, streaming when `delta_tx` is set
+/// and requesting JSON when `schema` is set
+async fn run(
+    text: &str,
+    config: &PostProcessConfig,
     prompt: &str,
-    model: Option<&str>,
+    schema: Option<&serde_json::Value>,
+    delta_tx: Option<&PostProcessDeltaSender>,
 ) -> Result<String> {
-    match post_processor {
+    let api_key_or_url = config.api_key_or_url.as_str();
+    let model = config.model.as_deref();
+    match config.processor {
         PostProcessor::None => Ok(text.to_string()),
-        PostProcessor::OpenAI => post_process_openai(text, api_key_or_url, prompt, model).await,
-        PostProcessor::Mistral => post_process_mistral(text, api_key_or_url, prompt, model).await,
-        PostProcessor::Ollama => post_process_ollama(text, api_key_or_url, prompt, model).await,
+        PostProcessor::OpenAI => {
+            let model = model.unwrap_or(DEFAULT_OPENAI_MODEL);
+            ChatRequest {
+                response_format: schema.map(structured_output::json_schema_format),
+                ..ChatRequest::new("OpenAI", OPENAI_CHAT_URL, api_key_or_url, model)
+            }
+            .send(prompt, text, delta_tx)
+            .await
+        }
+        PostProcessor::Mistral => {
+            let model = model.unwrap_or(DEFAULT_MISTRAL_MODEL);
+            ChatRequest {
+                response_format: schema.map(structured_output::json_schema_format),
+                ..ChatRequest::new("Mistral", MISTRAL_CHAT_URL, api_key_or_url, model)
+            }
+            .send(prompt, text, delta_tx)
+            .await
+        }
+        PostProcessor::Groq => {
+            // Only some Groq models support json_schema; JSON mode works on all
+            let model = model.unwrap_or(DEFAULT_GROQ_MODEL);
+            ChatRequest {
+                response_format: schema.map(|_| structured_output::json_object_format()),
+                ..ChatRequest::new("Groq", GROQ_CHAT_URL, api_key_or_url, model)
+            }
+            .send(prompt, text, delta_tx)
+            .await
+        }
+        PostProcessor::Anthropic => {
+            // No JSON mode: the schema is only in the prompt
+            post_process_anthropic(
+                text,
+                api_key_or_url,
+                prompt,
+                &config.anthropic,
+                model,
+                delta_tx,
+            )
+            .await
+        }
+        PostProcessor::Ollama => {
+            post_process_ollama(text, api_key_or_url, prompt, model, schema, delta_tx).await
+        }
+        PostProcessor::Custom => {
+            post_process_custom(
+                text,
+                &config.custom,
+                api_key_or_url,
+                prompt,
+                model,
+                schema,
+                delta_tx,
+            )
+            .await
+        }
     }
 }
 
 const DEFAULT_OPENAI_MODEL: &str = "gpt-5-nano";
+const DEFAULT_MISTRAL_MODEL: &str = "mistral-small-latest";
+const DEFAULT_GROQ_MODEL: &str = "llama-3.1-8b-instant";
+const DEFAULT_ANTHROPIC_MODEL: &str = "claude-haiku-4-5";
+const DEFAULT_ANTHROPIC_MAX_TOKENS: u32 = 4096;
+
+/// A request to an OpenAI-compatible chat completions endpoint
+struct ChatRequest<'a> {
+    /// Provider name for error messages
+    name: &'a str,
+    url: &'a str,
+    /// Sent as a Bearer token unless empty
+    api_key: &'a str,
+    model: &'a str,
+    /// Extra body fields (custom endpoints only)
+    params: Option<&'a serde_json::Map<String, serde_json::Value>>,
+    /// JSON output format (structured-output presets)
+    response_format: Option<serde_json::Value>,
+}
+
+impl<'a> ChatRequest<'a> {
+    fn new(name: &'a str, url: &'a str, api_key: &'a str, model: &'a str) -> Self {
+        Self {
+            name,
+            url,
+            api_key,
+            model,
+            params: None,
+            response_format: None,
+        }
+    }
+
+    /// Build the JSON body
+    ///
+    /// Extra params go in first so they cannot replace the model or messages.
+    fn body(&self, system_prompt: &str, text: &str) -> serde_json::Value {
+        let mut body = self.params.cloned().unwrap_or_default();
+        body.insert("model".to_string(), self.model.into());
+        body.insert(
+            "messages".to_string(),
+            serde_json::json!([
+                {"role": "system", "content": system_prompt},
+                {"role": "user", "content": text}
+            ]),
+        );
+        if let Some(response_format) = &self.response_format {
+            body.insert("response_format".to_string(), response_format.clone());
+        }
+        serde_json::Value::Object(body)
+    }
+
+    async fn send(
+        &self,
+        system_prompt: &str,
+        text: &str,
+        delta_tx: Option<&PostProcessDeltaSender>,
+    ) -> Result<String> {
+        let mut body = self.body(system_prompt, text);
+        if delta_tx.is_some() {
+            body["stream"] = true.into();
+        }
+
+        let client = get_http_client()?;
+        let mut request = client
+            .post(self.url)
+            .json(&body)
+            .timeout(std::time::Duration::from_secs(DEFAULT_TIMEOUT_SECS));
+        if !self.api_key.is_empty() {
+            request = request.header("Authorization", format!("Bearer {}", self.api_key));
+        }
+
+        let response = request.send().await.map_err(|e| {
+            if e.is_connect() {
+                anyhow!("Cannot connect to {} at {}", self.name, self.url)
+            } else {
+                anyhow!("{} request failed: {}", self.name, e)
+            }
+        })?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await?;
+            return Err(anyhow!(
+                "{} post-processing failed: {}",
+                self.name,
+                error_text
+            ));
+        }
+
+        if let Some(delta_tx) = delta_tx {
+            return self.read_stream(response, delta_tx).await;
+        }
 
-async fn post_process_openai(
+        let chat_response: ChatResponse = response.json().await?;
+        chat_response
+            .choices
+            .first()
+            .map(|c| c.message.content.clone())
+            .ok_or_else(|| anyhow!("No response from {}", self.name))
+    }
+
+    /// Collect a streamed (SSE) chat completion
+    async fn read_stream(
+        &self,
+        response: reqwest::Response,
+        delta_tx: &PostProcessDeltaSender,
+    ) -> Result<String> {
+        let mut streamed = StreamedText::new(delta_tx);
+
+        read_lines(response, |line| {
+            let Some(data) = sse_data(line) else {
+                return Ok(Flow::Continue);
+            };
+            if data == "[DONE]" {
+                return Ok(Flow::Stop);
+            }
+
+            let event: serde_json::Value = serde_json::from_str(data)?;
+            if let Some(error) = event.get("error") {
+                let message = error["message"].as_str().unwrap_or("unknown error");
+                return Err(anyhow!("{} post-processing failed: {}", self.name, message));
+            }
+            if let Some(delta) = event["choices"][0]["delta"]["content"].as_str() {
+                streamed.push(delta);
+            }
+            Ok(Flow::Continue)
+        })
+        .await?;
+
+        if streamed.text.is_empty() {
+            return Err(anyhow!("No response from {}", self.name));
+        }
+        Ok(streamed.text)
+    }
+}
+
+/// Post-process with a user-configured OpenAI-compatible server
+///
+/// The model comes from the preset override, then from the endpoint config.
+async fn post_process_custom(
     text: &str,
+    endpoint: &CustomEndpointConfig,
     api_key: &str,
     system_prompt: &str,
     model: Option<&str>,
+    schema: Option<&serde_json::Value>,
+    delta_tx: Option<&PostProcessDeltaSender>,
 ) -> Result<String> {
-    let model = model.unwrap_or(DEFAULT_OPENAI_MODEL);
-    let client = get_http_client()?;
-    let response = client
-        .post(OPENAI_CHAT_URL)
-        .header("Authorization", format!("Bearer {}", api_key))
-        .json(&serde_json::json!({
-            "model": model,
-            "messages": [
-                {"role": "system", "content": system_prompt},
-                {"role": "user", "content": text}
-            ]
-        }))
-        .timeout(std::time::Duration::from_secs(DEFAULT_TIMEOUT_SECS))
-        .send()
-        .await?;
+    let url = endpoint
+        .chat_url()
+        .ok_or_else(|| anyhow!("Custom post-processor base URL not configured"))?;
+    let model = model
+        .or(endpoint.model.as_deref())
+        .ok_or_else(|| anyhow!("Custom post-processor model not configured"))?;
 
-    if !response.status().is_success() {
-        let error_text = response.text().await?;
-        return Err(anyhow!("OpenAI post-processing failed: {}", error_text));
-    }
+    let request = ChatRequest {
+        params: Some(&endpoint.params),
+        response_format: schema.map(structured_output::json_schema_format),
+        ..ChatRequest::new("Custom post-processor", &url, api_key, model)
+    };
+    request.send(system_prompt, text, delta_tx).await
+}
 
-    let chat_response: ChatResponse = response.json().await?;
-    chat_response
-        .choices
-        .first()
-        .map(|c| c.message.content.clone())
-        .ok_or_else(|| anyhow!("No response from OpenAI"))
+/// Anthropic Messages API response structure
+#[derive(Debug, Deserialize)]
+struct AnthropicResponse {
+    content: Vec<AnthropicContent>,
+    #[serde(default)]
+    stop_reason: Option<String>,
 }
 
-const DEFAULT_MISTRAL_MODEL: &str = "mistral-small-latest";
+#[derive(Debug, Deserialize)]
+struct AnthropicContent {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    text: String,
+}
 
-async fn post_process_mistral(
+#[derive(Debug, Deserialize)]
+struct AnthropicErrorResponse {
+    error: AnthropicError,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicError {
+    message: String,
+}
+
+/// Post-process with Claude through the Messages API
+///
+/// The model comes from the preset override, then from `options`.
+async fn post_process_anthropic(
     text: &str,
     api_key: &str,
     system_prompt: &str,
+    options: &AnthropicConfig,
     model: Option<&str>,
+    delta_tx: Option<&PostProcessDeltaSender>,
 ) -> Result<String> {
-    let model = model.unwrap_or(DEFAULT_MISTRAL_MODEL);
+    let model = model
+        .or(options.model.as_deref())
+        .unwrap_or(DEFAULT_ANTHROPIC_MODEL);
+
+    let mut body = serde_json::json!({
+        "model": model,
+        "max_tokens": options.max_tokens.unwrap_or(DEFAULT_ANTHROPIC_MAX_TOKENS),
+        "system": system_prompt,
+        "messages": [
+            {"role": "user", "content": text}
+        ]
+    });
+    if let Some(temperature) = options.temperature {
+        body["temperature"] = temperature.into();
+    }
+    if delta_tx.is_some() {
+        body["stream"] = true.into();
+    }
+
     let client = get_http_client()?;
     let response = client
-        .post(MISTRAL_CHAT_URL)
-        .header("Authorization", format!("Bearer {}", api_key))
-        .json(&serde_json::json!({
-            "model": model,
-            "messages": [
-                {"role": "system", "content": system_prompt},
-                {"role": "user", "content": text}
-            ]
-        }))
+        .post(ANTHROPIC_MESSAGES_URL)
+        .header("x-api-key", api_key)
+        .header("anthropic-version", ANTHROPIC_VERSION)
+        .json(&body)
         .timeout(std::time::Duration::from_secs(DEFAULT_TIMEOUT_SECS))
         .send()
         .await?;
 
     if !response.status().is_success() {
         let error_text = response.text().await?;
-        return Err(anyhow!("Mistral post-processing failed: {}", error_text));
+        let message = serde_json::from_str::<AnthropicErrorResponse>(&error_text)
+            .map(|e| e.error.message)
+            .unwrap_or(error_text);
+        return Err(anyhow!("Anthropic post-processing failed: {}", message));
     }
 
-    let chat_response: ChatResponse = response.json().await?;
-    chat_response
-        .choices
-        .first()
-        .map(|c| c.message.content.clone())
-        .ok_or_else(|| anyhow!("No response from Mistral"))
+    let (text, stop_reason) = match delta_tx {
+        Some(delta_tx) => read_anthropic_stream(response, delta_tx).await?,
+        None => {
+            let anthropic_response: AnthropicResponse = response.json().await?;
+            let text: String = anthropic_response
+                .content
+                .iter()
+                .filter(|block| block.kind == "text")
+                .map(|block| block.text.as_str())
+                .collect();
+            (text, anthropic_response.stop_reason)
+        }
+    };
+
+    if stop_reason.as_deref() == Some("max_tokens") && crate::verbose::is_verbose() {
+        eprintln!("[post-process] Anthropic reply hit max_tokens and may be cut off");
+    }
+    if text.is_empty() {
+        return Err(anyhow!("No response from Anthropic"));
+    }
+    Ok(text)
+}
+
+/// Collect a streamed Messages API reply, returning the text and stop reason
+///
+/// Text arrives in `content_block_delta` events; `message_delta` carries the
+/// stop reason and `message_stop` ends the stream.
+async fn read_anthropic_stream(
+    response: reqwest::Response,
+    delta_tx: &PostProcessDeltaSender,
+) -> Result<(String, Option<String>)> {
+    let mut streamed = StreamedText::new(delta_tx);
+    let mut stop_reason = None;
+
+    read_lines(response, |line| {
+        let Some(data) = sse_data(line) else {
+            return Ok(Flow::Continue);
+        };
+
+        let event: serde_json::Value = serde_json::from_str(data)?;
+        match event["type"].as_str() {
+            Some("content_block_delta") => {
+                if let Some(delta) = event["delta"]["text"].as_str() {
+                    streamed.push(delta);
+                }
+            }
+            Some("message_delta") => {
+                stop_reason = event["delta"]["stop_reason"].as_str().map(String::from);
+            }
+            Some("message_stop") => return Ok(Flow::Stop),
+            Some("error") => {
+                let message = event["error"]["message"]
+                    .as_str()
+                    .unwrap_or("unknown error");
+                return Err(anyhow!("Anthropic post-processing failed: {}", message));
+            }
+            _ => {
+                // message_start, content_block_start/stop, ping
+            }
+        }
+        Ok(Flow::Continue)
+    })
+    .await?;
+
+    Ok((streamed.text, stop_reason))
 }
 
 use super::ollama::{DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL};
@@ -234,6 +682,8 @@ async fn post_process_ollama(
     server_url: &str,
     system_prompt: &str,
     model: Option<&str>,
+    schema: Option<&serde_json::Value>,
+    delta_tx: Option<&PostProcessDeltaSender>,
 ) -> Result<String> {
     let model = model.unwrap_or(DEFAULT_OLLAMA_MODEL);
     let base_url = if server_url.is_empty() {
@@ -243,17 +693,23 @@ async fn post_process_ollama(
     };
     let url = format!("{}/api/chat", base_url.trim_end_matches('/'));
 
+    let mut body = serde_json::json!({
+        "model": model,
+        "messages": [
+            {"role": "system", "content": system_prompt},
+            {"role": "user", "content": text}
+        ],
+        "stream": delta_tx.is_some()
+    });
+    if let Some(schema) = schema {
+        // Ollama constrains the reply to a JSON schema given as `format`
+        body["format"] = schema.clone();
+    }
+
     let client = get_http_client()?;
     let response = client
         .post(&url)
-        .json(&serde_json::json!({
-            "model": model,
-            "messages": [
-                {"role": "system", "content": system_prompt},
-                {"role": "user", "content": text}
-            ],
-            "stream": false
-        }))
+        .json(&body)
         .timeout(std::time::Duration::from_secs(120)) // Longer timeout for local LLM
         .send()
         .await
@@ -273,6 +729,47 @@ async fn post_process_ollama(
         return Err(anyhow!("Ollama post-processing failed: {}", error_text));
     }
 
+    if let Some(delta_tx) = delta_tx {
+        return read_ollama_stream(response, delta_tx).await;
+    }
+
     let ollama_response: OllamaResponse = response.json().await?;
     Ok(ollama_response.message.content.trim().to_string())
 }
+
+/// Streamed Ollama chat line (NDJSON)
+#[derive(Debug, Deserialize)]
+struct OllamaStreamLine {
+    #[serde(default)]
+    message: Option<OllamaMessage>,
+    #[serde(default)]
+    done: bool,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+/// Collect a streamed Ollama reply (one JSON object per line)
+async fn read_ollama_stream(
+    response: reqwest::Response,
+    delta_tx: &PostProcessDeltaSender,
+) -> Result<String> {
+    let mut streamed = StreamedText::new(delta_tx);
+
+    read_lines(response, |line| {
+        let chunk: OllamaStreamLine = serde_json::from_str(line)?;
+        if let Some(error) = chunk.error {
+            return Err(anyhow!("Ollama post-processing failed: {}", error));
+        }
+        if let Some(message) = chunk.message {
+            streamed.push(&message.content);
+        }
+        Ok(if chunk.done {
+            Flow::Stop
+        } else {
+            Flow::Continue
+        })
+    })
+    .await?;
+
+    Ok(streamed.text.trim().to_string())
+}
@@ -0,0 +1,148 @@
+//! JSON replies for structured-output presets
+//!
+//! A preset with an output schema asks the LLM for JSON instead of text.
+//! Providers with a JSON mode get the schema in the request (OpenAI, Mistral
+//! and custom servers as `response_format`, Ollama as `format`); every
+//! provider also gets it in the system prompt. The reply is then parsed and
+//! checked against the schema.
+//!
+//! Validation covers the common subset of JSON Schema: `type`, `properties`,
+//! `required`, `items` and `enum`. Other keywords are accepted but ignored.
+
+use serde_json::{Map, Value};
+
+/// System prompt addition describing the expected reply
+pub(crate) fn instructions(schema: &Value) -> String {
+    format!(
+        "Reply with only a JSON value matching this JSON schema, \
+        without code fences or explanations:\n{schema}"
+    )
+}
+
+/// OpenAI-style `response_format` requesting replies that follow `schema`
+///
+/// Not strict: strict mode only accepts schemas where every property is
+/// required and `additionalProperties` is false.
+pub(crate) fn json_schema_format(schema: &Value) -> Value {
+    serde_json::json!({
+        "type": "json_schema",
+        "json_schema": {
+            "name": "output",
+            "schema": schema,
+            "strict": false
+        }
+    })
+}
+
+/// `response_format` for servers that only know plain JSON mode
+pub(crate) fn json_object_format() -> Value {
+    serde_json::json!({ "type": "json_object" })
+}
+
+/// Parse a reply and check it against `schema`
+///
+/// Tolerates a surrounding markdown code fence, which some models add even
+/// when told not to.
+pub(crate) fn parse_reply(reply: &str, schema: &Value) -> Result<Value, String> {
+    let json = strip_code_fence(reply.trim());
+    let value: Value =
+        serde_json::from_str(json).map_err(|e| format!("reply is not valid JSON ({e})"))?;
+    check(&value, schema, "$")?;
+    Ok(value)
+}
+
+/// Strip a ```` ```json ... ``` ```` fence around the reply, if any
+fn strip_code_fence(reply: &str) -> &str {
+    let Some(inner) = reply
+        .strip_prefix("```")
+        .and_then(|rest| rest.strip_suffix("```"))
+    else {
+        return reply;
+    };
+    // Drop the language tag on the opening line
+    match inner.split_once('\n') {
+        Some((tag, body)) if !tag.trim_start().starts_with(['{', '[']) => body.trim(),
+        _ => inner.trim(),
+    }
+}
+
+/// Whether `value` has the JSON Schema type `name`
+fn has_type(value: &Value, name: &str) -> bool {
+    match name {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        // Unknown type names are not ours to reject
+        _ => true,
+    }
+}
+
+/// Check `value` against `schema`; `path` locates it in error messages
+fn check(value: &Value, schema: &Value, path: &str) -> Result<(), String> {
+    let Some(schema) = schema.as_object() else {
+        return Ok(());
+    };
+
+    match schema.get("type") {
+        Some(Value::String(name)) if !has_type(value, name) => {
+            return Err(format!("{path} should be of type {name}"));
+        }
+        Some(Value::Array(names))
+            if !names
+                .iter()
+                .filter_map(Value::as_str)
+                .any(|name| has_type(value, name)) =>
+        {
+            return Err(format!("{path} has the wrong type"));
+        }
+        _ => {}
+    }
+
+    if let Some(Value::Array(allowed)) = schema.get("enum")
+        && !allowed.contains(value)
+    {
+        return Err(format!(
+            "{path} should be one of {}",
+            Value::from(allowed.clone())
+        ));
+    }
+
+    match value {
+        Value::Object(object) => check_object(object, schema, path),
+        Value::Array(items) => match schema.get("items") {
+            Some(item_schema) => items
+                .iter()
+                .enumerate()
+                .try_for_each(|(i, item)| check(item, item_schema, &format!("{path}[{i}]"))),
+            None => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+fn check_object(
+    object: &Map<String, Value>,
+    schema: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    if let Some(Value::Array(required)) = schema.get("required") {
+        for name in required.iter().filter_map(Value::as_str) {
+            if !object.contains_key(name) {
+                return Err(format!("{path} is missing required field '{name}'"));
+            }
+        }
+    }
+
+    if let Some(Value::Object(properties)) = schema.get("properties") {
+        for (name, property_schema) in properties {
+            if let Some(field) = object.get(name) {
+                check(field, property_schema, &format!("{path}.{name}"))?;
+            }
+        }
+    }
+    Ok(())
+}
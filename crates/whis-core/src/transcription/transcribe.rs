@@ -10,20 +10,20 @@ use anyhow::{Context, Result};
 
 use crate::config::TranscriptionProvider;
 use crate::http::get_http_client;
-use crate::provider::{TranscriptionRequest, registry};
+use crate::provider::{ProviderOptions, TranscriptionRequest, registry};
 
 /// Maximum words to search for overlap between chunks
 const MAX_OVERLAP_WORDS: usize = 15;
 
 /// Result of transcribing a single chunk
-struct ChunkTranscription {
-    index: usize,
-    text: String,
-    has_leading_overlap: bool,
+pub(crate) struct ChunkTranscription {
+    pub index: usize,
+    pub text: String,
+    pub has_leading_overlap: bool,
 }
 
 /// Merge transcription results, handling overlaps
-fn merge_transcriptions(transcriptions: Vec<ChunkTranscription>) -> String {
+pub(crate) fn merge_transcriptions(transcriptions: Vec<ChunkTranscription>) -> String {
     if transcriptions.is_empty() {
         return String::new();
     }
@@ -115,6 +115,12 @@ fn remove_overlap(existing: &str, new_text: &str) -> String {
 //
 
 use crate::audio::chunker::AudioChunk as ProgressiveChunk;
+#[cfg(feature = "local-transcription")]
+use crate::provider::TranscriptionResult;
+#[cfg(feature = "local-transcription")]
+use crate::settings::WhisperTuning;
+#[cfg(feature = "local-transcription")]
+use std::collections::HashMap;
 
 /// Progressive transcription for cloud providers
 ///
@@ -124,12 +130,14 @@ use crate::audio::chunker::AudioChunk as ProgressiveChunk;
 ///
 /// # Arguments
 /// * `provider` - The transcription provider to use
+/// * `options` - Provider options sent with each chunk's request
 /// * `api_key` - API key for the provider
 /// * `language` - Optional language hint
 /// * `chunk_rx` - Channel receiving audio chunks during recording
 /// * `progress_callback` - Optional progress reporting
 pub async fn progressive_transcribe_cloud(
     provider: &TranscriptionProvider,
+    options: &ProviderOptions,
     api_key: &str,
     language: Option<&str>,
     mut chunk_rx: tokio::sync::mpsc::UnboundedReceiver<ProgressiveChunk>,
@@ -146,17 +154,15 @@ pub async fn progressive_transcribe_cloud(
         let chunk_index = chunk.index;
         let has_leading_overlap = chunk.has_leading_overlap;
 
-        // Convert samples to MP3
-        let mp3_data =
-            samples_to_mp3(&chunk.samples).context("Failed to encode audio chunk to MP3")?;
-
-        let request = TranscriptionRequest {
-            audio_data: mp3_data,
-            language: language.map(|s| s.to_string()),
-            filename: format!("audio_chunk_{chunk_index}.mp3"),
-            mime_type: "audio/mpeg".to_string(),
-            progress: None,
-        };
+        // Encode samples (MP3, or WAV for raw-PCM providers)
+        let request = TranscriptionRequest::from_samples(
+            provider,
+            &chunk.samples,
+            language.map(|s| s.to_string()),
+            &format!("audio_chunk_{chunk_index}"),
+        )
+        .with_context(|| format!("Failed to encode audio chunk {chunk_index}"))?
+        .with_options(options.clone());
 
         let result = provider_impl
             .transcribe_async(client, api_key, request)
@@ -186,17 +192,32 @@ pub async fn progressive_transcribe_cloud(
 /// (sequential processing). The model is loaded once and reused to minimize memory
 /// usage (constant 2GB, compared to 6GB with the previous parallel worker architecture).
 ///
+/// When `language` is `None`, the result reports the language Whisper detected
+/// for most chunks.
+///
 /// # Arguments
-/// * `model_path` - Path to local model directory
+/// * `provider` - `LocalWhisper` or `LocalParakeet`
+/// * `model_path` - Path to the model file (Whisper) or directory (Parakeet)
+/// * `language` - Optional language hint (Whisper only)
+/// * `tuning` - Whisper inference parameters (settings merged with preset overrides)
 /// * `chunk_rx` - Channel receiving audio chunks during recording
 /// * `progress_callback` - Optional progress reporting
 #[cfg(feature = "local-transcription")]
 pub async fn progressive_transcribe_local(
+    provider: &TranscriptionProvider,
     model_path: &str,
+    language: Option<&str>,
+    tuning: &WhisperTuning,
     mut chunk_rx: tokio::sync::mpsc::UnboundedReceiver<ProgressiveChunk>,
     progress_callback: Option<Box<dyn Fn(usize, usize) + Send + Sync>>,
-) -> Result<String> {
+) -> Result<TranscriptionResult> {
+    let whisper = match provider {
+        TranscriptionProvider::LocalWhisper => true,
+        TranscriptionProvider::LocalParakeet => false,
+        other => anyhow::bail!("'{}' is not a local provider", other.as_str()),
+    };
     let mut transcriptions = Vec::new();
+    let mut languages: HashMap<String, usize> = HashMap::new();
     let mut chunk_count = 0;
 
     // Process chunks sequentially as they arrive (true progressive)
@@ -206,15 +227,29 @@ pub async fn progressive_transcribe_local(
         let has_leading_overlap = chunk.has_leading_overlap;
         let samples = chunk.samples;
         let model_path_owned = model_path.to_string();
+        let language = language.map(str::to_string);
+        let tuning = tuning.clone();
 
         // Run transcription in blocking task (CPU-bound work)
         let result = tokio::task::spawn_blocking(move || {
-            crate::provider::transcribe_raw_parakeet(&model_path_owned, samples)
+            if whisper {
+                crate::provider::transcribe_raw(
+                    &model_path_owned,
+                    &samples,
+                    language.as_deref(),
+                    &tuning,
+                )
+            } else {
+                crate::provider::transcribe_raw_parakeet(&model_path_owned, samples)
+            }
         })
         .await
         .context("Transcription task panicked")?
         .context("Transcription failed")?;
 
+        if let Some(detected) = result.language {
+            *languages.entry(detected).or_default() += 1;
+        }
         transcriptions.push(ChunkTranscription {
             index: chunk_index,
             text: result.text,
@@ -228,14 +263,12 @@ pub async fn progressive_transcribe_local(
     }
 
     // Results are already in correct order (sequential processing, no sorting needed)
-    Ok(merge_transcriptions(transcriptions))
-}
-
-/// Convert f32 samples to MP3 bytes
-fn samples_to_mp3(samples: &[f32]) -> Result<Vec<u8>> {
-    use crate::audio::create_encoder;
-    let encoder = create_encoder();
-    encoder
-        .encode_samples(samples, crate::resample::WHISPER_SAMPLE_RATE)
-        .context("Failed to encode audio to MP3")
+    Ok(TranscriptionResult {
+        text: merge_transcriptions(transcriptions),
+        language: languages
+            .into_iter()
+            .max_by_key(|(_, count)| *count)
+            .map(|(language, _)| language),
+        words: Vec::new(),
+    })
 }
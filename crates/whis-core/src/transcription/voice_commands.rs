@@ -0,0 +1,385 @@
+//! Spoken punctuation and formatting commands.
+//!
+//! Turns dictated commands into formatting without an LLM, e.g.
+//! "Dear Sam, comma, new line thanks. Period." → "Dear Sam,\nthanks.".
+//! Runs as a deterministic stage after the replacement dictionary and before
+//! any LLM post-processing.
+//!
+//! # Commands
+//!
+//! | English                      | German                         | Result                 |
+//! |------------------------------|--------------------------------|------------------------|
+//! | new line                     | neue Zeile                     | line break             |
+//! | new paragraph                | neuer Absatz                   | blank line             |
+//! | comma / period / full stop   | Komma / Punkt                  | `,` `.`                |
+//! | question mark                | Fragezeichen                   | `?`                    |
+//! | exclamation mark             | Ausrufezeichen                 | `!`                    |
+//! | colon / semicolon            | Doppelpunkt / Semikolon        | `:` `;`                |
+//! | open paren / close paren     | Klammer auf / Klammer zu       | `(` `)`                |
+//! | bullet                       | Aufzählungspunkt               | `- ` on a new line     |
+//! | all caps ... end caps        | alles groß ... Ende groß       | UPPERCASE in between   |
+//! | scratch that                 | streich das                    | delete previous phrase |
+//!
+//! Commands are matched on whole words, ignoring case and the punctuation the
+//! transcription model tends to add around them ("Hello, comma, world").
+//! Single-word commands are also ordinary words ("the period of time", "der
+//! Punkt"), so they only apply when spoken as an utterance of their own: with a
+//! pause (start, end, model-inserted punctuation or another command) on both
+//! sides. A bullet only needs the pause before it, as the item follows.
+//!
+//! The table is chosen from the transcription language, configured or detected
+//! by the provider (German for `de`, English otherwise).
+
+/// What a spoken command does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    /// Punctuation attached to the previous word
+    Punct(&'static str),
+    NewLine,
+    NewParagraph,
+    OpenParen,
+    CloseParen,
+    Bullet,
+    CapsOn,
+    CapsOff,
+    ScratchThat,
+}
+
+/// Spoken phrases (lowercase words) and their commands
+type CommandTable = &'static [(&'static [&'static str], Command)];
+
+const ENGLISH: CommandTable = &[
+    (&["new", "line"], Command::NewLine),
+    (&["newline"], Command::NewLine),
+    (&["new", "paragraph"], Command::NewParagraph),
+    (&["comma"], Command::Punct(",")),
+    (&["period"], Command::Punct(".")),
+    (&["full", "stop"], Command::Punct(".")),
+    (&["question", "mark"], Command::Punct("?")),
+    (&["exclamation", "mark"], Command::Punct("!")),
+    (&["exclamation", "point"], Command::Punct("!")),
+    (&["colon"], Command::Punct(":")),
+    (&["semicolon"], Command::Punct(";")),
+    (&["open", "paren"], Command::OpenParen),
+    (&["open", "parenthesis"], Command::OpenParen),
+    (&["close", "paren"], Command::CloseParen),
+    (&["close", "parenthesis"], Command::CloseParen),
+    (&["bullet", "point"], Command::Bullet),
+    (&["bullet"], Command::Bullet),
+    (&["all", "caps"], Command::CapsOn),
+    (&["end", "caps"], Command::CapsOff),
+    (&["scratch", "that"], Command::ScratchThat),
+];
+
+const GERMAN: CommandTable = &[
+    (&["neue", "zeile"], Command::NewLine),
+    (&["zeilenumbruch"], Command::NewLine),
+    (&["neuer", "absatz"], Command::NewParagraph),
+    (&["komma"], Command::Punct(",")),
+    (&["punkt"], Command::Punct(".")),
+    (&["fragezeichen"], Command::Punct("?")),
+    (&["ausrufezeichen"], Command::Punct("!")),
+    (&["doppelpunkt"], Command::Punct(":")),
+    (&["semikolon"], Command::Punct(";")),
+    (&["klammer", "auf"], Command::OpenParen),
+    (&["klammer", "zu"], Command::CloseParen),
+    (&["aufzählungspunkt"], Command::Bullet),
+    (&["spiegelstrich"], Command::Bullet),
+    (&["alles", "groß"], Command::CapsOn),
+    (&["ende", "groß"], Command::CapsOff),
+    (&["streich", "das"], Command::ScratchThat),
+    (&["lösch", "das"], Command::ScratchThat),
+];
+
+/// Command table for a transcription language (ISO 639-1, None = auto)
+fn table_for(language: Option<&str>) -> CommandTable {
+    match language.map(|l| l.to_lowercase()) {
+        Some(l) if l == "de" || l.starts_with("de-") || l.starts_with("de_") => GERMAN,
+        _ => ENGLISH,
+    }
+}
+
+/// Punctuation the transcription model adds that a spoken command replaces
+const SOFT_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?'];
+
+/// Lowercase a word and strip surrounding punctuation for matching
+fn normalize(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+/// Longest command starting at `words[0]`, with the number of words it spans
+fn match_command(table: CommandTable, words: &[String]) -> Option<(Command, usize)> {
+    table
+        .iter()
+        .filter(|(phrase, _)| {
+            phrase.len() <= words.len() && phrase.iter().zip(words).all(|(p, w)| p == w)
+        })
+        .max_by_key(|(phrase, _)| phrase.len())
+        .map(|(phrase, command)| (*command, phrase.len()))
+}
+
+/// Whether the single-word command at `words[i]` is spoken on its own
+///
+/// `after_command` is set when a command ended right before `words[i]`.
+fn is_utterance(
+    table: CommandTable,
+    command: Command,
+    words: &[&str],
+    normalized: &[String],
+    i: usize,
+    after_command: bool,
+) -> bool {
+    let pause_before = i == 0 || after_command || words[i - 1].ends_with(SOFT_PUNCTUATION);
+    let pause_after = i + 1 == words.len()
+        || words[i].ends_with(SOFT_PUNCTUATION)
+        || match_command(table, &normalized[i + 1..]).is_some();
+    pause_before && (pause_after || command == Command::Bullet)
+}
+
+/// Output being assembled, with the spacing/casing state between words
+struct Writer {
+    out: String,
+    /// Insert a space before the next word
+    space: bool,
+    /// Capitalize the next word (after `.`, `?`, `!`)
+    capitalize: bool,
+    /// Uppercase words until "end caps"
+    caps: bool,
+    /// Phrase boundaries (after commands and sentence ends) for "scratch that"
+    boundaries: Vec<usize>,
+}
+
+impl Writer {
+    fn word(&mut self, word: &str) {
+        if self.space && !self.out.is_empty() {
+            self.out.push(' ');
+        }
+        if self.caps {
+            self.out.push_str(&word.to_uppercase());
+        } else if self.capitalize {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                self.out.extend(first.to_uppercase());
+                self.out.push_str(chars.as_str());
+            }
+        } else {
+            self.out.push_str(word);
+        }
+        self.space = true;
+        self.capitalize = false;
+
+        // Model-inserted sentence ends also end a phrase
+        if word.ends_with(['.', '!', '?']) {
+            self.boundaries.push(self.out.len());
+        }
+    }
+
+    fn trim_end(&mut self) {
+        let len = self.out.trim_end_matches([' ', '\t']).len();
+        self.out.truncate(len);
+    }
+
+    fn command(&mut self, command: Command) {
+        match command {
+            Command::Punct(mark) => {
+                self.trim_end();
+                let len = self.out.trim_end_matches(SOFT_PUNCTUATION).len();
+                self.out.truncate(len);
+                self.out.push_str(mark);
+                self.space = true;
+                self.capitalize = matches!(mark, "." | "?" | "!");
+            }
+            Command::NewLine => {
+                self.trim_end();
+                self.out.push('\n');
+                self.space = false;
+            }
+            Command::NewParagraph => {
+                self.trim_end();
+                self.out.push_str("\n\n");
+                self.space = false;
+                self.capitalize = true;
+            }
+            Command::OpenParen => {
+                if self.space && !self.out.is_empty() {
+                    self.out.push(' ');
+                }
+                self.out.push('(');
+                self.space = false;
+            }
+            Command::CloseParen => {
+                self.trim_end();
+                self.out.push(')');
+                self.space = true;
+            }
+            Command::Bullet => {
+                self.trim_end();
+                if !self.out.is_empty() && !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+                self.out.push_str("- ");
+                self.space = false;
+                self.capitalize = true;
+            }
+            Command::CapsOn => self.caps = true,
+            Command::CapsOff => self.caps = false,
+            Command::ScratchThat => {
+                // Delete back to the last boundary before the current end, so
+                // "scratch that" right after a command removes the phrase before it
+                self.trim_end();
+                let end = self.out.len();
+                self.boundaries.retain(|&b| b < end);
+                let start = self.boundaries.last().copied().unwrap_or(0);
+                self.out.truncate(start);
+                self.trim_end();
+                self.space = !self.out.is_empty() && !self.out.ends_with(['\n', '(', ' ']);
+                self.capitalize = self.out.is_empty() || self.out.ends_with(['.', '!', '?']);
+                return;
+            }
+        }
+        self.boundaries.push(self.out.len());
+    }
+}
+
+/// Apply spoken formatting commands to a transcript
+///
+/// `language` selects the command table (ISO 639-1 code, None = English); pass
+/// the detected language when none is configured.
+pub fn apply_voice_commands(text: &str, language: Option<&str>) -> String {
+    let table = table_for(language);
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let normalized: Vec<String> = words.iter().map(|w| normalize(w)).collect();
+
+    let mut writer = Writer {
+        out: String::with_capacity(text.len()),
+        space: false,
+        capitalize: false,
+        caps: false,
+        boundaries: Vec::new(),
+    };
+
+    let mut i = 0;
+    let mut after_command = false;
+    while i < words.len() {
+        let command = match_command(table, &normalized[i..]).filter(|&(command, len)| {
+            len > 1 || is_utterance(table, command, &words, &normalized, i, after_command)
+        });
+        match command {
+            Some((command, len)) => {
+                writer.command(command);
+                i += len;
+            }
+            None => {
+                writer.word(words[i]);
+                i += 1;
+            }
+        }
+        after_command = command.is_some();
+    }
+
+    writer.trim_end();
+    writer.out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn en(text: &str) -> String {
+        apply_voice_commands(text, Some("en"))
+    }
+
+    fn de(text: &str) -> String {
+        apply_voice_commands(text, Some("de"))
+    }
+
+    #[test]
+    fn punctuation_spoken_on_its_own() {
+        assert_eq!(en("Hello, comma, world. Period."), "Hello, world.");
+        assert_eq!(en("Is it done? Question mark"), "Is it done?");
+        assert_eq!(en("Note, colon, buy milk"), "Note: buy milk");
+        assert_eq!(en("Stop. Full stop next"), "Stop. Next");
+    }
+
+    #[test]
+    fn single_words_inside_sentences_are_left_alone() {
+        assert_eq!(
+            en("The period of time with a comma in it"),
+            "The period of time with a comma in it"
+        );
+        assert_eq!(en("I love this period"), "I love this period");
+        assert_eq!(en("he dodged the bullet"), "he dodged the bullet");
+        assert_eq!(
+            de("Das ist der Punkt, an dem wir Komma sagen"),
+            "Das ist der Punkt, an dem wir Komma sagen"
+        );
+    }
+
+    #[test]
+    fn single_word_next_to_another_command() {
+        assert_eq!(en("Done period new line next"), "Done period\nnext");
+        assert_eq!(en("Done. Period new line next"), "Done.\nNext");
+        assert_eq!(en("Hi. Comma new line there"), "Hi,\nthere");
+    }
+
+    #[test]
+    fn line_breaks_and_paragraphs() {
+        assert_eq!(
+            en("Dear Sam, comma, new line thanks. Period."),
+            "Dear Sam,\nthanks."
+        );
+        assert_eq!(en("First. New paragraph second"), "First.\n\nSecond");
+    }
+
+    #[test]
+    fn bullets_start_a_new_line() {
+        assert_eq!(
+            en("Shopping list. Bullet milk bullet point eggs"),
+            "Shopping list.\n- Milk\n- Eggs"
+        );
+        assert_eq!(
+            de("Einkaufsliste: Aufzählungspunkt Milch"),
+            "Einkaufsliste:\n- Milch"
+        );
+    }
+
+    #[test]
+    fn parens_and_caps() {
+        assert_eq!(en("call it open paren soon close paren"), "call it (soon)");
+        assert_eq!(
+            en("this is all caps very important end caps okay"),
+            "this is VERY IMPORTANT okay"
+        );
+    }
+
+    #[test]
+    fn scratch_that_removes_the_previous_phrase() {
+        assert_eq!(
+            en("Meet at noon. Meet at one scratch that meet at two"),
+            "Meet at noon. Meet at two"
+        );
+        assert_eq!(en("wrong words scratch that right"), "Right");
+    }
+
+    #[test]
+    fn german_table_for_german_transcripts() {
+        assert_eq!(
+            de("Hallo, Komma, neue Zeile bis bald. Punkt."),
+            "Hallo,\nbis bald."
+        );
+        assert_eq!(
+            apply_voice_commands("Klammer auf ja Klammer zu", Some("de-DE")),
+            "(ja)"
+        );
+        // English commands are ordinary words in German transcripts, and vice versa
+        assert_eq!(de("new line"), "new line");
+        assert_eq!(en("neue Zeile"), "neue Zeile");
+    }
+
+    #[test]
+    fn english_without_a_language() {
+        assert_eq!(apply_voice_commands("a new line b", None), "a\nb");
+        assert_eq!(apply_voice_commands("a new line b", Some("fr")), "a\nb");
+    }
+}
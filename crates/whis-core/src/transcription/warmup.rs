@@ -18,6 +18,7 @@
 //!     provider_api_key: Some("sk-...".to_string()),
 //!     post_processor: Some("openai".to_string()),
 //!     post_processor_api_key: Some("sk-...".to_string()),
+//!     ..Default::default()
 //! };
 //!
 //! // Warm up in background (non-blocking)
@@ -39,6 +40,7 @@ use tokio_tungstenite::{
 };
 
 use crate::http::{get_http_client, warmup_http_client};
+use crate::settings::DataRegion;
 
 /// Warmup timeout for individual operations
 const WARMUP_TIMEOUT_SECS: u64 = 5;
@@ -48,12 +50,21 @@ const OPENAI_REALTIME_WS_URL: &str =
     "wss://api.openai.com/v1/realtime?model=gpt-4o-realtime-preview";
 const DEEPGRAM_REALTIME_WS_URL: &str =
     "wss://api.deepgram.com/v1/listen?model=nova-2&encoding=linear16&sample_rate=16000&channels=1";
+const ASSEMBLYAI_REALTIME_WS_URL: &str =
+    "wss://streaming.assemblyai.com/v3/ws?sample_rate=16000&encoding=pcm_s16le";
+const SPEECHMATICS_EU_REALTIME_WS_URL: &str = "wss://eu2.rt.speechmatics.com/v2";
+const SPEECHMATICS_US_REALTIME_WS_URL: &str = "wss://us2.rt.speechmatics.com/v2";
 
 /// HTTP endpoints for batch providers and post-processors
 const OPENAI_API_URL: &str = "https://api.openai.com";
 const DEEPGRAM_API_URL: &str = "https://api.deepgram.com";
+const ASSEMBLYAI_API_URL: &str = "https://api.assemblyai.com";
+const SPEECHMATICS_EU_API_URL: &str = "https://eu1.asr.api.speechmatics.com";
+const SPEECHMATICS_US_API_URL: &str = "https://us1.asr.api.speechmatics.com";
+const GLADIA_API_URL: &str = "https://api.gladia.io";
 const GROQ_API_URL: &str = "https://api.groq.com";
 const MISTRAL_API_URL: &str = "https://api.mistral.ai";
+const ANTHROPIC_API_URL: &str = "https://api.anthropic.com";
 
 /// Configuration for connection warmup.
 ///
@@ -64,11 +75,15 @@ pub struct WarmupConfig {
     pub provider: Option<String>,
     /// API key for the transcription provider
     pub provider_api_key: Option<String>,
+    /// Region of the Speechmatics endpoints to warm
+    pub speechmatics_region: DataRegion,
 
-    /// Post-processing provider name (e.g., "openai", "mistral")
+    /// Post-processing provider name (e.g., "openai", "mistral", "custom")
     pub post_processor: Option<String>,
-    /// API key for the post-processor
+    /// API key for the post-processor (may be empty for custom endpoints)
     pub post_processor_api_key: Option<String>,
+    /// Base URL of the custom post-processing endpoint
+    pub custom_base_url: Option<String>,
 }
 
 /// Warm up connections based on configuration.
@@ -98,8 +113,9 @@ pub async fn warmup_configured(config: &WarmupConfig) -> Result<()> {
     if let (Some(provider), Some(api_key)) = (&config.provider, &config.provider_api_key) {
         let provider = provider.clone();
         let api_key = api_key.clone();
+        let region = config.speechmatics_region;
         warmup_tasks.push(tokio::spawn(async move {
-            warmup_provider(&provider, &api_key).await
+            warmup_provider(&provider, &api_key, region).await
         }));
     }
 
@@ -111,8 +127,9 @@ pub async fn warmup_configured(config: &WarmupConfig) -> Result<()> {
         if processor != "none" {
             let processor = processor.clone();
             let api_key = api_key.clone();
+            let custom_base_url = config.custom_base_url.clone();
             warmup_tasks.push(tokio::spawn(async move {
-                warmup_post_processor(&processor, &api_key).await
+                warmup_post_processor(&processor, &api_key, custom_base_url.as_deref()).await
             }));
         }
     }
@@ -136,12 +153,21 @@ pub async fn warmup_configured(config: &WarmupConfig) -> Result<()> {
 
 /// Warm up a transcription provider.
 ///
-/// For realtime providers (openai-realtime, deepgram-realtime), establishes
-/// a WebSocket connection and immediately closes it to warm DNS/TLS.
+/// For realtime providers (openai-realtime, deepgram-realtime, assemblyai-realtime,
+/// speechmatics-realtime), establishes a WebSocket connection and immediately
+/// closes it to warm DNS/TLS. Gladia sessions need an HTTP init first, so
+/// gladia-realtime only warms the HTTP endpoint.
 ///
 /// For batch providers (openai, deepgram, groq), makes a HEAD request
 /// to warm HTTP connection.
-async fn warmup_provider(provider: &str, api_key: &str) -> Result<()> {
+///
+/// For Wyoming servers, checks the server answers a `describe` request.
+/// Speechmatics endpoints are warmed in `speechmatics_region`.
+async fn warmup_provider(
+    provider: &str,
+    api_key: &str,
+    speechmatics_region: DataRegion,
+) -> Result<()> {
     match provider {
         "openai-realtime" => {
             warmup_websocket_openai(api_key).await?;
@@ -149,6 +175,12 @@ async fn warmup_provider(provider: &str, api_key: &str) -> Result<()> {
         "deepgram-realtime" => {
             warmup_websocket_deepgram(api_key).await?;
         }
+        "assemblyai-realtime" => {
+            warmup_websocket_assemblyai(api_key).await?;
+        }
+        "speechmatics-realtime" => {
+            warmup_websocket_speechmatics(api_key, speechmatics_region).await?;
+        }
         "openai" => {
             warmup_http_endpoint(OPENAI_API_URL, Some(api_key), "Bearer").await?;
         }
@@ -158,6 +190,25 @@ async fn warmup_provider(provider: &str, api_key: &str) -> Result<()> {
         "groq" => {
             warmup_http_endpoint(GROQ_API_URL, Some(api_key), "Bearer").await?;
         }
+        "assemblyai" => {
+            // AssemblyAI takes the bare key (no scheme); DNS/TLS is all we need here
+            warmup_http_endpoint(ASSEMBLYAI_API_URL, None, "").await?;
+        }
+        "speechmatics" => {
+            let url = match speechmatics_region {
+                DataRegion::Eu => SPEECHMATICS_EU_API_URL,
+                DataRegion::Us => SPEECHMATICS_US_API_URL,
+            };
+            warmup_http_endpoint(url, Some(api_key), "Bearer").await?;
+        }
+        "gladia" | "gladia-realtime" => {
+            // Gladia authenticates with x-gladia-key; DNS/TLS is all we need here
+            warmup_http_endpoint(GLADIA_API_URL, None, "").await?;
+        }
+        "wyoming" | "wyoming-realtime" => {
+            // The "API key" is the server address
+            warmup_wyoming(api_key).await?;
+        }
         _ => {
             // Unknown provider or local - nothing to warm
             if crate::verbose::is_verbose() {
@@ -169,7 +220,13 @@ async fn warmup_provider(provider: &str, api_key: &str) -> Result<()> {
 }
 
 /// Warm up a post-processing provider (HTTP only).
-async fn warmup_post_processor(processor: &str, api_key: &str) -> Result<()> {
+///
+/// Custom endpoints are warmed at `custom_base_url`.
+async fn warmup_post_processor(
+    processor: &str,
+    api_key: &str,
+    custom_base_url: Option<&str>,
+) -> Result<()> {
     match processor {
         "openai" => {
             warmup_http_endpoint(OPENAI_API_URL, Some(api_key), "Bearer").await?;
@@ -177,6 +234,19 @@ async fn warmup_post_processor(processor: &str, api_key: &str) -> Result<()> {
         "mistral" => {
             warmup_http_endpoint(MISTRAL_API_URL, Some(api_key), "Bearer").await?;
         }
+        "groq" => {
+            warmup_http_endpoint(GROQ_API_URL, Some(api_key), "Bearer").await?;
+        }
+        "anthropic" => {
+            // Anthropic authenticates with x-api-key; DNS/TLS is all we need here
+            warmup_http_endpoint(ANTHROPIC_API_URL, None, "").await?;
+        }
+        "custom" => {
+            if let Some(url) = custom_base_url {
+                let key = Some(api_key).filter(|k| !k.is_empty());
+                warmup_http_endpoint(url.trim(), key, "Bearer").await?;
+            }
+        }
         "ollama" => {
             // Ollama is local, no warmup needed for network
             // Could potentially warmup local connection but usually instant
@@ -325,3 +395,137 @@ async fn warmup_websocket_deepgram(api_key: &str) -> Result<()> {
 
     Ok(())
 }
+
+/// Warm up AssemblyAI Realtime WebSocket connection.
+///
+/// Connects with authentication, then immediately closes.
+/// This warms DNS + TLS session cache.
+async fn warmup_websocket_assemblyai(api_key: &str) -> Result<()> {
+    let result = timeout(Duration::from_secs(WARMUP_TIMEOUT_SECS), async {
+        // AssemblyAI takes the bare API key as the Authorization header
+        let mut request = ASSEMBLYAI_REALTIME_WS_URL.into_client_request()?;
+        request
+            .headers_mut()
+            .insert(AUTHORIZATION, HeaderValue::from_str(api_key)?);
+
+        // Connect
+        let (ws_stream, _) = connect_async(request).await?;
+        let (mut write, _read) = ws_stream.split();
+
+        // End the session right away so no audio time is billed
+        let _ = write
+            .send(Message::Text(r#"{"type":"Terminate"}"#.to_string().into()))
+            .await;
+        let _ = write.send(Message::Close(None)).await;
+
+        if crate::verbose::is_verbose() {
+            eprintln!("[warmup] AssemblyAI WebSocket warmup succeeded");
+        }
+
+        Ok::<_, anyhow::Error>(())
+    })
+    .await;
+
+    match result {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => {
+            if crate::verbose::is_verbose() {
+                eprintln!(
+                    "[warmup] AssemblyAI WebSocket warmup failed (still warms DNS/TLS): {}",
+                    e
+                );
+            }
+        }
+        Err(_) => {
+            if crate::verbose::is_verbose() {
+                eprintln!("[warmup] AssemblyAI WebSocket warmup timeout");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Warm up Speechmatics Realtime WebSocket connection in `region`.
+///
+/// Connects with authentication, then immediately closes (before
+/// `StartRecognition`, so no session is started).
+async fn warmup_websocket_speechmatics(api_key: &str, region: DataRegion) -> Result<()> {
+    let url = match region {
+        DataRegion::Eu => SPEECHMATICS_EU_REALTIME_WS_URL,
+        DataRegion::Us => SPEECHMATICS_US_REALTIME_WS_URL,
+    };
+
+    let result = timeout(Duration::from_secs(WARMUP_TIMEOUT_SECS), async {
+        // Build request with auth headers
+        let mut request = url.into_client_request()?;
+        request.headers_mut().insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {}", api_key))?,
+        );
+
+        // Connect
+        let (ws_stream, _) = connect_async(request).await?;
+        let (mut write, _read) = ws_stream.split();
+
+        // Send close frame
+        let _ = write.send(Message::Close(None)).await;
+
+        if crate::verbose::is_verbose() {
+            eprintln!("[warmup] Speechmatics WebSocket warmup succeeded");
+        }
+
+        Ok::<_, anyhow::Error>(())
+    })
+    .await;
+
+    match result {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => {
+            if crate::verbose::is_verbose() {
+                eprintln!(
+                    "[warmup] Speechmatics WebSocket warmup failed (still warms DNS/TLS): {}",
+                    e
+                );
+            }
+        }
+        Err(_) => {
+            if crate::verbose::is_verbose() {
+                eprintln!("[warmup] Speechmatics WebSocket warmup timeout");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Warm up a Wyoming server with a `describe` → `info` round trip.
+///
+/// Checks the server is reachable before recording ends (TCP, no TLS).
+async fn warmup_wyoming(url: &str) -> Result<()> {
+    let result = timeout(
+        Duration::from_secs(WARMUP_TIMEOUT_SECS),
+        crate::provider::wyoming_describe(url),
+    )
+    .await;
+
+    match result {
+        Ok(Ok(())) => {
+            if crate::verbose::is_verbose() {
+                eprintln!("[warmup] Wyoming server warmup succeeded");
+            }
+        }
+        Ok(Err(e)) => {
+            if crate::verbose::is_verbose() {
+                eprintln!("[warmup] Wyoming server warmup failed: {}", e);
+            }
+        }
+        Err(_) => {
+            if crate::verbose::is_verbose() {
+                eprintln!("[warmup] Wyoming server warmup timeout");
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -17,7 +17,7 @@
 //! │   ├── whisper.rs     - Whisper model commands
 //! │   ├── parakeet.rs    - Parakeet model commands (feature-gated)
 //! │   └── mod.rs         - Public API
-//! ├── presets.rs         - Preset CRUD
+//! ├── presets.rs         - Preset CRUD, export/import
 //! ├── ollama.rs          - Ollama integration
 //! ├── bubble.rs          - Bubble overlay commands
 //! └── mod.rs             - Public API (this file)
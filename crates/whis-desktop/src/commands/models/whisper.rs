@@ -5,7 +5,7 @@
 use super::downloads::get_whisper_lock;
 use crate::state::AppState;
 use tauri::{AppHandle, Emitter, Manager, State};
-use whis_core::model::{ModelType, WhisperModel};
+use whis_core::model::{ModelType, SystemResources, WhisperModel};
 
 /// Progress event payload for model download
 #[derive(Clone, serde::Serialize)]
@@ -19,6 +19,10 @@ pub struct DownloadProgress {
 pub struct WhisperModelInfo {
     pub name: String,
     pub description: String,
+    pub family: String,
+    pub quantization: Option<String>,
+    pub size: String,
+    pub recommended: bool,
     pub installed: bool,
     pub path: String,
 }
@@ -47,10 +51,14 @@ pub async fn download_whisper_model(app: AppHandle, model_name: String) -> Resul
             total: 0,
         });
 
-        let path = WhisperModel.default_path(&model_name);
+        // Imported models are already on disk and can't be downloaded
+        let path = match whis_core::model::import::find(&WhisperModel, &model_name) {
+            Some(imported) if WhisperModel.find(&model_name).is_none() => imported.path,
+            _ => WhisperModel.default_path(&model_name),
+        };
 
-        // Skip download if model already exists
-        if path.exists() {
+        // Skip download if model is already complete
+        if WhisperModel.verify(&path) {
             // Clear download state
             *state.active_download.lock().unwrap() = None;
             return Ok(path.to_string_lossy().to_string());
@@ -81,20 +89,27 @@ pub async fn download_whisper_model(app: AppHandle, model_name: String) -> Resul
     .map_err(|e| e.to_string())?
 }
 
-/// Check if the configured whisper model path points to an existing file
+/// Check if the configured whisper model path points to a complete model file
 #[tauri::command]
 pub fn is_whisper_model_valid(state: State<'_, AppState>) -> bool {
     let settings = state.settings.lock().unwrap();
     settings
         .transcription
         .whisper_model_path()
-        .map(|p| std::path::Path::new(&p).exists())
+        .map(|p| WhisperModel.verify(std::path::Path::new(&p)))
         .unwrap_or(false)
 }
 
 /// Get available whisper models for download
+///
+/// Models come in catalog order (grouped by family), with one marked as
+/// recommended for this machine's RAM and CPU cores. Models imported with
+/// `whis model import` are listed last.
 #[tauri::command]
-pub fn get_whisper_models() -> Vec<WhisperModelInfo> {
+pub fn get_whisper_models(state: State<'_, AppState>) -> Vec<WhisperModelInfo> {
+    let english_only = state.with_settings(|s| s.transcription.language.as_deref() == Some("en"));
+    let recommended = whis_core::model::recommend_whisper(&SystemResources::detect(), english_only);
+
     WhisperModel
         .models()
         .iter()
@@ -103,9 +118,36 @@ pub fn get_whisper_models() -> Vec<WhisperModelInfo> {
             WhisperModelInfo {
                 name: model.name.to_string(),
                 description: model.description.to_string(),
-                installed: path.exists(),
+                family: model.family.to_string(),
+                quantization: model.quantization.map(String::from),
+                size: format_size_mb(model.size_mb.unwrap_or(0)),
+                recommended: model.name == recommended,
+                installed: WhisperModel.verify(&path),
                 path: path.to_string_lossy().to_string(),
             }
         })
+        .chain(
+            whis_core::model::import::list(&WhisperModel)
+                .into_iter()
+                .map(|model| WhisperModelInfo {
+                    description: format!("Imported from {}", model.source.display()),
+                    family: "imported".to_string(),
+                    quantization: None,
+                    size: format_size_mb(model.size_bytes / 1_000_000),
+                    recommended: false,
+                    installed: WhisperModel.verify(&model.path),
+                    path: model.path.to_string_lossy().to_string(),
+                    name: model.name,
+                }),
+        )
         .collect()
 }
+
+/// Format a model size for display ("574 MB", "1.6 GB")
+fn format_size_mb(mb: u64) -> String {
+    if mb >= 1000 {
+        format!("{:.1} GB", mb as f64 / 1000.0)
+    } else {
+        format!("{mb} MB")
+    }
+}
@@ -1,10 +1,11 @@
 //! Preset Management Commands
 //!
 //! Provides Tauri commands for managing transcription presets (built-in and user-created).
-//! Presets contain predefined prompts and post-processing configurations.
+//! Presets contain predefined prompts and post-processing configurations, and can be
+//! shared as JSON bundles (export/import).
 
 use crate::state::AppState;
-use tauri::State;
+use tauri::{AppHandle, State};
 
 /// Preset info for the UI
 #[derive(serde::Serialize)]
@@ -61,7 +62,11 @@ pub fn list_presets() -> Vec<PresetInfo> {
 
 /// Apply a preset - updates settings with the preset's configuration and sets it as active
 #[tauri::command]
-pub async fn apply_preset(name: String, state: State<'_, AppState>) -> Result<(), String> {
+pub async fn apply_preset(
+    name: String,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
     use whis_core::preset::Preset;
 
     let (preset, _) = Preset::load(&name)?;
@@ -80,11 +85,12 @@ pub async fn apply_preset(name: String, state: State<'_, AppState>) -> Result<()
         }
 
         // Set this preset as active
-        settings.ui.active_preset = Some(name);
+        settings.ui.active_preset = Some(name.clone());
 
         // Save the settings
         settings.save().map_err(|e| e.to_string())?;
     }
+    crate::dbus::publish_active_preset(&app, Some(&name));
 
     // Clear cached transcription config since settings changed
     *state.transcription_config.lock().unwrap() = None;
@@ -103,11 +109,15 @@ pub fn get_active_preset(state: State<'_, AppState>) -> Option<String> {
 #[tauri::command]
 pub async fn set_active_preset(
     name: Option<String>,
+    app: AppHandle,
     state: State<'_, AppState>,
 ) -> Result<(), String> {
-    let mut settings = state.settings.lock().unwrap();
-    settings.ui.active_preset = name;
-    settings.save().map_err(|e| e.to_string())?;
+    {
+        let mut settings = state.settings.lock().unwrap();
+        settings.ui.active_preset = name.clone();
+        settings.save().map_err(|e| e.to_string())?;
+    }
+    crate::dbus::publish_active_preset(&app, name.as_deref());
     Ok(())
 }
 
@@ -131,10 +141,11 @@ pub fn get_preset_details(name: String) -> Result<PresetDetails, String> {
 /// Create a new user preset
 #[tauri::command]
 pub fn create_preset(input: CreatePresetInput) -> Result<PresetInfo, String> {
-    use whis_core::preset::Preset;
+    use whis_core::preset::{Preset, validate_prompt};
 
-    // Validate name
+    // Validate name and prompt variables
     Preset::validate_name(&input.name, false)?;
+    validate_prompt(&input.prompt).map_err(|e| e.to_string())?;
 
     // Check if preset already exists
     if Preset::load(&input.name).is_ok() {
@@ -148,6 +159,10 @@ pub fn create_preset(input: CreatePresetInput) -> Result<PresetInfo, String> {
         prompt: input.prompt,
         post_processor: input.post_processor,
         model: input.model,
+        whisper: None,
+        steps: Vec::new(),
+        dictionary: Vec::new(),
+        output: None,
     };
 
     preset.save()?;
@@ -162,7 +177,7 @@ pub fn create_preset(input: CreatePresetInput) -> Result<PresetInfo, String> {
 /// Update an existing user preset
 #[tauri::command]
 pub fn update_preset(name: String, input: UpdatePresetInput) -> Result<PresetInfo, String> {
-    use whis_core::preset::Preset;
+    use whis_core::preset::{Preset, validate_prompt};
 
     // Check it's not a built-in
     if Preset::is_builtin(&name) {
@@ -172,6 +187,8 @@ pub fn update_preset(name: String, input: UpdatePresetInput) -> Result<PresetInf
     // Check preset exists
     let (mut preset, _) = Preset::load(&name)?;
 
+    validate_prompt(&input.prompt).map_err(|e| e.to_string())?;
+
     // Update fields
     preset.description = input.description.clone();
     preset.prompt = input.prompt;
@@ -190,7 +207,11 @@ pub fn update_preset(name: String, input: UpdatePresetInput) -> Result<PresetInf
 
 /// Delete a user preset
 #[tauri::command]
-pub fn delete_preset(name: String, state: State<'_, AppState>) -> Result<(), String> {
+pub fn delete_preset(
+    name: String,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
     use whis_core::preset::Preset;
 
     // Delete the preset file
@@ -202,8 +223,43 @@ pub fn delete_preset(name: String, state: State<'_, AppState>) -> Result<(), Str
         if settings.ui.active_preset.as_deref() == Some(&name) {
             settings.ui.active_preset = None;
             settings.save().map_err(|e| e.to_string())?;
+            crate::dbus::publish_active_preset(&app, None);
         }
     }
 
     Ok(())
 }
+
+/// Export presets as a shareable JSON bundle
+#[tauri::command]
+pub fn export_presets(names: Vec<String>) -> Result<String, String> {
+    use whis_core::preset::PresetBundle;
+
+    PresetBundle::export(&names)?.to_json()
+}
+
+/// Import presets from a JSON bundle
+#[tauri::command]
+pub async fn import_presets(
+    bundle: String,
+    on_conflict: whis_core::preset::ConflictPolicy,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Vec<whis_core::preset::ImportOutcome>, String> {
+    use whis_core::preset::{ImportOutcome, PresetBundle};
+
+    let outcomes = PresetBundle::from_json(&bundle)?.import(on_conflict)?;
+
+    // An overwritten active preset must be re-applied to settings (prompt and
+    // post-processor are copied there when a preset is applied)
+    let active_preset = state.settings.lock().unwrap().ui.active_preset.clone();
+    if let Some(active) = active_preset
+        && outcomes
+            .iter()
+            .any(|o| matches!(o, ImportOutcome::Overwritten { name } if *name == active))
+    {
+        apply_preset(active, app, state).await?;
+    }
+
+    Ok(outcomes)
+}
@@ -66,6 +66,9 @@ pub async fn save_settings(
         *state.transcription_config.lock().unwrap() = None;
     }
 
+    // Keep the HTTP API's `/transcribe` endpoint on the saved provider and keys
+    crate::http_api::publish_settings(&app, &settings.transcription);
+
     // Only update shortcut if it actually changed
     let needs_restart = if shortcut_changed {
         crate::shortcuts::update_shortcut(&app, &settings.shortcuts.desktop_key)
@@ -113,6 +116,9 @@ pub async fn check_config_readiness(
             let key_provider = match provider {
                 "openai-realtime" => "openai",
                 "deepgram-realtime" => "deepgram",
+                "assemblyai-realtime" => "assemblyai",
+                "speechmatics-realtime" => "speechmatics",
+                "gladia-realtime" => "gladia",
                 _ => provider,
             };
 
@@ -144,6 +150,22 @@ pub async fn check_config_readiness(
                 _ => (false, Some("Ollama not running".to_string())),
             }
         }
+        "custom" => {
+            let custom = Settings::load().post_processing.custom;
+            if custom.chat_url().is_none() {
+                (
+                    false,
+                    Some("Custom endpoint URL not configured".to_string()),
+                )
+            } else if custom.model.is_none() {
+                (
+                    false,
+                    Some("Custom endpoint model not configured".to_string()),
+                )
+            } else {
+                (true, None)
+            }
+        }
         post_processor => {
             if api_keys.get(post_processor).is_none_or(|k| k.is_empty()) {
                 (
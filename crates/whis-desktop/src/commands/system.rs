@@ -99,8 +99,10 @@ pub async fn warmup_connections() -> Result<(), String> {
     let config = WarmupConfig {
         provider,
         provider_api_key,
+        speechmatics_region: settings.transcription.regions.speechmatics,
         post_processor,
         post_processor_api_key,
+        custom_base_url: settings.post_processing.custom.base_url.clone(),
     };
 
     // Run warmup (best-effort, errors are logged but not propagated)
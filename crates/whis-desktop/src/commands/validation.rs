@@ -63,3 +63,18 @@ pub fn validate_deepgram_api_key(api_key: String) -> Result<bool, String> {
 pub fn validate_elevenlabs_api_key(api_key: String) -> Result<bool, String> {
     validate_key_format(&api_key, "ElevenLabs", None, Some(20))
 }
+
+#[tauri::command]
+pub fn validate_assemblyai_api_key(api_key: String) -> Result<bool, String> {
+    validate_key_format(&api_key, "AssemblyAI", None, Some(20))
+}
+
+#[tauri::command]
+pub fn validate_speechmatics_api_key(api_key: String) -> Result<bool, String> {
+    validate_key_format(&api_key, "Speechmatics", None, Some(20))
+}
+
+#[tauri::command]
+pub fn validate_gladia_api_key(api_key: String) -> Result<bool, String> {
+    validate_key_format(&api_key, "Gladia", None, Some(20))
+}
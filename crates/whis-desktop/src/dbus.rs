@@ -0,0 +1,84 @@
+//! D-Bus Recorder Bridge (Linux)
+//!
+//! Connects the shared `ink.whis.Recorder` D-Bus object (`whis_core::dbus`) to
+//! the desktop recording workflow, so GNOME Shell / KDE extensions and scripts
+//! can control recording over the session bus. Method calls are dispatched
+//! through the same `toggle_recording` entry point as shortcuts and the tray.
+//!
+//! The publish helpers are no-ops on other platforms, when the `dbus` feature
+//! is off, or when the bus name could not be acquired.
+
+#[cfg(all(feature = "dbus", target_os = "linux"))]
+use crate::state::AppState;
+use crate::state::RecordingState;
+use tauri::AppHandle;
+#[cfg(all(feature = "dbus", target_os = "linux"))]
+use tauri::Manager;
+
+/// Serve the recorder object on the session bus
+#[cfg(all(feature = "dbus", target_os = "linux"))]
+pub fn start(app: AppHandle) {
+    use whis_core::warn;
+
+    let active_preset = app
+        .state::<AppState>()
+        .with_settings(|s| s.ui.active_preset.clone());
+
+    tauri::async_runtime::spawn(async move {
+        let (handle, mut rx) = match whis_core::dbus::start_service(active_preset.as_deref()).await
+        {
+            Ok(service) => service,
+            Err(e) => {
+                warn!("D-Bus interface disabled: {e}");
+                return;
+            }
+        };
+
+        println!(
+            "D-Bus interface registered as {}",
+            whis_core::dbus::BUS_NAME
+        );
+        *app.state::<AppState>().dbus.lock().unwrap() = Some(handle);
+
+        while let Some(request) = rx.recv().await {
+            let result = crate::recording::handle_remote_command(&app, request.command);
+            let _ = request.reply.send(result);
+        }
+    });
+}
+
+#[cfg(not(all(feature = "dbus", target_os = "linux")))]
+pub fn start(_app: AppHandle) {}
+
+/// Publish a recording state change (`State` property)
+pub fn publish_state(app: &AppHandle, state: RecordingState) {
+    #[cfg(all(feature = "dbus", target_os = "linux"))]
+    if let Some(dbus) = app.state::<AppState>().dbus.lock().unwrap().as_ref() {
+        dbus.set_state(state);
+    }
+
+    #[cfg(not(all(feature = "dbus", target_os = "linux")))]
+    let _ = (app, state);
+}
+
+/// Publish the active preset (`ActivePreset` property)
+pub fn publish_active_preset(app: &AppHandle, preset: Option<&str>) {
+    #[cfg(all(feature = "dbus", target_os = "linux"))]
+    if let Some(dbus) = app.state::<AppState>().dbus.lock().unwrap().as_ref() {
+        dbus.set_active_preset(preset);
+    }
+
+    #[cfg(not(all(feature = "dbus", target_os = "linux")))]
+    let _ = (app, preset);
+}
+
+/// Publish a completed transcript (`TranscriptReady` signal)
+pub fn publish_transcript(app: &AppHandle, text: &str) {
+    #[cfg(all(feature = "dbus", target_os = "linux"))]
+    if let Some(dbus) = app.state::<AppState>().dbus.lock().unwrap().as_ref() {
+        dbus.transcript_ready(text);
+    }
+
+    #[cfg(not(all(feature = "dbus", target_os = "linux")))]
+    let _ = (app, text);
+}
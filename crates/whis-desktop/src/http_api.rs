@@ -0,0 +1,98 @@
+//! Local HTTP API Bridge
+//!
+//! Connects the shared HTTP API server (`whis_core::http_api`) to the desktop
+//! recording workflow. Commands from HTTP clients are dispatched through the same
+//! `toggle_recording` entry point as shortcuts and the tray, and state changes
+//! are published back to `/status` and `/events` subscribers.
+//!
+//! Opt-in via `services.http_api.enabled` in settings. The publish helpers are
+//! no-ops when the server is disabled or the `http-api` feature is off.
+
+#[cfg(feature = "http-api")]
+use crate::state::AppState;
+use crate::state::RecordingState;
+use tauri::AppHandle;
+#[cfg(feature = "http-api")]
+use tauri::Manager;
+
+/// Start the HTTP API server if enabled in settings
+#[cfg(feature = "http-api")]
+pub fn start(app: AppHandle) {
+    use whis_core::warn;
+
+    let (config, transcription) = app
+        .state::<AppState>()
+        .with_settings(|s| (s.services.http_api.clone(), s.transcription.clone()));
+    if !config.enabled {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        let (handle, mut rx) = match whis_core::http_api::start_server(&config, transcription).await
+        {
+            Ok(server) => server,
+            Err(e) => {
+                warn!("HTTP API disabled: {e}");
+                return;
+            }
+        };
+
+        println!("HTTP API listening on http://127.0.0.1:{}", config.port);
+        *app.state::<AppState>().http_api.lock().unwrap() = Some(handle);
+
+        while let Some(request) = rx.recv().await {
+            let result = crate::recording::handle_remote_command(&app, request.command);
+            let _ = request.reply.send(result);
+        }
+    });
+}
+
+#[cfg(not(feature = "http-api"))]
+pub fn start(_app: AppHandle) {}
+
+/// Hand changed transcription settings to the `/transcribe` endpoint
+pub fn publish_settings(
+    app: &AppHandle,
+    transcription: &whis_core::settings::TranscriptionSettings,
+) {
+    #[cfg(feature = "http-api")]
+    if let Some(api) = app.state::<AppState>().http_api.lock().unwrap().as_ref() {
+        api.set_transcription_settings(transcription.clone());
+    }
+
+    #[cfg(not(feature = "http-api"))]
+    let _ = (app, transcription);
+}
+
+/// Publish a recording state change to HTTP API clients
+pub fn publish_state(app: &AppHandle, state: RecordingState) {
+    #[cfg(feature = "http-api")]
+    if let Some(api) = app.state::<AppState>().http_api.lock().unwrap().as_ref() {
+        api.set_state(state);
+    }
+
+    #[cfg(not(feature = "http-api"))]
+    let _ = (app, state);
+}
+
+/// Publish a completed transcript to HTTP API clients
+pub fn publish_transcript(app: &AppHandle, text: &str) {
+    #[cfg(feature = "http-api")]
+    if let Some(api) = app.state::<AppState>().http_api.lock().unwrap().as_ref() {
+        api.transcript_ready(text);
+    }
+
+    #[cfg(not(feature = "http-api"))]
+    let _ = (app, text);
+}
+
+/// Publish a recording or transcription error to HTTP API clients
+pub fn publish_error(app: &AppHandle, message: &str) {
+    #[cfg(feature = "http-api")]
+    if let Some(api) = app.state::<AppState>().http_api.lock().unwrap().as_ref() {
+        api.report_error(message);
+    }
+
+    #[cfg(not(feature = "http-api"))]
+    let _ = (app, message);
+}
@@ -9,6 +9,8 @@
 //! whis-desktop/
 //! ├── bubble/        - Floating bubble overlay (experimental)
 //! ├── commands/      - Tauri command handlers (30+ commands)
+//! ├── dbus.rs        - D-Bus recorder interface bridge (Linux)
+//! ├── http_api.rs    - Local HTTP API bridge (opt-in)
 //! ├── recording/     - Recording orchestration & pipeline
 //! ├── shortcuts/     - Global keyboard shortcuts (3 backends)
 //! ├── tray/          - System tray UI & interactions
@@ -20,6 +22,8 @@
 
 pub mod bubble;
 mod commands;
+mod dbus;
+mod http_api;
 pub mod recording;
 pub mod shortcuts;
 mod state;
@@ -72,6 +76,12 @@ pub fn run(start_in_tray: bool) {
             // Start IPC listener for --toggle CLI commands
             shortcuts::start_ipc_listener(app.handle().clone());
 
+            // Start local HTTP API for browser extensions / editor plugins (opt-in)
+            http_api::start(app.handle().clone());
+
+            // Serve ink.whis.Recorder on the session bus for shell extensions (Linux)
+            dbus::start(app.handle().clone());
+
             // Only show main window if NOT starting in tray
             if !start_in_tray {
                 window::show_main_window(app.handle())?;
@@ -100,6 +110,9 @@ pub fn run(start_in_tray: bool) {
             commands::validate_groq_api_key,
             commands::validate_deepgram_api_key,
             commands::validate_elevenlabs_api_key,
+            commands::validate_assemblyai_api_key,
+            commands::validate_speechmatics_api_key,
+            commands::validate_gladia_api_key,
             // Recording commands
             commands::get_status,
             commands::is_api_configured,
@@ -141,6 +154,8 @@ pub fn run(start_in_tray: bool) {
             commands::create_preset,
             commands::update_preset,
             commands::delete_preset,
+            commands::export_presets,
+            commands::import_presets,
             // Ollama commands
             commands::test_ollama_connection,
             commands::list_ollama_models,
@@ -3,14 +3,16 @@
 //! Handles starting and stopping audio recording with state management.
 
 use super::config::load_transcription_config;
-use crate::state::{AppState, RecordingState};
+use crate::state::{AppState, RecordingState, RecordingTarget};
 use tauri::AppHandle;
+#[cfg(feature = "realtime")]
+use tauri::Emitter;
 use tokio::sync::{mpsc, oneshot};
 #[cfg(feature = "local-transcription")]
 use whis_core::progressive_transcribe_local;
 use whis_core::{
-    AudioRecorder, ChunkerConfig, PostProcessor, ProgressiveChunker, Settings,
-    TranscriptionProvider, progressive_transcribe_cloud,
+    AudioRecorder, ChunkerConfig, PostProcessor, Preset, ProgressiveChunker, ProviderOptions,
+    TranscriptionProvider, TranscriptionResult, progressive_transcribe_cloud,
 };
 
 /// Start recording with progressive transcription (default mode)
@@ -18,14 +20,41 @@ use whis_core::{
 /// Starts streaming audio recording and spawns background tasks for:
 /// - Progressive audio chunking (90s target, VAD-aware) for non-realtime providers
 /// - WebSocket streaming for realtime providers (deepgram-realtime, openai-realtime)
+/// - Utterance-by-utterance transcription for local providers in streaming mode
 /// - Transcription during recording (parallel for cloud providers, sequential for local providers)
 ///
 /// The transcription result will be available via the oneshot channel
 /// stored in AppState when recording completes.
-pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), String> {
+pub fn start_recording_sync(app: &AppHandle, state: &AppState) -> Result<(), String> {
     // Cancel any pending idle model unload (user is recording again)
     state.cancel_idle_unload();
 
+    // Pick the preset from the focused window while the app being dictated into
+    // still has focus, in the background so a slow compositor query doesn't hold
+    // up the hotkey. Local models get the preset's Whisper tuning through
+    // `overrides_rx`, the pipeline awaits the target.
+    let ui_settings = state.settings.lock().unwrap().ui.clone();
+    let (overrides_tx, overrides_rx) = oneshot::channel();
+    let target = tauri::async_runtime::spawn(async move {
+        let window = whis_core::active_window::detect_active_window_async().await;
+        let preset = ui_settings.preset_for_window(window.as_ref());
+        if preset != ui_settings.active_preset {
+            println!(
+                "Using preset '{}' for the focused app",
+                preset.as_deref().unwrap_or("none")
+            );
+        }
+        let whisper_overrides = preset
+            .as_deref()
+            .and_then(|name| Preset::load(name).ok())
+            .and_then(|(preset, _)| preset.whisper);
+        let _ = overrides_tx.send(whisper_overrides);
+        RecordingTarget { window, preset }
+    });
+    *state.recording_target.lock().unwrap() = Some(target);
+    #[cfg(not(feature = "local-transcription"))]
+    let _ = overrides_rx;
+
     // Load transcription config if not already loaded
     let (provider, api_key, language) = {
         let mut config_guard = state.transcription_config.lock().unwrap();
@@ -41,7 +70,15 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
     };
 
     // Check if this is a realtime provider (for branching later)
-    let is_realtime = whis_core::is_realtime_provider(&provider);
+    let is_realtime = state.settings.lock().unwrap().transcription.uses_realtime();
+
+    // Provider options (AssemblyAI, regions, ...) sent with every request
+    let options = state
+        .settings
+        .lock()
+        .unwrap()
+        .transcription
+        .provider_options();
 
     // Create recorder and start streaming
     let mut recorder = AudioRecorder::new().map_err(|e| e.to_string())?;
@@ -123,22 +160,81 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
         // REALTIME PATH: Stream audio directly to WebSocket (no chunking)
         #[cfg(feature = "realtime")]
         {
-            let realtime_backend =
-                whis_core::get_realtime_backend(&provider).map_err(|e| e.to_string())?;
+            // Local models have no backend; they stream through `transcribe_local` below
+            let realtime_backend = if provider.is_local() {
+                None
+            } else {
+                Some(
+                    whis_core::get_realtime_backend(&provider, &options)
+                        .map_err(|e| e.to_string())?,
+                )
+            };
+
+            // Local models stream with the preset's Whisper tuning and the VAD setting
+            #[cfg(feature = "local-transcription")]
+            let local = whis_core::LocalRealtimeProvider::for_provider(&provider)
+                .map(|engine| (engine, state.settings.lock().unwrap().clone()));
 
+            // Forward partial transcripts to the main window (the bubble only shows state)
+            let (partial_tx, mut partial_rx) = mpsc::unbounded_channel::<String>();
+            let app_handle = app.clone();
             tauri::async_runtime::spawn(async move {
+                while let Some(partial) = partial_rx.recv().await {
+                    let _ = app_handle.emit("transcription-partial", partial);
+                }
+            });
+
+            let provider = provider.clone();
+            let transcription_task = tauri::async_runtime::spawn(async move {
+                #[cfg(feature = "local-transcription")]
+                if let Some((engine, settings)) = local {
+                    let whisper_overrides = overrides_rx.await.ok().flatten();
+                    let result = match whis_core::LocalStreamConfig::from_settings(
+                        &settings,
+                        whisper_overrides.as_ref(),
+                    ) {
+                        Ok(config) => engine
+                            .transcribe_local(
+                                &api_key,
+                                audio_rx_unbounded,
+                                language,
+                                config,
+                                Some(partial_tx),
+                            )
+                            .await
+                            .map_err(|e| e.to_string()),
+                        Err(e) => Err(e.to_string()),
+                    };
+                    let _ = result_tx.send(result);
+                    return;
+                }
+
+                let Some(realtime_backend) = realtime_backend else {
+                    let _ = result_tx.send(Err(format!(
+                        "Provider '{}' requires the 'local-transcription' feature",
+                        provider.as_str()
+                    )));
+                    return;
+                };
                 let result = realtime_backend
-                    .transcribe_stream(&api_key, audio_rx_unbounded, language)
+                    .transcribe_stream(&api_key, audio_rx_unbounded, language, Some(partial_tx))
                     .await
+                    .map(|text| TranscriptionResult {
+                        text,
+                        language: None,
+                        words: Vec::new(),
+                    })
                     .map_err(|e| e.to_string());
                 let _ = result_tx.send(result);
             });
+            *state.transcription_tasks.lock().unwrap() = vec![transcription_task];
 
             println!("Recording started (realtime streaming mode)...");
         }
 
         #[cfg(not(feature = "realtime"))]
         {
+            let _ = app;
             return Err(format!(
                 "Provider '{}' requires the 'realtime' feature (not enabled in this build)",
                 provider.as_str()
@@ -159,49 +255,54 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
 
         // Spawn chunker task
         let mut chunker = ProgressiveChunker::new(chunker_config, chunk_tx);
-        tauri::async_runtime::spawn(async move {
+        let chunker_task = tauri::async_runtime::spawn(async move {
             let _ = chunker.consume_stream(audio_rx_unbounded, None).await;
         });
 
+        // Whisper tuning for local models (merged with the preset's below)
+        #[cfg(feature = "local-transcription")]
+        let whisper = state
+            .settings
+            .lock()
+            .unwrap()
+            .transcription
+            .local_models
+            .whisper
+            .clone();
+
         // Spawn transcription task
-        tauri::async_runtime::spawn(async move {
-            let result: Result<String, String> = {
+        let transcription_task = tauri::async_runtime::spawn(async move {
+            let result: Result<TranscriptionResult, String> = {
+                // Local models: api_key is the model path
                 #[cfg(feature = "local-transcription")]
-                if provider == TranscriptionProvider::LocalParakeet {
-                    match Settings::load().transcription.parakeet_model_path() {
-                        Some(model_path) => {
-                            progressive_transcribe_local(&model_path, chunk_rx, None)
-                                .await
-                                .map_err(|e| e.to_string())
-                        }
-                        None => Err("Parakeet model path not configured".to_string()),
+                if provider.is_local() {
+                    let whisper_overrides = overrides_rx.await.ok().flatten();
+                    let tuning = whisper.with_overrides(whisper_overrides.as_ref());
+                    match tuning.validate() {
+                        Ok(()) => progressive_transcribe_local(
+                            &provider,
+                            &api_key,
+                            language.as_deref(),
+                            &tuning,
+                            chunk_rx,
+                            None,
+                        )
+                        .await
+                        .map_err(|e| e.to_string()),
+                        Err(e) => Err(e.to_string()),
                     }
                 } else {
-                    progressive_transcribe_cloud(
-                        &provider,
-                        &api_key,
-                        language.as_deref(),
-                        chunk_rx,
-                        None,
-                    )
-                    .await
-                    .map_err(|e| e.to_string())
+                    transcribe_cloud(&provider, &options, &api_key, language.as_deref(), chunk_rx)
+                        .await
                 }
 
                 #[cfg(not(feature = "local-transcription"))]
-                progressive_transcribe_cloud(
-                    &provider,
-                    &api_key,
-                    language.as_deref(),
-                    chunk_rx,
-                    None,
-                )
-                .await
-                .map_err(|e| e.to_string())
+                transcribe_cloud(&provider, &options, &api_key, language.as_deref(), chunk_rx).await
             };
 
             let _ = result_tx.send(result);
         });
+        *state.transcription_tasks.lock().unwrap() = vec![chunker_task, transcription_task];
 
         println!("Recording started (progressive mode)...");
     }
@@ -213,3 +314,39 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
 
     Ok(())
 }
+
+/// Progressive cloud transcription (cloud providers don't report a language here)
+async fn transcribe_cloud(
+    provider: &TranscriptionProvider,
+    options: &ProviderOptions,
+    api_key: &str,
+    language: Option<&str>,
+    chunk_rx: mpsc::UnboundedReceiver<whis_core::ProgressiveChunk>,
+) -> Result<TranscriptionResult, String> {
+    let text = progressive_transcribe_cloud(provider, options, api_key, language, chunk_rx, None)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(TranscriptionResult {
+        text,
+        language: None,
+        words: Vec::new(),
+    })
+}
+
+/// Cancel the active recording and discard its audio
+///
+/// Aborts the chunker and transcription tasks (so no captured audio is sent
+/// to the provider), stops the audio stream and drops the result receiver.
+pub fn cancel_recording_sync(state: &AppState) {
+    for task in state.transcription_tasks.lock().unwrap().drain(..) {
+        task.abort();
+    }
+    if let Some(mut recorder) = state.recorder.lock().unwrap().take() {
+        let _ = recorder.stop_recording();
+    }
+    state.transcription_rx.lock().unwrap().take();
+    state.recording_target.lock().unwrap().take();
+    *state.state.lock().unwrap() = RecordingState::Idle;
+
+    println!("Recording cancelled");
+}
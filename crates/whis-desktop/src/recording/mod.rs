@@ -2,7 +2,7 @@
 //!
 //! Manages the complete recording workflow:
 //! - Configuration loading and validation
-//! - Audio recording control (start/stop)
+//! - Audio recording control (start/stop/cancel)
 //! - Transcription pipeline (transcribe → post-process → clipboard)
 //!
 //! ## Architecture
@@ -12,7 +12,7 @@
 //! ├── config.rs      - Configuration loading from settings
 //! ├── control.rs     - Start/stop recording logic
 //! ├── pipeline.rs    - Transcription pipeline orchestration
-//! └── mod.rs         - Public API (toggle, start, stop)
+//! └── mod.rs         - Public API (toggle, start, stop, cancel)
 //! ```
 
 pub mod config;
@@ -21,11 +21,11 @@ pub mod pipeline;
 
 // Re-export public APIs
 pub use config::load_transcription_config;
-pub use control::start_recording_sync;
+pub use control::{cancel_recording_sync, start_recording_sync};
 pub use pipeline::stop_and_transcribe;
 
 use crate::state::{AppState, RecordingState};
-use crate::{bubble, tray};
+use crate::{bubble, dbus, http_api, tray};
 use tauri::{AppHandle, Manager};
 use whis_core::error;
 
@@ -40,10 +40,13 @@ pub fn toggle_recording(app: AppHandle) {
             // Start recording
             if let Err(e) = start_recording_sync(&app, &state) {
                 error!("Failed to start recording: {e}");
+                http_api::publish_error(&app, &e);
             } else {
                 // Update UI (tray and bubble)
                 tray::menu::update_tray(&app, RecordingState::Recording);
                 bubble::show_bubble(&app);
+                http_api::publish_state(&app, RecordingState::Recording);
+                dbus::publish_state(&app, RecordingState::Recording);
             }
         }
         RecordingState::Recording => {
@@ -53,15 +56,20 @@ pub fn toggle_recording(app: AppHandle) {
                 // Update UI to transcribing state
                 tray::menu::update_tray(&app_clone, RecordingState::Transcribing);
                 bubble::update_bubble_state(&app_clone, RecordingState::Transcribing);
+                http_api::publish_state(&app_clone, RecordingState::Transcribing);
+                dbus::publish_state(&app_clone, RecordingState::Transcribing);
 
                 // Run transcription pipeline
                 if let Err(e) = stop_and_transcribe(&app_clone).await {
                     error!("Failed to transcribe: {e}");
+                    http_api::publish_error(&app_clone, &e);
                 }
 
                 // Update UI back to idle
                 tray::menu::update_tray(&app_clone, RecordingState::Idle);
                 bubble::hide_bubble(&app_clone);
+                http_api::publish_state(&app_clone, RecordingState::Idle);
+                dbus::publish_state(&app_clone, RecordingState::Idle);
             });
         }
         RecordingState::Transcribing => {
@@ -69,3 +77,43 @@ pub fn toggle_recording(app: AppHandle) {
         }
     }
 }
+
+/// Dispatch a command from the HTTP API or D-Bus to the recording workflow
+#[cfg(any(feature = "http-api", all(feature = "dbus", target_os = "linux")))]
+pub fn handle_remote_command(
+    app: &AppHandle,
+    command: whis_core::RemoteCommand,
+) -> Result<(), String> {
+    use whis_core::RemoteAction;
+
+    let current_state = app.state::<AppState>().get_state();
+    match command.resolve(current_state)? {
+        RemoteAction::Toggle => {
+            toggle_recording(app.clone());
+            if current_state == RecordingState::Idle
+                && app.state::<AppState>().get_state() == RecordingState::Idle
+            {
+                return Err("Failed to start recording".to_string());
+            }
+        }
+        RemoteAction::Cancel => cancel_recording(app.clone()),
+    }
+    Ok(())
+}
+
+/// Cancel an active recording without transcribing
+/// Called from the HTTP API and D-Bus
+pub fn cancel_recording(app: AppHandle) {
+    let state = app.state::<AppState>();
+    if *state.state.lock().unwrap() != RecordingState::Recording {
+        return;
+    }
+
+    cancel_recording_sync(&state);
+
+    // Update UI back to idle
+    tray::menu::update_tray(&app, RecordingState::Idle);
+    bubble::hide_bubble(&app);
+    http_api::publish_state(&app, RecordingState::Idle);
+    dbus::publish_state(&app, RecordingState::Idle);
+}
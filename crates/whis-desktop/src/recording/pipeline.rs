@@ -2,17 +2,26 @@
 //!
 //! Orchestrates the full transcription pipeline:
 //! 1. Finalize recording (encode audio)
-//! 2. Transcribe audio (single or parallel chunks)
-//! 3. Post-process transcription (optional)
+//! 2. Transcribe audio (single or parallel chunks), apply the replacement dictionary
+//!    and spoken formatting commands
+//! 3. Post-process transcription (optional; multi-step presets run each step in
+//!    order, LLM replies stream as `post-process-progress` events; structured-output
+//!    presets end with a rendered JSON reply)
 //! 4. Copy to clipboard
 //! 5. Emit completion event
 
-use crate::state::{AppState, RecordingState};
+use crate::state::{AppState, RecordingState, RecordingTarget};
 use std::time::Duration;
 use tauri::{AppHandle, Emitter, Manager};
+use whis_core::preset::{
+    Preset, PresetStep, PromptContext, Replacement, StructuredOutput, apply_replacements,
+    expand_prompt,
+};
+use whis_core::settings::PostProcessingSettings;
 use whis_core::{
-    DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessor, TranscriptionProvider,
-    copy_to_clipboard, ollama, post_process, warn,
+    DEFAULT_POST_PROCESSING_PROMPT, Dictionary, PostProcessConfig, PostProcessor, Settings,
+    TranscriptionProvider, apply_voice_commands, copy_to_clipboard, ollama, post_process_streaming,
+    post_process_structured, warn,
 };
 #[cfg(feature = "local-transcription")]
 use whis_core::{unload_parakeet, whisper_unload_model};
@@ -58,57 +67,101 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
     };
 
     // Wait for transcription to complete (rx_guard dropped, so this is Send-safe)
-    let transcription = rx
+    let result = rx
         .await
         .map_err(|_| "Transcription task dropped unexpectedly".to_string())?
         .map_err(|e| format!("Transcription failed: {e}"))?;
+    if let Some(detected) = &result.language {
+        println!("Detected language: {detected}");
+    }
+
+    // Configured language, else the one detected by the provider
+    let language = state
+        .settings
+        .lock()
+        .unwrap()
+        .transcription
+        .language
+        .clone()
+        .or(result.language);
+
+    // Window and preset picked when recording started (per-app rule or the
+    // active preset)
+    let target = state.recording_target.lock().unwrap().take();
+    let target = match target {
+        Some(target) => target.await.unwrap_or_default(),
+        None => RecordingTarget::default(),
+    };
+    let preset_name = target
+        .preset
+        .or_else(|| state.settings.lock().unwrap().ui.active_preset.clone());
 
-    // Extract post-processing config and clipboard method from settings
-    let (post_process_config, clipboard_method) = {
+    // Apply the replacement dictionary (with the preset's entries) and spoken
+    // formatting commands
+    let transcription = {
+        let voice_commands = state
+            .settings
+            .lock()
+            .unwrap()
+            .post_processing
+            .voice_commands;
+        let preset = preset_name
+            .as_deref()
+            .and_then(|name| Preset::load(name).ok())
+            .map(|(preset, _)| preset);
+        let text = Dictionary::load().apply(&result.text, preset.as_ref());
+        if voice_commands {
+            apply_voice_commands(&text, language.as_deref())
+        } else {
+            text
+        }
+    };
+
+    // Resolve post-processing steps, prompt variables and clipboard method from settings
+    let (steps, prompt_context, clipboard_method) = {
         let settings = state.settings.lock().unwrap();
         let clipboard_method = settings.ui.clipboard_backend.clone();
-        let post_process_config = if settings.post_processing.enabled
+        let prompt_context = PromptContext {
+            language: language.clone(),
+            active_app: target.window.map(|window| window.app),
+            ..PromptContext::from_settings(&settings)
+        };
+        let steps = if settings.post_processing.enabled
             && settings.post_processing.processor != PostProcessor::None
         {
-            let processor = settings.post_processing.processor.clone();
-            let prompt = settings
-                .post_processing
-                .prompt
-                .clone()
-                .unwrap_or_else(|| DEFAULT_POST_PROCESSING_PROMPT.to_string());
-            let ollama_model = settings.services.ollama.model.clone();
-            let ollama_keep_alive = settings.services.ollama.keep_alive();
-
-            let api_key_or_url = if processor.requires_api_key() {
-                settings
-                    .post_processing
-                    .api_key_from_settings(&settings.transcription.api_keys)
-            } else if processor == PostProcessor::Ollama {
-                let ollama_url = settings
-                    .services
-                    .ollama
-                    .url()
-                    .unwrap_or_else(|| ollama::DEFAULT_OLLAMA_URL.to_string());
-                Some(ollama_url)
-            } else {
-                None
-            };
-
-            api_key_or_url.map(|key_or_url| PostProcessConfig {
-                processor,
-                prompt,
-                api_key_or_url: key_or_url,
-                ollama_model,
-                ollama_keep_alive,
-            })
+            resolve_steps(&settings, preset_name.as_deref())
         } else {
-            None
+            Vec::new()
         };
-        (post_process_config, clipboard_method)
+        (steps, prompt_context, clipboard_method)
     };
 
-    // Apply post-processing if configured
-    let final_text = if let Some(config) = post_process_config {
+    // Apply post-processing steps in order (a failing step keeps the text so far)
+    let mut final_text = transcription;
+    if !steps.is_empty() {
+        println!("Post-processing...");
+        let _ = app.emit("post-process-started", ());
+    }
+    for step in steps {
+        let (config, output) = match step {
+            Step::Replace(replacements) => {
+                final_text = apply_replacements(&final_text, &replacements);
+                continue;
+            }
+            Step::Llm { config, output } => (config, output),
+        };
+
+        // Fill in {{clipboard}}, {{date}}, ... (read before the clipboard is overwritten)
+        let config = match expand_prompt(&config.prompt, &prompt_context) {
+            Ok(prompt) => PostProcessConfig { prompt, ..*config },
+            Err(e) => {
+                let warning = format!("Prompt: {e}");
+                warn!("Post-processing: {warning}");
+                let _ = app.emit("post-process-warning", &warning);
+                break;
+            }
+        };
+
         if config.processor == PostProcessor::Ollama {
             let url_for_check = config.api_key_or_url.clone();
             let ollama_result = tauri::async_runtime::spawn_blocking(move || {
@@ -121,17 +174,11 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
                 let warning = format!("Ollama: {e}");
                 warn!("Post-processing: {warning}");
                 let _ = app.emit("post-process-warning", &warning);
-                copy_to_clipboard(&transcription, clipboard_method).map_err(|e| e.to_string())?;
-                println!(
-                    "Done (unprocessed): {}",
-                    &transcription[..transcription.len().min(50)]
-                );
-                let _ = app.emit("transcription-complete", &transcription);
-                return Ok(());
+                break;
             }
 
             // Re-warm Ollama model (in case it unloaded during long recording > keep_alive timeout)
-            if config.ollama_model.is_some() {
+            if config.model.is_some() {
                 {
                     let settings = state.settings.lock().unwrap();
                     settings.services.ollama.preload();
@@ -141,43 +188,57 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
             }
         }
 
-        println!("Post-processing...");
-        let _ = app.emit("post-process-started", ());
+        // Structured replies are not streamed: only the rendered JSON is useful
+        if let Some(output) = output {
+            match post_process_structured(&final_text, &config, &output.schema).await {
+                Ok(value) => final_text = output.render(&value),
+                Err(e) => {
+                    let warning = e.to_string();
+                    warn!("Post-processing: {warning}");
+                    let _ = app.emit("post-process-warning", &warning);
+                    break;
+                }
+            }
+            // Later replace steps run on the rendered reply
+            continue;
+        }
 
-        let model = if config.processor == PostProcessor::Ollama {
-            config.ollama_model.as_deref()
-        } else {
-            None
-        };
+        // Forward the reply to the frontend as it streams in (text so far)
+        let (delta_tx, mut delta_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+        let progress_app = app.clone();
+        let progress = tauri::async_runtime::spawn(async move {
+            let mut so_far = String::new();
+            while let Some(delta) = delta_rx.recv().await {
+                so_far.push_str(&delta);
+                let _ = progress_app.emit("post-process-progress", so_far.trim_start());
+            }
+        });
 
-        match post_process(
-            &transcription,
-            &config.processor,
-            &config.api_key_or_url,
-            &config.prompt,
-            model,
-        )
-        .await
-        {
-            Ok(processed) => processed,
+        let result = post_process_streaming(&final_text, &config, &delta_tx).await;
+        drop(delta_tx);
+        let _ = progress.await;
+
+        // Clipboard only gets the complete reply
+        match result {
+            Ok(processed) => final_text = processed,
             Err(e) => {
                 let warning = e.to_string();
                 warn!("Post-processing: {warning}");
                 let _ = app.emit("post-process-warning", &warning);
-                transcription
+                break;
             }
         }
-    } else {
-        transcription
-    };
+    }
 
     // Copy to clipboard
     copy_to_clipboard(&final_text, clipboard_method).map_err(|e| e.to_string())?;
 
     println!("Done: {}", &final_text[..final_text.len().min(50)]);
 
-    // Emit event to frontend
+    // Emit event to frontend, HTTP API and D-Bus clients
     let _ = app.emit("transcription-complete", &final_text);
+    crate::http_api::publish_transcript(app, &final_text);
+    crate::dbus::publish_transcript(app, &final_text);
 
     // Schedule idle model unload (if configured)
     schedule_idle_model_unload(app, state);
@@ -185,6 +246,108 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
     Ok(())
 }
 
+/// A post-processing step resolved against settings
+enum Step {
+    Replace(Vec<Replacement>),
+    Llm {
+        config: Box<PostProcessConfig>,
+        /// Set on the last LLM step of a structured-output preset
+        output: Option<StructuredOutput>,
+    },
+}
+
+/// Resolve the preset's steps (or the configured prompt) against settings
+///
+/// LLM steps whose post-processor has no API key/URL are skipped with a warning.
+fn resolve_steps(settings: &Settings, preset: Option<&str>) -> Vec<Step> {
+    let default_prompt = || {
+        settings
+            .post_processing
+            .prompt
+            .clone()
+            .unwrap_or_else(|| DEFAULT_POST_PROCESSING_PROMPT.to_string())
+    };
+
+    // Multi-step, structured-output and rule-selected presets run their own
+    // steps; the active single-prompt preset is already applied to settings
+    // (prompt and post-processor)
+    let (steps, output) = match preset.map(Preset::load) {
+        Some(Ok((preset, _)))
+            if !preset.steps.is_empty()
+                || preset.output.is_some()
+                || settings.ui.active_preset.as_deref() != Some(preset.name.as_str()) =>
+        {
+            (preset.pipeline(), preset.output)
+        }
+        _ => (
+            vec![PresetStep::Llm {
+                prompt: default_prompt(),
+                post_processor: None,
+                model: None,
+            }],
+            None,
+        ),
+    };
+    let output_step = steps.iter().rposition(PresetStep::is_llm);
+
+    steps
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, step)| match step {
+            PresetStep::Replace { replacements } => Some(Step::Replace(replacements)),
+            PresetStep::Llm {
+                prompt,
+                post_processor,
+                model,
+            } => {
+                let processor = post_processor
+                    .and_then(|p| p.parse().ok())
+                    .unwrap_or_else(|| settings.post_processing.processor.clone());
+
+                let (api_key_or_url, model) = if processor == PostProcessor::Ollama {
+                    let ollama_url = settings
+                        .services
+                        .ollama
+                        .url()
+                        .unwrap_or_else(|| ollama::DEFAULT_OLLAMA_URL.to_string());
+                    (
+                        Some(ollama_url),
+                        model.or_else(|| settings.services.ollama.model.clone()),
+                    )
+                } else {
+                    // Cloud key, or the (possibly empty) key of a configured custom endpoint
+                    let post_processing = PostProcessingSettings {
+                        processor: processor.clone(),
+                        ..settings.post_processing.clone()
+                    };
+                    (
+                        post_processing.api_key_from_settings(&settings.transcription.api_keys),
+                        model,
+                    )
+                };
+
+                let Some(api_key_or_url) = api_key_or_url else {
+                    warn!("Post-processing: {processor} is not configured, skipping step");
+                    return None;
+                };
+
+                Some(Step::Llm {
+                    config: Box::new(PostProcessConfig {
+                        processor,
+                        prompt,
+                        api_key_or_url,
+                        model,
+                        ollama_keep_alive: settings.services.ollama.keep_alive(),
+                        custom: settings.post_processing.custom.clone(),
+                        anthropic: settings.post_processing.anthropic.clone(),
+                    }),
+                    output: output.clone().filter(|_| Some(index) == output_step),
+                })
+            }
+        })
+        .collect()
+}
+
 /// Schedule automatic model unload after idle timeout
 ///
 /// If keep_model_loaded is true and unload_after_minutes > 0, spawns a background
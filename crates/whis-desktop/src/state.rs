@@ -2,6 +2,7 @@ use std::sync::Mutex;
 use tauri::menu::MenuItem;
 use tokio::sync::oneshot;
 pub use whis_core::RecordingState;
+use whis_core::active_window::ActiveWindow;
 use whis_core::{AudioRecorder, Settings, TranscriptionProvider};
 
 #[cfg(target_os = "linux")]
@@ -14,6 +15,15 @@ pub struct TranscriptionConfig {
     pub language: Option<String>,
 }
 
+/// What a recording is dictated into, looked up when it starts
+#[derive(Debug, Default)]
+pub struct RecordingTarget {
+    /// Focused window (None if it could not be detected)
+    pub window: Option<ActiveWindow>,
+    /// Preset for that window (per-app rule or the active preset)
+    pub preset: Option<String>,
+}
+
 /// Active model download state (persists across window close/reopen)
 #[derive(Clone, Debug)]
 pub struct DownloadState {
@@ -37,11 +47,24 @@ pub struct AppState {
     pub tray_available: Mutex<bool>,
     /// Active model download (if any)
     pub active_download: Mutex<Option<DownloadState>>,
+    /// Focused window and preset of the current recording (looked up in the
+    /// background when it starts)
+    pub recording_target: Mutex<Option<tauri::async_runtime::JoinHandle<RecordingTarget>>>,
     /// Progressive transcription result receiver (if progressive mode active)
-    pub transcription_rx: Mutex<Option<oneshot::Receiver<Result<String, String>>>>,
+    pub transcription_rx:
+        Mutex<Option<oneshot::Receiver<Result<whis_core::TranscriptionResult, String>>>>,
+    /// Chunker and transcription tasks of the current recording
+    /// Aborted on cancel so captured audio is not uploaded
+    pub transcription_tasks: Mutex<Vec<tauri::async_runtime::JoinHandle<()>>>,
     /// JoinHandle for pending idle model unload task (if any)
     /// Used to cancel the unload when a new recording starts
     pub idle_unload_handle: Mutex<Option<tauri::async_runtime::JoinHandle<()>>>,
+    /// Local HTTP API handle for publishing state and transcripts (if enabled)
+    #[cfg(feature = "http-api")]
+    pub http_api: Mutex<Option<whis_core::http_api::ApiHandle>>,
+    /// D-Bus recorder handle for publishing state and transcripts (Linux)
+    #[cfg(all(feature = "dbus", target_os = "linux"))]
+    pub dbus: Mutex<Option<whis_core::dbus::DbusHandle>>,
     /// Guard for rdev::grab() keyboard listener (Linux only)
     #[cfg(target_os = "linux")]
     pub rdev_guard: Mutex<Option<RdevGrabGuard>>,
@@ -62,8 +85,14 @@ impl AppState {
             portal_bind_error: Mutex::new(None),
             tray_available: Mutex::new(tray_available),
             active_download: Mutex::new(None),
+            recording_target: Mutex::new(None),
             transcription_rx: Mutex::new(None),
+            transcription_tasks: Mutex::new(Vec::new()),
             idle_unload_handle: Mutex::new(None),
+            #[cfg(feature = "http-api")]
+            http_api: Mutex::new(None),
+            #[cfg(all(feature = "dbus", target_os = "linux"))]
+            dbus: Mutex::new(None),
             #[cfg(target_os = "linux")]
             rdev_guard: Mutex::new(None),
             #[cfg(target_os = "linux")]
@@ -5,14 +5,6 @@ import { listen } from '@tauri-apps/api/event'
 import { computed, onMounted, onUnmounted, ref, watch } from 'vue'
 import { settingsStore } from '../stores/settings'
 
-// Model sizes for display (rounded to nearest 50 MB)
-const MODEL_SIZES: Record<string, string> = {
-  tiny: '100 MB',
-  base: '150 MB',
-  small: '450 MB',
-  medium: '1.5 GB',
-}
-
 /**
  * Composable for managing Whisper model downloads and validation.
  * Used in ApiKeyView for local transcription configuration.
@@ -49,10 +41,11 @@ export function useWhisperModel() {
   async function loadWhisperModels() {
     try {
       availableModels.value = await invoke<WhisperModelInfo[]>('get_whisper_models')
-      // If a model is already installed, select it by default
-      const installed = availableModels.value.find(m => m.installed)
-      if (installed) {
-        selectedModel.value = installed.name
+      // Select an installed model by default, otherwise the recommended one
+      const preferred = availableModels.value.find(m => m.installed)
+        ?? availableModels.value.find(m => m.recommended)
+      if (preferred) {
+        selectedModel.value = preferred.name
       }
     }
     catch (e) {
@@ -127,7 +120,7 @@ export function useWhisperModel() {
 
   // Get size for selected model
   const selectedModelSize = computed(() => {
-    return MODEL_SIZES[selectedModel.value] ?? ''
+    return availableModels.value.find(m => m.name === selectedModel.value)?.size ?? ''
   })
 
   // Setup watchers and lifecycle
@@ -173,8 +166,5 @@ export function useWhisperModel() {
     checkWhisperModel,
     loadWhisperModels,
     downloadModel,
-
-    // Constants
-    MODEL_SIZES,
   }
 }
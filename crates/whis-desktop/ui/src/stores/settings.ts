@@ -1,4 +1,4 @@
-import type { BackendInfo, CliShortcutMode, PostProcessor, Provider, Settings, ShortcutPathMismatch } from '../types'
+import type { AnthropicOptions, BackendInfo, CliShortcutMode, DataRegion, PostProcessor, PresetRule, Provider, RegionOptions, Settings, ShortcutPathMismatch } from '../types'
 import { invoke } from '@tauri-apps/api/core'
 import { nextTick, reactive, readonly, watch } from 'vue'
 
@@ -58,12 +58,20 @@ function getDefaultSettings(): Settings {
       local_models: {
         whisper_path: null,
         parakeet_path: null,
+        whisper: {},
+        streaming: false,
       },
+      assemblyai: { speaker_labels: false },
+      regions: { speechmatics: 'eu', gladia: 'eu' },
+      wyoming: { url: null },
     },
     post_processing: {
       enabled: false,
+      voice_commands: false,
       processor: defaults.post_processor,
       prompt: null,
+      custom: { base_url: null, model: null, api_key: null },
+      anthropic: {},
     },
     services: {
       ollama: {
@@ -87,6 +95,7 @@ function getDefaultSettings(): Settings {
         threshold: defaults.vad_threshold,
       },
       active_preset: null,
+      preset_rules: [],
       bubble: {
         enabled: false,
         position: 'none',
@@ -185,12 +194,20 @@ async function load() {
       local_models: {
         whisper_path: settings.transcription.local_models.whisper_path,
         parakeet_path: settings.transcription.local_models.parakeet_path,
+        whisper: settings.transcription.local_models.whisper ?? {},
+        streaming: settings.transcription.local_models.streaming ?? false,
       },
+      assemblyai: settings.transcription.assemblyai ?? { speaker_labels: false },
+      regions: settings.transcription.regions ?? { speechmatics: 'eu', gladia: 'eu' },
+      wyoming: settings.transcription.wyoming ?? { url: null },
     }
     state.post_processing = {
       enabled: settings.post_processing.enabled ?? false,
+      voice_commands: settings.post_processing.voice_commands ?? false,
       processor: settings.post_processing.processor || defaults.post_processor,
       prompt: settings.post_processing.prompt,
+      custom: settings.post_processing.custom ?? { base_url: null, model: null, api_key: null },
+      anthropic: settings.post_processing.anthropic ?? {},
     }
     state.services = {
       ollama: {
@@ -214,6 +231,7 @@ async function load() {
         threshold: settings.ui.vad.threshold ?? defaults.vad_threshold,
       },
       active_preset: settings.ui.active_preset,
+      preset_rules: settings.ui.preset_rules ?? [],
       bubble: {
         enabled: settings.ui.bubble?.enabled ?? false,
         position: settings.ui.bubble?.position ?? 'none',
@@ -380,6 +398,10 @@ function setApiKey(provider: string, key: string) {
   state.transcription.api_keys = { ...state.transcription.api_keys, [provider]: key }
 }
 
+function setRegion(provider: keyof RegionOptions, region: DataRegion) {
+  state.transcription.regions = { ...state.transcription.regions, [provider]: region }
+}
+
 function setWhisperModelPath(value: string | null) {
   state.transcription.local_models.whisper_path = value
 }
@@ -392,6 +414,18 @@ function setPostProcessor(value: PostProcessor) {
   state.post_processing.processor = value
 }
 
+function setVoiceCommands(value: boolean) {
+  state.post_processing.voice_commands = value
+}
+
+function setCustomEndpoint(field: 'base_url' | 'model' | 'api_key', value: string | null) {
+  state.post_processing.custom = { ...state.post_processing.custom, [field]: value }
+}
+
+function setAnthropicOption<K extends keyof AnthropicOptions>(field: K, value: AnthropicOptions[K]) {
+  state.post_processing.anthropic = { ...state.post_processing.anthropic, [field]: value }
+}
+
 function setOllamaUrl(value: string | null) {
   state.services.ollama.url = value
 }
@@ -404,6 +438,18 @@ function setPostProcessingPrompt(value: string | null) {
   state.post_processing.prompt = value
 }
 
+function addPresetRule(preset: string) {
+  state.ui.preset_rules = [...state.ui.preset_rules, { app: null, title: null, preset }]
+}
+
+function updatePresetRule(index: number, rule: PresetRule) {
+  state.ui.preset_rules = state.ui.preset_rules.map((r, i) => (i === index ? rule : r))
+}
+
+function removePresetRule(index: number) {
+  state.ui.preset_rules = state.ui.preset_rules.filter((_, i) => i !== index)
+}
+
 function setDesktopKey(value: string) {
   state.shortcuts.desktop_key = value
 }
@@ -530,12 +576,19 @@ export const settingsStore = {
   setProvider,
   setLanguage,
   setApiKey,
+  setRegion,
   setWhisperModelPath,
   setParakeetModelPath,
   setPostProcessor,
+  setVoiceCommands,
+  setCustomEndpoint,
+  setAnthropicOption,
   setOllamaUrl,
   setOllamaModel,
   setPostProcessingPrompt,
+  addPresetRule,
+  updatePresetRule,
+  removePresetRule,
   setDesktopKey,
   setPortalShortcut,
   setMicrophoneDevice,
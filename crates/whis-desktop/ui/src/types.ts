@@ -14,6 +14,14 @@ export type Provider
     | 'deepgram'
     | 'deepgram-realtime'
     | 'elevenlabs'
+    | 'assemblyai'
+    | 'assemblyai-realtime'
+    | 'speechmatics'
+    | 'speechmatics-realtime'
+    | 'gladia'
+    | 'gladia-realtime'
+    | 'wyoming'
+    | 'wyoming-realtime'
     | 'local-whisper'
     | 'local-parakeet'
 
@@ -21,11 +29,55 @@ export type Provider
 export type TranscriptionMethod = 'standard' | 'streaming'
 
 // Text post-processing providers
-export type PostProcessor = 'none' | 'openai' | 'mistral' | 'ollama'
+export type PostProcessor = 'none' | 'openai' | 'mistral' | 'groq' | 'anthropic' | 'ollama' | 'custom'
+
+// OpenAI-compatible server used by the custom post-processor
+export interface CustomEndpoint {
+  base_url: string | null
+  model: string | null
+  api_key: string | null
+  params?: Record<string, unknown>
+}
+
+// Claude request options for the Anthropic post-processor (null = default)
+export interface AnthropicOptions {
+  model?: string | null
+  max_tokens?: number | null
+  temperature?: number | null
+}
 
 // CLI shortcut mode
 export type CliShortcutMode = 'system' | 'direct'
 
+// Local Whisper inference parameters (unset fields use backend defaults)
+export interface WhisperTuning {
+  threads?: number
+  beam_size?: number
+  temperature?: number
+  temperature_increment?: number
+  no_speech_threshold?: number
+  suppress_blank?: boolean
+}
+
+// AssemblyAI options (speaker labels, keyterm prompting)
+export interface AssemblyAIOptions {
+  speaker_labels: boolean
+  keyterms?: string[]
+}
+
+// Where audio is processed for providers with regional endpoints
+export type DataRegion = 'eu' | 'us'
+
+export interface RegionOptions {
+  speechmatics: DataRegion
+  gladia: DataRegion
+}
+
+// Wyoming ASR server on the local network (configured via the CLI)
+export interface WyomingOptions {
+  url: string | null
+}
+
 // All settings from the backend (nested structure)
 export interface Settings {
   transcription: {
@@ -35,12 +87,20 @@ export interface Settings {
     local_models: {
       whisper_path: string | null
       parakeet_path: string | null
+      whisper: WhisperTuning
+      streaming: boolean
     }
+    assemblyai: AssemblyAIOptions
+    regions: RegionOptions
+    wyoming: WyomingOptions
   }
   post_processing: {
     enabled: boolean
+    voice_commands: boolean
     processor: PostProcessor
     prompt: string | null
+    custom: CustomEndpoint
+    anthropic: AnthropicOptions
   }
   services: {
     ollama: {
@@ -64,6 +124,7 @@ export interface Settings {
       threshold: number
     }
     active_preset: string | null
+    preset_rules: PresetRule[]
     bubble: {
       enabled: boolean
       position: string // Kept for backwards compatibility, no longer used by UI
@@ -105,6 +166,10 @@ export interface SaveSettingsResponse {
 export interface WhisperModelInfo {
   name: string
   description: string
+  family: string
+  quantization: string | null
+  size: string
+  recommended: boolean
   installed: boolean
   path: string
 }
@@ -118,6 +183,13 @@ export interface ParakeetModelInfo {
   path: string
 }
 
+// Per-app preset rule (app/title match case-insensitive substrings)
+export interface PresetRule {
+  app?: string | null
+  title?: string | null
+  preset: string
+}
+
 // Preset info from backend
 export interface PresetInfo {
   name: string
@@ -135,6 +207,13 @@ export interface PresetDetails {
   is_builtin: boolean
 }
 
+// Result of importing one preset from a bundle
+export type ImportOutcome
+  = | { action: 'added', name: string }
+    | { action: 'renamed', from: string, name: string }
+    | { action: 'overwritten', name: string }
+    | { action: 'skipped', name: string }
+
 // Cloud provider configuration
 export interface CloudProviderInfo {
   value: Provider
@@ -155,7 +234,29 @@ export function normalizeProvider(provider: Provider): Provider {
       return 'openai'
     case 'deepgram-realtime':
       return 'deepgram'
+    case 'assemblyai-realtime':
+      return 'assemblyai'
+    case 'speechmatics-realtime':
+      return 'speechmatics'
+    case 'gladia-realtime':
+      return 'gladia'
+    case 'wyoming-realtime':
+      return 'wyoming'
     default:
       return provider
   }
 }
+
+// Base providers with a realtime (streaming) variant
+export function supportsStreaming(provider: Provider | string): boolean {
+  return provider === 'openai'
+    || provider === 'deepgram'
+    || provider === 'assemblyai'
+    || provider === 'speechmatics'
+    || provider === 'gladia'
+}
+
+// Base providers that let the user pick the processing region
+export function supportsRegion(provider: Provider | string): provider is keyof RegionOptions {
+  return provider === 'speechmatics' || provider === 'gladia'
+}
@@ -17,5 +17,8 @@ export const MODEL_SIZES: Record<string, string> = {
 export const POST_PROCESSOR_OPTIONS: SelectOption[] = [
   { value: 'openai', label: 'OpenAI (cloud)' },
   { value: 'mistral', label: 'Mistral (cloud)' },
+  { value: 'groq', label: 'Groq (cloud)' },
+  { value: 'anthropic', label: 'Anthropic (cloud)' },
   { value: 'ollama', label: 'Ollama (local)' },
+  { value: 'custom', label: 'Custom (OpenAI-compatible)' },
 ]
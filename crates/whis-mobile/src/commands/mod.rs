@@ -3,7 +3,7 @@
 //! ## Modules
 //!
 //! - `system` - Status and validation commands
-//! - `presets` - Preset CRUD operations
+//! - `presets` - Preset CRUD and export/import
 //! - `recording` - Audio transcription commands
 
 pub mod presets;
@@ -1,11 +1,12 @@
 //! Preset management commands.
 //!
-//! Handles listing, viewing, creating, updating, and deleting presets.
+//! Handles listing, viewing, creating, updating, and deleting presets, and
+//! exporting/importing them as shareable bundles.
 
 use std::path::PathBuf;
 use tauri::Manager;
 use tauri_plugin_store::StoreExt;
-use whis_core::preset::{Preset, PresetSource};
+use whis_core::preset::{ConflictPolicy, ImportOutcome, Preset, PresetBundle, PresetSource};
 
 /// Get the presets directory for this app using Tauri's path API.
 /// This works correctly on Android where dirs::config_dir() returns None.
@@ -146,6 +147,10 @@ pub fn create_preset(
         prompt: input.prompt,
         post_processor: None,
         model: None,
+        whisper: None,
+        steps: Vec::new(),
+        dictionary: Vec::new(),
+        output: None,
     };
 
     preset.save_to(&presets_dir)?;
@@ -206,3 +211,21 @@ pub fn delete_preset(app: tauri::AppHandle, name: String) -> Result<(), String>
 
     Ok(())
 }
+
+/// Export presets as a shareable JSON bundle.
+#[tauri::command]
+pub fn export_presets(app: tauri::AppHandle, names: Vec<String>) -> Result<String, String> {
+    let presets_dir = get_presets_dir(&app)?;
+    PresetBundle::export_from(&names, &presets_dir)?.to_json()
+}
+
+/// Import presets from a JSON bundle.
+#[tauri::command]
+pub fn import_presets(
+    app: tauri::AppHandle,
+    bundle: String,
+    on_conflict: ConflictPolicy,
+) -> Result<Vec<ImportOutcome>, String> {
+    let presets_dir = get_presets_dir(&app)?;
+    PresetBundle::from_json(&bundle)?.import_to(&presets_dir, on_conflict)
+}
@@ -3,7 +3,9 @@
 //! Handles audio transcription via batch and streaming modes.
 //! Business logic is delegated to the `recording` module.
 
-use crate::recording::pipeline::{apply_post_processing, is_post_processing_enabled};
+use crate::recording::pipeline::{
+    apply_post_processing, apply_text_rules, is_post_processing_enabled,
+};
 use crate::recording::provider::api_key_store_key;
 use crate::state::{AppState, RecordingState};
 use tauri::{Emitter, State};
@@ -98,6 +100,7 @@ async fn transcribe_audio_inner(
         filename: filename.to_string(),
         mime_type: mime_type.clone(),
         progress: None,
+        options: load_provider_options(&store),
     };
 
     let result = provider_impl
@@ -105,7 +108,7 @@ async fn transcribe_audio_inner(
         .await
         .map_err(|e| e.to_string())?;
 
-    let text = result.text;
+    let text = apply_text_rules(app, result.text, result.language, &store);
 
     // Apply post-processing if enabled (requires active preset + post-processor)
     if is_post_processing_enabled(&store) {
@@ -176,14 +179,36 @@ pub async fn transcribe_streaming_start(
     let recording_state_arc = state.recording_state.clone();
     let realtime_tx_arc = state.realtime_audio_tx.clone();
     let provider_for_task = provider_str.clone();
+
+    // Forward partial transcripts to the frontend as they arrive
+    let (partial_tx, mut partial_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+    let partial_app = app.clone();
+    tokio::spawn(async move {
+        while let Some(partial) = partial_rx.recv().await {
+            let _ = partial_app.emit("transcription-partial", partial);
+        }
+    });
+
     tokio::spawn(async move {
         // Dispatch to correct streaming provider
         let result = match provider_for_task.as_str() {
             "openai" | "openai-realtime" => {
-                OpenAIRealtimeProvider::transcribe_stream(&api_key, audio_rx, language).await
+                OpenAIRealtimeProvider::transcribe_stream(
+                    &api_key,
+                    audio_rx,
+                    language,
+                    Some(partial_tx),
+                )
+                .await
             }
             "deepgram" | "deepgram-realtime" => {
-                DeepgramRealtimeProvider::transcribe_stream(&api_key, audio_rx, language).await
+                DeepgramRealtimeProvider::transcribe_stream(
+                    &api_key,
+                    audio_rx,
+                    language,
+                    Some(partial_tx),
+                )
+                .await
             }
             _ => Err(anyhow::anyhow!(
                 "Streaming not supported for {}",
@@ -195,6 +220,7 @@ pub async fn transcribe_streaming_start(
             Ok(transcript) => {
                 // Apply post-processing if enabled
                 let final_text = if let Ok(store) = app.store("settings.json") {
+                    let transcript = apply_text_rules(&app, transcript, None, &store);
                     if is_post_processing_enabled(&store) {
                         let _ = app.emit("post-processing-started", ());
                     }
@@ -287,7 +313,7 @@ pub async fn transcribe_streaming_stop(state: State<'_, AppState>) -> Result<(),
 // - Chunks are transcribed in parallel (cloud providers)
 // - Results are combined when recording stops
 
-use crate::recording::config::load_transcription_config;
+use crate::recording::config::{load_provider_options, load_transcription_config};
 use tokio::sync::{mpsc, oneshot};
 use whis_core::{ChunkerConfig, ProgressiveChunker, progressive_transcribe_cloud};
 
@@ -312,6 +338,7 @@ pub async fn start_recording(
     let provider = config.provider.clone();
     let api_key = config.api_key.clone();
     let language = config.language.clone();
+    let options = config.options.clone();
 
     // Set state to recording
     {
@@ -366,10 +393,16 @@ pub async fn start_recording(
     // Spawn transcription task
     // This task will complete when chunk_rx closes (either chunker finishes or fails)
     tokio::spawn(async move {
-        let result =
-            progressive_transcribe_cloud(&provider, &api_key, language.as_deref(), chunk_rx, None)
-                .await
-                .map_err(|e| e.to_string());
+        let result = progressive_transcribe_cloud(
+            &provider,
+            &options,
+            &api_key,
+            language.as_deref(),
+            chunk_rx,
+            None,
+        )
+        .await
+        .map_err(|e| e.to_string());
 
         if result_tx.send(result).is_err() {
             warn!("Failed to send transcription result - receiver dropped");
@@ -454,8 +487,9 @@ pub async fn stop_recording(
         .map_err(|_| "Transcription task was cancelled")?
         .map_err(|e| format!("Transcription failed: {}", e))?;
 
-    // Apply post-processing if enabled
+    // Apply the dictionary, then post-processing if enabled
     let store = app.store("settings.json").map_err(|e| e.to_string())?;
+    let transcription = apply_text_rules(&app, transcription, None, &store);
     if is_post_processing_enabled(&store) {
         let _ = app.emit("post-processing-started", ());
     }
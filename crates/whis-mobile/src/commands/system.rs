@@ -1,5 +1,6 @@
 //! System status and validation commands.
 
+use crate::recording::config::{load_custom_endpoint, load_provider_options};
 use crate::recording::provider::api_key_store_key;
 use crate::state::{AppState, RecordingState};
 use tauri::State;
@@ -72,7 +73,14 @@ pub async fn warmup_connections(app: tauri::AppHandle) -> Result<(), String> {
         .and_then(|v| v.as_str().map(String::from))
         .filter(|p| p != "none");
 
+    let custom = load_custom_endpoint(&store);
     let post_processor_api_key = post_processor.as_ref().and_then(|p| {
+        if p == "custom" {
+            // Empty key for servers without authentication
+            return custom
+                .chat_url()
+                .map(|_| custom.api_key.clone().unwrap_or_default());
+        }
         // Post-processor uses same key lookup pattern
         api_key_store_key(p)
             .and_then(|key| store.get(key))
@@ -83,8 +91,10 @@ pub async fn warmup_connections(app: tauri::AppHandle) -> Result<(), String> {
     let config = WarmupConfig {
         provider,
         provider_api_key,
+        speechmatics_region: load_provider_options(&store).regions.speechmatics,
         post_processor,
         post_processor_api_key,
+        custom_base_url: custom.base_url,
     };
 
     // Run warmup (best-effort, errors are logged but not propagated)
@@ -6,7 +6,7 @@
 //! whis-mobile/
 //! ├── commands/           - Tauri command handlers (organized by domain)
 //! │   ├── system.rs       - Status, validation
-//! │   ├── presets.rs      - Preset CRUD
+//! │   ├── presets.rs      - Preset CRUD, export/import
 //! │   └── recording.rs    - Audio transcription (batch + streaming)
 //! ├── recording/          - Recording business logic
 //! │   ├── config.rs       - Transcription config from Tauri store
@@ -84,6 +84,8 @@ pub fn run() {
             commands::create_preset,
             commands::update_preset,
             commands::delete_preset,
+            commands::export_presets,
+            commands::import_presets,
             // Recording commands (batch - legacy)
             commands::transcribe_audio,
             // Recording commands (progressive - matches CLI/desktop)
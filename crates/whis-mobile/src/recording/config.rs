@@ -5,15 +5,17 @@
 
 use super::provider::api_key_store_key;
 use crate::state::{AppState, TranscriptionConfig};
-use tauri_plugin_store::StoreExt;
+use tauri_plugin_store::{Store, StoreExt};
+use whis_core::ProviderOptions;
 use whis_core::config::TranscriptionProvider;
+use whis_core::settings::{AnthropicConfig, CustomEndpointConfig};
 
 /// Load transcription configuration from Tauri store.
 ///
 /// Checks the cached config first. If not cached or if provider/key changed,
 /// loads fresh from the store.
 ///
-/// Returns the provider, API key, and optional language setting.
+/// Returns the provider, API key, optional language setting and provider options.
 pub fn load_transcription_config(
     app: &tauri::AppHandle,
     state: &AppState,
@@ -51,6 +53,7 @@ pub fn load_transcription_config(
         provider,
         api_key,
         language,
+        options: load_provider_options(&store),
     };
 
     // Cache the config
@@ -61,3 +64,45 @@ pub fn load_transcription_config(
 
     Ok(config)
 }
+
+/// Load provider options from the Tauri store.
+///
+/// Provider sections use the desktop settings layout (e.g. the `assemblyai`
+/// key holds `{ "speaker_labels": ..., "keyterms": [...] }` and `regions`
+/// holds `{ "speechmatics": "eu", "gladia": "us" }`). Missing or malformed
+/// entries fall back to the defaults.
+pub fn load_provider_options(store: &Store<tauri::Wry>) -> ProviderOptions {
+    ProviderOptions {
+        assemblyai: store
+            .get("assemblyai")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default(),
+        regions: store
+            .get("regions")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default(),
+        ..ProviderOptions::default()
+    }
+}
+
+/// Load the custom post-processing endpoint from the Tauri store.
+///
+/// The `custom_endpoint` key holds the desktop `post_processing.custom` layout
+/// (`{ "base_url": ..., "model": ..., "api_key": ..., "params": {...} }`).
+pub fn load_custom_endpoint(store: &Store<tauri::Wry>) -> CustomEndpointConfig {
+    store
+        .get("custom_endpoint")
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+/// Load the Anthropic post-processing options from the Tauri store.
+///
+/// The `anthropic` key holds the desktop `post_processing.anthropic` layout
+/// (`{ "model": ..., "max_tokens": ..., "temperature": ... }`).
+pub fn load_anthropic_options(store: &Store<tauri::Wry>) -> AnthropicConfig {
+    store
+        .get("anthropic")
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
@@ -1,12 +1,18 @@
 //! Transcription pipeline utilities.
 //!
-//! Handles post-processing, clipboard operations, and event emission.
+//! Handles the replacement dictionary, spoken formatting commands,
+//! post-processing, clipboard operations, and event emission.
 //! This mirrors the pattern in whis-desktop's recording/pipeline.rs.
 
 use tauri::Emitter;
-use whis_core::preset::Preset;
-use whis_core::{PostProcessor, error, post_process, warn};
+use whis_core::configuration::DEFAULT_OLLAMA_KEEP_ALIVE;
+use whis_core::preset::{Dictionary, Preset, PresetStep, apply_replacements};
+use whis_core::{
+    PostProcessConfig, PostProcessor, apply_voice_commands, error, post_process,
+    post_process_structured, warn,
+};
 
+use super::config::{load_anthropic_options, load_custom_endpoint};
 use crate::commands::presets::get_presets_dir;
 
 /// Check if post-processing is enabled (has post-processor and active preset).
@@ -28,13 +34,57 @@ pub fn is_post_processing_enabled(store: &tauri_plugin_store::Store<tauri::Wry>)
         .is_some()
 }
 
+/// Apply the replacement dictionary (and the active preset's entries), then
+/// spoken formatting commands if enabled.
+///
+/// Runs on every transcript, whether or not post-processing is enabled.
+/// `detected_language` (from the provider) picks the command table when no
+/// language is configured.
+pub fn apply_text_rules(
+    app: &tauri::AppHandle,
+    text: String,
+    detected_language: Option<String>,
+    store: &tauri_plugin_store::Store<tauri::Wry>,
+) -> String {
+    // Dictionary is stored next to presets/ in the app config dir
+    let text = match get_presets_dir(app) {
+        Ok(presets_dir) => {
+            let active_preset = store
+                .get("active_preset")
+                .and_then(|v| v.as_str().map(String::from))
+                .and_then(|name| Preset::load_from(&name, &presets_dir).ok())
+                .map(|(preset, _)| preset);
+            let dictionary_path = presets_dir.with_file_name("dictionary.json");
+            Dictionary::load_from(&dictionary_path).apply(&text, active_preset.as_ref())
+        }
+        Err(e) => {
+            error!("Failed to get presets dir: {}", e);
+            text
+        }
+    };
+
+    let voice_commands = store
+        .get("voice_commands")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if !voice_commands {
+        return text;
+    }
+    let language = store
+        .get("language")
+        .and_then(|v| v.as_str().map(String::from))
+        .or(detected_language);
+    apply_voice_commands(&text, language.as_deref())
+}
+
 /// Apply post-processing to transcription if enabled.
 ///
 /// Post-processing is applied when:
 /// 1. An active preset is set, AND
 /// 2. A post-processor is configured (not "none")
 ///
-/// Returns the processed text, or original text on error/skip.
+/// Returns the processed text, or original text on error/skip. Structured-output
+/// presets return their JSON reply rendered for output.
 pub async fn apply_post_processing(
     app: &tauri::AppHandle,
     text: String,
@@ -82,32 +132,92 @@ pub async fn apply_post_processing(
         }
     };
 
-    // Get API key for post-processor
-    let api_key = match post_processor {
-        PostProcessor::OpenAI => store.get("openai_api_key"),
-        PostProcessor::Mistral => store.get("mistral_api_key"),
-        _ => None,
-    }
-    .and_then(|v| v.as_str().map(String::from));
-
-    let api_key = match api_key {
-        Some(key) if !key.is_empty() => key,
-        _ => {
-            warn!(
-                "Post-processing: No API key configured for {}",
-                post_processor
-            );
-            return text;
+    // Custom OpenAI-compatible server and Anthropic request options
+    let custom = load_custom_endpoint(store);
+    let anthropic = load_anthropic_options(store);
+
+    // Run the preset's steps in order (a failing step keeps the text so far)
+    let mut text = text;
+    let steps = preset.pipeline();
+    let output_step = steps.iter().rposition(PresetStep::is_llm);
+    for (index, step) in steps.into_iter().enumerate() {
+        let (prompt, processor, model) = match step {
+            PresetStep::Replace { replacements } => {
+                text = apply_replacements(&text, &replacements);
+                continue;
+            }
+            PresetStep::Llm {
+                prompt,
+                post_processor: step_processor,
+                model,
+            } => {
+                let processor = step_processor
+                    .and_then(|p| p.parse().ok())
+                    .unwrap_or_else(|| post_processor.clone());
+                (prompt, processor, model)
+            }
+        };
+
+        // Get API key for the step's post-processor (custom servers may have none)
+        let api_key = match processor {
+            PostProcessor::OpenAI => store.get("openai_api_key"),
+            PostProcessor::Mistral => store.get("mistral_api_key"),
+            PostProcessor::Groq => store.get("groq_api_key"),
+            PostProcessor::Anthropic => store.get("anthropic_api_key"),
+            _ => None,
         }
-    };
+        .and_then(|v| v.as_str().map(String::from))
+        .filter(|key| !key.is_empty())
+        .or_else(|| {
+            (processor == PostProcessor::Custom && custom.chat_url().is_some())
+                .then(|| custom.api_key.clone().unwrap_or_default())
+        });
 
-    // Apply post-processing with preset's prompt
-    match post_process(&text, &post_processor, &api_key, &preset.prompt, None).await {
-        Ok(processed) => processed,
-        Err(e) => {
-            error!("Post-processing failed: {}", e);
-            let _ = app.emit("post-process-warning", e.to_string());
-            text // Return original on error
+        let api_key = match api_key {
+            Some(key) => key,
+            _ => {
+                warn!("Post-processing: No API key configured for {}", processor);
+                return text;
+            }
+        };
+
+        let config = PostProcessConfig {
+            processor,
+            prompt,
+            api_key_or_url: api_key,
+            model,
+            ollama_keep_alive: DEFAULT_OLLAMA_KEEP_ALIVE.to_string(),
+            custom: custom.clone(),
+            anthropic: anthropic.clone(),
+        };
+
+        // The last LLM step of a structured-output preset replies in JSON;
+        // later replace steps run on the rendered reply
+        if let Some(output) = preset
+            .output
+            .as_ref()
+            .filter(|_| Some(index) == output_step)
+        {
+            match post_process_structured(&text, &config, &output.schema).await {
+                Ok(value) => text = output.render(&value),
+                Err(e) => {
+                    error!("Post-processing failed: {}", e);
+                    let _ = app.emit("post-process-warning", e.to_string());
+                    return text;
+                }
+            }
+            continue;
+        }
+
+        match post_process(&text, &config).await {
+            Ok(processed) => text = processed,
+            Err(e) => {
+                error!("Post-processing failed: {}", e);
+                let _ = app.emit("post-process-warning", e.to_string());
+                return text; // Keep the text so far on error
+            }
         }
     }
+
+    text
 }
@@ -36,7 +36,16 @@ pub fn validate_api_key_format(key: &str, provider: &str) -> bool {
     match provider {
         "openai" | "openai-realtime" => key.starts_with("sk-") && key.len() > 20,
         "groq" => key.starts_with("gsk_") && key.len() > 20,
-        "mistral" | "deepgram" | "deepgram-realtime" | "elevenlabs" => key.len() > 20,
+        "mistral"
+        | "deepgram"
+        | "deepgram-realtime"
+        | "elevenlabs"
+        | "assemblyai"
+        | "assemblyai-realtime"
+        | "speechmatics"
+        | "speechmatics-realtime"
+        | "gladia"
+        | "gladia-realtime" => key.len() > 20,
         _ => false,
     }
 }
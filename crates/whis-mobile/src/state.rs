@@ -5,6 +5,7 @@
 
 use std::sync::{Arc, Mutex};
 use tokio::sync::{mpsc, oneshot};
+use whis_core::ProviderOptions;
 pub use whis_core::RecordingState;
 use whis_core::config::TranscriptionProvider;
 
@@ -14,6 +15,7 @@ pub struct TranscriptionConfig {
     pub provider: TranscriptionProvider,
     pub api_key: String,
     pub language: Option<String>,
+    pub options: ProviderOptions,
 }
 
 /// Application state shared across Tauri commands.
@@ -1,4 +1,4 @@
-import type { CreatePresetInput, PresetDetails, PresetInfo, UpdatePresetInput } from '../types'
+import type { ConflictPolicy, CreatePresetInput, ImportOutcome, PresetDetails, PresetInfo, UpdatePresetInput } from '../types'
 import { invoke } from '@tauri-apps/api/core'
 import { reactive, readonly } from 'vue'
 
@@ -86,4 +86,14 @@ export const presetsStore = {
       state.selectedPreset = null
     }
   },
+
+  async exportPresets(names: string[]): Promise<string> {
+    return invoke<string>('export_presets', { names })
+  },
+
+  async importPresets(bundle: string, onConflict: ConflictPolicy): Promise<ImportOutcome[]> {
+    const outcomes = await invoke<ImportOutcome[]>('import_presets', { bundle, onConflict })
+    await this.loadPresets()
+    return outcomes
+  },
 }
@@ -14,6 +14,7 @@ const state = reactive({
   isProgressiveMode: false,
   error: null as string | null,
   lastTranscription: null as string | null,
+  partialTranscription: null as string | null,
   configValid: false,
 })
 
@@ -51,6 +52,11 @@ async function initialize() {
     state.isPostProcessing = true
   })
 
+  // Listen for partial transcripts while streaming
+  const unlistenPartial = await listen<string>('transcription-partial', (event) => {
+    state.partialTranscription = event.payload
+  })
+
   // Listen for transcription complete event
   const unlistenComplete = await listen<string>('transcription-complete', (event) => {
     state.lastTranscription = event.payload
@@ -68,7 +74,7 @@ async function initialize() {
     state.error = `Post-processing failed: ${event.payload}. Raw transcript copied.`
   })
 
-  cleanupListeners = [unlistenPostProcess, unlistenComplete, unlistenError, unlistenWarning]
+  cleanupListeners = [unlistenPostProcess, unlistenPartial, unlistenComplete, unlistenError, unlistenWarning]
 }
 
 /**
@@ -127,6 +133,7 @@ function resetState() {
   state.isPostProcessing = false
   state.isStreaming = false
   state.isProgressiveMode = false
+  state.partialTranscription = null
   recordingStartTime = null
 }
 
@@ -136,6 +143,7 @@ function resetState() {
 async function startRecording() {
   try {
     state.error = null
+    state.partialTranscription = null
     state.isRecording = true
     recordingStartTime = Date.now()
 
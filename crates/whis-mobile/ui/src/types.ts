@@ -47,6 +47,15 @@ export interface PresetDetails {
   is_builtin: boolean
 }
 
+// Result of importing one preset from a bundle
+export type ImportOutcome
+  = | { action: 'added', name: string }
+    | { action: 'renamed', from: string, name: string }
+    | { action: 'overwritten', name: string }
+    | { action: 'skipped', name: string }
+
+export type ConflictPolicy = 'skip' | 'rename' | 'overwrite'
+
 // Audio chunk for streaming
 export interface AudioChunk {
   samples: Float32Array